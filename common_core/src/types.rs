@@ -5,6 +5,9 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+/// 가격/수량 등 금액 계산용 고정소수점 십진수 (serde 직렬화 시 문자열)
+pub use rust_decimal::Decimal;
+
 /// 암호화폐 거래 쌍(Symbol Pair)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SymbolPair {
@@ -36,16 +39,16 @@ pub struct Price {
     pub timestamp: DateTime<Utc>,
 }
 
-/// OHLCV(시가, 고가, 저가, 종가, 거래량) 데이터
+/// OHLCV(시가, 고가, 저가, 종가, 거래량) 데이터 (가격·거래량은 `Decimal`)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Candle {
     pub symbol: SymbolPair,
     pub timestamp: DateTime<Utc>,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub volume: f64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
 }
 
 /// 타임프레임 정의 (차트 기간)
@@ -0,0 +1,4 @@
+//! 데이터 저장소 모듈
+//!
+//! `StorageType::Disk`의 열 지향 시계열 엔진은 워크스페이스 크레이트인
+//! `cryptolytica_market_domain::storage::disk`에 있습니다.
@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use std::collections::HashMap;
+use cryptolytica_shared_kernel::clock::Clock;
+use cryptolytica_shared_kernel::id::new_id_at;
 
 /// 거래소 식별자
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -91,10 +93,11 @@ impl Exchange {
         name: impl Into<String>,
         exchange_type: ExchangeType,
         base_url: impl Into<String>,
+        clock: &dyn Clock,
     ) -> Self {
-        let now = Utc::now();
+        let now = clock.now();
         Self {
-            id: Uuid::new_v4(),
+            id: new_id_at(now),
             exchange_id,
             name: name.into(),
             exchange_type,
@@ -110,15 +113,15 @@ impl Exchange {
     }
     
     /// 거래소 상태 변경
-    pub fn update_status(&mut self, status: ExchangeStatus) {
+    pub fn update_status(&mut self, status: ExchangeStatus, clock: &dyn Clock) {
         self.status = status;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
     
     /// 기능 지원 여부 설정
-    pub fn set_feature(&mut self, feature: impl Into<String>, supported: bool) {
+    pub fn set_feature(&mut self, feature: impl Into<String>, supported: bool, clock: &dyn Clock) {
         self.features.insert(feature.into(), supported);
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
     
     /// 기능 지원 여부 확인
@@ -127,9 +130,9 @@ impl Exchange {
     }
     
     /// 웹소켓 URL 설정
-    pub fn set_websocket_url(&mut self, url: impl Into<String>) {
+    pub fn set_websocket_url(&mut self, url: impl Into<String>, clock: &dyn Clock) {
         self.websocket_url = Some(url.into());
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
     
     /// 활성 상태 확인
@@ -141,14 +144,22 @@ impl Exchange {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+    use cryptolytica_shared_kernel::clock::SimulatedClock;
+    
+    fn test_clock() -> SimulatedClock {
+        SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+    }
     
     #[test]
     fn test_exchange_creation() {
+        let clock = test_clock();
         let exchange = Exchange::new(
             ExchangeId::new("binance"),
             "Binance",
             ExchangeType::Centralized,
-            "https://api.binance.com"
+            "https://api.binance.com",
+            &clock
         );
         
         assert_eq!(exchange.exchange_id.value(), "binance");
@@ -158,21 +169,24 @@ mod tests {
         assert_eq!(exchange.base_url, "https://api.binance.com");
         assert!(exchange.websocket_url.is_none());
         assert!(exchange.features.is_empty());
+        assert_eq!(exchange.created_at, clock.now());
     }
     
     #[test]
     fn test_feature_support() {
+        let clock = test_clock();
         let mut exchange = Exchange::new(
             ExchangeId::new("upbit"),
             "Upbit",
             ExchangeType::Centralized,
-            "https://api.upbit.com"
+            "https://api.upbit.com",
+            &clock
         );
         
         assert!(!exchange.supports_feature("websocket"));
         
-        exchange.set_feature("websocket", true);
-        exchange.set_feature("futures", false);
+        exchange.set_feature("websocket", true, &clock);
+        exchange.set_feature("futures", false, &clock);
         
         assert!(exchange.supports_feature("websocket"));
         assert!(!exchange.supports_feature("futures"));
@@ -181,17 +195,21 @@ mod tests {
     
     #[test]
     fn test_status_update() {
+        let clock = test_clock();
         let mut exchange = Exchange::new(
             ExchangeId::new("bithumb"),
             "Bithumb",
             ExchangeType::Centralized,
-            "https://api.bithumb.com"
+            "https://api.bithumb.com",
+            &clock
         );
         
         assert!(exchange.is_active());
         
-        exchange.update_status(ExchangeStatus::Maintenance);
+        let updated_at = clock.advance(chrono::Duration::minutes(30));
+        exchange.update_status(ExchangeStatus::Maintenance, &clock);
         assert_eq!(exchange.status, ExchangeStatus::Maintenance);
+        assert_eq!(exchange.updated_at, updated_at);
         assert!(!exchange.is_active());
     }
 } 
\ No newline at end of file
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use cryptolytica_shared_kernel::clock::Clock;
+use cryptolytica_shared_kernel::id::new_id_at;
+use cryptolytica_shared_kernel::types::{Decimal, SymbolPair};
+
+use super::exchange::ExchangeId;
+
+/// 마켓(거래쌍) 엔티티
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Market {
+    /// 고유 식별자 (UUID)
+    pub id: Uuid,
+    /// 거래소 코드
+    pub exchange_id: ExchangeId,
+    /// 표준 거래 쌍
+    pub symbol: SymbolPair,
+    /// 거래소 고유 심볼 (예: BTCUSDT, KRW-BTC)
+    pub exchange_symbol: String,
+    /// 거래 가능 여부
+    pub active: bool,
+    /// 가격 소수 자릿수
+    pub price_precision: u32,
+    /// 수량 소수 자릿수
+    pub quantity_precision: u32,
+    /// 최소 주문 수량
+    pub min_quantity: Option<Decimal>,
+    /// 최소 주문 금액
+    pub min_notional: Option<Decimal>,
+    /// 생성 시간
+    pub created_at: DateTime<Utc>,
+    /// 마지막 업데이트 시간
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Market {
+    /// 새 마켓 객체 생성
+    pub fn new(
+        exchange_id: ExchangeId,
+        symbol: SymbolPair,
+        exchange_symbol: impl Into<String>,
+        price_precision: u32,
+        quantity_precision: u32,
+        clock: &dyn Clock,
+    ) -> Self {
+        let now = clock.now();
+        Self {
+            id: new_id_at(now),
+            exchange_id,
+            symbol,
+            exchange_symbol: exchange_symbol.into(),
+            active: true,
+            price_precision,
+            quantity_precision,
+            min_quantity: None,
+            min_notional: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 심볼이 이 마켓을 가리키는지 확인 (표준 표기 또는 거래소 고유 심볼)
+    pub fn matches_symbol(&self, symbol: &str) -> bool {
+        self.symbol.to_standard_notation().eq_ignore_ascii_case(symbol) || self.exchange_symbol == symbol
+    }
+
+    /// 거래 가능 여부 변경
+    pub fn set_active(&mut self, active: bool, clock: &dyn Clock) {
+        self.active = active;
+        self.updated_at = clock.now();
+    }
+}
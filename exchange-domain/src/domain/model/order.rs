@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use cryptolytica_shared_kernel::clock::Clock;
+use cryptolytica_shared_kernel::id::new_id_at;
+use cryptolytica_shared_kernel::types::{Decimal, SymbolPair};
+
+pub use cryptolytica_shared_kernel::types::{OrderSide, OrderStatus, OrderType};
+
+use super::exchange::ExchangeId;
+
+/// 주문 엔티티
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    /// 고유 식별자 (UUID)
+    pub id: Uuid,
+    /// 거래소 코드
+    pub exchange_id: ExchangeId,
+    /// 거래소가 부여한 주문 ID (제출 전에는 없음)
+    pub exchange_order_id: Option<String>,
+    /// 클라이언트 주문 ID
+    pub client_order_id: Option<String>,
+    /// 거래 쌍
+    pub symbol: SymbolPair,
+    /// 주문 유형
+    pub order_type: OrderType,
+    /// 주문 방향
+    pub side: OrderSide,
+    /// 주문 상태
+    pub status: OrderStatus,
+    /// 지정가 (시장가 주문은 없음)
+    pub price: Option<Decimal>,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 누적 체결 수량
+    pub filled_quantity: Decimal,
+    /// 평균 체결 가격
+    pub average_fill_price: Option<Decimal>,
+    /// 생성 시간
+    pub created_at: DateTime<Utc>,
+    /// 마지막 업데이트 시간
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Order {
+    /// 새 주문 객체 생성 (거래소 제출 전 상태)
+    pub fn new(
+        exchange_id: ExchangeId,
+        symbol: SymbolPair,
+        order_type: OrderType,
+        side: OrderSide,
+        quantity: Decimal,
+        price: Option<Decimal>,
+        clock: &dyn Clock,
+    ) -> Self {
+        let now = clock.now();
+        Self {
+            id: new_id_at(now),
+            exchange_id,
+            exchange_order_id: None,
+            client_order_id: None,
+            symbol,
+            order_type,
+            side,
+            status: OrderStatus::Created,
+            price,
+            quantity,
+            filled_quantity: Decimal::ZERO,
+            average_fill_price: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 클라이언트 주문 ID 설정
+    pub fn with_client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// 거래소 접수 반영
+    pub fn accept(&mut self, exchange_order_id: impl Into<String>, clock: &dyn Clock) {
+        self.exchange_order_id = Some(exchange_order_id.into());
+        self.status = OrderStatus::New;
+        self.updated_at = clock.now();
+    }
+
+    /// 누적 체결 반영 (전량 체결되면 완료 상태)
+    pub fn record_fill(&mut self, filled_quantity: Decimal, average_fill_price: Decimal, clock: &dyn Clock) {
+        self.filled_quantity = filled_quantity;
+        self.average_fill_price = Some(average_fill_price);
+        self.status = if filled_quantity >= self.quantity {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        self.updated_at = clock.now();
+    }
+
+    /// 주문 상태 변경
+    pub fn update_status(&mut self, status: OrderStatus, clock: &dyn Clock) {
+        self.status = status;
+        self.updated_at = clock.now();
+    }
+
+    /// 미체결 수량
+    pub fn remaining_quantity(&self) -> Decimal {
+        (self.quantity - self.filled_quantity).max(Decimal::ZERO)
+    }
+
+    /// 아직 진행 중인 주문인지 확인
+    pub fn is_open(&self) -> bool {
+        !self.status.is_terminal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use cryptolytica_shared_kernel::clock::SimulatedClock;
+
+    #[test]
+    fn test_order_fill_lifecycle() {
+        let clock = SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+        let mut order = Order::new(
+            ExchangeId::new("binance"),
+            SymbolPair::new("BTC", "USDT"),
+            OrderType::Limit,
+            OrderSide::Buy,
+            Decimal::new(2, 0),
+            Some(Decimal::new(50000, 0)),
+            &clock,
+        );
+        assert_eq!(order.status, OrderStatus::Created);
+
+        order.accept("12345", &clock);
+        assert_eq!(order.exchange_order_id.as_deref(), Some("12345"));
+        assert_eq!(order.status, OrderStatus::New);
+
+        order.record_fill(Decimal::ONE, Decimal::new(50000, 0), &clock);
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.remaining_quantity(), Decimal::ONE);
+
+        order.record_fill(Decimal::new(2, 0), Decimal::new(50000, 0), &clock);
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert!(!order.is_open());
+    }
+}
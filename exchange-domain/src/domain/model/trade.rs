@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use cryptolytica_shared_kernel::id::new_id_at;
+use cryptolytica_shared_kernel::types::{Decimal, OrderSide, SymbolPair};
+
+use super::exchange::ExchangeId;
+
+/// 거래(체결) 엔티티
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trade {
+    /// 고유 식별자 (UUID)
+    pub id: Uuid,
+    /// 거래소 코드
+    pub exchange_id: ExchangeId,
+    /// 거래소가 부여한 체결 ID
+    pub exchange_trade_id: String,
+    /// 체결된 주문 ID (내 주문이 아닌 시장 체결이면 없음)
+    pub order_id: Option<Uuid>,
+    /// 거래 쌍
+    pub symbol: SymbolPair,
+    /// 체결 방향
+    pub side: OrderSide,
+    /// 체결 가격
+    pub price: Decimal,
+    /// 체결 수량
+    pub quantity: Decimal,
+    /// 수수료
+    pub fee: Option<Decimal>,
+    /// 수수료 자산
+    pub fee_asset: Option<String>,
+    /// 체결 시간
+    pub executed_at: DateTime<Utc>,
+}
+
+impl Trade {
+    /// 새 거래 객체 생성 (ID는 체결 시간 기준으로 정렬됨)
+    pub fn new(
+        exchange_id: ExchangeId,
+        exchange_trade_id: impl Into<String>,
+        symbol: SymbolPair,
+        side: OrderSide,
+        price: Decimal,
+        quantity: Decimal,
+        executed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: new_id_at(executed_at),
+            exchange_id,
+            exchange_trade_id: exchange_trade_id.into(),
+            order_id: None,
+            symbol,
+            side,
+            price,
+            quantity,
+            fee: None,
+            fee_asset: None,
+            executed_at,
+        }
+    }
+
+    /// 체결된 주문 지정
+    pub fn with_order(mut self, order_id: Uuid) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    /// 수수료 지정
+    pub fn with_fee(mut self, fee: Decimal, fee_asset: impl Into<String>) -> Self {
+        self.fee = Some(fee);
+        self.fee_asset = Some(fee_asset.into());
+        self
+    }
+
+    /// 체결 금액 (가격 × 수량)
+    pub fn notional(&self) -> Decimal {
+        self.price * self.quantity
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! 이 모듈은 거래소 도메인에서 사용하는 리포지토리 인터페이스들을 정의합니다.
 //! DDD 관점에서 리포지토리는 도메인 객체와 영속성 계층 사이의 추상화를 제공합니다.
+//!
+//! 상태 변경의 결과로 이벤트를 발행해야 하면 `*_with_events` 메서드를 사용합니다.
+//! 구현체는 상태와 이벤트를 한 트랜잭션으로 아웃박스에 기록하고, 발행은 `OutboxRelay`가 맡습니다.
 
 use async_trait::async_trait;
 use uuid::Uuid;
-use cryptolytica_shared_kernel::error::Result as SharedResult;
+use cryptolytica_shared_kernel::types::Result as SharedResult;
+use cryptolytica_shared_kernel::events::OutboxBatch;
 
 use crate::domain::model::{
     Exchange,
@@ -54,6 +58,12 @@ pub trait OrderRepository: Send + Sync {
     
     /// 주문 벌크 저장
     async fn save_batch(&self, orders: &[Order]) -> SharedResult<Vec<Order>>;
+    
+    /// 주문 저장과 이벤트 기록을 한 트랜잭션으로 수행 (둘 다 반영되거나 둘 다 반영되지 않음)
+    async fn save_with_events(&self, order: &Order, events: &OutboxBatch) -> SharedResult<Order>;
+    
+    /// 주문 벌크 저장과 이벤트 기록을 한 트랜잭션으로 수행
+    async fn save_batch_with_events(&self, orders: &[Order], events: &OutboxBatch) -> SharedResult<Vec<Order>>;
 }
 
 /// 거래(체결) 리포지토리 인터페이스
@@ -73,6 +83,9 @@ pub trait TradeRepository: Send + Sync {
     
     /// 거래 벌크 저장
     async fn save_batch(&self, trades: &[Trade]) -> SharedResult<Vec<Trade>>;
+    
+    /// 거래 벌크 저장과 이벤트 기록을 한 트랜잭션으로 수행 (둘 다 반영되거나 둘 다 반영되지 않음)
+    async fn save_batch_with_events(&self, trades: &[Trade], events: &OutboxBatch) -> SharedResult<Vec<Trade>>;
 }
 
 /// 마켓(거래쌍) 리포지토리 인터페이스
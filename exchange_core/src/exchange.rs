@@ -6,7 +6,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use cryptolytica_common_core::types::{SymbolPair, Timeframe, Candle, Price, ExchangeId, AssetType};
+use cryptolytica_common_core::types::{Decimal, SymbolPair, Timeframe, Candle, Price, ExchangeId, AssetType};
 use crate::error::Result;
 use crate::models::{OrderBook, OrderSide, OrderType, OrderStatus, TradeHistory, AccountBalance, Order, ExchangeInfo};
 
@@ -90,8 +90,8 @@ pub trait TradingProvider: Send + Sync {
         symbol: &SymbolPair, 
         side: OrderSide, 
         order_type: OrderType, 
-        amount: f64, 
-        price: Option<f64>,
+        amount: Decimal, 
+        price: Option<Decimal>,
         params: Option<std::collections::HashMap<String, String>>
     ) -> Result<Order>;
     
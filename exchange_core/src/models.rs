@@ -6,7 +6,10 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
-use cryptolytica_common_core::types::{SymbolPair, ExchangeId};
+use cryptolytica_common_core::types::{Decimal, SymbolPair, ExchangeId};
+use cryptolytica_shared_kernel::domain::service::{SymbolFormat, SymbolListing, SymbolRegistry};
+use cryptolytica_shared_kernel::domain::value_object::SymbolError;
+use cryptolytica_shared_kernel::types as kernel;
 
 /// 주문 방향(매수/매도)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -89,8 +92,8 @@ impl std::fmt::Display for OrderStatus {
 /// 오더북 항목(가격/수량)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrderBookEntry {
-    pub price: f64,
-    pub amount: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
 }
 
 /// 오더북(매수/매도 주문 목록)
@@ -109,9 +112,9 @@ pub struct TradeHistory {
     pub id: String,
     pub symbol: SymbolPair,
     pub side: OrderSide,
-    pub price: f64,
-    pub amount: f64,
-    pub cost: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
+    pub cost: Decimal,
     pub fee: Option<Fee>,
     pub timestamp: DateTime<Utc>,
 }
@@ -119,24 +122,22 @@ pub struct TradeHistory {
 /// 수수료 정보
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Fee {
-    pub cost: f64,
+    pub cost: Decimal,
     pub currency: String,
-    pub rate: Option<f64>,
+    pub rate: Option<Decimal>,
 }
 
 /// 계정 잔고 정보
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccountBalance {
     pub currency: String,
-    pub free: f64,
-    pub used: f64,
-    pub total: f64,
+    pub free: Decimal,
+    pub used: Decimal,
+    pub total: Decimal,
 }
 
 impl AccountBalance {
-    pub fn new(currency: impl Into<String>, free: f64, used: f64) -> Self {
-        let free = free;
-        let used = used;
+    pub fn new(currency: impl Into<String>, free: Decimal, used: Decimal) -> Self {
         Self {
             currency: currency.into(),
             free,
@@ -152,14 +153,17 @@ pub struct Order {
     pub id: String,
     pub client_order_id: Option<String>,
     pub symbol: SymbolPair,
+    // 파생상품 주문의 계약 정보 (현물은 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<kernel::Instrument>,
     pub side: OrderSide,
     pub type_: OrderType,
     pub status: OrderStatus,
-    pub price: Option<f64>,
-    pub amount: f64,
-    pub filled: f64,
-    pub remaining: f64,
-    pub cost: f64,
+    pub price: Option<Decimal>,
+    pub amount: Decimal,
+    pub filled: Decimal,
+    pub remaining: Decimal,
+    pub cost: Decimal,
     pub fee: Option<Fee>,
     pub timestamp: DateTime<Utc>,
     pub last_update: Option<DateTime<Utc>>,
@@ -173,11 +177,11 @@ pub struct SymbolConstraints {
     pub symbol: SymbolPair,
     pub price_precision: u8,
     pub amount_precision: u8,
-    pub min_amount: f64,
-    pub min_cost: Option<f64>,
-    pub max_amount: Option<f64>,
-    pub min_price: Option<f64>,
-    pub max_price: Option<f64>,
+    pub min_amount: Decimal,
+    pub min_cost: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub min_price: Option<Decimal>,
+    pub max_price: Option<Decimal>,
 }
 
 /// 거래소 정보
@@ -195,9 +199,81 @@ pub struct ExchangeInfo {
     pub version: String,
 }
 
+impl SymbolConstraints {
+    /// 심볼 레지스트리 상장 정보로 변환 (`native`는 거래소 고유 심볼)
+    ///
+    /// 소수 자릿수가 `Decimal::MAX_SCALE`(28)을 넘으면 호가·수량 단위를 표현할 수 없으므로 오류입니다.
+    pub fn to_listing(&self, native: &str) -> Result<SymbolListing, SymbolError> {
+        let mut listing = SymbolListing::new(native, to_kernel_pair(&self.symbol))
+            .with_tick_size(unit_of(native, self.price_precision)?)
+            .with_step_size(unit_of(native, self.amount_precision)?)
+            .with_min_quantity(self.min_amount);
+        if let Some(min_cost) = self.min_cost {
+            listing = listing.with_min_notional(min_cost);
+        }
+        Ok(listing)
+    }
+}
+
+/// 소수 자릿수의 최소 단위 (예: 3 -> 0.001)
+fn unit_of(native: &str, precision: u8) -> Result<Decimal, SymbolError> {
+    let precision = u32::from(precision);
+    if precision > Decimal::MAX_SCALE {
+        return Err(SymbolError::InvalidPrecision {
+            native: native.to_string(),
+            precision,
+            max: Decimal::MAX_SCALE,
+        });
+    }
+    Ok(Decimal::new(1, precision))
+}
+
+impl ExchangeInfo {
+    /// 심볼 레지스트리에 등록할 상장 목록 생성
+    ///
+    /// `symbol_constraints`의 키를 거래소 고유 심볼로 사용하고,
+    /// 제약 정보가 없는 심볼은 `format`에 따라 고유 심볼을 생성합니다.
+    pub fn symbol_listings(&self, format: SymbolFormat) -> Result<Vec<SymbolListing>, SymbolError> {
+        let mut natives: Vec<&String> = self.symbol_constraints.keys().collect();
+        natives.sort();
+
+        let mut listings: Vec<SymbolListing> = natives
+            .into_iter()
+            .map(|native| self.symbol_constraints[native].to_listing(native))
+            .collect::<Result<_, _>>()?;
+
+        for symbol in &self.symbols {
+            let pair = to_kernel_pair(symbol);
+            if !listings.iter().any(|listing| listing.pair == pair) {
+                listings.push(SymbolListing::new(format.format(&pair), pair));
+            }
+        }
+
+        Ok(listings)
+    }
+
+    /// 거래소 정보로 심볼 레지스트리 등록
+    pub fn register_symbols(
+        &self,
+        registry: &mut SymbolRegistry,
+        format: SymbolFormat,
+    ) -> Result<(), SymbolError> {
+        registry.register_exchange(
+            kernel::ExchangeId::new(self.id.0.clone()),
+            format,
+            self.symbol_listings(format)?,
+        )
+    }
+}
+
+fn to_kernel_pair(symbol: &SymbolPair) -> kernel::SymbolPair {
+    kernel::SymbolPair::new(symbol.base.as_str(), symbol.quote.as_str())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
     
     #[test]
     fn test_order_side_display() {
@@ -207,10 +283,90 @@ mod tests {
     
     #[test]
     fn test_account_balance() {
-        let balance = AccountBalance::new("BTC", 1.0, 0.5);
+        let balance = AccountBalance::new("BTC", dec!(1.0), dec!(0.5));
         assert_eq!(balance.currency, "BTC");
-        assert_eq!(balance.free, 1.0);
-        assert_eq!(balance.used, 0.5);
-        assert_eq!(balance.total, 1.5);
+        assert_eq!(balance.free, dec!(1.0));
+        assert_eq!(balance.used, dec!(0.5));
+        assert_eq!(balance.total, dec!(1.5));
+    }
+    
+    #[test]
+    fn test_balance_total_is_exact() {
+        let balance = AccountBalance::new("USDT", dec!(0.1), dec!(0.2));
+        assert_eq!(balance.total, dec!(0.3));
+        
+        let json = serde_json::to_value(&balance).unwrap();
+        assert_eq!(json["total"], "0.3");
+    }
+
+    #[test]
+    fn test_exchange_info_symbol_registry() {
+        let pepe = SymbolPair::new("1000PEPE", "USDC");
+        let mut symbol_constraints = HashMap::new();
+        symbol_constraints.insert(
+            "1000PEPEUSDC".to_string(),
+            SymbolConstraints {
+                symbol: pepe.clone(),
+                price_precision: 7,
+                amount_precision: 0,
+                min_amount: dec!(1),
+                min_cost: Some(dec!(5)),
+                max_amount: None,
+                min_price: None,
+                max_price: None,
+            },
+        );
+
+        let info = ExchangeInfo {
+            id: ExchangeId("binance".to_string()),
+            name: "Binance".to_string(),
+            symbols: vec![pepe, SymbolPair::new("XBT", "USDT")],
+            symbol_constraints,
+            timeframes: vec![],
+            has_websocket: true,
+            rate_limits: HashMap::new(),
+            features: HashMap::new(),
+            urls: HashMap::new(),
+            version: "v3".to_string(),
+        };
+
+        let mut registry = SymbolRegistry::with_common_aliases();
+        info.register_symbols(&mut registry, SymbolFormat::concatenated()).unwrap();
+
+        let exchange = kernel::ExchangeId::new("binance");
+        let pair = registry.to_canonical(&exchange, "1000PEPEUSDC").unwrap();
+        assert_eq!(pair, kernel::SymbolPair::new("1000PEPE", "USDC"));
+        assert_eq!(registry.listing(&exchange, &pair).unwrap().tick_size, Some(dec!(0.0000001)));
+        assert_eq!(registry.listing(&exchange, &pair).unwrap().min_notional, Some(dec!(5)));
+        assert_eq!(
+            registry.to_canonical(&exchange, "XBTUSDT").unwrap(),
+            kernel::SymbolPair::new("BTC", "USDT")
+        );
+    }
+
+    #[test]
+    fn test_listing_rejects_precision_beyond_decimal_scale() {
+        let constraints = SymbolConstraints {
+            symbol: SymbolPair::new("SHIB", "USDT"),
+            price_precision: 28,
+            amount_precision: 29,
+            min_amount: dec!(1),
+            min_cost: None,
+            max_amount: None,
+            min_price: None,
+            max_price: None,
+        };
+
+        assert_eq!(
+            constraints.to_listing("SHIBUSDT").unwrap_err(),
+            SymbolError::InvalidPrecision {
+                native: "SHIBUSDT".to_string(),
+                precision: 29,
+                max: 28,
+            }
+        );
+
+        let listing = SymbolConstraints { amount_precision: 0, ..constraints }.to_listing("SHIBUSDT").unwrap();
+        assert_eq!(listing.tick_size, Some(Decimal::new(1, 28)));
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,124 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use cryptolytica_infrastructure::events::WireCodec;
+use cryptolytica_shared_kernel::events::{create_event_header, CodecKind, EventHeader};
+
+const CODECS: [CodecKind; 3] = [CodecKind::Json, CodecKind::MessagePack, CodecKind::Binary];
+
+// 거래소 스트림과 같은 형태의 호가 단계
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PriceLevel {
+    price: Decimal,
+    quantity: Decimal,
+}
+
+// 호가창 스냅숏 (매수·매도 각 25단계)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OrderBookSnapshot {
+    exchange: String,
+    symbol: String,
+    sequence: u64,
+    bids: Vec<PriceLevel>,
+    asks: Vec<PriceLevel>,
+    timestamp: DateTime<Utc>,
+}
+
+// 체결
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TradeExecuted {
+    id: Uuid,
+    exchange: String,
+    symbol: String,
+    trade_id: String,
+    price: Decimal,
+    quantity: Decimal,
+    is_buyer_maker: bool,
+    timestamp: DateTime<Utc>,
+}
+
+fn order_book() -> OrderBookSnapshot {
+    let level = |i: i64, side: i64| PriceLevel {
+        price: Decimal::new(6_512_345 + side * i * 5, 2),
+        quantity: Decimal::new(1_250_000 + i * 37_113, 8),
+    };
+    OrderBookSnapshot {
+        exchange: "binance".to_string(),
+        symbol: "BTC-USDT".to_string(),
+        sequence: 48_213_998_771,
+        bids: (1..=25).map(|i| level(i, -1)).collect(),
+        asks: (1..=25).map(|i| level(i, 1)).collect(),
+        timestamp: Utc::now(),
+    }
+}
+
+fn trade() -> TradeExecuted {
+    TradeExecuted {
+        id: Uuid::new_v4(),
+        exchange: "binance".to_string(),
+        symbol: "BTC-USDT".to_string(),
+        trade_id: "3412877123".to_string(),
+        price: Decimal::new(6_512_345, 2),
+        quantity: Decimal::new(41_000, 8),
+        is_buyer_maker: false,
+        timestamp: Utc::now(),
+    }
+}
+
+fn header(event_type: &str) -> EventHeader {
+    let mut header = create_event_header("market-data", event_type, Some(Uuid::new_v4()));
+    header.causation_id = Some(Uuid::new_v4());
+    header
+}
+
+fn bench_payload<T>(c: &mut Criterion, name: &str, header: &EventHeader, payload: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    // 프레임 크기 비교
+    for kind in CODECS {
+        let size = WireCodec::new(kind).encode(header, payload).unwrap().len();
+        println!("{} {}: {} bytes", name, kind, size);
+    }
+
+    // 코덱마다 프레임 크기가 다르므로 처리량은 바이트가 아닌 이벤트 수로 비교
+    let mut encode = c.benchmark_group(format!("{}/encode", name));
+    encode.throughput(Throughput::Elements(1));
+    for kind in CODECS {
+        let codec = WireCodec::new(kind);
+        encode.bench_with_input(BenchmarkId::from_parameter(kind), &codec, |b, codec| {
+            b.iter(|| codec.encode(black_box(header), black_box(payload)).unwrap())
+        });
+    }
+    encode.finish();
+
+    let mut decode = c.benchmark_group(format!("{}/decode", name));
+    decode.throughput(Throughput::Elements(1));
+    for kind in CODECS {
+        let frame = WireCodec::new(kind).encode(header, payload).unwrap();
+        decode.bench_with_input(BenchmarkId::from_parameter(kind), &frame, |b, frame| {
+            b.iter(|| WireCodec::decode_parts::<T>(black_box(frame)).unwrap())
+        });
+    }
+    decode.finish();
+
+    // 라우팅에 필요한 헤더만 읽는 비용
+    let mut route = c.benchmark_group(format!("{}/decode_header", name));
+    for kind in CODECS {
+        let frame = WireCodec::new(kind).encode(header, payload).unwrap();
+        route.bench_with_input(BenchmarkId::from_parameter(kind), &frame, |b, frame| {
+            b.iter(|| WireCodec::decode_header(black_box(frame)).unwrap())
+        });
+    }
+    route.finish();
+}
+
+fn codec_benchmark(c: &mut Criterion) {
+    bench_payload(c, "orderbook_snapshot", &header("market.orderbook.snapshot"), &order_book());
+    bench_payload(c, "trade", &header("market.trade.executed"), &trade());
+}
+
+criterion_group!(benches, codec_benchmark);
+criterion_main!(benches);
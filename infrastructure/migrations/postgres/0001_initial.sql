@@ -0,0 +1,81 @@
+-- 거래소·시장 데이터 테이블 (PostgreSQL / TimescaleDB)
+-- 거래소·마켓·주문은 엔티티 전체를 data 열(JSONB)에 두고 조회·고유성 검사에 쓰는 값만 열로 꺼냄
+-- 체결과 캔들은 시계열 분석을 위해 열로 저장하며, TimescaleDB가 있으면 시작 시 하이퍼테이블로 전환됨
+-- (하이퍼테이블의 고유 제약은 시간 열을 포함해야 하므로 체결의 기본 키는 (id, executed_at))
+
+CREATE TABLE IF NOT EXISTS exchanges (
+    id          UUID    PRIMARY KEY,
+    exchange_id TEXT    NOT NULL UNIQUE,
+    active      BOOLEAN NOT NULL,
+    data        JSONB   NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS markets (
+    id              UUID  PRIMARY KEY,
+    exchange_id     TEXT  NOT NULL,
+    symbol          TEXT  NOT NULL,
+    exchange_symbol TEXT  NOT NULL,
+    data            JSONB NOT NULL,
+    UNIQUE (exchange_id, symbol)
+);
+
+CREATE TABLE IF NOT EXISTS orders (
+    id                UUID        PRIMARY KEY,
+    exchange_id       TEXT        NOT NULL,
+    exchange_order_id TEXT,
+    created_at        TIMESTAMPTZ NOT NULL,
+    data              JSONB       NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS ux_orders_exchange_order_id
+    ON orders (exchange_id, exchange_order_id) WHERE exchange_order_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS ix_orders_exchange_created ON orders (exchange_id, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS trades (
+    id                UUID        NOT NULL,
+    exchange_id       TEXT        NOT NULL,
+    exchange_trade_id TEXT        NOT NULL,
+    order_id          UUID,
+    symbol            TEXT        NOT NULL,
+    side              TEXT        NOT NULL,
+    price             NUMERIC     NOT NULL,
+    quantity          NUMERIC     NOT NULL,
+    fee               NUMERIC,
+    fee_asset         TEXT,
+    executed_at       TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id, executed_at),
+    UNIQUE (exchange_id, exchange_trade_id, executed_at)
+);
+CREATE INDEX IF NOT EXISTS ix_trades_id ON trades (id);
+CREATE INDEX IF NOT EXISTS ix_trades_exchange_trade_id ON trades (exchange_id, exchange_trade_id);
+CREATE INDEX IF NOT EXISTS ix_trades_order ON trades (order_id, executed_at) WHERE order_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS ix_trades_exchange_executed ON trades (exchange_id, executed_at DESC);
+
+CREATE TABLE IF NOT EXISTS candles (
+    exchange     TEXT        NOT NULL,
+    symbol       TEXT        NOT NULL,
+    timeframe    TEXT        NOT NULL,
+    open_time    TIMESTAMPTZ NOT NULL,
+    id           UUID        NOT NULL,
+    open         NUMERIC     NOT NULL,
+    high         NUMERIC     NOT NULL,
+    low          NUMERIC     NOT NULL,
+    close        NUMERIC     NOT NULL,
+    volume       NUMERIC     NOT NULL,
+    quote_volume NUMERIC,
+    is_complete  BOOLEAN     NOT NULL,
+    instrument   JSONB,
+    PRIMARY KEY (exchange, symbol, timeframe, open_time)
+);
+
+CREATE TABLE IF NOT EXISTS event_outbox (
+    sequence     BIGSERIAL   PRIMARY KEY,
+    event_id     UUID        NOT NULL UNIQUE,
+    event_type   TEXT        NOT NULL,
+    envelope     JSONB       NOT NULL,
+    status       TEXT        NOT NULL DEFAULT 'pending',
+    enqueued_at  TIMESTAMPTZ NOT NULL,
+    attempts     INTEGER     NOT NULL DEFAULT 0,
+    last_error   TEXT,
+    published_at TIMESTAMPTZ
+);
+CREATE INDEX IF NOT EXISTS idx_event_outbox_status ON event_outbox (status, sequence);
@@ -0,0 +1,19 @@
+-- 거래소 체결 ID는 거래소 안에서 고유 (exchange_id, exchange_trade_id)
+-- 하이퍼테이블의 고유 인덱스는 시간 열을 포함해야 하므로, 체결 테이블이 이미 하이퍼테이블이면
+-- (exchange_id, exchange_trade_id, executed_at) 인덱스를 두고 나머지 중복은 리포지토리가 트랜잭션 안에서 검사함
+-- 일반 테이블을 나중에 하이퍼테이블로 전환할 때도 시작 시 같은 방식으로 인덱스를 바꿈 (postgres/mod.rs)
+
+ALTER TABLE trades DROP CONSTRAINT IF EXISTS trades_exchange_id_exchange_trade_id_executed_at_key;
+DROP INDEX IF EXISTS ix_trades_exchange_trade_id;
+
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'timescaledb') THEN
+        IF EXISTS (SELECT 1 FROM timescaledb_information.hypertables WHERE hypertable_name = 'trades') THEN
+            CREATE UNIQUE INDEX IF NOT EXISTS ux_trades_exchange_trade_id_time
+                ON trades (exchange_id, exchange_trade_id, executed_at);
+            RETURN;
+        END IF;
+    END IF;
+    CREATE UNIQUE INDEX IF NOT EXISTS ux_trades_exchange_trade_id ON trades (exchange_id, exchange_trade_id);
+END $$;
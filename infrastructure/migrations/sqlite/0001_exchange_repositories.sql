@@ -0,0 +1,42 @@
+-- 거래소 도메인 리포지토리 테이블 (SQLite)
+-- 엔티티 전체는 data 열에 JSON으로 저장하고, 조회·고유성 검사에 쓰는 값만 열로 꺼내 둠
+-- 시각 열은 유닉스 밀리초 정수
+
+CREATE TABLE IF NOT EXISTS exchanges (
+    id          TEXT    PRIMARY KEY,
+    exchange_id TEXT    NOT NULL UNIQUE,
+    active      INTEGER NOT NULL,
+    data        TEXT    NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS orders (
+    id                TEXT    PRIMARY KEY,
+    exchange_id       TEXT    NOT NULL,
+    exchange_order_id TEXT,
+    created_at        INTEGER NOT NULL,
+    data              TEXT    NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS ux_orders_exchange_order_id
+    ON orders (exchange_id, exchange_order_id) WHERE exchange_order_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS ix_orders_exchange_created ON orders (exchange_id, created_at);
+
+CREATE TABLE IF NOT EXISTS trades (
+    id                TEXT    PRIMARY KEY,
+    exchange_id       TEXT    NOT NULL,
+    exchange_trade_id TEXT    NOT NULL,
+    order_id          TEXT,
+    executed_at       INTEGER NOT NULL,
+    data              TEXT    NOT NULL,
+    UNIQUE (exchange_id, exchange_trade_id)
+);
+CREATE INDEX IF NOT EXISTS ix_trades_order ON trades (order_id, executed_at);
+CREATE INDEX IF NOT EXISTS ix_trades_exchange_executed ON trades (exchange_id, executed_at);
+
+CREATE TABLE IF NOT EXISTS markets (
+    id              TEXT PRIMARY KEY,
+    exchange_id     TEXT NOT NULL,
+    symbol          TEXT NOT NULL,
+    exchange_symbol TEXT NOT NULL,
+    data            TEXT NOT NULL,
+    UNIQUE (exchange_id, symbol)
+);
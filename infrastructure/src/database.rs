@@ -0,0 +1,168 @@
+// database.rs
+//
+// 데이터베이스 공통 설정과 오류 변환
+// 설정의 `database` 항목을 해석하고, sqlx 오류를 `CoreError`로 분류함
+
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::{PgConnectOptions, PgSslMode};
+use std::str::FromStr;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::types::Result;
+
+/// 설정 파일의 데이터베이스 항목 키
+pub const DATABASE_CONFIG_KEY: &str = "database";
+
+/// 데이터베이스 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatabaseKind {
+    /// 인메모리 (테스트용)
+    #[default]
+    Memory,
+    /// PostgreSQL
+    Postgres,
+    /// TimescaleDB (확장이 설치된 PostgreSQL)
+    Timescaledb,
+    /// ClickHouse
+    Clickhouse,
+}
+
+/// 데이터베이스 설정
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseConfig {
+    /// 데이터베이스 종류
+    #[serde(rename = "type", default)]
+    pub kind: DatabaseKind,
+    /// 호스트
+    #[serde(default = "default_host")]
+    pub host: String,
+    /// 포트
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// 사용자 이름
+    #[serde(default)]
+    pub username: String,
+    /// 비밀번호
+    #[serde(default)]
+    pub password: String,
+    /// 데이터베이스 이름
+    #[serde(default)]
+    pub database: String,
+    /// 연결 풀 최대 연결 수
+    #[serde(default = "default_max_connections")]
+    pub max_connections: u32,
+    /// SSL 모드 (`disable`, `prefer`, `require`, `verify-ca`, `verify-full`)
+    #[serde(default = "default_ssl_mode")]
+    pub ssl_mode: String,
+}
+
+fn default_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_port() -> u16 {
+    5432
+}
+
+fn default_max_connections() -> u32 {
+    10
+}
+
+fn default_ssl_mode() -> String {
+    "prefer".to_string()
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            kind: DatabaseKind::default(),
+            host: default_host(),
+            port: default_port(),
+            username: String::new(),
+            password: String::new(),
+            database: String::new(),
+            max_connections: default_max_connections(),
+            ssl_mode: default_ssl_mode(),
+        }
+    }
+}
+
+impl DatabaseConfig {
+    /// 설정에서 `database` 항목 읽기 (없으면 인메모리)
+    pub fn from_config(config: &config::Config) -> Result<Self> {
+        match config.get::<Self>(DATABASE_CONFIG_KEY) {
+            Ok(settings) => Ok(settings),
+            Err(config::ConfigError::NotFound(_)) => Ok(Self::default()),
+            Err(e) => Err(CoreError::Configuration(format!("데이터베이스 설정 오류: {}", e))),
+        }
+    }
+
+    /// PostgreSQL 계열인지 여부
+    pub fn is_postgres(&self) -> bool {
+        matches!(self.kind, DatabaseKind::Postgres | DatabaseKind::Timescaledb)
+    }
+
+    /// PostgreSQL 연결 옵션
+    pub fn pg_connect_options(&self) -> Result<PgConnectOptions> {
+        let ssl_mode = PgSslMode::from_str(&self.ssl_mode)
+            .map_err(|e| CoreError::Configuration(format!("잘못된 ssl_mode {}: {}", self.ssl_mode, e)))?;
+        let mut options = PgConnectOptions::new()
+            .host(&self.host)
+            .port(self.port)
+            .ssl_mode(ssl_mode)
+            .application_name("cryptolytica");
+        if !self.username.is_empty() {
+            options = options.username(&self.username);
+        }
+        if !self.password.is_empty() {
+            options = options.password(&self.password);
+        }
+        if !self.database.is_empty() {
+            options = options.database(&self.database);
+        }
+        Ok(options)
+    }
+}
+
+/// sqlx 오류를 핵심 오류로 변환 (연결 문제는 재시도 가능한 오류로, 고유 키 중복은 유효성 오류로 분류)
+pub(crate) fn db_error(error: sqlx::Error) -> CoreError {
+    match error {
+        sqlx::Error::PoolTimedOut => CoreError::Timeout("데이터베이스 연결 풀 대기 시간 초과".to_string()),
+        sqlx::Error::Io(e) => CoreError::Io(e),
+        sqlx::Error::PoolClosed | sqlx::Error::WorkerCrashed => CoreError::Unavailable {
+            message: format!("데이터베이스 사용 불가: {}", error),
+            retry_after: None,
+        },
+        sqlx::Error::RowNotFound => CoreError::NotFound("데이터베이스 행을 찾을 수 없음".to_string()),
+        sqlx::Error::Database(e) if e.is_unique_violation() => {
+            CoreError::Validation(format!("고유 키 중복: {}", e.message()))
+        }
+        other => CoreError::Data(format!("데이터베이스 오류: {}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_database_config_from_yaml() {
+        let settings = config::Config::builder()
+            .add_source(config::File::from_str(
+                "database:\n  type: timescaledb\n  host: db\n  username: cryptolytica\n  database: cryptolytica\n  max_connections: 50\n  ssl_mode: disable\n",
+                config::FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap();
+
+        let database = DatabaseConfig::from_config(&settings).unwrap();
+        assert_eq!(database.kind, DatabaseKind::Timescaledb);
+        assert_eq!(database.max_connections, 50);
+        assert_eq!(database.port, 5432);
+        assert!(database.is_postgres());
+        assert!(database.pg_connect_options().is_ok());
+
+        let empty = config::Config::builder().build().unwrap();
+        assert_eq!(DatabaseConfig::from_config(&empty).unwrap().kind, DatabaseKind::Memory);
+    }
+}
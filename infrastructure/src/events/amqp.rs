@@ -0,0 +1,254 @@
+// amqp.rs
+//
+// AMQP(RabbitMQ) 브로커 전송 계층
+// 토픽 익스체인지에 이벤트 타입을 라우팅 키로 발행하고, 소비자 그룹마다 내구성 큐
+// `{익스체인지}.{그룹}`을 선언하여 토픽 패턴으로 바인딩함. 같은 큐를 소비하는 인스턴스들은
+// 메시지를 나눠 받음
+//
+// 발행은 발행자 확인(publisher confirm)을 기다리고, 거부된 메시지는 큐에 지정한
+// 데드 레터 익스체인지로 보냄
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use lapin::options::{
+    BasicAckOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions, BasicQosOptions,
+    ConfirmSelectOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions,
+};
+use lapin::publisher_confirm::Confirmation;
+use lapin::types::{AMQPValue, FieldTable, ShortString};
+use lapin::{BasicProperties, Channel, Connection, ConnectionProperties, ExchangeKind};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::types::Result;
+
+use super::broker::{
+    Acknowledger, BrokerConsumer, BrokerDelivery, BrokerTransport, ConsumerBinding, OutgoingMessage,
+};
+use super::factory::AmqpConfig;
+
+/// 영속 메시지 전달 모드
+const PERSISTENT: u8 = 2;
+
+fn amqp_error(context: &str, error: lapin::Error) -> CoreError {
+    CoreError::Unavailable {
+        message: format!("AMQP {} 실패: {}", context, error),
+        retry_after: None,
+    }
+}
+
+/// AMQP 브로커 전송 계층
+pub struct AmqpTransport {
+    config: AmqpConfig,
+    connection: Connection,
+    /// 발행 전용 채널 (발행자 확인 모드)
+    publisher: Channel,
+}
+
+impl AmqpTransport {
+    /// 브로커에 연결하고 토픽 익스체인지 선언
+    pub async fn connect(config: AmqpConfig) -> Result<Self> {
+        let connection = Connection::connect(&config.url, ConnectionProperties::default())
+            .await
+            .map_err(|e| amqp_error("연결", e))?;
+        let publisher = connection
+            .create_channel()
+            .await
+            .map_err(|e| amqp_error("채널 생성", e))?;
+
+        publisher
+            .exchange_declare(
+                &config.exchange,
+                ExchangeKind::Topic,
+                ExchangeDeclareOptions {
+                    durable: true,
+                    ..ExchangeDeclareOptions::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .map_err(|e| amqp_error("익스체인지 선언", e))?;
+        publisher
+            .confirm_select(ConfirmSelectOptions::default())
+            .await
+            .map_err(|e| amqp_error("발행자 확인 설정", e))?;
+
+        tracing::info!("AMQP 브로커 연결됨: 익스체인지 {}", config.exchange);
+        Ok(Self {
+            config,
+            connection,
+            publisher,
+        })
+    }
+
+    /// 소비자 그룹의 큐 이름
+    pub fn queue_name(&self, group: &str) -> String {
+        format!("{}.{}", self.config.exchange, group)
+    }
+}
+
+#[async_trait]
+impl BrokerTransport for AmqpTransport {
+    fn name(&self) -> &str {
+        "amqp"
+    }
+
+    async fn publish(&self, message: OutgoingMessage) -> Result<()> {
+        let properties = BasicProperties::default()
+            .with_content_type(ShortString::from(message.content_type))
+            .with_message_id(ShortString::from(message.event_id.to_string()))
+            .with_delivery_mode(PERSISTENT);
+
+        let confirmation = self
+            .publisher
+            .basic_publish(
+                &self.config.exchange,
+                &message.topic,
+                BasicPublishOptions::default(),
+                &message.payload,
+                properties,
+            )
+            .await
+            .map_err(|e| amqp_error("발행", e))?
+            .await
+            .map_err(|e| amqp_error("발행 확인", e))?;
+
+        match confirmation {
+            Confirmation::Nack(_) => Err(CoreError::Unavailable {
+                message: format!("AMQP 브로커가 메시지를 거부함: {}", message.topic),
+                retry_after: None,
+            }),
+            Confirmation::Ack(_) | Confirmation::NotRequested => Ok(()),
+        }
+    }
+
+    async fn consume(&self, binding: &ConsumerBinding) -> Result<Box<dyn BrokerConsumer>> {
+        let channel = self
+            .connection
+            .create_channel()
+            .await
+            .map_err(|e| amqp_error("채널 생성", e))?;
+        channel
+            .basic_qos(binding.prefetch, BasicQosOptions::default())
+            .await
+            .map_err(|e| amqp_error("prefetch 설정", e))?;
+
+        let queue = self.queue_name(&binding.group);
+        let mut arguments = FieldTable::default();
+        if let Some(exchange) = &self.config.dead_letter_exchange {
+            arguments.insert(
+                ShortString::from("x-dead-letter-exchange"),
+                AMQPValue::LongString(exchange.as_str().into()),
+            );
+        }
+        channel
+            .queue_declare(
+                &queue,
+                QueueDeclareOptions {
+                    durable: true,
+                    ..QueueDeclareOptions::default()
+                },
+                arguments,
+            )
+            .await
+            .map_err(|e| amqp_error("큐 선언", e))?;
+        channel
+            .queue_bind(
+                &queue,
+                &self.config.exchange,
+                &binding.pattern,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .map_err(|e| amqp_error("큐 바인딩", e))?;
+
+        let consumer = channel
+            .basic_consume(&queue, "", BasicConsumeOptions::default(), FieldTable::default())
+            .await
+            .map_err(|e| amqp_error("소비 시작", e))?;
+
+        tracing::debug!("AMQP 큐 소비 시작: {} ({})", queue, binding.pattern);
+        Ok(Box::new(AmqpConsumer {
+            _channel: channel,
+            consumer,
+        }))
+    }
+
+    async fn close(&self) -> Result<()> {
+        self.connection
+            .close(200, "종료")
+            .await
+            .map_err(|e| amqp_error("연결 종료", e))
+    }
+}
+
+/// AMQP 큐 소비자 (채널이 사라지면 소비도 끝나므로 함께 보관)
+struct AmqpConsumer {
+    _channel: Channel,
+    consumer: lapin::Consumer,
+}
+
+#[async_trait]
+impl BrokerConsumer for AmqpConsumer {
+    async fn next(&mut self) -> Option<Result<BrokerDelivery>> {
+        let delivery = match self.consumer.next().await? {
+            Ok(delivery) => delivery,
+            Err(e) => return Some(Err(amqp_error("메시지 수신", e))),
+        };
+
+        Some(Ok(BrokerDelivery::new(
+            delivery.routing_key.as_str(),
+            delivery.data,
+            delivery.redelivered,
+            Box::new(AmqpAcker(delivery.acker)),
+        )))
+    }
+}
+
+/// AMQP 메시지 확인 처리
+struct AmqpAcker(lapin::acker::Acker);
+
+#[async_trait]
+impl Acknowledger for AmqpAcker {
+    async fn ack(self: Box<Self>) -> Result<()> {
+        self.0
+            .ack(BasicAckOptions::default())
+            .await
+            .map_err(|e| amqp_error("메시지 확인", e))
+    }
+
+    async fn nack(self: Box<Self>, requeue: bool) -> Result<()> {
+        self.0
+            .nack(BasicNackOptions {
+                requeue,
+                ..BasicNackOptions::default()
+            })
+            .await
+            .map_err(|e| amqp_error("메시지 거부", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use crate::events::broker::tests::assert_round_trip;
+    use crate::events::broker::BrokerEventBus;
+    use cryptolytica_shared_kernel::events::AsyncEventBus;
+
+    /// 로컬 브로커 컨테이너에 대해 실행:
+    /// `docker run -d -p 5672:5672 rabbitmq:3` 후
+    /// `CRYPTOLYTICA_AMQP_URL=amqp://127.0.0.1:5672/%2f cargo test --features amqp -- --ignored`
+    #[tokio::test]
+    #[ignore = "AMQP 브로커 필요"]
+    async fn test_round_trip_through_rabbitmq() {
+        let url = std::env::var("CRYPTOLYTICA_AMQP_URL").unwrap_or_else(|_| "amqp://127.0.0.1:5672/%2f".to_string());
+        let mut config = AmqpConfig::new(url);
+        config.exchange = format!("cryptolytica.test.{}", uuid::Uuid::new_v4().simple());
+
+        let transport = AmqpTransport::connect(config).await.unwrap();
+        let bus = BrokerEventBus::new(Arc::new(transport)).with_source("test");
+        assert_round_trip(&bus, "amqp-test").await;
+        bus.shutdown(Duration::from_secs(5)).await.unwrap();
+    }
+}
@@ -0,0 +1,798 @@
+// async_event_bus.rs
+//
+// 비동기 인메모리 이벤트 버스 구현
+// 구독마다 유한 큐와 tokio 태스크를 두어, 느린 핸들러가 발행자나 다른 구독자를 막지 않도록 함
+// 발행 시점의 상관·인과 맥락과 스팬을 이벤트와 함께 큐에 넣어, 처리 태스크에서 그대로 이어감
+// 발행 수, 구독별 처리 결과·처리 시간·큐 깊이는 버스의 EventBusMetrics에 기록함
+
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, VecDeque};
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tracing::Instrument;
+use uuid::Uuid;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{
+    AsyncEventBus, AsyncEventHandler, BackpressurePolicy, Event, EventBusMetrics, EventContext, EventSchemaRegistry,
+    HandlerOutcome, SubscriptionHandle, SubscriptionMetrics, SubscriptionOptions, SubscriptionStats,
+};
+use cryptolytica_shared_kernel::types::Result;
+
+/// 발행된 이벤트와 발행 시점의 맥락
+struct Published<E> {
+    event: E,
+    /// 상관·인과 맥락
+    context: EventContext,
+    /// 발행 시점의 스팬 아래에 만든 이벤트 스팬
+    span: tracing::Span,
+}
+
+/// 큐 항목
+struct Entry<E> {
+    /// 큐 내 순번 (병합 시 위치 계산용)
+    seq: u64,
+    /// 병합 키
+    key: Option<String>,
+    event: Arc<Published<E>>,
+}
+
+/// 큐 상태
+struct QueueState<E> {
+    entries: VecDeque<Entry<E>>,
+    /// 병합 키 -> 대기 중인 항목 순번
+    keys: HashMap<String, u64>,
+    next_seq: u64,
+    closed: bool,
+}
+
+impl<E> QueueState<E> {
+    fn push_back(&mut self, key: Option<String>, event: Arc<Published<E>>) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if let Some(key) = &key {
+            self.keys.insert(key.clone(), seq);
+        }
+        self.entries.push_back(Entry { seq, key, event });
+    }
+
+    fn pop_front(&mut self) -> Option<Entry<E>> {
+        let entry = self.entries.pop_front()?;
+        if let Some(key) = &entry.key {
+            if self.keys.get(key) == Some(&entry.seq) {
+                self.keys.remove(key);
+            }
+        }
+        Some(entry)
+    }
+
+    /// 같은 키의 대기 중인 이벤트를 교체 (교체했으면 `None`, 없으면 이벤트 반환)
+    fn replace(&mut self, key: &str, event: Arc<Published<E>>) -> Option<Arc<Published<E>>> {
+        let (Some(&seq), Some(front)) = (self.keys.get(key), self.entries.front()) else {
+            return Some(event);
+        };
+        let index = (seq - front.seq) as usize;
+        self.entries[index].event = event;
+        None
+    }
+}
+
+/// 큐 삽입 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PushOutcome {
+    Queued,
+    Coalesced,
+    /// 새 이벤트 또는 가장 오래된 이벤트가 버려짐
+    Dropped,
+    Closed,
+}
+
+/// 배압 정책이 적용된 구독 큐 (단일 소비자)
+struct SubscriptionQueue<E> {
+    state: Mutex<QueueState<E>>,
+    capacity: usize,
+    policy: BackpressurePolicy,
+    /// 이벤트가 들어왔음을 소비자에게 알림
+    items: Notify,
+    /// 자리가 났음을 대기 중인 발행자에게 알림
+    space: Notify,
+    /// 큐 깊이를 기록할 구독 지표
+    metrics: Arc<SubscriptionMetrics>,
+}
+
+impl<E: Event> SubscriptionQueue<E> {
+    fn new(capacity: usize, policy: BackpressurePolicy, metrics: Arc<SubscriptionMetrics>) -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                entries: VecDeque::with_capacity(capacity),
+                keys: HashMap::new(),
+                next_seq: 0,
+                closed: false,
+            }),
+            capacity: capacity.max(1),
+            policy,
+            items: Notify::new(),
+            space: Notify::new(),
+            metrics,
+        }
+    }
+
+    async fn push(&self, event: Arc<Published<E>>) -> PushOutcome {
+        let key = match self.policy {
+            BackpressurePolicy::CoalesceByKey => event.event.partition_key(),
+            _ => None,
+        };
+
+        loop {
+            {
+                let mut state = self.lock();
+                if state.closed {
+                    // 다른 대기 발행자도 종료를 확인하도록 전달
+                    self.space.notify_one();
+                    return PushOutcome::Closed;
+                }
+
+                let event = match &key {
+                    Some(key) => match state.replace(key, event.clone()) {
+                        None => return PushOutcome::Coalesced,
+                        Some(event) => event,
+                    },
+                    None => event.clone(),
+                };
+
+                if state.entries.len() < self.capacity {
+                    state.push_back(key, event);
+                    self.metrics.set_queued(state.entries.len());
+                    if state.entries.len() < self.capacity {
+                        self.space.notify_one();
+                    }
+                    self.items.notify_one();
+                    return PushOutcome::Queued;
+                }
+
+                match self.policy {
+                    BackpressurePolicy::Block => {}
+                    BackpressurePolicy::DropNewest => return PushOutcome::Dropped,
+                    BackpressurePolicy::DropOldest | BackpressurePolicy::CoalesceByKey => {
+                        state.pop_front();
+                        state.push_back(key, event);
+                        self.items.notify_one();
+                        return PushOutcome::Dropped;
+                    }
+                }
+            }
+
+            self.space.notified().await;
+        }
+    }
+
+    /// 다음 이벤트 (닫히고 비었으면 `None`)
+    async fn pop(&self) -> Option<Arc<Published<E>>> {
+        loop {
+            {
+                let mut state = self.lock();
+                if let Some(entry) = state.pop_front() {
+                    self.metrics.set_queued(state.entries.len());
+                    self.space.notify_one();
+                    return Some(entry.event);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+
+            self.items.notified().await;
+        }
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.items.notify_one();
+        self.space.notify_waiters();
+        self.space.notify_one();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState<E>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 이벤트 타입별 구독
+struct Subscription<E> {
+    name: String,
+    queue: Arc<SubscriptionQueue<E>>,
+    metrics: Arc<SubscriptionMetrics>,
+    worker: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// 타입이 소거된 구독 (버스가 여러 이벤트 타입의 구독을 함께 보관하기 위함)
+trait ErasedSubscription: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn close(&self);
+    fn take_worker(&self) -> Option<JoinHandle<()>>;
+    fn stats(&self) -> SubscriptionStats;
+}
+
+impl<E: Event> ErasedSubscription for Subscription<E> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn close(&self) {
+        self.queue.close();
+    }
+
+    fn take_worker(&self) -> Option<JoinHandle<()>> {
+        self.worker.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+
+    fn stats(&self) -> SubscriptionStats {
+        self.metrics.stats()
+    }
+}
+
+type SubscriptionMap = HashMap<TypeId, Vec<(Uuid, Arc<dyn ErasedSubscription>)>>;
+
+/// 비동기 인메모리 이벤트 버스 구현
+///
+/// tokio 런타임 안에서 사용해야 하며, 구독마다 처리 태스크가 하나씩 생성됩니다.
+pub struct AsyncInMemoryEventBus {
+    subscriptions: RwLock<SubscriptionMap>,
+    closed: AtomicBool,
+    metrics: Arc<EventBusMetrics>,
+    /// 발행 전 검증할 이벤트 스키마
+    schemas: Option<Arc<EventSchemaRegistry>>,
+}
+
+impl Default for AsyncInMemoryEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncInMemoryEventBus {
+    /// 새로운 비동기 인메모리 이벤트 버스 생성 (지표의 버스 이름 "in_memory")
+    pub fn new() -> Self {
+        Self {
+            subscriptions: RwLock::new(HashMap::new()),
+            closed: AtomicBool::new(false),
+            metrics: Arc::new(EventBusMetrics::new("in_memory")),
+            schemas: None,
+        }
+    }
+
+    /// 지표를 기록할 곳 지정 (버스 이름을 바꾸거나 다른 구성 요소와 공유할 때)
+    pub fn with_metrics(mut self, metrics: Arc<EventBusMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// 버스 지표
+    pub fn metrics(&self) -> &Arc<EventBusMetrics> {
+        &self.metrics
+    }
+
+    /// 발행 전에 이벤트 타입과 스키마 버전이 등록되어 있는지 확인할 저장소 지정
+    pub fn with_schemas(mut self, schemas: Arc<EventSchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+
+    /// 이벤트 타입의 구독 목록 복사 (잠금을 잡은 채 대기하지 않기 위함)
+    fn subscriptions_for<E: Event>(&self) -> Vec<Arc<dyn ErasedSubscription>> {
+        let subscriptions = self.subscriptions.read().unwrap_or_else(|e| e.into_inner());
+        subscriptions
+            .get(&TypeId::of::<E>())
+            .map(|subs| subs.iter().map(|(_, sub)| sub.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn find(&self, handle: &SubscriptionHandle) -> Option<Arc<dyn ErasedSubscription>> {
+        let subscriptions = self.subscriptions.read().unwrap_or_else(|e| e.into_inner());
+        subscriptions
+            .get(&handle.event_type_id)?
+            .iter()
+            .find(|(id, _)| *id == handle.id)
+            .map(|(_, sub)| sub.clone())
+    }
+}
+
+/// 구독 처리 태스크
+async fn run_subscription<E, H>(name: String, queue: Arc<SubscriptionQueue<E>>, handler: H, metrics: Arc<SubscriptionMetrics>)
+where
+    E: Event,
+    H: AsyncEventHandler<E>,
+{
+    while let Some(published) = queue.pop().await {
+        let event = &published.event;
+        let handled = published
+            .context
+            .handling(*event.id())
+            .scope(handler.handle(event))
+            .instrument(published.span.clone());
+        let started = Instant::now();
+        let result = AssertUnwindSafe(handled).catch_unwind().await;
+        let elapsed = started.elapsed();
+        match result {
+            Ok(Ok(())) => {
+                metrics.record(HandlerOutcome::Delivered, elapsed);
+            }
+            Ok(Err(e)) => {
+                metrics.record(HandlerOutcome::Failed, elapsed);
+                tracing::error!("이벤트 핸들러 실행 중 오류 ({}): {} - {:?}", name, event.event_type(), e);
+            }
+            Err(panic) => {
+                metrics.record(HandlerOutcome::Panicked, elapsed);
+                let message = panic
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                tracing::error!("이벤트 핸들러 패닉 ({}): {} - {}", name, event.event_type(), message);
+            }
+        }
+    }
+
+    tracing::debug!("구독 처리 태스크 종료: {}", name);
+}
+
+#[async_trait]
+impl AsyncEventBus for AsyncInMemoryEventBus {
+    /// 이벤트 발행
+    async fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(CoreError::Unavailable {
+                message: "이벤트 버스가 종료되었습니다".to_string(),
+                retry_after: None,
+            });
+        }
+        if let Some(schemas) = &self.schemas {
+            schemas.validate(&event)?;
+        }
+
+        self.metrics.record_published(&event);
+        let subscriptions = self.subscriptions_for::<E>();
+        if subscriptions.is_empty() {
+            tracing::debug!("이벤트 발행됨: {} (구독자 없음)", event.event_type());
+            return Ok(());
+        }
+
+        let context = EventContext::of(&event);
+        let span = context.span(event.event_type(), *event.id());
+        let event = Arc::new(Published { event, context, span });
+        for subscription in &subscriptions {
+            let Some(subscription) = subscription.as_any().downcast_ref::<Subscription<E>>() else {
+                continue;
+            };
+
+            match subscription.queue.push(event.clone()).await {
+                PushOutcome::Queued | PushOutcome::Closed => {}
+                PushOutcome::Coalesced => {
+                    subscription.metrics.record_coalesced();
+                }
+                PushOutcome::Dropped => {
+                    subscription.metrics.record_dropped();
+                    tracing::warn!("구독 큐가 가득 차 이벤트를 버림: {} ({})", subscription.name, event.event.event_type());
+                }
+            }
+        }
+
+        tracing::debug!("이벤트 발행됨: {} (구독 {}개)", event.event.event_type(), subscriptions.len());
+        Ok(())
+    }
+
+    /// 이벤트 구독
+    fn subscribe<E, H>(&self, handler: H, options: SubscriptionOptions) -> Result<SubscriptionHandle>
+    where
+        E: Event + for<'de> Deserialize<'de>,
+        H: AsyncEventHandler<E>,
+    {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(CoreError::Unavailable {
+                message: "이벤트 버스가 종료되었습니다".to_string(),
+                retry_after: None,
+            });
+        }
+
+        let subscription_id = Uuid::new_v4();
+        let metrics = self.metrics.register::<E>(subscription_id, options.name.clone());
+        let queue = Arc::new(SubscriptionQueue::<E>::new(options.capacity, options.policy, metrics.clone()));
+        let worker = tokio::spawn(run_subscription(
+            options.name.clone(),
+            queue.clone(),
+            handler,
+            metrics.clone(),
+        ));
+
+        let subscription = Subscription {
+            name: options.name,
+            queue,
+            metrics,
+            worker: Mutex::new(Some(worker)),
+        };
+
+        let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+        subscriptions
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push((subscription_id, Arc::new(subscription)));
+
+        tracing::debug!("비동기 이벤트 구독 등록됨: {:?} ({:?})", TypeId::of::<E>(), options.policy);
+        Ok(SubscriptionHandle::new::<E>(subscription_id))
+    }
+
+    /// 구독 취소
+    fn unsubscribe(&self, handle: &SubscriptionHandle) -> Result<()> {
+        let removed = {
+            let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+            subscriptions.get_mut(&handle.event_type_id).and_then(|subs| {
+                let index = subs.iter().position(|(id, _)| *id == handle.id)?;
+                Some(subs.remove(index).1)
+            })
+        };
+
+        match removed {
+            Some(subscription) => {
+                // 남은 이벤트는 처리 태스크가 마저 처리한 뒤 종료
+                subscription.close();
+                self.metrics.unregister(&handle.id);
+                tracing::debug!("비동기 이벤트 구독 취소됨: {}", handle.id);
+                Ok(())
+            }
+            None => Err(CoreError::NotFound(format!("구독 핸들을 찾을 수 없음: {}", handle.id))),
+        }
+    }
+
+    /// 구독 처리 통계
+    fn stats(&self, handle: &SubscriptionHandle) -> Option<SubscriptionStats> {
+        self.find(handle).map(|subscription| subscription.stats())
+    }
+
+    /// 대기 중인 이벤트를 처리한 뒤 종료
+    async fn shutdown(&self, timeout: Duration) -> Result<()> {
+        self.closed.store(true, Ordering::Release);
+
+        let subscriptions: Vec<_> = {
+            let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+            subscriptions.drain().flat_map(|(_, subs)| subs).collect()
+        };
+
+        let mut workers = Vec::with_capacity(subscriptions.len());
+        for (id, subscription) in &subscriptions {
+            subscription.close();
+            self.metrics.unregister(id);
+            workers.extend(subscription.take_worker());
+        }
+
+        let aborts: Vec<_> = workers.iter().map(|worker| worker.abort_handle()).collect();
+        match tokio::time::timeout(timeout, futures::future::join_all(workers)).await {
+            Ok(_) => {
+                tracing::info!("이벤트 버스 종료 완료 (구독 {}개)", subscriptions.len());
+                Ok(())
+            }
+            Err(_) => {
+                let pending: usize = subscriptions.iter().map(|(_, sub)| sub.stats().queued).sum();
+                aborts.iter().for_each(|abort| abort.abort());
+                Err(CoreError::Timeout(format!(
+                    "이벤트 버스 종료 시간({:?}) 초과: 미처리 이벤트 {}개",
+                    timeout, pending
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use cryptolytica_shared_kernel::events::{EventHandler, SyncHandler};
+    use tokio::sync::Semaphore;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TickEvent {
+        id: Uuid,
+        symbol: String,
+        price: u32,
+        timestamp: DateTime<Utc>,
+    }
+
+    impl Event for TickEvent {
+        fn event_type(&self) -> &'static str {
+            "test.tick"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+
+        fn partition_key(&self) -> Option<String> {
+            Some(self.symbol.clone())
+        }
+    }
+
+    fn tick(symbol: &str, price: u32) -> TickEvent {
+        TickEvent {
+            id: Uuid::new_v4(),
+            symbol: symbol.to_string(),
+            price,
+            timestamp: Utc::now(),
+        }
+    }
+
+    type Received = Arc<Mutex<Vec<(String, u32)>>>;
+
+    /// 허가를 받을 때마다 이벤트 하나를 처리하는 핸들러
+    struct GatedHandler {
+        gate: Arc<Semaphore>,
+        started: Arc<Notify>,
+        received: Received,
+    }
+
+    impl GatedHandler {
+        fn new() -> (Self, Arc<Semaphore>, Arc<Notify>, Received) {
+            let gate = Arc::new(Semaphore::new(0));
+            let started = Arc::new(Notify::new());
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let handler = Self {
+                gate: gate.clone(),
+                started: started.clone(),
+                received: received.clone(),
+            };
+            (handler, gate, started, received)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncEventHandler<TickEvent> for GatedHandler {
+        async fn handle(&self, event: &TickEvent) -> Result<()> {
+            self.started.notify_one();
+            self.gate.acquire().await.unwrap().forget();
+            self.received.lock().unwrap().push((event.symbol.clone(), event.price));
+            Ok(())
+        }
+    }
+
+    struct Recorder(Arc<Mutex<Vec<u32>>>);
+
+    impl EventHandler<TickEvent> for Recorder {
+        fn handle(&self, event: &TickEvent) -> Result<()> {
+            if event.price == 13 {
+                panic!("핸들러 버그");
+            }
+            self.0.lock().unwrap().push(event.price);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_does_not_block_others() {
+        let bus = AsyncInMemoryEventBus::new();
+        let (slow, gate, started, slow_received) = GatedHandler::new();
+        let fast_received = Arc::new(Mutex::new(Vec::new()));
+
+        let slow_handle = bus
+            .subscribe(slow, SubscriptionOptions::new("slow").with_capacity(2).with_policy(BackpressurePolicy::DropOldest))
+            .unwrap();
+        bus.subscribe(SyncHandler(Recorder(fast_received.clone())), SubscriptionOptions::new("fast"))
+            .unwrap();
+
+        bus.publish(tick("BTC/USDT", 1)).await.unwrap();
+        started.notified().await;
+        for price in 2..=5 {
+            bus.publish(tick("BTC/USDT", price)).await.unwrap();
+        }
+
+        // 처리 중인 1을 제외하고 가장 최근 두 개만 남음
+        let stats = bus.stats(&slow_handle).unwrap();
+        assert_eq!(stats.queued, 2);
+        assert_eq!(stats.dropped, 2);
+
+        gate.add_permits(3);
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        let prices: Vec<u32> = slow_received.lock().unwrap().iter().map(|(_, p)| *p).collect();
+        assert_eq!(prices, vec![1, 4, 5]);
+        assert_eq!(*fast_received.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_track_queue_depth_and_outcomes() {
+        let bus = AsyncInMemoryEventBus::new();
+        let (slow, gate, started, _) = GatedHandler::new();
+        bus.subscribe(slow, SubscriptionOptions::new("slow")).unwrap();
+        let buggy = bus
+            .subscribe(SyncHandler(Recorder(Arc::new(Mutex::new(Vec::new())))), SubscriptionOptions::new("buggy"))
+            .unwrap();
+
+        for price in [12, 13, 14] {
+            bus.publish(tick("BTC/USDT", price)).await.unwrap();
+        }
+        started.notified().await;
+
+        let snapshot = bus.metrics().snapshot();
+        let tick_metrics = snapshot.event_type("test.tick").unwrap();
+        assert_eq!((tick_metrics.published, tick_metrics.subscribers), (3, 2));
+        assert_eq!(snapshot.subscription("slow").unwrap().stats.queued, 2);
+        assert_eq!(snapshot.subscription("slow").unwrap().event_type, "test.tick");
+
+        gate.add_permits(3);
+        let buggy_metrics = bus.metrics().subscription(&buggy.id).unwrap();
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        let stats = buggy_metrics.stats();
+        assert_eq!((stats.delivered, stats.panicked, stats.queued), (2, 1, 0));
+        assert_eq!(buggy_metrics.latency().count, 3);
+        // 종료한 버스의 구독은 지표에서 빠짐
+        assert!(bus.metrics().snapshot().subscriptions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest_and_coalesce_by_key() {
+        let bus = AsyncInMemoryEventBus::new();
+        let (newest, newest_gate, newest_started, newest_received) = GatedHandler::new();
+        let (coalesce, coalesce_gate, coalesce_started, coalesce_received) = GatedHandler::new();
+
+        bus.subscribe(newest, SubscriptionOptions::new("newest").with_capacity(2).with_policy(BackpressurePolicy::DropNewest))
+            .unwrap();
+        let coalesce_handle = bus
+            .subscribe(coalesce, SubscriptionOptions::new("ticks").with_policy(BackpressurePolicy::CoalesceByKey))
+            .unwrap();
+
+        bus.publish(tick("BTC/USDT", 0)).await.unwrap();
+        newest_started.notified().await;
+        coalesce_started.notified().await;
+
+        for (symbol, price) in [("BTC/USDT", 1), ("ETH/USDT", 2), ("BTC/USDT", 3), ("ETH/USDT", 4)] {
+            bus.publish(tick(symbol, price)).await.unwrap();
+        }
+        assert_eq!(bus.stats(&coalesce_handle).unwrap().coalesced, 2);
+
+        newest_gate.add_permits(3);
+        coalesce_gate.add_permits(3);
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        let newest: Vec<u32> = newest_received.lock().unwrap().iter().map(|(_, p)| *p).collect();
+        assert_eq!(newest, vec![0, 1, 2]);
+
+        // 같은 심볼은 최신 가격으로 교체되고, 심볼 간 순서는 유지
+        assert_eq!(
+            *coalesce_received.lock().unwrap(),
+            vec![
+                ("BTC/USDT".to_string(), 0),
+                ("BTC/USDT".to_string(), 3),
+                ("ETH/USDT".to_string(), 4),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_block_policy_waits_for_space() {
+        let bus = Arc::new(AsyncInMemoryEventBus::new());
+        let (handler, gate, started, received) = GatedHandler::new();
+        bus.subscribe(handler, SubscriptionOptions::new("block").with_capacity(1)).unwrap();
+
+        bus.publish(tick("BTC/USDT", 1)).await.unwrap();
+        started.notified().await;
+        bus.publish(tick("BTC/USDT", 2)).await.unwrap();
+
+        // 큐가 가득 찼으므로 세 번째 발행은 대기
+        let publisher = {
+            let bus = bus.clone();
+            tokio::spawn(async move { bus.publish(tick("BTC/USDT", 3)).await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!publisher.is_finished());
+
+        gate.add_permits(3);
+        publisher.await.unwrap().unwrap();
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        let prices: Vec<u32> = received.lock().unwrap().iter().map(|(_, p)| *p).collect();
+        assert_eq!(prices, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_panic_is_isolated_to_subscription() {
+        let bus = AsyncInMemoryEventBus::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let handle = bus
+            .subscribe(SyncHandler(Recorder(received.clone())), SubscriptionOptions::new("buggy"))
+            .unwrap();
+
+        for price in [12, 13, 14] {
+            bus.publish(tick("BTC/USDT", price)).await.unwrap();
+        }
+
+        let subscription = bus.find(&handle).unwrap();
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![12, 14]);
+        let stats = subscription.stats();
+        assert_eq!((stats.delivered, stats.panicked), (2, 1));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_and_rejects_new_events() {
+        let bus = AsyncInMemoryEventBus::new();
+        let (handler, gate, _started, received) = GatedHandler::new();
+        let handle = bus.subscribe(handler, SubscriptionOptions::new("drain")).unwrap();
+
+        for price in 1..=3 {
+            bus.publish(tick("BTC/USDT", price)).await.unwrap();
+        }
+
+        // 제한 시간 안에 처리하지 못하면 오류
+        let (stuck, _stuck_gate, _, _) = GatedHandler::new();
+        let other = AsyncInMemoryEventBus::new();
+        other.subscribe(stuck, SubscriptionOptions::new("stuck")).unwrap();
+        other.publish(tick("BTC/USDT", 1)).await.unwrap();
+        assert!(matches!(other.shutdown(Duration::from_millis(20)).await, Err(CoreError::Timeout(_))));
+
+        gate.add_permits(3);
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+        assert_eq!(received.lock().unwrap().len(), 3);
+
+        assert!(bus.publish(tick("BTC/USDT", 4)).await.is_err());
+        assert!(bus.stats(&handle).is_none());
+        assert!(matches!(bus.unsubscribe(&handle), Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_publish_validates_schemas() {
+        let mut schemas = EventSchemaRegistry::new();
+        schemas.register("test.tick", 2).unwrap();
+        let bus = AsyncInMemoryEventBus::new().with_schemas(Arc::new(schemas));
+
+        // 이벤트의 스키마 버전(1)이 현재 버전(2)으로 올릴 수 없는 버전이면 거부
+        assert!(matches!(bus.publish(tick("BTC/USDT", 1)).await, Err(CoreError::Validation(_))));
+
+        let mut schemas = EventSchemaRegistry::new();
+        schemas.register("test.tick", 1).unwrap();
+        let bus = AsyncInMemoryEventBus::new().with_schemas(Arc::new(schemas));
+        bus.publish(tick("BTC/USDT", 1)).await.unwrap();
+    }
+
+    struct ContextRecorder(Arc<Mutex<Vec<Option<EventContext>>>>);
+
+    #[async_trait]
+    impl AsyncEventHandler<TickEvent> for ContextRecorder {
+        async fn handle(&self, _: &TickEvent) -> Result<()> {
+            tokio::task::yield_now().await;
+            self.0.lock().unwrap().push(EventContext::current());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_context_follows_event_into_worker() {
+        let bus = AsyncInMemoryEventBus::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        bus.subscribe(ContextRecorder(seen.clone()), SubscriptionOptions::new("context"))
+            .unwrap();
+
+        // 맥락 없이 발행한 이벤트는 자신이 흐름의 시작
+        let root = tick("BTC/USDT", 1);
+        bus.publish(root.clone()).await.unwrap();
+
+        // 다른 이벤트를 처리하는 중에 발행한 이벤트는 그 흐름을 이음
+        let cause = EventContext::new(Uuid::new_v4(), Some(Uuid::new_v4()));
+        let child = tick("BTC/USDT", 2);
+        cause.scope(bus.publish(child.clone())).await.unwrap();
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen[0], Some(EventContext::new(root.id, Some(root.id))));
+        assert_eq!(seen[1], Some(EventContext::new(cause.correlation_id, Some(child.id))));
+    }
+}
@@ -0,0 +1,944 @@
+// broker.rs
+//
+// 메시지 브로커 기반 이벤트 버스
+// 이벤트를 봉투로 직렬화하여 브로커 전송 계층(AMQP, Kafka, 내장 브로커)으로 주고받음
+// 발행 코덱은 `with_codec`으로 고르며(기본 JSON), 수신 측은 프레임 접두사로 코덱을 판별하므로
+// 코덱이 다른 발행자가 섞여 있어도 같은 구독으로 처리함
+//
+// 구독 이름이 소비자 그룹이 되어, 같은 이름으로 구독한 인스턴스들은 이벤트를 나눠 처리하고
+// 이름이 다른 구독은 각자 모든 이벤트를 받음. 핸들러가 성공하면 확인(ack)하고, 실패하거나
+// 패닉하면 다시 넣지 않고 거부(nack)하여 브로커의 데드 레터 설정을 따르도록 함
+//
+// 봉투 헤더의 상관·인과 맥락은 처리 태스크에서 복원하므로, 핸들러가 발행하는 이벤트는
+// 프로세스를 넘어서도 같은 인과 사슬에 이어짐
+//
+// 지표의 버스 이름은 전송 계층 이름이며, 대기열은 브로커에 있으므로 구독의 큐 깊이는 기록하지 않음
+
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::Instrument;
+use uuid::Uuid;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{
+    header_with_context, AsyncEventBus, AsyncEventHandler, CodecKind, Event, EventBusMetrics, EventContext,
+    EventHeader, EventSchemaRegistry, HandlerOutcome, SubscriptionHandle, SubscriptionMetrics, SubscriptionOptions,
+    SubscriptionStats, TopicPattern,
+};
+use cryptolytica_shared_kernel::resilience::RetryPolicy;
+use cryptolytica_shared_kernel::types::Result;
+
+use super::codec::WireCodec;
+
+/// 모든 토픽에 일치하는 패턴
+const ALL_TOPICS: &str = "#";
+
+/// 브로커 연결 재시도 최대 대기 시간
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// 브로커로 보낼 메시지
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingMessage {
+    /// 토픽 (이벤트 타입, AMQP 라우팅 키 / Kafka 토픽 이름)
+    pub topic: String,
+    /// 파티션 키 (Kafka 메시지 키)
+    pub key: Option<String>,
+    /// 이벤트 ID (AMQP message_id / Kafka 헤더)
+    pub event_id: Uuid,
+    /// 봉투 코덱의 콘텐츠 타입 (AMQP content_type / Kafka 헤더)
+    pub content_type: &'static str,
+    /// 직렬화된 봉투
+    pub payload: Vec<u8>,
+}
+
+/// 소비자 그룹과 토픽 패턴의 연결
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerBinding {
+    /// 소비자 그룹 (구독 이름)
+    pub group: String,
+    /// 토픽 패턴 (`*`는 한 단어, `#`은 0개 이상의 단어)
+    pub pattern: String,
+    /// 확인 전에 미리 받아 둘 최대 메시지 수
+    pub prefetch: u16,
+}
+
+/// 받은 메시지의 확인 처리
+#[async_trait]
+pub trait Acknowledger: Send {
+    /// 처리 완료 확인
+    async fn ack(self: Box<Self>) -> Result<()>;
+
+    /// 처리 거부 (`requeue`가 거짓이면 브로커의 데드 레터 설정을 따름)
+    async fn nack(self: Box<Self>, requeue: bool) -> Result<()>;
+}
+
+/// 브로커에서 받은 메시지
+pub struct BrokerDelivery {
+    /// 토픽
+    pub topic: String,
+    /// 직렬화된 봉투
+    pub payload: Vec<u8>,
+    /// 이전에 전달되었다가 다시 전달된 메시지인지 여부
+    pub redelivered: bool,
+    acker: Box<dyn Acknowledger>,
+}
+
+impl BrokerDelivery {
+    /// 받은 메시지 생성
+    pub fn new(topic: impl Into<String>, payload: Vec<u8>, redelivered: bool, acker: Box<dyn Acknowledger>) -> Self {
+        Self {
+            topic: topic.into(),
+            payload,
+            redelivered,
+            acker,
+        }
+    }
+
+    /// 처리 완료 확인
+    pub async fn ack(self) -> Result<()> {
+        self.acker.ack().await
+    }
+
+    /// 처리 거부
+    pub async fn nack(self, requeue: bool) -> Result<()> {
+        self.acker.nack(requeue).await
+    }
+}
+
+impl fmt::Debug for BrokerDelivery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BrokerDelivery")
+            .field("topic", &self.topic)
+            .field("payload_len", &self.payload.len())
+            .field("redelivered", &self.redelivered)
+            .finish()
+    }
+}
+
+/// 소비자 그룹의 메시지 스트림
+#[async_trait]
+pub trait BrokerConsumer: Send {
+    /// 다음 메시지 (연결이 닫히면 `None`)
+    ///
+    /// 구독 취소 시 대기 중인 호출이 취소될 수 있으므로, 취소되어도 메시지를 잃지 않아야 합니다.
+    async fn next(&mut self) -> Option<Result<BrokerDelivery>>;
+}
+
+/// 브로커 전송 계층
+#[async_trait]
+pub trait BrokerTransport: Send + Sync + 'static {
+    /// 전송 계층 이름 (로그용)
+    fn name(&self) -> &str;
+
+    /// 메시지 발행 (브로커가 받아들일 때까지 대기)
+    async fn publish(&self, message: OutgoingMessage) -> Result<()>;
+
+    /// 소비자 그룹에 패턴을 연결하고 메시지 스트림 열기
+    async fn consume(&self, binding: &ConsumerBinding) -> Result<Box<dyn BrokerConsumer>>;
+
+    /// 연결 종료
+    async fn close(&self) -> Result<()>;
+}
+
+/// 브로커 구독
+struct BrokerSubscription {
+    name: String,
+    metrics: Arc<SubscriptionMetrics>,
+    stop: watch::Sender<bool>,
+    worker: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl BrokerSubscription {
+    fn stop(&self) -> Option<JoinHandle<()>> {
+        let _ = self.stop.send(true);
+        self.worker.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+
+    fn stats(&self) -> SubscriptionStats {
+        self.metrics.stats()
+    }
+}
+
+/// 브로커 기반 이벤트 버스
+///
+/// 대기열은 브로커에 있으므로 `SubscriptionOptions`의 용량과 배압 정책 대신 prefetch로
+/// 처리 중인 메시지 수를 제한합니다. 핸들러가 실패한 메시지는 재시도 정책에 따라 백오프 후
+/// 다시 처리하고, 시도 횟수나 기한을 소진하면 거부하여 데드 레터로 보냅니다.
+/// tokio 런타임 안에서 사용해야 합니다.
+pub struct BrokerEventBus {
+    transport: Arc<dyn BrokerTransport>,
+    source: String,
+    prefetch: u16,
+    codec: WireCodec,
+    subscriptions: RwLock<HashMap<Uuid, BrokerSubscription>>,
+    closed: AtomicBool,
+    metrics: Arc<EventBusMetrics>,
+    schemas: Option<Arc<EventSchemaRegistry>>,
+    retry: RetryPolicy,
+}
+
+impl BrokerEventBus {
+    /// 전송 계층으로 이벤트 버스 생성 (소스 "cryptolytica", prefetch 64, JSON 코덱, 최대 3회 시도)
+    pub fn new(transport: Arc<dyn BrokerTransport>) -> Self {
+        Self {
+            metrics: Arc::new(EventBusMetrics::new(transport.name())),
+            transport,
+            source: "cryptolytica".to_string(),
+            prefetch: 64,
+            codec: WireCodec::default(),
+            subscriptions: RwLock::new(HashMap::new()),
+            closed: AtomicBool::new(false),
+            schemas: None,
+            retry: RetryPolicy::new(3),
+        }
+    }
+
+    /// 봉투 헤더에 기록할 발행 소스 지정
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// 구독마다 미리 받아 둘 최대 메시지 수 지정 (0은 1로 보정)
+    pub fn with_prefetch(mut self, prefetch: u16) -> Self {
+        self.prefetch = prefetch.max(1);
+        self
+    }
+
+    /// 발행에 쓸 봉투 코덱 지정 (수신은 코덱과 관계없이 프레임으로 판별)
+    pub fn with_codec(mut self, codec: CodecKind) -> Self {
+        self.codec = WireCodec::new(codec);
+        self
+    }
+
+    /// 발행에 쓰는 봉투 코덱
+    pub fn codec(&self) -> CodecKind {
+        self.codec.kind()
+    }
+
+    /// 지표를 기록할 곳 지정 (버스 이름을 바꾸거나 다른 구성 요소와 공유할 때)
+    pub fn with_metrics(mut self, metrics: Arc<EventBusMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// 핸들러가 실패한 메시지의 재시도 정책 지정
+    ///
+    /// 오류 종류와 관계없이 재시도합니다. 재시도하는 동안 해당 구독의 다음 메시지는 기다리므로
+    /// 파티션 안의 순서가 유지됩니다.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 이벤트 스키마 저장소 지정
+    ///
+    /// 발행할 때 이벤트 타입과 버전이 등록되어 있는지 확인하고, 수신할 때 이전 버전의 페이로드에
+    /// 업캐스터를 적용한 뒤 구독 타입으로 복원합니다.
+    pub fn with_schemas(mut self, schemas: Arc<EventSchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+
+    /// 전송 계층
+    pub fn transport(&self) -> &Arc<dyn BrokerTransport> {
+        &self.transport
+    }
+
+    /// 버스 지표
+    pub fn metrics(&self) -> &Arc<EventBusMetrics> {
+        &self.metrics
+    }
+
+    /// 토픽 패턴으로 구독 (예: `exchange.order.*`)
+    ///
+    /// 패턴에 일치하지만 구독 타입으로 복원할 수 없는 이벤트는 실패로 보고 거부합니다.
+    pub fn subscribe_topic<E, H>(&self, pattern: &str, handler: H, options: SubscriptionOptions) -> Result<SubscriptionHandle>
+    where
+        E: Event + for<'de> Deserialize<'de>,
+        H: AsyncEventHandler<E>,
+    {
+        TopicPattern::new(pattern)?;
+        self.spawn_subscription(pattern, None, handler, options)
+    }
+
+    fn ensure_open(&self) -> Result<()> {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(CoreError::Unavailable {
+                message: "이벤트 버스가 종료되었습니다".to_string(),
+                retry_after: None,
+            });
+        }
+        Ok(())
+    }
+
+    fn spawn_subscription<E, H>(
+        &self,
+        pattern: &str,
+        only_type: Option<String>,
+        handler: H,
+        options: SubscriptionOptions,
+    ) -> Result<SubscriptionHandle>
+    where
+        E: Event + for<'de> Deserialize<'de>,
+        H: AsyncEventHandler<E>,
+    {
+        self.ensure_open()?;
+
+        let subscription_id = Uuid::new_v4();
+        let binding = ConsumerBinding {
+            group: options.name.clone(),
+            pattern: pattern.to_string(),
+            prefetch: self.prefetch,
+        };
+        let metrics = self.metrics.register::<E>(subscription_id, options.name.clone());
+        let (stop, stopped) = watch::channel(false);
+        let worker = tokio::spawn(run_consumer(
+            self.transport.clone(),
+            binding,
+            Consumer {
+                only_type,
+                handler,
+                schemas: self.schemas.clone(),
+                retry: self.retry.clone(),
+                bus_metrics: self.metrics.clone(),
+                metrics: metrics.clone(),
+                _event: std::marker::PhantomData,
+            },
+            stopped,
+        ));
+
+        self.subscriptions.write().unwrap_or_else(|e| e.into_inner()).insert(
+            subscription_id,
+            BrokerSubscription {
+                name: options.name,
+                metrics,
+                stop,
+                worker: Mutex::new(Some(worker)),
+            },
+        );
+
+        tracing::debug!("브로커 구독 등록됨: {} ({})", pattern, self.transport.name());
+        Ok(SubscriptionHandle::new::<E>(subscription_id))
+    }
+}
+
+/// 구독 하나의 메시지 처리기
+struct Consumer<E, H> {
+    /// 모든 토픽을 받는 구독의 이벤트 타입 ID (다른 타입은 건너뜀)
+    ///
+    /// 없으면 패턴에 일치한 메시지는 모두 구독 타입이어야 합니다.
+    only_type: Option<String>,
+    handler: H,
+    /// 이전 스키마 버전의 페이로드를 업캐스트할 저장소
+    schemas: Option<Arc<EventSchemaRegistry>>,
+    /// 핸들러 실패 시 재시도 정책
+    retry: RetryPolicy,
+    /// 수신한 이벤트의 타입 이름을 버스 지표에 알리기 위함
+    bus_metrics: Arc<EventBusMetrics>,
+    metrics: Arc<SubscriptionMetrics>,
+    _event: std::marker::PhantomData<fn() -> E>,
+}
+
+impl<E, H> Consumer<E, H>
+where
+    E: Event + for<'de> Deserialize<'de>,
+    H: AsyncEventHandler<E>,
+{
+    async fn handle(&self, group: &str, delivery: BrokerDelivery) {
+        if let Some(only_type) = &self.only_type {
+            // 모든 토픽을 받는 구독은 다른 타입의 이벤트만 건너뛰고, 같은 타입의 해석 실패는 아래에서 거부
+            if WireCodec::decode_header(&delivery.payload).is_ok_and(|header| header.event_type != *only_type) {
+                Self::settle(group, delivery.ack().await);
+                return;
+            }
+        }
+
+        let (header, event) = match self.decode(&delivery.payload) {
+            Ok((header, event)) if event.event_type() == header.event_type => (header, event),
+            Ok((header, event)) => {
+                self.metrics.record_rejected();
+                tracing::error!(
+                    "구독 타입과 다른 이벤트 ({}): {} (구독 타입 {})",
+                    group,
+                    header.event_type,
+                    event.event_type()
+                );
+                Self::settle(group, delivery.nack(false).await);
+                return;
+            }
+            Err(e) => {
+                self.metrics.record_rejected();
+                tracing::error!("이벤트 봉투 해석 실패 ({}): {} - {}", group, delivery.topic, e);
+                Self::settle(group, delivery.nack(false).await);
+                return;
+            }
+        };
+
+        self.bus_metrics.observe(&event);
+        let outcome = if self.dispatch(group, &header, &event).await {
+            delivery.ack().await
+        } else {
+            tracing::error!("재시도를 소진하여 데드 레터로 보냄 ({}): {} ({})", group, header.event_type, header.id);
+            delivery.nack(false).await
+        };
+        Self::settle(group, outcome);
+    }
+
+    /// 핸들러 실행 (실패하면 재시도 정책에 따라 백오프 후 다시 실행하고, 끝내 성공했는지 반환)
+    async fn dispatch(&self, group: &str, header: &EventHeader, event: &E) -> bool {
+        let context = EventContext::from_header(header);
+        let first_attempt = Instant::now();
+        let mut attempt = 1;
+
+        loop {
+            let handled = context
+                .handling(header.id)
+                .scope(self.handler.handle(event))
+                .instrument(context.span(&header.event_type, header.id));
+            let started = Instant::now();
+            let result = AssertUnwindSafe(handled).catch_unwind().await;
+            let elapsed = started.elapsed();
+            match result {
+                Ok(Ok(())) => {
+                    self.metrics.record(HandlerOutcome::Delivered, elapsed);
+                    return true;
+                }
+                Ok(Err(e)) => {
+                    self.metrics.record(HandlerOutcome::Failed, elapsed);
+                    tracing::error!(
+                        "이벤트 핸들러 실행 중 오류 ({}, {}번째 시도): {} - {:?}",
+                        group,
+                        attempt,
+                        header.event_type,
+                        e
+                    );
+                }
+                Err(panic) => {
+                    self.metrics.record(HandlerOutcome::Panicked, elapsed);
+                    let message = panic
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_default();
+                    tracing::error!(
+                        "이벤트 핸들러 패닉 ({}, {}번째 시도): {} - {}",
+                        group,
+                        attempt,
+                        header.event_type,
+                        message
+                    );
+                }
+            }
+
+            if attempt >= self.retry.max_attempts() {
+                return false;
+            }
+            let delay = self.retry.backoff().delay(attempt - 1);
+            if let Some(deadline) = self.retry.deadline() {
+                if first_attempt.elapsed() + delay >= deadline {
+                    return false;
+                }
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn decode(&self, frame: &[u8]) -> Result<(EventHeader, E)> {
+        match &self.schemas {
+            Some(schemas) => WireCodec::decode_upcast(frame, schemas),
+            None => WireCodec::decode_parts(frame),
+        }
+    }
+
+    fn settle(group: &str, outcome: Result<()>) {
+        if let Err(e) = outcome {
+            tracing::warn!("메시지 확인 응답 실패 ({}): {:?}", group, e);
+        }
+    }
+}
+
+/// 구독 처리 태스크 (연결이 끊기면 지수 백오프로 다시 연결)
+async fn run_consumer<E, H>(
+    transport: Arc<dyn BrokerTransport>,
+    binding: ConsumerBinding,
+    consumer: Consumer<E, H>,
+    mut stopped: watch::Receiver<bool>,
+) where
+    E: Event + for<'de> Deserialize<'de>,
+    H: AsyncEventHandler<E>,
+{
+    let mut delay = Duration::from_millis(100);
+
+    while !*stopped.borrow() {
+        let stream = tokio::select! {
+            _ = stopped.changed() => break,
+            stream = transport.consume(&binding) => stream,
+        };
+
+        match stream {
+            Ok(mut stream) => {
+                delay = Duration::from_millis(100);
+                loop {
+                    let next = tokio::select! {
+                        _ = stopped.changed() => None,
+                        next = stream.next() => next,
+                    };
+                    match next {
+                        Some(Ok(delivery)) => consumer.handle(&binding.group, delivery).await,
+                        Some(Err(e)) => {
+                            tracing::warn!("브로커 메시지 수신 오류 ({}): {:?}", binding.group, e);
+                            break;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "브로커 구독 연결 실패 ({}), {:?} 후 재시도: {:?}",
+                    binding.group,
+                    delay,
+                    e
+                );
+            }
+        }
+
+        if *stopped.borrow() {
+            break;
+        }
+        tokio::select! {
+            _ = stopped.changed() => break,
+            _ = tokio::time::sleep(delay) => {}
+        }
+        delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+    }
+
+    tracing::debug!("브로커 구독 처리 태스크 종료: {}", binding.group);
+}
+
+#[async_trait]
+impl AsyncEventBus for BrokerEventBus {
+    /// 설정된 코덱의 봉투로 직렬화하여 이벤트 타입 토픽으로 발행
+    async fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
+        self.ensure_open()?;
+        if let Some(schemas) = &self.schemas {
+            schemas.validate(&event)?;
+        }
+
+        let header = header_with_context(&event, &self.source, &EventContext::of(&event));
+        let message = OutgoingMessage {
+            topic: header.event_type.clone(),
+            key: event.partition_key(),
+            event_id: header.id,
+            content_type: self.codec.content_type(),
+            payload: self.codec.encode(&header, &event)?,
+        };
+        self.transport.publish(message).await?;
+        self.metrics.record_published(&event);
+
+        tracing::debug!("이벤트 발행됨: {} ({})", event.event_type(), self.transport.name());
+        Ok(())
+    }
+
+    /// 모든 토픽을 받아 구독 타입의 이벤트만 처리
+    ///
+    /// 브로커가 다른 타입의 이벤트까지 전달하므로, 가능하면 `subscribe_topic`으로 범위를 좁힙니다.
+    /// 다른 타입을 구분하려면 `SubscriptionOptions::with_event_type`으로 구독 타입의 이벤트 타입 ID를
+    /// 지정해야 하며, 같은 타입이지만 복원할 수 없는 이벤트는 거부합니다.
+    fn subscribe<E, H>(&self, handler: H, options: SubscriptionOptions) -> Result<SubscriptionHandle>
+    where
+        E: Event + for<'de> Deserialize<'de>,
+        H: AsyncEventHandler<E>,
+    {
+        let only_type = options.event_type.clone().ok_or_else(|| {
+            CoreError::Validation(format!(
+                "모든 토픽을 받는 브로커 구독에는 이벤트 타입 ID가 필요함 (SubscriptionOptions::with_event_type): {}",
+                options.name
+            ))
+        })?;
+        self.spawn_subscription(ALL_TOPICS, Some(only_type), handler, options)
+    }
+
+    /// 구독 취소 (처리 중인 메시지는 마저 처리하고, 브로커의 대기열은 그대로 남김)
+    fn unsubscribe(&self, handle: &SubscriptionHandle) -> Result<()> {
+        let removed = self
+            .subscriptions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&handle.id);
+
+        match removed {
+            Some(subscription) => {
+                subscription.stop();
+                self.metrics.unregister(&handle.id);
+                tracing::debug!("브로커 구독 취소됨: {} ({})", subscription.name, handle.id);
+                Ok(())
+            }
+            None => Err(CoreError::NotFound(format!("구독 핸들을 찾을 수 없음: {}", handle.id))),
+        }
+    }
+
+    /// 구독 처리 통계 (대기 중인 메시지는 브로커에 있으므로 `queued`는 항상 0)
+    fn stats(&self, handle: &SubscriptionHandle) -> Option<SubscriptionStats> {
+        self.subscriptions
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&handle.id)
+            .map(BrokerSubscription::stats)
+    }
+
+    /// 구독 태스크를 멈추고 처리 중인 메시지를 마친 뒤 연결 종료
+    async fn shutdown(&self, timeout: Duration) -> Result<()> {
+        self.closed.store(true, Ordering::Release);
+
+        let subscriptions: Vec<_> = {
+            let mut subscriptions = self.subscriptions.write().unwrap_or_else(|e| e.into_inner());
+            subscriptions.drain().map(|(_, subscription)| subscription).collect()
+        };
+        for subscription in &subscriptions {
+            self.metrics.unregister(&subscription.metrics.id());
+        }
+        let workers: Vec<_> = subscriptions.iter().filter_map(BrokerSubscription::stop).collect();
+        let aborts: Vec<_> = workers.iter().map(|worker| worker.abort_handle()).collect();
+
+        let drained = tokio::time::timeout(timeout, futures::future::join_all(workers)).await;
+        if drained.is_err() {
+            aborts.iter().for_each(|abort| abort.abort());
+        }
+        self.transport.close().await?;
+
+        match drained {
+            Ok(_) => {
+                tracing::info!("브로커 이벤트 버스 종료 완료 (구독 {}개)", subscriptions.len());
+                Ok(())
+            }
+            Err(_) => Err(CoreError::Timeout(format!(
+                "브로커 이벤트 버스 종료 시간({:?}) 초과",
+                timeout
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use cryptolytica_shared_kernel::resilience::ExponentialBackoff;
+    use crate::events::embedded_broker::EmbeddedBroker;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub(crate) struct OrderFilled {
+        pub id: Uuid,
+        pub order_id: String,
+        pub timestamp: DateTime<Utc>,
+    }
+
+    impl Event for OrderFilled {
+        fn event_type(&self) -> &'static str {
+            "exchange.order.filled"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+
+        fn partition_key(&self) -> Option<String> {
+            Some(self.order_id.clone())
+        }
+    }
+
+    pub(crate) fn filled(order_id: &str) -> OrderFilled {
+        OrderFilled {
+            id: Uuid::new_v4(),
+            order_id: order_id.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    type Received = Arc<Mutex<Vec<(String, Option<EventContext>)>>>;
+
+    /// 받은 주문 ID와 맥락을 기록하고, "reject"로 시작하는 주문은 항상,
+    /// "flaky"로 시작하는 주문은 처음 한 번만 실패시키는 핸들러
+    #[derive(Clone, Default)]
+    pub(crate) struct Recorder {
+        pub received: Received,
+    }
+
+    #[async_trait]
+    impl AsyncEventHandler<OrderFilled> for Recorder {
+        async fn handle(&self, event: &OrderFilled) -> Result<()> {
+            let first = {
+                let mut received = self.received.lock().unwrap();
+                let first = received.iter().all(|(id, _)| *id != event.order_id);
+                received.push((event.order_id.clone(), EventContext::current()));
+                first
+            };
+            if event.order_id.starts_with("reject") || (first && event.order_id.starts_with("flaky")) {
+                return Err(CoreError::Validation(format!("거부된 주문: {}", event.order_id)));
+            }
+            Ok(())
+        }
+    }
+
+    impl Recorder {
+        /// 받은 주문 ID (재시도로 여러 번 받은 주문은 한 번만)
+        pub fn orders(&self) -> Vec<String> {
+            let mut orders: Vec<_> = self.received.lock().unwrap().iter().map(|(id, _)| id.clone()).collect();
+            orders.sort();
+            orders.dedup();
+            orders
+        }
+
+        /// 핸들러가 호출된 횟수 (재시도 포함)
+        pub fn attempts(&self) -> usize {
+            self.received.lock().unwrap().len()
+        }
+    }
+
+    /// 조건이 참이 될 때까지 대기
+    pub(crate) async fn eventually(mut condition: impl FnMut() -> bool) {
+        for _ in 0..1000 {
+            if condition() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("제한 시간 안에 조건이 충족되지 않음");
+    }
+
+    /// 실제 브로커나 내장 브로커에 공통으로 적용하는 왕복 검증
+    ///
+    /// 같은 그룹의 두 구독은 이벤트를 나눠 받고, 다른 그룹은 모든 이벤트를 받으며,
+    /// 실패한 이벤트는 재시도를 거쳐 거부되고, 핸들러에서 본 맥락은 봉투 헤더에서 복원되어야 합니다.
+    pub(crate) async fn assert_round_trip(bus: &BrokerEventBus, group_prefix: &str) {
+        let worker_a = Recorder::default();
+        let worker_b = Recorder::default();
+        let audit = Recorder::default();
+        let workers = format!("{}.workers", group_prefix);
+        let a = bus
+            .subscribe_topic("exchange.order.*", worker_a.clone(), SubscriptionOptions::new(workers.clone()))
+            .unwrap();
+        bus.subscribe_topic("exchange.order.*", worker_b.clone(), SubscriptionOptions::new(workers))
+            .unwrap();
+        let audit_options = SubscriptionOptions::new(format!("{}.audit", group_prefix)).with_event_type("exchange.order.filled");
+        bus.subscribe::<OrderFilled, _>(audit.clone(), audit_options).unwrap();
+
+        // 구독 태스크가 브로커에 연결될 시간을 줌
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let first = filled("order-1");
+        let first_id = first.id;
+        bus.publish(first).await.unwrap();
+        bus.publish(filled("order-2")).await.unwrap();
+        bus.publish(filled("reject-3")).await.unwrap();
+
+        eventually(|| worker_a.orders().len() + worker_b.orders().len() == 3 && audit.orders().len() == 3).await;
+
+        let mut shared = worker_a.orders();
+        shared.extend(worker_b.orders());
+        shared.sort();
+        assert_eq!(shared, ["order-1", "order-2", "reject-3"]);
+        assert_eq!(audit.orders(), ["order-1", "order-2", "reject-3"]);
+
+        let contexts = audit.received.lock().unwrap().clone();
+        let (_, context) = contexts.iter().find(|(id, _)| id == "order-1").unwrap();
+        assert_eq!(*context, Some(EventContext::new(first_id, Some(first_id))));
+
+        // 재시도를 포함한 모든 시도가 지표에 기록됨
+        eventually(|| {
+            let stats = bus.stats(&a).unwrap();
+            stats.delivered + stats.failed == worker_a.attempts() as u64
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_through_embedded_broker() {
+        let broker = Arc::new(EmbeddedBroker::new());
+        let bus = BrokerEventBus::new(broker.clone()).with_source("test");
+
+        assert_round_trip(&bus, "test").await;
+
+        // 실패한 이벤트는 시도 횟수(3회)를 소진한 뒤 데드 레터로 보냄
+        eventually(|| broker.dead_letters("test.workers").len() == 1 && broker.dead_letters("test.audit").len() == 1)
+            .await;
+        assert_eq!(broker.dead_letters("test.workers")[0].topic, "exchange.order.filled");
+
+        let snapshot = bus.metrics().snapshot();
+        assert_eq!(snapshot.bus, "embedded");
+        let filled_metrics = snapshot.event_type("exchange.order.filled").unwrap();
+        assert_eq!((filled_metrics.published, filled_metrics.subscribers), (3, 3));
+        let audit = snapshot.subscription("test.audit").unwrap();
+        assert_eq!((audit.stats.delivered, audit.stats.failed, audit.latency.count), (2, 3, 5));
+
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+        assert!(bus.publish(filled("order-4")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_is_retried_before_dead_lettering() {
+        let broker = Arc::new(EmbeddedBroker::new());
+        let backoff = ExponentialBackoff::new(Duration::from_millis(10), Duration::from_millis(50));
+        let bus = BrokerEventBus::new(broker.clone()).with_retry_policy(RetryPolicy::new(2).with_backoff(backoff));
+        let recorder = Recorder::default();
+        let handle = bus
+            .subscribe_topic("exchange.order.*", recorder.clone(), SubscriptionOptions::new("retry"))
+            .unwrap();
+        eventually(|| broker.has_group("retry")).await;
+
+        // 처음 한 번 실패한 주문은 다시 전달되어 처리되고, 계속 실패하는 주문만 데드 레터로 감
+        bus.publish(filled("flaky-1")).await.unwrap();
+        bus.publish(filled("reject-2")).await.unwrap();
+        eventually(|| broker.dead_letters("retry").len() == 1).await;
+
+        assert_eq!(recorder.orders(), ["flaky-1", "reject-2"]);
+        assert_eq!(recorder.attempts(), 4);
+        let stats = bus.stats(&handle).unwrap();
+        assert_eq!((stats.delivered, stats.failed), (1, 3));
+
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mixed_codec_publishers_share_subscription() {
+        let broker = Arc::new(EmbeddedBroker::new());
+        let json = BrokerEventBus::new(broker.clone());
+        let binary = BrokerEventBus::new(broker.clone()).with_codec(CodecKind::Binary);
+        let msgpack = BrokerEventBus::new(broker.clone()).with_codec(CodecKind::MessagePack);
+        let recorder = Recorder::default();
+        json.subscribe_topic("exchange.order.*", recorder.clone(), SubscriptionOptions::new("mixed"))
+            .unwrap();
+        eventually(|| broker.has_group("mixed")).await;
+
+        json.publish(filled("order-1")).await.unwrap();
+        binary.publish(filled("order-2")).await.unwrap();
+        msgpack.publish(filled("order-3")).await.unwrap();
+        eventually(|| recorder.orders() == ["order-1", "order-2", "order-3"]).await;
+
+        for bus in [json, binary, msgpack] {
+            bus.shutdown(Duration::from_secs(1)).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schemas_validate_publish_and_upcast_deliveries() {
+        let broker = Arc::new(EmbeddedBroker::new());
+        let unregistered = BrokerEventBus::new(broker.clone()).with_schemas(Arc::new(EventSchemaRegistry::new()));
+        assert!(matches!(
+            unregistered.publish(filled("order-0")).await,
+            Err(CoreError::Validation(_))
+        ));
+
+        // v1 페이로드는 주문 ID를 "order" 필드에 담았음
+        let mut schemas = EventSchemaRegistry::new();
+        schemas.register("exchange.order.filled", 2).unwrap();
+        schemas
+            .register_upcaster("exchange.order.filled", 1, |mut payload| {
+                let order = payload["order"].take();
+                payload["order_id"] = order;
+                Ok(payload)
+            })
+            .unwrap();
+        let bus = BrokerEventBus::new(broker.clone()).with_schemas(Arc::new(schemas));
+        let recorder = Recorder::default();
+        bus.subscribe_topic("exchange.order.*", recorder.clone(), SubscriptionOptions::new("upcast"))
+            .unwrap();
+        eventually(|| broker.has_group("upcast")).await;
+
+        let old = filled("order-1");
+        let mut header = header_with_context(&old, "legacy", &EventContext::for_new_event(old.id));
+        header.version = "1".to_string();
+        let payload = serde_json::json!({ "id": old.id, "order": "order-1", "timestamp": old.timestamp });
+        publish_raw(&broker, &header, payload).await;
+        eventually(|| recorder.orders() == ["order-1"]).await;
+
+        for bus in [unregistered, bus] {
+            bus.shutdown(Duration::from_secs(1)).await.unwrap();
+        }
+    }
+
+    /// 헤더와 페이로드를 직접 지정하여 브로커에 발행
+    async fn publish_raw(broker: &EmbeddedBroker, header: &EventHeader, payload: serde_json::Value) {
+        broker
+            .publish(OutgoingMessage {
+                topic: header.event_type.clone(),
+                key: None,
+                event_id: header.id,
+                content_type: WireCodec::default().content_type(),
+                payload: WireCodec::default().encode(header, &payload).unwrap(),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_catch_all_subscription_rejects_undecodable_events_of_its_type() {
+        let broker = Arc::new(EmbeddedBroker::new());
+        let bus = BrokerEventBus::new(broker.clone());
+        let recorder = Recorder::default();
+
+        // 구독 타입을 모르면 다른 타입과 해석 실패를 구분할 수 없으므로 거부
+        assert!(matches!(
+            bus.subscribe::<OrderFilled, _>(recorder.clone(), SubscriptionOptions::new("untyped")),
+            Err(CoreError::Validation(_))
+        ));
+
+        let options = SubscriptionOptions::new("audit").with_event_type("exchange.order.filled");
+        let handle = bus.subscribe::<OrderFilled, _>(recorder.clone(), options).unwrap();
+        eventually(|| broker.has_group("audit")).await;
+
+        // 다른 타입의 이벤트는 조용히 건너뜀
+        let other = filled("order-0");
+        let mut header = header_with_context(&other, "test", &EventContext::for_new_event(other.id));
+        header.event_type = "exchange.order.cancelled".to_string();
+        publish_raw(&broker, &header, serde_json::json!({ "reason": "user" })).await;
+
+        // 같은 타입이지만 페이로드가 맞지 않는 이벤트는 거부하여 데드 레터로 보냄
+        let broken = filled("order-1");
+        let header = header_with_context(&broken, "test", &EventContext::for_new_event(broken.id));
+        publish_raw(&broker, &header, serde_json::json!({ "id": broken.id })).await;
+
+        bus.publish(filled("order-2")).await.unwrap();
+        eventually(|| recorder.orders() == ["order-2"] && broker.dead_letters("audit").len() == 1).await;
+        assert_eq!(broker.dead_letters("audit")[0].event_id, broken.id);
+        // 건너뛴 이벤트는 지표에 남지 않고, 거부한 이벤트는 실패로 기록
+        let stats = bus.stats(&handle).unwrap();
+        assert_eq!((stats.delivered, stats.failed), (1, 1));
+
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_broker_outage() {
+        let broker = Arc::new(EmbeddedBroker::new());
+        broker.set_available(false);
+        let bus = BrokerEventBus::new(broker.clone());
+        let recorder = Recorder::default();
+        bus.subscribe_topic("exchange.#", recorder.clone(), SubscriptionOptions::new("late"))
+            .unwrap();
+
+        assert!(bus.publish(filled("order-1")).await.is_err());
+
+        broker.set_available(true);
+        eventually(|| broker.has_group("late")).await;
+        bus.publish(filled("order-2")).await.unwrap();
+        eventually(|| recorder.orders() == ["order-2"]).await;
+
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+    }
+}
@@ -0,0 +1,185 @@
+// checkpoint_store.rs
+//
+// 파일 기반 프로젝션 체크포인트 저장소
+// 프로젝션마다 `{이름}.checkpoint.json` 파일 하나에 마지막 체크포인트를 저장함
+// 임시 파일에 쓰고 동기화한 뒤 이름을 바꾸므로 쓰는 도중 종료되어도 이전 체크포인트는 온전함
+
+use std::fs::{self, File};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{Checkpoint, CheckpointStore};
+use cryptolytica_shared_kernel::types::Result;
+
+/// 체크포인트 파일 접미사
+const CHECKPOINT_SUFFIX: &str = "checkpoint.json";
+
+/// 파일 기반 체크포인트 저장소
+#[derive(Debug, Clone)]
+pub struct FileCheckpointStore {
+    dir: PathBuf,
+}
+
+impl FileCheckpointStore {
+    /// 디렉터리를 열어 저장소 생성 (없으면 생성)
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// 저장소 디렉터리
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 프로젝션의 체크포인트 파일 경로 (경로에 쓸 수 없는 문자는 `_`로 바꿈)
+    pub fn path(&self, projection: &str) -> PathBuf {
+        let safe: String = projection
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{}.{}", safe, CHECKPOINT_SUFFIX))
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self, projection: &str) -> Result<Option<Checkpoint>> {
+        let path = self.path(projection);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let checkpoint: Checkpoint = serde_json::from_slice(&bytes)
+            .map_err(|e| CoreError::Data(format!("손상된 체크포인트 {}: {}", path.display(), e)))?;
+        if checkpoint.projection != projection {
+            return Err(CoreError::Data(format!(
+                "체크포인트 내용이 파일 위치와 다름: {} ({})",
+                path.display(),
+                checkpoint.projection
+            )));
+        }
+        Ok(Some(checkpoint))
+    }
+
+    fn save(&self, checkpoint: &Checkpoint) -> Result<()> {
+        let path = self.path(&checkpoint.projection);
+        let temp = path.with_extension("tmp");
+        {
+            let mut file = File::create(&temp)?;
+            file.write_all(&serde_json::to_vec_pretty(checkpoint)?)?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp, &path)?;
+        // 이름 변경을 디스크에 반영 (지원하지 않는 플랫폼은 무시)
+        if let Ok(dir) = File::open(&self.dir) {
+            let _ = dir.sync_all();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use uuid::Uuid;
+    use cryptolytica_shared_kernel::events::{
+        envelope_of, Event, EventEnvelope, Projection, ProjectionHandlers, ProjectionRunner,
+    };
+    use crate::events::event_log::{EventLogConfig, FileEventLog};
+
+    /// 심볼별 체결 수 (상태가 외부 저장소에 남는다고 가정)
+    #[derive(Default)]
+    struct TradeCounts(Mutex<HashMap<String, u64>>);
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Traded {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+        symbol: String,
+    }
+
+    impl Event for Traded {
+        fn event_type(&self) -> &'static str {
+            "market.trade.executed"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    impl Projection for TradeCounts {
+        fn name(&self) -> &str {
+            "market/trade_counts"
+        }
+
+        fn handlers(&self) -> ProjectionHandlers<Self> {
+            ProjectionHandlers::new().on("market.trade.executed", |counts: &Self, event: &Traded| {
+                *counts.0.lock().unwrap().entry(event.symbol.clone()).or_default() += 1;
+                Ok(())
+            })
+        }
+
+        fn reset(&self) -> Result<()> {
+            self.0.lock().unwrap().clear();
+            Ok(())
+        }
+
+        fn persists_state(&self) -> bool {
+            true
+        }
+    }
+
+    fn trade(symbol: &str) -> EventEnvelope<Value> {
+        let event = Traded {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            symbol: symbol.to_string(),
+        };
+        envelope_of(&event, "test").unwrap()
+    }
+
+    #[test]
+    fn test_runner_resumes_from_file_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = Arc::new(FileEventLog::open(EventLogConfig::new(dir.path().join("log"))).unwrap());
+        let store = Arc::new(FileCheckpointStore::open(dir.path().join("checkpoints")).unwrap());
+        for symbol in ["BTC/USDT", "ETH/USDT", "BTC/USDT"] {
+            log.append(&trade(symbol)).unwrap();
+        }
+
+        {
+            let runner = ProjectionRunner::new(log.clone(), store.clone());
+            runner.register(Arc::new(TradeCounts::default())).unwrap();
+            assert_eq!(runner.catch_up().unwrap(), 3);
+            runner.checkpoint().unwrap();
+        }
+        assert!(store.path("market/trade_counts").ends_with("market_trade_counts.checkpoint.json"));
+        assert_eq!(store.load("market/trade_counts").unwrap().unwrap().offset, 3);
+
+        // 재시작하면 체크포인트 이후의 레코드만 적용
+        log.append(&trade("ETH/USDT")).unwrap();
+        let runner = ProjectionRunner::new(log.clone(), store.clone());
+        let counts = Arc::new(TradeCounts::default());
+        runner.register(counts.clone()).unwrap();
+        assert_eq!(runner.catch_up().unwrap(), 1);
+        assert_eq!(counts.0.lock().unwrap().get("ETH/USDT"), Some(&1));
+        assert_eq!(runner.lag("market/trade_counts"), Some(0));
+
+        // 손상된 체크포인트는 오류
+        fs::write(store.path("market/trade_counts"), b"{").unwrap();
+        assert!(store.load("market/trade_counts").is_err());
+    }
+}
@@ -0,0 +1,335 @@
+// codec.rs
+//
+// 이벤트 봉투 코덱 구현
+// MessagePack 코덱과 스키마 기반 이진 코덱, 그리고 설정된 코덱으로 인코딩하고 프레임 접두사로
+// 디코더를 고르는 WireCodec을 제공함
+//
+// 이진 코덱 본문 형식 (버전 1, 정수는 리틀 엔디언):
+//   [ID 16바이트][초 i64][나노초 u32][소스][이벤트 타입][상관 ID][원인 ID][헤더 버전][페이로드]
+//   문자열은 u16 길이 + UTF-8, 선택 ID는 u8 표시(0/1) + 16바이트
+//   페이로드는 필드 이름 없는 MessagePack 배열이므로 발행자와 구독자가 같은 페이로드 스키마를
+//   알아야 하며, 스키마를 바꿀 때는 헤더의 이벤트 버전을 올려 구분함
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{
+    split_frame, CodecKind, EnvelopeCodec, EnvelopeRef, EventEnvelope, EventHeader, EventSchemaError,
+    EventSchemaRegistry, HeaderOnly, JsonCodec, OwnedEnvelope,
+};
+use cryptolytica_shared_kernel::types::Result;
+
+/// MessagePack 코덱
+///
+/// 필드 이름을 포함한 맵으로 직렬화하므로 JSON과 같은 방식으로 필드 추가·생략을 견딥니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessagePackCodec;
+
+impl EnvelopeCodec for MessagePackCodec {
+    fn kind(&self) -> CodecKind {
+        CodecKind::MessagePack
+    }
+
+    fn encode_body<T: Serialize>(&self, header: &EventHeader, payload: &T, out: &mut Vec<u8>) -> Result<()> {
+        rmp_serde::encode::write_named(out, &EnvelopeRef { header, payload })
+            .map_err(|e| CoreError::Data(format!("MessagePack 직렬화 실패: {}", e)))
+    }
+
+    fn decode_header_body(&self, body: &[u8], _version: u8) -> Result<EventHeader> {
+        rmp_serde::from_slice::<HeaderOnly>(body)
+            .map(|envelope| envelope.header)
+            .map_err(|e| CoreError::Data(format!("MessagePack 헤더 역직렬화 실패: {}", e)))
+    }
+
+    fn decode_body<T: DeserializeOwned>(&self, body: &[u8], _version: u8) -> Result<(EventHeader, T)> {
+        rmp_serde::from_slice::<OwnedEnvelope<T>>(body)
+            .map(|envelope| (envelope.header, envelope.payload))
+            .map_err(|e| CoreError::Data(format!("MessagePack 역직렬화 실패: {}", e)))
+    }
+}
+
+/// 스키마 기반 이진 코덱
+///
+/// 헤더는 고정 배치로 직접 쓰고 페이로드는 필드 이름 없이 순서대로 씁니다.
+/// 페이로드 필드에 `skip_serializing_if`를 쓰면 순서가 어긋나므로 이 코덱에 쓰는 이벤트에는 두지 않습니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BinaryCodec;
+
+impl BinaryCodec {
+    fn put_str(out: &mut Vec<u8>, value: &str) -> Result<()> {
+        let len = u16::try_from(value.len())
+            .map_err(|_| CoreError::Data(format!("이진 헤더 문자열이 너무 김: {}바이트", value.len())))?;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(value.as_bytes());
+        Ok(())
+    }
+
+    fn put_optional_id(out: &mut Vec<u8>, value: Option<Uuid>) {
+        match value {
+            Some(id) => {
+                out.push(1);
+                out.extend_from_slice(id.as_bytes());
+            }
+            None => out.push(0),
+        }
+    }
+
+    fn encode_header(header: &EventHeader, out: &mut Vec<u8>) -> Result<()> {
+        out.extend_from_slice(header.id.as_bytes());
+        out.extend_from_slice(&header.timestamp.timestamp().to_le_bytes());
+        out.extend_from_slice(&header.timestamp.timestamp_subsec_nanos().to_le_bytes());
+        Self::put_str(out, &header.source)?;
+        Self::put_str(out, &header.event_type)?;
+        Self::put_optional_id(out, header.correlation_id);
+        Self::put_optional_id(out, header.causation_id);
+        Self::put_str(out, &header.version)
+    }
+
+    /// 헤더를 읽고 나머지(페이로드) 반환
+    fn decode_header(body: &[u8]) -> Result<(EventHeader, &[u8])> {
+        let mut reader = Reader { bytes: body };
+        let id = reader.id()?;
+        let secs = i64::from_le_bytes(reader.array()?);
+        let nanos = u32::from_le_bytes(reader.array()?);
+        let timestamp = DateTime::<Utc>::from_timestamp(secs, nanos)
+            .ok_or_else(|| CoreError::Data(format!("이진 헤더의 시각이 범위를 벗어남: {}.{}", secs, nanos)))?;
+        let source = reader.string()?;
+        let event_type = reader.string()?;
+        let correlation_id = reader.optional_id()?;
+        let causation_id = reader.optional_id()?;
+        let version = reader.string()?;
+
+        let header = EventHeader {
+            id,
+            timestamp,
+            source,
+            event_type,
+            correlation_id,
+            causation_id,
+            version,
+            codec: None,
+        };
+        Ok((header, reader.bytes))
+    }
+}
+
+/// 이진 헤더 읽기 도우미
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(CoreError::Data("이진 헤더가 잘림".to_string()));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().expect("길이 확인됨"))
+    }
+
+    fn id(&mut self) -> Result<Uuid> {
+        Ok(Uuid::from_bytes(self.array()?))
+    }
+
+    fn optional_id(&mut self) -> Result<Option<Uuid>> {
+        match self.array::<1>()?[0] {
+            0 => Ok(None),
+            1 => self.id().map(Some),
+            flag => Err(CoreError::Data(format!("이진 헤더의 선택 ID 표시가 잘못됨: {}", flag))),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = u16::from_le_bytes(self.array()?) as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| CoreError::Data(format!("이진 헤더 문자열 오류: {}", e)))
+    }
+}
+
+impl EnvelopeCodec for BinaryCodec {
+    fn kind(&self) -> CodecKind {
+        CodecKind::Binary
+    }
+
+    fn encode_body<T: Serialize>(&self, header: &EventHeader, payload: &T, out: &mut Vec<u8>) -> Result<()> {
+        Self::encode_header(header, out)?;
+        rmp_serde::encode::write(out, payload).map_err(|e| CoreError::Data(format!("이진 페이로드 직렬화 실패: {}", e)))
+    }
+
+    fn decode_header_body(&self, body: &[u8], _version: u8) -> Result<EventHeader> {
+        Self::decode_header(body).map(|(header, _)| header)
+    }
+
+    fn decode_body<T: DeserializeOwned>(&self, body: &[u8], _version: u8) -> Result<(EventHeader, T)> {
+        let (header, payload) = Self::decode_header(body)?;
+        let payload = rmp_serde::from_slice(payload)
+            .map_err(|e| CoreError::Data(format!("이진 페이로드 역직렬화 실패 ({}): {}", header.event_type, e)))?;
+        Ok((header, payload))
+    }
+}
+
+/// 전송용 코덱 선택기
+///
+/// 발행은 설정된 코덱으로 하고, 수신은 설정과 관계없이 프레임 접두사로 코덱을 고르므로
+/// 코덱이 다른 발행자가 섞여 있어도 같은 구독에서 처리할 수 있습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WireCodec {
+    kind: CodecKind,
+}
+
+impl WireCodec {
+    /// 발행에 쓸 코덱 지정
+    pub fn new(kind: CodecKind) -> Self {
+        Self { kind }
+    }
+
+    /// 발행에 쓰는 코덱
+    pub fn kind(&self) -> CodecKind {
+        self.kind
+    }
+
+    /// 발행 메시지의 콘텐츠 타입
+    pub fn content_type(&self) -> &'static str {
+        self.kind.content_type()
+    }
+
+    /// 설정된 코덱으로 프레임 직렬화
+    pub fn encode<T: Serialize>(&self, header: &EventHeader, payload: &T) -> Result<Vec<u8>> {
+        match self.kind {
+            CodecKind::Json => JsonCodec.encode(header, payload),
+            CodecKind::MessagePack => MessagePackCodec.encode(header, payload),
+            CodecKind::Binary => BinaryCodec.encode(header, payload),
+        }
+    }
+
+    /// 프레임의 코덱으로 헤더만 읽기
+    pub fn decode_header(frame: &[u8]) -> Result<EventHeader> {
+        match split_frame(frame)?.0 {
+            CodecKind::Json => JsonCodec.decode_header(frame),
+            CodecKind::MessagePack => MessagePackCodec.decode_header(frame),
+            CodecKind::Binary => BinaryCodec.decode_header(frame),
+        }
+    }
+
+    /// 프레임의 코덱으로 헤더와 페이로드 읽기
+    pub fn decode_parts<T: DeserializeOwned>(frame: &[u8]) -> Result<(EventHeader, T)> {
+        match split_frame(frame)?.0 {
+            CodecKind::Json => JsonCodec.decode_parts(frame),
+            CodecKind::MessagePack => MessagePackCodec.decode_parts(frame),
+            CodecKind::Binary => BinaryCodec.decode_parts(frame),
+        }
+    }
+
+    /// 프레임의 코덱으로 헤더와 페이로드를 읽되, 이전 스키마 버전이면 업캐스터를 먼저 적용
+    ///
+    /// 현재 버전이거나 저장소에 없는 이벤트 타입은 `decode_parts`와 같습니다. 이진 코덱 페이로드는
+    /// 필드 이름이 없어 업캐스트할 수 없으므로 이전 버전이면 오류를 반환합니다.
+    pub fn decode_upcast<T: DeserializeOwned>(frame: &[u8], schemas: &EventSchemaRegistry) -> Result<(EventHeader, T)> {
+        let header = Self::decode_header(frame)?;
+        let Some(current) = schemas.current_version(&header.event_type) else {
+            return Self::decode_parts(frame);
+        };
+        let version = header
+            .schema_version()
+            .ok_or_else(|| EventSchemaError::InvalidVersion(header.version.clone()))?;
+        if version == current {
+            return Self::decode_parts(frame);
+        }
+
+        let (header, payload) = match split_frame(frame)?.0 {
+            CodecKind::Json => JsonCodec.decode_parts::<Value>(frame)?,
+            CodecKind::MessagePack => MessagePackCodec.decode_parts::<Value>(frame)?,
+            CodecKind::Binary => {
+                return Err(CoreError::Data(format!(
+                    "이진 코덱 페이로드는 업캐스트할 수 없음: {} v{} (현재 v{})",
+                    header.event_type, version, current
+                )));
+            }
+        };
+        let payload = schemas.upcast(&header.event_type, version, payload)?;
+        let payload = serde_json::from_value(payload)
+            .map_err(|e| CoreError::Data(format!("업캐스트한 페이로드 역직렬화 실패 ({}): {}", header.event_type, e)))?;
+        Ok((header, payload))
+    }
+
+    /// 프레임의 코덱으로 봉투 읽기
+    pub fn decode<T: Serialize + DeserializeOwned>(frame: &[u8]) -> Result<EventEnvelope<T>> {
+        let (header, payload) = Self::decode_parts(frame)?;
+        Ok(EventEnvelope { header, payload })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use cryptolytica_shared_kernel::events::{create_event_header, FRAME_MAGIC};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Level {
+        price: String,
+        quantity: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct BookSnapshot {
+        symbol: String,
+        sequence: u64,
+        bids: Vec<Level>,
+        asks: Vec<Level>,
+        checksum: Option<u32>,
+    }
+
+    fn book() -> BookSnapshot {
+        let level = |i: u32| Level {
+            price: format!("{}.{}", 42_000 + i, i),
+            quantity: 0.25 * f64::from(i + 1),
+        };
+        BookSnapshot {
+            symbol: "BTC-USDT".to_string(),
+            sequence: 987_654_321,
+            bids: (0..20).map(level).collect(),
+            asks: (20..40).map(level).collect(),
+            checksum: None,
+        }
+    }
+
+    #[test]
+    fn test_mixed_codecs_round_trip() {
+        let mut header = create_event_header("market-data", "market.orderbook.snapshot", Some(Uuid::new_v4()));
+        header.causation_id = Some(Uuid::new_v4());
+        header.version = "2".to_string();
+
+        let mut sizes = Vec::new();
+        for kind in [CodecKind::Json, CodecKind::MessagePack, CodecKind::Binary] {
+            let frame = WireCodec::new(kind).encode(&header, &book()).unwrap();
+            assert_eq!(frame[0] == FRAME_MAGIC, kind != CodecKind::Json);
+            sizes.push(frame.len());
+
+            // 수신 측은 어느 코덱이든 프레임만 보고 읽음
+            let envelope: EventEnvelope<BookSnapshot> = WireCodec::decode(&frame).unwrap();
+            assert_eq!(envelope.payload, book());
+            assert_eq!(envelope.header.id, header.id);
+            assert_eq!(envelope.header.timestamp, header.timestamp);
+            assert_eq!(envelope.header.causation_id, header.causation_id);
+            assert_eq!(envelope.header.version, "2");
+            assert_eq!(envelope.header.codec, Some(format!("{}/1", kind)));
+            assert_eq!(WireCodec::decode_header(&frame).unwrap().event_type, "market.orderbook.snapshot");
+        }
+        // 필드 이름을 빼는 만큼 작아짐
+        assert!(sizes[0] > sizes[1] && sizes[1] > sizes[2], "{:?}", sizes);
+
+        // 다른 코덱의 디코더는 프레임을 거부하고, 잘린 이진 프레임은 오류
+        let frame = BinaryCodec.encode(&header, &book()).unwrap();
+        assert!(MessagePackCodec.decode_header(&frame).is_err());
+        assert!(WireCodec::decode_header(&frame[..20]).is_err());
+    }
+}
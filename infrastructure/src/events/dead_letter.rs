@@ -0,0 +1,205 @@
+// dead_letter.rs
+//
+// 인메모리 데드 레터 저장소 구현
+// 목록은 첫 실패 시각 순이며, 용량을 넘으면 첫 실패가 가장 오래된 데드 레터부터 버림
+
+use std::collections::VecDeque;
+use std::sync::RwLock;
+use uuid::Uuid;
+use cryptolytica_shared_kernel::events::{DeadLetter, DeadLetterStore};
+use cryptolytica_shared_kernel::types::Result;
+
+/// 인메모리 데드 레터 저장소
+#[derive(Debug)]
+pub struct InMemoryDeadLetterStore {
+    letters: RwLock<VecDeque<DeadLetter>>,
+    capacity: usize,
+}
+
+impl Default for InMemoryDeadLetterStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryDeadLetterStore {
+    /// 기본 용량(10,000건)으로 생성
+    pub fn new() -> Self {
+        Self::with_capacity(10_000)
+    }
+
+    /// 용량을 지정하여 생성 (0은 1로 보정)
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            letters: RwLock::new(VecDeque::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 보관 중인 데드 레터 수
+    pub fn len(&self) -> usize {
+        self.letters.read().unwrap().len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl DeadLetterStore for InMemoryDeadLetterStore {
+    fn put(&self, letter: DeadLetter) -> Result<()> {
+        let mut letters = self.letters.write().unwrap();
+
+        if let Some(existing) = letters.iter_mut().find(|existing| existing.id == letter.id) {
+            *existing = letter;
+            return Ok(());
+        }
+
+        if letters.len() >= self.capacity {
+            let oldest = letters
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, letter)| letter.first_failed_at)
+                .map(|(index, _)| index);
+            if let Some(evicted) = oldest.and_then(|index| letters.remove(index)) {
+                tracing::warn!(
+                    "데드 레터 저장소 용량 초과로 가장 오래된 항목 폐기: {} ({})",
+                    evicted.id,
+                    evicted.event_type()
+                );
+            }
+        }
+        letters.push_back(letter);
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<DeadLetter>> {
+        let mut letters: Vec<_> = self.letters.read().unwrap().iter().cloned().collect();
+        letters.sort_by_key(|letter| letter.first_failed_at);
+        Ok(letters)
+    }
+
+    fn get(&self, id: &Uuid) -> Result<Option<DeadLetter>> {
+        Ok(self.letters.read().unwrap().iter().find(|letter| letter.id == *id).cloned())
+    }
+
+    fn remove(&self, id: &Uuid) -> Result<Option<DeadLetter>> {
+        let mut letters = self.letters.write().unwrap();
+        Ok(letters
+            .iter()
+            .position(|letter| letter.id == *id)
+            .and_then(|index| letters.remove(index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use cryptolytica_shared_kernel::events::{create_event_header, EventEnvelope};
+    use serde_json::json;
+
+    fn letter(error: &str) -> DeadLetter {
+        let now = Utc::now();
+        DeadLetter {
+            id: Uuid::new_v4(),
+            subscription_id: Uuid::new_v4(),
+            subscription: "fills".to_string(),
+            envelope: EventEnvelope {
+                header: create_event_header("test", "exchange.order.filled", None),
+                payload: json!({ "order_id": "order-1" }),
+            },
+            error: error.to_string(),
+            attempts: 3,
+            first_failed_at: now,
+            last_failed_at: now,
+        }
+    }
+
+    #[test]
+    fn test_put_replaces_same_id() {
+        let store = InMemoryDeadLetterStore::new();
+        let first = letter("timeout");
+        let other = letter("rejected");
+        store.put(first.clone()).unwrap();
+        store.put(other.clone()).unwrap();
+
+        let mut retried = first.clone();
+        retried.error = "still failing".to_string();
+        retried.attempts = 4;
+        store.put(retried).unwrap();
+
+        // 같은 ID는 자리를 유지한 채 교체
+        assert_eq!(store.len(), 2);
+        let ids: Vec<_> = store.list().unwrap().iter().map(|letter| letter.id).collect();
+        assert_eq!(ids, [first.id, other.id]);
+        let stored = store.get(&first.id).unwrap().unwrap();
+        assert_eq!((stored.error.as_str(), stored.attempts), ("still failing", 4));
+        assert_eq!(stored.event_type(), "exchange.order.filled");
+    }
+
+    #[test]
+    fn test_capacity_evicts_oldest() {
+        let store = InMemoryDeadLetterStore::with_capacity(2);
+        let letters: Vec<_> = (0..3).map(|i| letter(&format!("error-{}", i))).collect();
+        for letter in &letters {
+            store.put(letter.clone()).unwrap();
+        }
+
+        assert_eq!(store.len(), 2);
+        assert!(store.get(&letters[0].id).unwrap().is_none());
+        let ids: Vec<_> = store.list().unwrap().iter().map(|letter| letter.id).collect();
+        assert_eq!(ids, [letters[1].id, letters[2].id]);
+
+        // 0은 1로 보정
+        let single = InMemoryDeadLetterStore::with_capacity(0);
+        single.put(letter("a")).unwrap();
+        single.put(letter("b")).unwrap();
+        assert_eq!(single.len(), 1);
+    }
+
+    #[test]
+    fn test_list_orders_by_first_failure() {
+        let store = InMemoryDeadLetterStore::with_capacity(2);
+        let mut late = letter("late");
+        late.first_failed_at += Duration::seconds(10);
+        let early = letter("early");
+        store.put(late.clone()).unwrap();
+        store.put(early.clone()).unwrap();
+
+        // 교체해도 첫 실패 시각 순서를 유지
+        let mut retried = late.clone();
+        retried.attempts = 4;
+        retried.last_failed_at += Duration::seconds(20);
+        store.put(retried).unwrap();
+        let ids: Vec<_> = store.list().unwrap().iter().map(|letter| letter.id).collect();
+        assert_eq!(ids, [early.id, late.id]);
+
+        // 용량 초과 시 첫 실패가 가장 오래된 항목을 버림
+        let middle = letter("middle");
+        store.put(middle.clone()).unwrap();
+        let ids: Vec<_> = store.list().unwrap().iter().map(|letter| letter.id).collect();
+        assert_eq!(ids, [middle.id, late.id]);
+    }
+
+    #[test]
+    fn test_get_and_remove() {
+        let store = InMemoryDeadLetterStore::new();
+        let kept = letter("kept");
+        let removed = letter("removed");
+        store.put(kept.clone()).unwrap();
+        store.put(removed.clone()).unwrap();
+
+        assert_eq!(store.get(&kept.id).unwrap().unwrap().error, "kept");
+        assert!(store.get(&Uuid::new_v4()).unwrap().is_none());
+
+        assert_eq!(store.remove(&removed.id).unwrap().unwrap().id, removed.id);
+        assert!(store.remove(&removed.id).unwrap().is_none());
+        assert!(store.get(&removed.id).unwrap().is_none());
+        assert_eq!(store.len(), 1);
+
+        store.remove(&kept.id).unwrap();
+        assert!(store.is_empty());
+    }
+}
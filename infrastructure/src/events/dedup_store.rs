@@ -0,0 +1,171 @@
+// dedup_store.rs
+//
+// 파일 기반 중복 제거 저장소
+// 처리한 멱등 키를 JSON 줄 단위로 추가 기록하여 재시작 후에도 중복 제거 창을 복원함.
+// 창 밖으로 밀려난 키가 쌓이면 남은 키만 다시 써서 파일을 압축함
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use cryptolytica_shared_kernel::events::{DedupStore, DedupWindow, InMemoryDedupStore};
+use cryptolytica_shared_kernel::types::Result;
+
+/// 파일에 기록되는 한 줄
+#[derive(Debug, Serialize, Deserialize)]
+struct DedupLine {
+    key: String,
+    /// 기록 시각 (제거 줄이면 없음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seen_at: Option<DateTime<Utc>>,
+}
+
+/// 파일 기반 중복 제거 저장소
+#[derive(Debug)]
+pub struct FileDedupStore {
+    path: PathBuf,
+    memory: InMemoryDedupStore,
+    file: Mutex<(File, usize)>,
+}
+
+impl FileDedupStore {
+    /// 파일을 열어 창을 복원 (없으면 생성)
+    ///
+    /// 손상된 줄(쓰기 도중 종료 등)은 건너뜁니다.
+    pub fn open(path: impl AsRef<Path>, window: DedupWindow) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let memory = InMemoryDedupStore::new(window);
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                let Ok(line) = serde_json::from_str::<DedupLine>(&line?) else {
+                    tracing::warn!("중복 제거 파일의 손상된 줄 건너뜀: {}", path.display());
+                    continue;
+                };
+                match line.seen_at {
+                    Some(seen_at) => {
+                        memory.insert_if_absent(&line.key, seen_at)?;
+                    }
+                    None => memory.remove(&line.key)?,
+                }
+            }
+        }
+
+        let file = Self::rewrite(&path, &memory)?;
+        tracing::info!("중복 제거 창 복원: {} (키 {}개)", path.display(), memory.len());
+
+        Ok(Self {
+            path,
+            file: Mutex::new((file, memory.len())),
+            memory,
+        })
+    }
+
+    /// 파일 경로
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 기억 중인 키 수
+    pub fn len(&self) -> usize {
+        self.memory.len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_empty()
+    }
+
+    /// 창 안의 키만 남기도록 파일을 다시 써서 추가 모드로 엶
+    fn rewrite(path: &Path, memory: &InMemoryDedupStore) -> Result<File> {
+        let temp = path.with_extension("compact");
+        {
+            let mut out = File::create(&temp)?;
+            for (key, seen_at) in memory.entries() {
+                Self::write_line(&mut out, &DedupLine { key, seen_at: Some(seen_at) })?;
+            }
+            out.sync_data()?;
+        }
+        fs::rename(&temp, path)?;
+        Ok(OpenOptions::new().append(true).open(path)?)
+    }
+
+    fn write_line(out: &mut File, line: &DedupLine) -> Result<()> {
+        let mut bytes = serde_json::to_vec(line)?;
+        bytes.push(b'\n');
+        out.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// 한 줄 추가 후, 줄 수가 창 크기의 두 배를 넘으면 압축
+    fn append(&self, line: DedupLine) -> Result<()> {
+        let mut file = self.file.lock().unwrap();
+        Self::write_line(&mut file.0, &line)?;
+        file.1 += 1;
+
+        if file.1 > self.memory.window().max_entries.saturating_mul(2) {
+            file.0 = Self::rewrite(&self.path, &self.memory)?;
+            file.1 = self.memory.len();
+        }
+        Ok(())
+    }
+}
+
+impl DedupStore for FileDedupStore {
+    fn insert_if_absent(&self, key: &str, seen_at: DateTime<Utc>) -> Result<bool> {
+        let inserted = self.memory.insert_if_absent(key, seen_at)?;
+        if inserted {
+            self.append(DedupLine {
+                key: key.to_string(),
+                seen_at: Some(seen_at),
+            })?;
+        }
+        Ok(inserted)
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        self.memory.remove(key)?;
+        self.append(DedupLine {
+            key: key.to_string(),
+            seen_at: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_window_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("order-cache.dedup");
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        {
+            let store = FileDedupStore::open(&path, DedupWindow::new(3)).unwrap();
+            for key in ["a", "b", "c", "d", "e", "f", "g"] {
+                assert!(store.insert_if_absent(key, now).unwrap());
+            }
+            store.remove("g").unwrap();
+        }
+
+        let store = FileDedupStore::open(&path, DedupWindow::new(3)).unwrap();
+        assert_eq!(store.len(), 2);
+        assert!(!store.insert_if_absent("e", now).unwrap());
+        assert!(!store.insert_if_absent("f", now).unwrap());
+        assert!(store.insert_if_absent("g", now).unwrap());
+        assert!(store.insert_if_absent("a", now).unwrap());
+
+        // 압축 후 파일에는 창 안의 키만 남음
+        let lines = fs::read_to_string(&path).unwrap().lines().count();
+        assert!(lines <= 6);
+    }
+}
@@ -0,0 +1,307 @@
+// embedded_broker.rs
+//
+// 프로세스 내장 브로커
+// AMQP 토픽 익스체인지와 같은 의미(패턴 바인딩, 소비자 그룹 대기열, ack/nack)를 메모리에서 흉내 내어
+// 외부 브로커 없이 BrokerEventBus를 개발·테스트할 수 있게 함
+//
+// 바인딩된 그룹이 없는 토픽의 메시지는 버려지고, 확인되지 않은 채 사라진 메시지는 대기열 앞으로
+// 되돌아감. 다시 넣지 않고 거부된 메시지는 그룹별 데드 레터로 보관함
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use async_trait::async_trait;
+use tokio::sync::Notify;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::TopicPattern;
+use cryptolytica_shared_kernel::types::Result;
+
+use super::broker::{
+    Acknowledger, BrokerConsumer, BrokerDelivery, BrokerTransport, ConsumerBinding, OutgoingMessage,
+};
+
+/// 그룹 대기열 상태
+#[derive(Default)]
+struct QueueState {
+    /// 전달을 기다리는 메시지 (다시 전달되는 메시지인지 여부 포함)
+    ready: VecDeque<(OutgoingMessage, bool)>,
+    /// 전달되었지만 아직 확인되지 않은 메시지 수
+    unacked: usize,
+    dead: Vec<OutgoingMessage>,
+}
+
+/// 소비자 그룹 대기열
+struct GroupQueue {
+    patterns: RwLock<Vec<TopicPattern>>,
+    state: Mutex<QueueState>,
+    notify: Notify,
+    /// 브로커 가용 여부 (브로커와 공유)
+    available: Arc<AtomicBool>,
+}
+
+impl GroupQueue {
+    fn matches(&self, topic: &str) -> bool {
+        self.patterns.read().unwrap().iter().any(|pattern| pattern.matches(topic))
+    }
+
+    fn push(&self, message: OutgoingMessage) {
+        self.state.lock().unwrap().ready.push_back((message, false));
+        self.notify.notify_waiters();
+    }
+
+    fn settle(&self, message: OutgoingMessage, requeue: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.unacked = state.unacked.saturating_sub(1);
+        if requeue {
+            state.ready.push_front((message, true));
+            drop(state);
+            self.notify.notify_waiters();
+        } else {
+            state.dead.push(message);
+        }
+    }
+}
+
+/// 내장 브로커의 메시지 확인 처리 (확인 없이 사라지면 대기열로 되돌림)
+struct EmbeddedAcker {
+    queue: Arc<GroupQueue>,
+    message: Option<OutgoingMessage>,
+}
+
+#[async_trait]
+impl Acknowledger for EmbeddedAcker {
+    async fn ack(mut self: Box<Self>) -> Result<()> {
+        if self.message.take().is_some() {
+            let mut state = self.queue.state.lock().unwrap();
+            state.unacked = state.unacked.saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    async fn nack(mut self: Box<Self>, requeue: bool) -> Result<()> {
+        if let Some(message) = self.message.take() {
+            self.queue.settle(message, requeue);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for EmbeddedAcker {
+    fn drop(&mut self) {
+        if let Some(message) = self.message.take() {
+            self.queue.settle(message, true);
+        }
+    }
+}
+
+/// 내장 브로커 소비자 (같은 그룹의 소비자들은 한 대기열을 나눠 소비)
+struct EmbeddedConsumer {
+    queue: Arc<GroupQueue>,
+}
+
+#[async_trait]
+impl BrokerConsumer for EmbeddedConsumer {
+    async fn next(&mut self) -> Option<Result<BrokerDelivery>> {
+        loop {
+            let notified = self.queue.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if !self.queue.available.load(Ordering::Acquire) {
+                return None;
+            }
+            {
+                let mut state = self.queue.state.lock().unwrap();
+                if let Some((message, redelivered)) = state.ready.pop_front() {
+                    state.unacked += 1;
+                    let acker = EmbeddedAcker {
+                        queue: self.queue.clone(),
+                        message: Some(message.clone()),
+                    };
+                    return Some(Ok(BrokerDelivery::new(
+                        message.topic,
+                        message.payload,
+                        redelivered,
+                        Box::new(acker),
+                    )));
+                }
+            }
+
+            notified.await;
+        }
+    }
+}
+
+/// 프로세스 내장 브로커
+///
+/// 대기열 용량과 prefetch는 제한하지 않습니다.
+pub struct EmbeddedBroker {
+    groups: RwLock<HashMap<String, Arc<GroupQueue>>>,
+    available: Arc<AtomicBool>,
+}
+
+impl Default for EmbeddedBroker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmbeddedBroker {
+    /// 새로운 내장 브로커 생성
+    pub fn new() -> Self {
+        Self {
+            groups: RwLock::new(HashMap::new()),
+            available: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    /// 브로커 장애 흉내 (사용 불가 동안 발행·구독은 실패하고 열린 소비자는 닫힘)
+    pub fn set_available(&self, available: bool) {
+        self.available.store(available, Ordering::Release);
+        if !available {
+            for queue in self.groups.read().unwrap().values() {
+                queue.notify.notify_waiters();
+            }
+        }
+    }
+
+    /// 소비자 그룹 대기열이 있는지 여부
+    pub fn has_group(&self, group: &str) -> bool {
+        self.groups.read().unwrap().contains_key(group)
+    }
+
+    /// 그룹에서 전달을 기다리거나 확인되지 않은 메시지 수
+    pub fn pending(&self, group: &str) -> usize {
+        self.groups
+            .read()
+            .unwrap()
+            .get(group)
+            .map(|queue| {
+                let state = queue.state.lock().unwrap();
+                state.ready.len() + state.unacked
+            })
+            .unwrap_or(0)
+    }
+
+    /// 그룹에서 다시 넣지 않고 거부된 메시지
+    pub fn dead_letters(&self, group: &str) -> Vec<OutgoingMessage> {
+        self.groups
+            .read()
+            .unwrap()
+            .get(group)
+            .map(|queue| queue.state.lock().unwrap().dead.clone())
+            .unwrap_or_default()
+    }
+
+    fn ensure_available(&self) -> Result<()> {
+        if !self.available.load(Ordering::Acquire) {
+            return Err(CoreError::Unavailable {
+                message: "내장 브로커를 사용할 수 없습니다".to_string(),
+                retry_after: None,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BrokerTransport for EmbeddedBroker {
+    fn name(&self) -> &str {
+        "embedded"
+    }
+
+    async fn publish(&self, message: OutgoingMessage) -> Result<()> {
+        self.ensure_available()?;
+        for queue in self.groups.read().unwrap().values() {
+            if queue.matches(&message.topic) {
+                queue.push(message.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn consume(&self, binding: &ConsumerBinding) -> Result<Box<dyn BrokerConsumer>> {
+        self.ensure_available()?;
+        let pattern = TopicPattern::new(&binding.pattern)?;
+
+        let queue = self
+            .groups
+            .write()
+            .unwrap()
+            .entry(binding.group.clone())
+            .or_insert_with(|| {
+                Arc::new(GroupQueue {
+                    patterns: RwLock::new(Vec::new()),
+                    state: Mutex::new(QueueState::default()),
+                    notify: Notify::new(),
+                    available: self.available.clone(),
+                })
+            })
+            .clone();
+
+        {
+            let mut patterns = queue.patterns.write().unwrap();
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+        Ok(Box::new(EmbeddedConsumer { queue }))
+    }
+
+    async fn close(&self) -> Result<()> {
+        self.set_available(false);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn message(topic: &str) -> OutgoingMessage {
+        OutgoingMessage {
+            topic: topic.to_string(),
+            key: None,
+            event_id: Uuid::new_v4(),
+            content_type: "text/plain",
+            payload: topic.as_bytes().to_vec(),
+        }
+    }
+
+    fn binding(group: &str, pattern: &str) -> ConsumerBinding {
+        ConsumerBinding {
+            group: group.to_string(),
+            pattern: pattern.to_string(),
+            prefetch: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unacked_messages_are_redelivered() {
+        let broker = EmbeddedBroker::new();
+        let mut consumer = broker.consume(&binding("fills", "exchange.order.*")).await.unwrap();
+
+        broker.publish(message("exchange.order.filled")).await.unwrap();
+        broker.publish(message("market.price.updated")).await.unwrap();
+        assert_eq!(broker.pending("fills"), 1);
+
+        // 확인하지 않고 버리면 다시 전달됨
+        let delivery = consumer.next().await.unwrap().unwrap();
+        assert!(!delivery.redelivered);
+        drop(delivery);
+
+        let delivery = consumer.next().await.unwrap().unwrap();
+        assert!(delivery.redelivered);
+        delivery.nack(true).await.unwrap();
+
+        let delivery = consumer.next().await.unwrap().unwrap();
+        delivery.nack(false).await.unwrap();
+        assert_eq!(broker.pending("fills"), 0);
+        assert_eq!(broker.dead_letters("fills").len(), 1);
+
+        broker.close().await.unwrap();
+        assert!(consumer.next().await.is_none());
+        assert!(broker.publish(message("exchange.order.filled")).await.is_err());
+    }
+}
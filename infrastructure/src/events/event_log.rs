@@ -0,0 +1,952 @@
+// event_log.rs
+//
+// 파일 기반 세그먼트 이벤트 로그
+// 각 EventEnvelope를 단조 증가 오프셋, 기록 시각, CRC32 체크섬과 함께 세그먼트 파일에 추가하고,
+// 재시작 후 오프셋이나 시각부터 재생하여 읽기 모델(MarketDataCache, OrderCache 등)을 다시 구성
+//
+// - 세그먼트 파일 이름: 첫 오프셋을 20자리로 채운 `{base_offset}.log`
+// - 레코드 형식 (리틀 엔디언): [본문 길이 u32][CRC32 u32][오프셋 u64][기록 시각 ms i64][봉투 JSON]
+//   체크섬은 오프셋부터 본문 끝까지를 덮음
+// - 열 때 마지막 세그먼트의 잘린 꼬리 레코드는 잘라내고, 그 외의 손상은 오류로 보고
+// - 쓰기나 동기화가 실패하면 활성 세그먼트를 마지막 레코드 끝으로 되돌리고, 되돌리기마저 실패하면
+//   이후 추가를 거부 (잘린 레코드 뒤에 새 레코드가 쌓이면 다시 열 때 확인된 레코드까지 잘려 나감)
+// - 보존 정책은 활성 세그먼트를 제외한 닫힌 세그먼트를 통째로 삭제
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, Read, Seek, SeekFrom, Write};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use cryptolytica_shared_kernel::clock::{system_clock, SharedClock};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{envelope_of, EnvelopeHandler, Event, EventEnvelope, ProjectionSource};
+
+/// 레코드 헤더 크기 (길이 + CRC + 오프셋 + 시각)
+const RECORD_HEADER_LEN: usize = 4 + 4 + 8 + 8;
+/// 세그먼트 파일 확장자
+const SEGMENT_EXTENSION: &str = "log";
+
+/// 이벤트 로그 오류
+#[derive(Error, Debug)]
+pub enum EventLogError {
+    #[error("이벤트 로그 I/O 오류: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("이벤트 봉투 직렬화 오류: {0}")]
+    Serialization(String),
+
+    #[error("손상된 세그먼트 {segment} (위치 {position}): {reason}")]
+    Corrupt {
+        segment: PathBuf,
+        position: u64,
+        reason: String,
+    },
+
+    #[error("보존 정책으로 삭제된 오프셋: {requested} (가장 오래된 오프셋 {first})")]
+    OffsetOutOfRange { requested: u64, first: u64 },
+
+    #[error("실패한 쓰기를 되돌리지 못해 추가할 수 없는 로그: {0}")]
+    Poisoned(String),
+}
+
+impl From<EventLogError> for CoreError {
+    fn from(error: EventLogError) -> Self {
+        match error {
+            EventLogError::Io(e) => CoreError::Io(e),
+            EventLogError::OffsetOutOfRange { .. } => CoreError::NotFound(error.to_string()),
+            EventLogError::Poisoned(_) => CoreError::Unavailable {
+                message: error.to_string(),
+                retry_after: None,
+            },
+            _ => CoreError::Data(error.to_string()),
+        }
+    }
+}
+
+type LogResult<T> = std::result::Result<T, EventLogError>;
+
+/// 보존 정책 (둘 다 지정하면 어느 하나라도 넘는 세그먼트를 삭제)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// 마지막 레코드가 이 기간보다 오래된 세그먼트 삭제
+    pub max_age: Option<Duration>,
+    /// 전체 로그 크기가 이 값을 넘으면 오래된 세그먼트부터 삭제
+    pub max_bytes: Option<u64>,
+}
+
+/// 이벤트 로그 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventLogConfig {
+    /// 세그먼트 파일 디렉터리
+    pub dir: PathBuf,
+    /// 세그먼트 최대 크기 (넘으면 새 세그먼트로 교체)
+    pub segment_bytes: u64,
+    /// 보존 정책
+    pub retention: RetentionPolicy,
+    /// 레코드마다 디스크 동기화 여부
+    pub sync_on_append: bool,
+}
+
+impl EventLogConfig {
+    /// 기본 설정 (세그먼트 64MiB, 무기한 보존, 동기화 안 함)
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            segment_bytes: 64 * 1024 * 1024,
+            retention: RetentionPolicy::default(),
+            sync_on_append: false,
+        }
+    }
+
+    /// 세그먼트 최대 크기 지정
+    pub fn with_segment_bytes(mut self, segment_bytes: u64) -> Self {
+        self.segment_bytes = segment_bytes.max(1);
+        self
+    }
+
+    /// 보존 정책 지정
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// 레코드마다 디스크 동기화 여부 지정
+    pub fn with_sync_on_append(mut self, sync_on_append: bool) -> Self {
+        self.sync_on_append = sync_on_append;
+        self
+    }
+}
+
+/// 로그 레코드
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    /// 오프셋
+    pub offset: u64,
+    /// 로그에 기록된 시각
+    pub appended_at: DateTime<Utc>,
+    /// 이벤트 봉투
+    pub envelope: EventEnvelope<Value>,
+}
+
+/// 재생 시작 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayFrom {
+    /// 남아 있는 가장 오래된 레코드부터
+    Beginning,
+    /// 지정한 오프셋부터
+    Offset(u64),
+    /// 지정한 시각 이후에 기록된 레코드부터
+    Time(DateTime<Utc>),
+}
+
+/// 보존 정책 적용 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionReport {
+    /// 삭제된 세그먼트 수
+    pub removed_segments: usize,
+    /// 삭제된 바이트 수
+    pub removed_bytes: u64,
+    /// 적용 후 가장 오래된 오프셋
+    pub first_offset: u64,
+}
+
+/// 세그먼트 메타데이터
+#[derive(Debug, Clone)]
+struct Segment {
+    base_offset: u64,
+    path: PathBuf,
+    size: u64,
+    first_appended: Option<DateTime<Utc>>,
+    last_appended: Option<DateTime<Utc>>,
+}
+
+impl Segment {
+    fn new(dir: &Path, base_offset: u64) -> Self {
+        Self {
+            base_offset,
+            path: dir.join(format!("{:020}.{}", base_offset, SEGMENT_EXTENSION)),
+            size: 0,
+            first_appended: None,
+            last_appended: None,
+        }
+    }
+}
+
+/// 활성 세그먼트 파일 (테스트에서 쓰기 실패를 주입할 수 있게 추상화)
+trait SegmentFile: Write + Seek + Send + fmt::Debug {
+    fn sync_data(&self) -> io::Result<()>;
+    fn set_len(&self, size: u64) -> io::Result<()>;
+}
+
+impl SegmentFile for File {
+    fn sync_data(&self) -> io::Result<()> {
+        File::sync_data(self)
+    }
+
+    fn set_len(&self, size: u64) -> io::Result<()> {
+        File::set_len(self, size)
+    }
+}
+
+/// 쓰기 상태
+#[derive(Debug)]
+struct LogState {
+    segments: Vec<Segment>,
+    active: Box<dyn SegmentFile>,
+    next_offset: u64,
+    /// 실패한 쓰기를 되돌리지 못한 이유 (있으면 추가 거부)
+    poisoned: Option<String>,
+}
+
+/// 파일 기반 세그먼트 이벤트 로그
+///
+/// 복제해도 같은 로그를 공유합니다. `EnvelopeHandler`를 구현하므로
+/// `TopicEventBus::subscribe_topic`에 `#` 패턴으로 구독시켜 버스의 모든 이벤트를 기록할 수 있습니다.
+#[derive(Debug, Clone)]
+pub struct FileEventLog {
+    config: Arc<EventLogConfig>,
+    clock: SharedClock,
+    state: Arc<Mutex<LogState>>,
+}
+
+impl FileEventLog {
+    /// 시스템 시계로 로그 열기 (없으면 생성)
+    pub fn open(config: EventLogConfig) -> LogResult<Self> {
+        Self::open_with_clock(config, system_clock())
+    }
+
+    /// 지정한 시계로 로그 열기 (없으면 생성)
+    pub fn open_with_clock(config: EventLogConfig, clock: SharedClock) -> LogResult<Self> {
+        fs::create_dir_all(&config.dir)?;
+
+        let mut segments = Vec::new();
+        for entry in fs::read_dir(&config.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(SEGMENT_EXTENSION) {
+                continue;
+            }
+            if let Some(base_offset) = path.file_stem().and_then(|s| s.to_str()).and_then(|s| s.parse().ok()) {
+                segments.push(Segment::new(&config.dir, base_offset));
+            }
+        }
+        segments.sort_by_key(|segment| segment.base_offset);
+
+        // 세그먼트를 검증하며 다음 오프셋 복구
+        let mut next_offset = segments.first().map_or(0, |segment| segment.base_offset);
+        let last_index = segments.len().saturating_sub(1);
+        for (index, segment) in segments.iter_mut().enumerate() {
+            if segment.base_offset != next_offset {
+                return Err(EventLogError::Corrupt {
+                    segment: segment.path.clone(),
+                    position: 0,
+                    reason: format!("오프셋 {}이(가) 와야 하지만 {}에서 시작", next_offset, segment.base_offset),
+                });
+            }
+            next_offset = recover_segment(segment, index == last_index)?;
+        }
+
+        if segments.is_empty() {
+            segments.push(Segment::new(&config.dir, 0));
+        }
+        let active = Box::new(open_for_append(&segments[segments.len() - 1].path)?);
+
+        tracing::info!(
+            "이벤트 로그 열림: {} (세그먼트 {}개, 다음 오프셋 {})",
+            config.dir.display(),
+            segments.len(),
+            next_offset
+        );
+
+        Ok(Self {
+            config: Arc::new(config),
+            clock,
+            state: Arc::new(Mutex::new(LogState {
+                segments,
+                active,
+                next_offset,
+                poisoned: None,
+            })),
+        })
+    }
+
+    /// 설정
+    pub fn config(&self) -> &EventLogConfig {
+        &self.config
+    }
+
+    /// 다음에 기록될 오프셋
+    pub fn next_offset(&self) -> u64 {
+        self.state.lock().unwrap().next_offset
+    }
+
+    /// 남아 있는 가장 오래된 오프셋
+    pub fn first_offset(&self) -> u64 {
+        self.state.lock().unwrap().segments[0].base_offset
+    }
+
+    /// 전체 로그 크기 (바이트)
+    pub fn size_bytes(&self) -> u64 {
+        self.state.lock().unwrap().segments.iter().map(|segment| segment.size).sum()
+    }
+
+    /// 봉투 추가 후 부여된 오프셋 반환
+    pub fn append(&self, envelope: &EventEnvelope<Value>) -> LogResult<u64> {
+        let body = serde_json::to_vec(envelope).map_err(|e| EventLogError::Serialization(e.to_string()))?;
+        let appended_at = self.clock.now();
+
+        let mut state = self.state.lock().unwrap();
+        if let Some(reason) = &state.poisoned {
+            return Err(EventLogError::Poisoned(reason.clone()));
+        }
+        let offset = state.next_offset;
+        let record = encode_record(offset, appended_at, &body)?;
+
+        let active_size = state.segments.last().map_or(0, |segment| segment.size);
+        if active_size > 0 && active_size + record.len() as u64 > self.config.segment_bytes {
+            self.roll(&mut state)?;
+        }
+
+        if let Err(error) = write_record(state.active.as_mut(), &record, self.config.sync_on_append) {
+            // 일부만 기록된 레코드를 잘라내 다음 레코드가 확인된 레코드 바로 뒤에 오게 함
+            let size = state.segments.last().map_or(0, |segment| segment.size);
+            if let Err(rewind_error) = rewind(state.active.as_mut(), size) {
+                tracing::error!("이벤트 로그 쓰기 되돌리기 실패, 이후 추가 거부: {}", rewind_error);
+                state.poisoned = Some(format!("{} (되돌리기 실패: {})", error, rewind_error));
+            }
+            return Err(error.into());
+        }
+
+        let segment = state.segments.last_mut().expect("활성 세그먼트가 항상 존재해야 함");
+        segment.size += record.len() as u64;
+        segment.first_appended.get_or_insert(appended_at);
+        segment.last_appended = Some(appended_at);
+        state.next_offset += 1;
+
+        Ok(offset)
+    }
+
+    /// 이벤트를 봉투로 변환하여 추가
+    pub fn append_event<E: Event + Serialize>(&self, event: &E, source: &str) -> LogResult<u64> {
+        let envelope = envelope_of(event, source).map_err(|e| EventLogError::Serialization(e.to_string()))?;
+        self.append(&envelope)
+    }
+
+    /// 활성 세그먼트를 디스크에 동기화
+    pub fn sync(&self) -> LogResult<()> {
+        self.state.lock().unwrap().active.sync_data()?;
+        Ok(())
+    }
+
+    /// 시작 위치부터 현재 끝까지 읽는 리더 생성
+    ///
+    /// 리더 생성 이후에 추가된 레코드는 읽지 않습니다.
+    pub fn read_from(&self, from: ReplayFrom) -> LogResult<EventLogReader> {
+        let state = self.state.lock().unwrap();
+        let first_offset = state.segments[0].base_offset;
+
+        let (start_index, start_offset, not_before) = match from {
+            ReplayFrom::Beginning => (0, first_offset, None),
+            ReplayFrom::Offset(offset) if offset < first_offset => {
+                return Err(EventLogError::OffsetOutOfRange {
+                    requested: offset,
+                    first: first_offset,
+                });
+            }
+            ReplayFrom::Offset(offset) => {
+                let index = state
+                    .segments
+                    .iter()
+                    .rposition(|segment| segment.base_offset <= offset)
+                    .unwrap_or(0);
+                (index, offset, None)
+            }
+            ReplayFrom::Time(time) => {
+                let index = state
+                    .segments
+                    .iter()
+                    .position(|segment| segment.last_appended.is_some_and(|last| last >= time))
+                    .unwrap_or(state.segments.len() - 1);
+                (index, state.segments[index].base_offset, Some(time))
+            }
+        };
+
+        Ok(EventLogReader {
+            segments: state.segments[start_index..]
+                .iter()
+                .map(|segment| (segment.path.clone(), segment.size))
+                .collect(),
+            current: None,
+            start_offset,
+            end_offset: state.next_offset,
+            not_before,
+        })
+    }
+
+    /// 시작 위치부터 레코드를 순서대로 처리 (읽기 모델 재구성용)
+    ///
+    /// 처리한 레코드 수를 반환하며, 처리 함수가 오류를 반환하면 즉시 중단합니다.
+    pub fn replay<F>(&self, from: ReplayFrom, mut apply: F) -> cryptolytica_shared_kernel::types::Result<u64>
+    where
+        F: FnMut(LogRecord) -> cryptolytica_shared_kernel::types::Result<()>,
+    {
+        let mut count = 0;
+        for record in self.read_from(from)? {
+            apply(record?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// 시작 위치부터 봉투를 핸들러에 전달
+    pub fn replay_into<H: EnvelopeHandler>(
+        &self,
+        from: ReplayFrom,
+        handler: &H,
+    ) -> cryptolytica_shared_kernel::types::Result<u64> {
+        self.replay(from, |record| handler.handle(&record.envelope))
+    }
+
+    /// 보존 정책 적용 (활성 세그먼트는 삭제하지 않음)
+    pub fn apply_retention(&self) -> LogResult<RetentionReport> {
+        let mut state = self.state.lock().unwrap();
+        self.enforce_retention(&mut state)
+    }
+
+    /// 새 세그먼트로 교체
+    fn roll(&self, state: &mut LogState) -> LogResult<()> {
+        state.active.flush()?;
+        if self.config.sync_on_append {
+            state.active.sync_data()?;
+        }
+
+        let segment = Segment::new(&self.config.dir, state.next_offset);
+        state.active = Box::new(open_for_append(&segment.path)?);
+        tracing::debug!("이벤트 로그 세그먼트 교체: {}", segment.path.display());
+        state.segments.push(segment);
+
+        let report = self.enforce_retention(state)?;
+        if report.removed_segments > 0 {
+            tracing::info!(
+                "이벤트 로그 보존 정책 적용: 세그먼트 {}개 ({} 바이트) 삭제",
+                report.removed_segments,
+                report.removed_bytes
+            );
+        }
+        Ok(())
+    }
+
+    fn enforce_retention(&self, state: &mut LogState) -> LogResult<RetentionReport> {
+        let retention = &self.config.retention;
+        let cutoff = retention
+            .max_age
+            .and_then(|age| chrono::Duration::from_std(age).ok())
+            .map(|age| self.clock.now() - age);
+        let mut total: u64 = state.segments.iter().map(|segment| segment.size).sum();
+
+        let mut report = RetentionReport::default();
+        while state.segments.len() > 1 {
+            let oldest = &state.segments[0];
+            let expired = cutoff.is_some_and(|cutoff| oldest.last_appended.is_none_or(|last| last < cutoff));
+            let oversized = retention.max_bytes.is_some_and(|max| total > max);
+            if !expired && !oversized {
+                break;
+            }
+
+            fs::remove_file(&oldest.path)?;
+            total -= oldest.size;
+            report.removed_segments += 1;
+            report.removed_bytes += oldest.size;
+            state.segments.remove(0);
+        }
+
+        report.first_offset = state.segments[0].base_offset;
+        Ok(report)
+    }
+}
+
+impl EnvelopeHandler for FileEventLog {
+    fn handle(&self, envelope: &EventEnvelope<Value>) -> cryptolytica_shared_kernel::types::Result<()> {
+        self.append(envelope)?;
+        Ok(())
+    }
+}
+
+impl ProjectionSource for FileEventLog {
+    fn first_offset(&self) -> u64 {
+        FileEventLog::first_offset(self)
+    }
+
+    fn next_offset(&self) -> u64 {
+        FileEventLog::next_offset(self)
+    }
+
+    fn read(
+        &self,
+        from: u64,
+        apply: &mut dyn FnMut(u64, EventEnvelope<Value>) -> cryptolytica_shared_kernel::types::Result<()>,
+    ) -> cryptolytica_shared_kernel::types::Result<u64> {
+        self.replay(ReplayFrom::Offset(from), |record| apply(record.offset, record.envelope))
+    }
+}
+
+/// 이벤트 로그 리더
+///
+/// 세그먼트를 차례로 열어 레코드를 반환합니다. 읽는 도중 보존 정책으로 아직 열지 않은
+/// 세그먼트가 삭제되면 I/O 오류를 반환합니다.
+#[derive(Debug)]
+pub struct EventLogReader {
+    /// 남은 세그먼트 (경로, 생성 시점 크기)
+    segments: VecDeque<(PathBuf, u64)>,
+    current: Option<SegmentCursor>,
+    start_offset: u64,
+    end_offset: u64,
+    not_before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct SegmentCursor {
+    path: PathBuf,
+    reader: BufReader<File>,
+    position: u64,
+    limit: u64,
+}
+
+impl EventLogReader {
+    fn next_record(&mut self) -> LogResult<Option<LogRecord>> {
+        loop {
+            let cursor = match &mut self.current {
+                Some(cursor) => cursor,
+                None => match self.segments.pop_front() {
+                    Some((path, limit)) => self.current.insert(SegmentCursor {
+                        reader: BufReader::new(File::open(&path)?),
+                        path,
+                        position: 0,
+                        limit,
+                    }),
+                    None => return Ok(None),
+                },
+            };
+
+            if cursor.position >= cursor.limit {
+                self.current = None;
+                continue;
+            }
+
+            let (record, length) = match read_record(&mut cursor.reader)? {
+                Ok(Some(decoded)) => decoded,
+                Ok(None) | Err(_) => {
+                    return Err(EventLogError::Corrupt {
+                        segment: cursor.path.clone(),
+                        position: cursor.position,
+                        reason: "레코드를 읽을 수 없음".to_string(),
+                    });
+                }
+            };
+            cursor.position += length;
+
+            if record.offset >= self.end_offset {
+                return Ok(None);
+            }
+            if record.offset < self.start_offset || self.not_before.is_some_and(|time| record.appended_at < time) {
+                continue;
+            }
+            return Ok(Some(record));
+        }
+    }
+}
+
+impl Iterator for EventLogReader {
+    type Item = LogResult<LogRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record().transpose()
+    }
+}
+
+fn open_for_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn write_record(file: &mut dyn SegmentFile, record: &[u8], sync: bool) -> io::Result<()> {
+    file.write_all(record)?;
+    if sync {
+        file.sync_data()?;
+    }
+    Ok(())
+}
+
+/// 활성 세그먼트를 `size` 바이트로 잘라내고 쓰기 위치를 끝으로 옮김
+fn rewind(file: &mut dyn SegmentFile, size: u64) -> io::Result<()> {
+    file.set_len(size)?;
+    file.seek(SeekFrom::Start(size))?;
+    Ok(())
+}
+
+fn encode_record(offset: u64, appended_at: DateTime<Utc>, body: &[u8]) -> LogResult<Vec<u8>> {
+    let length = u32::try_from(body.len())
+        .map_err(|_| EventLogError::Serialization(format!("봉투가 너무 큼: {} 바이트", body.len())))?;
+
+    let mut record = Vec::with_capacity(RECORD_HEADER_LEN + body.len());
+    record.extend_from_slice(&length.to_le_bytes());
+    record.extend_from_slice(&[0; 4]);
+    record.extend_from_slice(&offset.to_le_bytes());
+    record.extend_from_slice(&appended_at.timestamp_millis().to_le_bytes());
+    record.extend_from_slice(body);
+
+    let checksum = crc32fast::hash(&record[8..]);
+    record[4..8].copy_from_slice(&checksum.to_le_bytes());
+    Ok(record)
+}
+
+/// 레코드 하나를 읽음
+///
+/// 바깥 `Result`는 I/O 오류, 안쪽 `Result`는 잘리거나 손상된 레코드(사유)를 뜻하며,
+/// 파일 끝이면 `Ok(Ok(None))`을 반환합니다.
+#[allow(clippy::type_complexity)]
+fn read_record<R: Read>(reader: &mut R) -> io::Result<std::result::Result<Option<(LogRecord, u64)>, String>> {
+    let mut header = [0u8; RECORD_HEADER_LEN];
+    match read_full(reader, &mut header)? {
+        0 => return Ok(Ok(None)),
+        n if n < RECORD_HEADER_LEN => return Ok(Err("잘린 레코드 헤더".to_string())),
+        _ => {}
+    }
+
+    let length = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+    let checksum = u32::from_le_bytes(header[4..8].try_into().unwrap());
+    let offset = u64::from_le_bytes(header[8..16].try_into().unwrap());
+    let millis = i64::from_le_bytes(header[16..24].try_into().unwrap());
+
+    let mut body = vec![0u8; length];
+    if read_full(reader, &mut body)? < length {
+        return Ok(Err("잘린 레코드 본문".to_string()));
+    }
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&header[8..]);
+    hasher.update(&body);
+    if hasher.finalize() != checksum {
+        return Ok(Err(format!("오프셋 {}의 체크섬 불일치", offset)));
+    }
+
+    let Some(appended_at) = DateTime::from_timestamp_millis(millis) else {
+        return Ok(Err(format!("오프셋 {}의 잘못된 기록 시각", offset)));
+    };
+    let envelope = match serde_json::from_slice(&body) {
+        Ok(envelope) => envelope,
+        Err(e) => return Ok(Err(format!("오프셋 {}의 봉투 해석 실패: {}", offset, e))),
+    };
+
+    let record = LogRecord {
+        offset,
+        appended_at,
+        envelope,
+    };
+    Ok(Ok(Some((record, (RECORD_HEADER_LEN + length) as u64))))
+}
+
+/// 버퍼를 가능한 만큼 채우고 읽은 바이트 수 반환
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// 세그먼트를 검증하고 메타데이터를 채운 뒤 다음 오프셋 반환
+///
+/// 마지막 세그먼트의 손상된 꼬리는 잘라내고(쓰기 도중 종료된 경우), 다른 세그먼트의 손상은 오류입니다.
+fn recover_segment(segment: &mut Segment, is_last: bool) -> LogResult<u64> {
+    let mut file = OpenOptions::new().read(true).write(is_last).open(&segment.path)?;
+    let file_len = file.metadata()?.len();
+    let mut reader = BufReader::new(&mut file);
+
+    let mut position = 0;
+    let mut next_offset = segment.base_offset;
+    loop {
+        let failure = match read_record(&mut reader)? {
+            Ok(None) => break,
+            Ok(Some((record, _))) if record.offset != next_offset => {
+                format!("오프셋 {}이(가) 와야 하지만 {}을(를) 읽음", next_offset, record.offset)
+            }
+            Ok(Some((record, length))) => {
+                segment.first_appended.get_or_insert(record.appended_at);
+                segment.last_appended = Some(record.appended_at);
+                position += length;
+                next_offset += 1;
+                continue;
+            }
+            Err(reason) => reason,
+        };
+
+        if !is_last {
+            return Err(EventLogError::Corrupt {
+                segment: segment.path.clone(),
+                position,
+                reason: failure,
+            });
+        }
+
+        tracing::warn!(
+            "이벤트 로그 꼬리 손상 복구: {} ({} 바이트 잘라냄, 사유: {})",
+            segment.path.display(),
+            file_len - position,
+            failure
+        );
+        drop(reader);
+        file.set_len(position)?;
+        file.seek(SeekFrom::End(0))?;
+        break;
+    }
+
+    segment.size = position;
+    Ok(next_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use cryptolytica_shared_kernel::clock::SimulatedClock;
+    use cryptolytica_shared_kernel::events::create_event_header;
+    use serde_json::json;
+
+    fn envelope(n: u64) -> EventEnvelope<Value> {
+        EventEnvelope {
+            header: create_event_header("test", "market.price.updated", None),
+            payload: json!({ "symbol": "BTC/USDT", "n": n }),
+        }
+    }
+
+    fn payload_numbers(records: impl Iterator<Item = LogResult<LogRecord>>) -> Vec<u64> {
+        records
+            .map(|record| record.unwrap().envelope.payload["n"].as_u64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_append_and_replay_after_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = EventLogConfig::new(dir.path()).with_segment_bytes(512);
+
+        {
+            let log = FileEventLog::open(config.clone()).unwrap();
+            for n in 0..20 {
+                assert_eq!(log.append(&envelope(n)).unwrap(), n);
+            }
+        }
+
+        // 재시작 후 오프셋이 이어지고 세그먼트가 여러 개로 나뉘어 있어야 함
+        let log = FileEventLog::open(config).unwrap();
+        assert_eq!(log.next_offset(), 20);
+        assert!(fs::read_dir(dir.path()).unwrap().count() > 1);
+        assert_eq!(log.append(&envelope(20)).unwrap(), 20);
+
+        let all = payload_numbers(log.read_from(ReplayFrom::Beginning).unwrap());
+        assert_eq!(all, (0..=20).collect::<Vec<_>>());
+
+        let tail = payload_numbers(log.read_from(ReplayFrom::Offset(17)).unwrap());
+        assert_eq!(tail, vec![17, 18, 19, 20]);
+
+        let mut rebuilt = Vec::new();
+        let count = log
+            .replay(ReplayFrom::Offset(19), |record| {
+                rebuilt.push(record.offset);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(rebuilt, vec![19, 20]);
+    }
+
+    #[test]
+    fn test_torn_tail_is_truncated() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = EventLogConfig::new(dir.path());
+        {
+            let log = FileEventLog::open(config.clone()).unwrap();
+            for n in 0..3 {
+                log.append(&envelope(n)).unwrap();
+            }
+        }
+
+        // 마지막 레코드를 쓰는 도중 종료된 상황
+        let segment = dir.path().join(format!("{:020}.log", 0));
+        let len = fs::metadata(&segment).unwrap().len();
+        OpenOptions::new().write(true).open(&segment).unwrap().set_len(len - 5).unwrap();
+
+        let log = FileEventLog::open(config).unwrap();
+        assert_eq!(log.next_offset(), 2);
+        assert_eq!(log.append(&envelope(9)).unwrap(), 2);
+        let all = payload_numbers(log.read_from(ReplayFrom::Beginning).unwrap());
+        assert_eq!(all, vec![0, 1, 9]);
+    }
+
+    /// 지정한 바이트만 쓰고 한 번 실패하는 세그먼트 파일
+    #[derive(Debug)]
+    struct ShortWrite {
+        file: File,
+        remaining: Option<usize>,
+        fail_set_len: bool,
+    }
+
+    impl Write for ShortWrite {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match self.remaining {
+                Some(0) => {
+                    self.remaining = None;
+                    Err(io::Error::other("디스크 공간 부족"))
+                }
+                Some(remaining) => {
+                    let written = self.file.write(&buf[..buf.len().min(remaining)])?;
+                    self.remaining = Some(remaining - written);
+                    Ok(written)
+                }
+                None => self.file.write(buf),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.file.flush()
+        }
+    }
+
+    impl Seek for ShortWrite {
+        fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+            self.file.seek(position)
+        }
+    }
+
+    impl SegmentFile for ShortWrite {
+        fn sync_data(&self) -> io::Result<()> {
+            self.file.sync_data()
+        }
+
+        fn set_len(&self, size: u64) -> io::Result<()> {
+            if self.fail_set_len {
+                return Err(io::Error::other("잘라내기 실패"));
+            }
+            self.file.set_len(size)
+        }
+    }
+
+    fn inject_short_write(log: &FileEventLog, bytes: usize, fail_set_len: bool) {
+        let mut state = log.state.lock().unwrap();
+        let path = state.segments.last().unwrap().path.clone();
+        state.active = Box::new(ShortWrite {
+            file: open_for_append(&path).unwrap(),
+            remaining: Some(bytes),
+            fail_set_len,
+        });
+    }
+
+    #[test]
+    fn test_short_write_is_rewound() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = EventLogConfig::new(dir.path());
+        {
+            let log = FileEventLog::open(config.clone()).unwrap();
+            log.append(&envelope(0)).unwrap();
+            log.append(&envelope(1)).unwrap();
+
+            // 레코드 일부만 기록된 뒤 실패하면 오프셋을 소비하지 않고 잘린 바이트를 제거
+            inject_short_write(&log, 10, false);
+            assert!(matches!(log.append(&envelope(2)), Err(EventLogError::Io(_))));
+            assert_eq!(log.next_offset(), 2);
+            assert_eq!(log.append(&envelope(3)).unwrap(), 2);
+            assert_eq!(fs::metadata(dir.path().join(format!("{:020}.log", 0))).unwrap().len(), log.size_bytes());
+        }
+
+        // 다시 열어도 확인된 레코드가 모두 남아 있음
+        let log = FileEventLog::open(config).unwrap();
+        assert_eq!(log.next_offset(), 3);
+        let all = payload_numbers(log.read_from(ReplayFrom::Beginning).unwrap());
+        assert_eq!(all, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_failed_rewind_poisons_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = FileEventLog::open(EventLogConfig::new(dir.path())).unwrap();
+        log.append(&envelope(0)).unwrap();
+
+        inject_short_write(&log, 10, true);
+        assert!(matches!(log.append(&envelope(1)), Err(EventLogError::Io(_))));
+
+        // 잘린 바이트 뒤에 레코드를 쌓지 않도록 이후 추가를 거부
+        let error = log.append(&envelope(2)).unwrap_err();
+        assert!(matches!(error, EventLogError::Poisoned(_)));
+        assert!(matches!(CoreError::from(error), CoreError::Unavailable { .. }));
+        assert_eq!(log.next_offset(), 1);
+    }
+
+    #[test]
+    fn test_checksum_detects_corruption() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = EventLogConfig::new(dir.path()).with_segment_bytes(256);
+        {
+            let log = FileEventLog::open(config.clone()).unwrap();
+            for n in 0..6 {
+                log.append(&envelope(n)).unwrap();
+            }
+        }
+
+        // 닫힌 첫 세그먼트의 본문 한 바이트 변조
+        let segment = dir.path().join(format!("{:020}.log", 0));
+        let mut bytes = fs::read(&segment).unwrap();
+        bytes[RECORD_HEADER_LEN + 2] ^= 0xff;
+        fs::write(&segment, bytes).unwrap();
+
+        assert!(matches!(FileEventLog::open(config), Err(EventLogError::Corrupt { .. })));
+    }
+
+    #[test]
+    fn test_replay_from_time_and_retention() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let clock = Arc::new(SimulatedClock::new(start));
+        let dir = tempfile::tempdir().unwrap();
+        let config = EventLogConfig::new(dir.path())
+            .with_segment_bytes(256)
+            .with_retention(RetentionPolicy {
+                max_age: Some(Duration::from_secs(3600)),
+                max_bytes: None,
+            });
+        let log = FileEventLog::open_with_clock(config, clock.clone()).unwrap();
+
+        for n in 0..10 {
+            log.append(&envelope(n)).unwrap();
+            clock.advance(chrono::Duration::minutes(10));
+        }
+
+        // 30분 시점 이후에 기록된 레코드부터
+        let from_time = payload_numbers(log.read_from(ReplayFrom::Time(start + chrono::Duration::minutes(30))).unwrap());
+        assert_eq!(from_time, (3..10).collect::<Vec<_>>());
+
+        // 현재 100분 → 40분 이전에 끝난 세그먼트 삭제
+        let report = log.apply_retention().unwrap();
+        assert!(report.removed_segments > 0);
+        assert_eq!(report.first_offset, log.first_offset());
+        assert!(log.first_offset() > 0);
+        assert!(matches!(
+            log.read_from(ReplayFrom::Offset(0)),
+            Err(EventLogError::OffsetOutOfRange { requested: 0, .. })
+        ));
+
+        let remaining = payload_numbers(log.read_from(ReplayFrom::Beginning).unwrap());
+        assert_eq!(remaining.first().copied(), Some(log.first_offset()));
+        assert_eq!(remaining.last().copied(), Some(9));
+    }
+}
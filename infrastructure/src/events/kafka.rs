@@ -0,0 +1,295 @@
+// kafka.rs
+//
+// Kafka 브로커 전송 계층
+// 이벤트 타입마다 `{접두사}{이벤트 타입}` 토픽에 파티션 키를 메시지 키로 발행하고,
+// 구독 이름을 group.id로 하는 소비자가 토픽 패턴을 정규식 구독으로 바꿔 소비함
+//
+// 오프셋은 핸들러가 성공한 뒤에만 저장하여(enable.auto.offset.store=false) 최소 한 번 전달을
+// 보장함. Kafka에는 메시지 단위 거부가 없으므로, 거부된 메시지는 데드 레터 토픽이 설정되어
+// 있으면 그쪽으로 옮기고 오프셋을 넘김
+
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::error::KafkaError;
+use rdkafka::message::{Header, Message, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::types::Result;
+
+use super::broker::{
+    Acknowledger, BrokerConsumer, BrokerDelivery, BrokerTransport, ConsumerBinding, OutgoingMessage,
+};
+use super::factory::KafkaConfig;
+
+fn kafka_error(context: &str, error: KafkaError) -> CoreError {
+    CoreError::Unavailable {
+        message: format!("Kafka {} 실패: {}", context, error),
+        retry_after: None,
+    }
+}
+
+/// 토픽 패턴을 Kafka 정규식 구독으로 변환 (`*`는 한 단어, `#`은 0개 이상의 단어)
+pub fn topic_regex(prefix: &str, pattern: &str) -> String {
+    fn escape(literal: &str) -> String {
+        literal
+            .chars()
+            .flat_map(|c| {
+                let escaped = matches!(c, '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$');
+                escaped.then_some('\\').into_iter().chain(std::iter::once(c))
+            })
+            .collect()
+    }
+
+    let mut regex = format!("^{}", escape(prefix));
+    for (index, segment) in pattern.split('.').enumerate() {
+        let separator = if index == 0 { "" } else { "\\." };
+        match segment {
+            "#" if index == 0 => regex.push_str("(.*)"),
+            "#" => regex.push_str("(\\..*)?"),
+            "*" => {
+                regex.push_str(separator);
+                regex.push_str("[^.]+");
+            }
+            literal => {
+                regex.push_str(separator);
+                regex.push_str(&escape(literal));
+            }
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// Kafka 브로커 전송 계층
+pub struct KafkaTransport {
+    config: KafkaConfig,
+    producer: FutureProducer,
+}
+
+impl KafkaTransport {
+    /// 멱등 발행자 생성 (브로커 연결은 첫 발행·구독 시 이루어짐)
+    pub fn connect(config: KafkaConfig) -> Result<Self> {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &config.brokers)
+            .set("client.id", &config.client_id)
+            .set("enable.idempotence", "true")
+            .set("message.timeout.ms", config.send_timeout_ms.to_string())
+            .create()
+            .map_err(|e| kafka_error("발행자 생성", e))?;
+
+        tracing::info!("Kafka 발행자 생성됨: {}", config.brokers);
+        Ok(Self { config, producer })
+    }
+
+    /// 이벤트 타입의 토픽 이름
+    pub fn topic_name(&self, event_type: &str) -> String {
+        format!("{}{}", self.config.topic_prefix, event_type)
+    }
+}
+
+#[async_trait]
+impl BrokerTransport for KafkaTransport {
+    fn name(&self) -> &str {
+        "kafka"
+    }
+
+    async fn publish(&self, message: OutgoingMessage) -> Result<()> {
+        let topic = self.topic_name(&message.topic);
+        let event_id = message.event_id.to_string();
+        let headers = OwnedHeaders::new()
+            .insert(Header {
+                key: "content-type",
+                value: Some(message.content_type),
+            })
+            .insert(Header {
+                key: "event-id",
+                value: Some(event_id.as_str()),
+            });
+
+        let mut record = FutureRecord::<str, [u8]>::to(&topic)
+            .payload(&message.payload)
+            .headers(headers);
+        if let Some(key) = &message.key {
+            record = record.key(key.as_str());
+        }
+
+        self.producer
+            .send(record, Duration::from_millis(self.config.send_timeout_ms))
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| kafka_error("발행", e))
+    }
+
+    async fn consume(&self, binding: &ConsumerBinding) -> Result<Box<dyn BrokerConsumer>> {
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &self.config.brokers)
+            .set("client.id", &self.config.client_id)
+            .set("group.id", &binding.group)
+            .set("enable.auto.commit", "true")
+            .set("enable.auto.offset.store", "false")
+            .set("auto.offset.reset", "earliest")
+            // 정규식 구독이 새로 생긴 이벤트 타입 토픽을 빨리 발견하도록 함
+            .set("topic.metadata.refresh.interval.ms", "5000")
+            .create()
+            .map_err(|e| kafka_error("소비자 생성", e))?;
+
+        let regex = topic_regex(&self.config.topic_prefix, &binding.pattern);
+        consumer
+            .subscribe(&[regex.as_str()])
+            .map_err(|e| kafka_error("구독", e))?;
+
+        tracing::debug!("Kafka 소비 시작: {} ({})", binding.group, regex);
+        Ok(Box::new(KafkaConsumer {
+            consumer: Arc::new(consumer),
+            prefix: self.config.topic_prefix.clone(),
+            dead_letter: self
+                .config
+                .dead_letter_topic
+                .clone()
+                .map(|topic| (self.producer.clone(), topic)),
+        }))
+    }
+
+    async fn close(&self) -> Result<()> {
+        let producer = self.producer.clone();
+        let timeout = Duration::from_millis(self.config.send_timeout_ms);
+        tokio::task::spawn_blocking(move || producer.flush(timeout))
+            .await
+            .map_err(|e| CoreError::Unknown(format!("Kafka 발행자 정리 태스크 실패: {}", e)))?
+            .map_err(|e| kafka_error("발행자 비우기", e))
+    }
+}
+
+/// Kafka 소비자
+struct KafkaConsumer {
+    consumer: Arc<StreamConsumer>,
+    prefix: String,
+    dead_letter: Option<(FutureProducer, String)>,
+}
+
+#[async_trait]
+impl BrokerConsumer for KafkaConsumer {
+    async fn next(&mut self) -> Option<Result<BrokerDelivery>> {
+        let message = match self.consumer.recv().await {
+            Ok(message) => message,
+            Err(e) => return Some(Err(kafka_error("메시지 수신", e))),
+        };
+
+        let topic = message.topic().to_string();
+        let acker = KafkaAcker {
+            consumer: self.consumer.clone(),
+            topic: topic.clone(),
+            partition: message.partition(),
+            offset: message.offset(),
+            key: message.key().map(<[u8]>::to_vec),
+            payload: message.payload().map(<[u8]>::to_vec).unwrap_or_default(),
+            dead_letter: self.dead_letter.clone(),
+        };
+        let event_type = topic.strip_prefix(&self.prefix).unwrap_or(&topic).to_string();
+        let payload = acker.payload.clone();
+
+        Some(Ok(BrokerDelivery::new(event_type, payload, false, Box::new(acker))))
+    }
+}
+
+/// Kafka 메시지 확인 처리 (확인은 다음 커밋에 포함될 오프셋 저장)
+struct KafkaAcker {
+    consumer: Arc<StreamConsumer>,
+    topic: String,
+    partition: i32,
+    offset: i64,
+    key: Option<Vec<u8>>,
+    payload: Vec<u8>,
+    dead_letter: Option<(FutureProducer, String)>,
+}
+
+impl KafkaAcker {
+    fn store_offset(&self) -> Result<()> {
+        self.consumer
+            .store_offset(&self.topic, self.partition, self.offset)
+            .map_err(|e| kafka_error("오프셋 저장", e))
+    }
+}
+
+#[async_trait]
+impl Acknowledger for KafkaAcker {
+    async fn ack(self: Box<Self>) -> Result<()> {
+        self.store_offset()
+    }
+
+    async fn nack(self: Box<Self>, requeue: bool) -> Result<()> {
+        if requeue {
+            // 오프셋을 저장하지 않으면 재조정이나 재시작 후 다시 전달됨
+            tracing::warn!(
+                "Kafka 메시지를 오프셋 저장 없이 남김: {}[{}]@{}",
+                self.topic,
+                self.partition,
+                self.offset
+            );
+            return Ok(());
+        }
+
+        match &self.dead_letter {
+            Some((producer, dead_letter_topic)) => {
+                let source = format!("{}[{}]@{}", self.topic, self.partition, self.offset);
+                let headers = OwnedHeaders::new().insert(Header {
+                    key: "source",
+                    value: Some(source.as_str()),
+                });
+                let mut record = FutureRecord::<[u8], [u8]>::to(dead_letter_topic)
+                    .payload(&self.payload)
+                    .headers(headers);
+                if let Some(key) = &self.key {
+                    record = record.key(key);
+                }
+                producer
+                    .send(record, Duration::from_secs(5))
+                    .await
+                    .map_err(|(e, _)| kafka_error("데드 레터 발행", e))?;
+            }
+            None => {
+                tracing::warn!(
+                    "데드 레터 토픽이 없어 거부된 Kafka 메시지를 건너뜀: {}[{}]@{}",
+                    self.topic,
+                    self.partition,
+                    self.offset
+                );
+            }
+        }
+        self.store_offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::broker::tests::assert_round_trip;
+    use crate::events::broker::BrokerEventBus;
+    use cryptolytica_shared_kernel::events::AsyncEventBus;
+
+    #[test]
+    fn test_topic_regex() {
+        assert_eq!(topic_regex("prod.", "exchange.order.*"), "^prod\\.exchange\\.order\\.[^.]+$");
+        assert_eq!(topic_regex("", "exchange.#"), "^exchange(\\..*)?$");
+        assert_eq!(topic_regex("", "#"), "^(.*)$");
+    }
+
+    /// 로컬 브로커 컨테이너에 대해 실행:
+    /// `docker run -d -p 9092:9092 apache/kafka:3.7.0` 후
+    /// `CRYPTOLYTICA_KAFKA_BROKERS=127.0.0.1:9092 cargo test --features kafka -- --ignored`
+    #[tokio::test]
+    #[ignore = "Kafka 브로커 필요"]
+    async fn test_round_trip_through_kafka() {
+        let brokers = std::env::var("CRYPTOLYTICA_KAFKA_BROKERS").unwrap_or_else(|_| "127.0.0.1:9092".to_string());
+        let mut config = KafkaConfig::new(brokers);
+        config.topic_prefix = format!("test-{}.", uuid::Uuid::new_v4().simple());
+
+        let transport = KafkaTransport::connect(config).unwrap();
+        let bus = BrokerEventBus::new(Arc::new(transport)).with_source("test");
+        assert_round_trip(&bus, "kafka-test").await;
+        bus.shutdown(Duration::from_secs(5)).await.unwrap();
+    }
+}
@@ -2,23 +2,84 @@
 //
 // 인메모리 이벤트 버스 구현
 // 도메인 간 이벤트 통신을 위한 메모리 내 이벤트 버스
+//
+// 핸들러가 실패하면 구독별 재시도 정책에 따라 재시도를 예약하고, 정책을 소진하면
+// 데드 레터 저장소로 옮김. 예약된 재시도는 다음 발행 시점이나 process_retries 호출 시 실행됨
+//
+// 핸들러는 발행된 이벤트의 상관·인과 맥락(EventContext)과 추적 스팬 안에서 실행되므로,
+// 핸들러가 발행하는 이벤트에는 상관 ID와 원인 ID가 자동으로 기록됨
+//
+// 발행 수와 구독별 첫 전달의 처리 결과·처리 시간은 EventBusMetrics에 기록함 (핸들러를 발행
+// 흐름에서 바로 실행하므로 큐 깊이는 항상 0)
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
-use cryptolytica_shared_kernel::events::{Event, EventBus, EventHandler, SubscriptionHandle};
+use serde_json::Value;
+use cryptolytica_shared_kernel::clock::{system_clock, SharedClock};
+use cryptolytica_shared_kernel::events::{
+    envelope_of, envelope_with_context, DeadLetter, DeadLetterQueue, DeadLetterStore, DeliveryMetrics,
+    DeliveryOutcome, EnvelopeHandler, Event, EventBus, EventBusMetrics, EventContext, EventEnvelope, EventHandler,
+    EventSchemaRegistry, HandlerOutcome, SubscriptionHandle, TopicEventBus, TopicSubscription,
+};
 use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::id::new_id_at;
+use cryptolytica_shared_kernel::resilience::RetryPolicy;
 use cryptolytica_shared_kernel::types::Result;
 
-type BoxedHandler<E> = Box<dyn Fn(&E) -> Result<()> + Send + Sync + 'static>;
+use super::dead_letter::InMemoryDeadLetterStore;
+
+type BoxedHandler<E> = Arc<dyn Fn(&E) -> Result<()> + Send + Sync + 'static>;
 type AnyBoxedHandler = Box<dyn Any + Send + Sync>;
+type TopicHandler = (Uuid, TopicSubscription, Arc<dyn EnvelopeHandler>);
+/// 봉투 페이로드를 구독의 이벤트 타입으로 복원하여 다시 전달하는 함수
+type Redeliver = Arc<dyn Fn(&Value) -> Result<()> + Send + Sync + 'static>;
+
+/// 구독별 전달 정책
+struct Delivery {
+    name: String,
+    policy: RetryPolicy,
+    redeliver: Redeliver,
+}
+
+/// 재시도 대기 중인 전달
+struct PendingRetry {
+    subscription_id: Uuid,
+    envelope: EventEnvelope<Value>,
+    error: String,
+    attempts: u32,
+    first_failed_at: DateTime<Utc>,
+    due_at: DateTime<Utc>,
+}
 
 /// 인메모리 이벤트 버스 구현
-#[derive(Default)]
 pub struct InMemoryEventBus {
     handlers: RwLock<HashMap<TypeId, Vec<(Uuid, AnyBoxedHandler)>>>,
+    /// 토픽 패턴 구독 (JSON 봉투로 전달)
+    topic_handlers: RwLock<Vec<TopicHandler>>,
+    /// 봉투 헤더에 기록할 발생 소스
+    source: String,
+    /// 구독별 전달 정책
+    deliveries: RwLock<HashMap<Uuid, Arc<Delivery>>>,
+    /// 예약된 재시도
+    pending: Mutex<Vec<PendingRetry>>,
+    /// 데드 레터 저장소
+    dead_letters: Arc<dyn DeadLetterStore>,
+    clock: SharedClock,
+    /// 발행·처리 지표
+    metrics: Arc<EventBusMetrics>,
+    /// 발행 전 검증할 이벤트 스키마
+    schemas: Option<Arc<EventSchemaRegistry>>,
+}
+
+impl Default for InMemoryEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InMemoryEventBus {
@@ -26,11 +87,302 @@ impl InMemoryEventBus {
     pub fn new() -> Self {
         Self {
             handlers: RwLock::new(HashMap::new()),
+            topic_handlers: RwLock::new(Vec::new()),
+            source: "in-memory".to_string(),
+            deliveries: RwLock::new(HashMap::new()),
+            pending: Mutex::new(Vec::new()),
+            dead_letters: Arc::new(InMemoryDeadLetterStore::new()),
+            clock: system_clock(),
+            metrics: Arc::new(EventBusMetrics::new("in_memory_sync")),
+            schemas: None,
+        }
+    }
+    
+    /// 지표를 기록할 곳 지정 (버스 이름을 바꾸거나 다른 구성 요소와 공유할 때)
+    pub fn with_metrics(mut self, metrics: Arc<EventBusMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+    
+    /// 발행·처리 지표
+    pub fn metrics(&self) -> &Arc<EventBusMetrics> {
+        &self.metrics
+    }
+    
+    /// 재시도 예약과 데드 레터 기록에 사용할 시계 지정
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// 데드 레터 저장소 지정
+    pub fn with_dead_letter_store(mut self, store: Arc<dyn DeadLetterStore>) -> Self {
+        self.dead_letters = store;
+        self
+    }
+    
+    /// 발행 전에 이벤트 타입과 스키마 버전이 등록되어 있는지 확인할 저장소 지정
+    pub fn with_schemas(mut self, schemas: Arc<EventSchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+    
+    /// 재시도 정책을 지정하여 구독
+    ///
+    /// 핸들러가 실패하면 백오프 후 재시도하고, 시도 횟수나 기한을 소진하면 데드 레터로 옮깁니다.
+    /// 재시도는 오류 종류와 관계없이 수행합니다 (예: 주문 생성 이벤트보다 먼저 도착한 체결 이벤트).
+    pub fn subscribe_with_retry<E, H>(
+        &self,
+        handler: H,
+        name: impl Into<String>,
+        policy: RetryPolicy,
+    ) -> Result<SubscriptionHandle>
+    where
+        E: Event + for<'de> Deserialize<'de>,
+        H: EventHandler<E>,
+    {
+        let handler = Arc::new(handler);
+        let boxed_handler: BoxedHandler<E> = Arc::new(move |event: &E| {
+            handler.handle(event)
+        });
+        
+        Ok(self.register_handler(boxed_handler, name.into(), policy))
+    }
+    
+    /// 기한이 된 재시도를 실행하고 실행한 수를 반환
+    ///
+    /// 발행할 때마다 자동으로 호출되며, 발행이 뜸한 경우 주기적으로 호출해야 합니다.
+    pub fn process_retries(&self) -> usize {
+        let now = self.clock.now();
+        let due: Vec<PendingRetry> = {
+            let mut pending = self.pending.lock().unwrap();
+            let (due, waiting) = std::mem::take(&mut *pending)
+                .into_iter()
+                .partition(|retry| retry.due_at <= now);
+            *pending = waiting;
+            due
+        };
+        
+        let count = due.len();
+        for mut retry in due {
+            let Some(delivery) = self.delivery(&retry.subscription_id) else {
+                tracing::debug!("구독이 취소되어 재시도 폐기: {}", retry.envelope.header.event_type);
+                continue;
+            };
+            
+            retry.attempts += 1;
+            match Self::redeliver(&delivery, &retry.envelope) {
+                Ok(()) => {
+                    self.metrics.record_delivery(DeliveryOutcome::Recovered);
+                    tracing::info!(
+                        subscription = %delivery.name,
+                        attempts = retry.attempts,
+                        "재시도로 이벤트 처리 성공: {}",
+                        retry.envelope.header.event_type
+                    );
+                }
+                Err(e) => {
+                    self.metrics.record_delivery(DeliveryOutcome::Failed);
+                    retry.error = e.to_string();
+                    self.retry_or_dead_letter(&delivery, retry);
+                }
+            }
+        }
+        count
+    }
+    
+    /// 예약된 재시도 수
+    pub fn pending_retries(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+    
+    /// 전달 결과 지표
+    pub fn delivery_metrics(&self) -> DeliveryMetrics {
+        self.metrics.delivery()
+    }
+    
+    fn delivery(&self, subscription_id: &Uuid) -> Option<Arc<Delivery>> {
+        self.deliveries.read().unwrap().get(subscription_id).cloned()
+    }
+    
+    /// 봉투에 기록된 맥락 안에서 다시 전달
+    fn redeliver(delivery: &Delivery, envelope: &EventEnvelope<Value>) -> Result<()> {
+        let header = &envelope.header;
+        let context = EventContext::from_header(header);
+        context.span(&header.event_type, header.id).in_scope(|| {
+            let _handling = context.handling(header.id).enter();
+            (delivery.redeliver)(&envelope.payload)
+        })
+    }
+    
+    /// 핸들러 실패 처리 (첫 실패)
+    fn handle_failure<E: Event + Serialize>(
+        &self,
+        subscription_id: Uuid,
+        event: &E,
+        context: &EventContext,
+        error: CoreError,
+    ) {
+        self.metrics.record_delivery(DeliveryOutcome::Failed);
+        let Some(delivery) = self.delivery(&subscription_id) else {
+            return;
+        };
+        
+        let envelope = match envelope_with_context(event, &self.source, context) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                tracing::error!("실패한 이벤트의 봉투 생성 실패: {} - {:?}", event.event_type(), e);
+                return;
+            }
+        };
+        
+        let now = self.clock.now();
+        self.retry_or_dead_letter(&delivery, PendingRetry {
+            subscription_id,
+            envelope,
+            error: error.to_string(),
+            attempts: 1,
+            first_failed_at: now,
+            due_at: now,
+        });
+    }
+    
+    /// 정책이 허용하면 재시도를 예약하고, 아니면 데드 레터로 옮김
+    fn retry_or_dead_letter(&self, delivery: &Delivery, mut retry: PendingRetry) {
+        let now = self.clock.now();
+        let policy = &delivery.policy;
+        let expired = policy.deadline().is_some_and(|deadline| {
+            (now - retry.first_failed_at).to_std().is_ok_and(|elapsed| elapsed >= deadline)
+        });
+        
+        if retry.attempts < policy.max_attempts() && !expired {
+            let delay = policy.backoff().delay(retry.attempts - 1);
+            retry.due_at = now + chrono::Duration::from_std(delay).unwrap_or_else(|_| chrono::Duration::zero());
+            self.metrics.record_delivery(DeliveryOutcome::Retried);
+            tracing::warn!(
+                subscription = %delivery.name,
+                attempt = retry.attempts,
+                ?delay,
+                "이벤트 처리 재시도 예약: {}",
+                retry.error
+            );
+            self.pending.lock().unwrap().push(retry);
+            return;
         }
+        
+        let letter = DeadLetter {
+            id: new_id_at(now),
+            subscription_id: retry.subscription_id,
+            subscription: delivery.name.clone(),
+            envelope: retry.envelope,
+            error: retry.error,
+            attempts: retry.attempts,
+            first_failed_at: retry.first_failed_at,
+            last_failed_at: now,
+        };
+        tracing::error!(
+            subscription = %letter.subscription,
+            attempts = letter.attempts,
+            "이벤트를 데드 레터로 이동: {} - {}",
+            letter.event_type(),
+            letter.error
+        );
+        
+        match self.dead_letters.put(letter) {
+            Ok(()) => self.metrics.record_delivery(DeliveryOutcome::DeadLettered),
+            Err(e) => tracing::error!("데드 레터 저장 실패: {:?}", e),
+        }
+    }
+    
+    /// 봉투 헤더의 발생 소스 지정
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+    
+    /// 토픽 구독 핸들의 타입 ID
+    fn topic_type_id() -> TypeId {
+        TypeId::of::<EventEnvelope<Value>>()
+    }
+    
+    /// 토픽 구독에 이벤트 전달
+    fn dispatch_topics<E: Event + Serialize>(&self, event: &E, context: &EventContext) -> usize {
+        let topic_handlers: Vec<_> = self
+            .topic_handlers
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(id, subscription, handler)| (*id, subscription.clone(), handler.clone()))
+            .collect();
+        if topic_handlers.is_empty() {
+            return 0;
+        }
+        
+        let envelope = match envelope_with_context(event, &self.source, context) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                tracing::error!("토픽 구독용 봉투 생성 실패: {} - {:?}", event.event_type(), e);
+                return 0;
+            }
+        };
+        
+        let mut delivered = 0;
+        for (id, subscription, handler) in topic_handlers {
+            if !subscription.matches(&envelope) {
+                continue;
+            }
+            delivered += 1;
+            let started = Instant::now();
+            let result = handler.handle(&envelope);
+            self.record_handled(&id, &result, started);
+            if let Err(e) = result {
+                tracing::error!("토픽 핸들러 실행 중 오류 ({}): {:?}", subscription.pattern(), e);
+            }
+        }
+        delivered
+    }
+    
+    /// 구독의 처리 결과와 처리 시간 기록
+    fn record_handled(&self, subscription_id: &Uuid, result: &Result<()>, started: Instant) {
+        if let Some(metrics) = self.metrics.subscription(subscription_id) {
+            let outcome = if result.is_ok() { HandlerOutcome::Delivered } else { HandlerOutcome::Failed };
+            metrics.record(outcome, started.elapsed());
+        }
+    }
+    
+    /// 타입별 핸들러 등록
+    fn register_handler<E: Event + for<'de> Deserialize<'de>>(
+        &self,
+        handler: BoxedHandler<E>,
+        name: String,
+        policy: RetryPolicy,
+    ) -> SubscriptionHandle {
+        let type_id = TypeId::of::<E>();
+        let subscription_id = Uuid::new_v4();
+        
+        let typed_handler = handler.clone();
+        let redeliver: Redeliver = Arc::new(move |payload: &Value| {
+            let event: E = serde_json::from_value(payload.clone())?;
+            typed_handler(&event)
+        });
+        self.metrics.register::<E>(subscription_id, name.clone());
+        self.deliveries.write().unwrap().insert(
+            subscription_id,
+            Arc::new(Delivery { name, policy, redeliver }),
+        );
+        
+        let mut handlers = self.handlers.write().unwrap();
+        handlers
+            .entry(type_id)
+            .or_default()
+            .push((subscription_id, Box::new(handler)));
+        
+        tracing::debug!("이벤트 구독 등록됨: {:?}", type_id);
+        SubscriptionHandle::new::<E>(subscription_id)
     }
     
     /// 이벤트 타입에 대한 핸들러 가져오기
-    fn get_handlers_for_type<E: Event + for<'de> Deserialize<'de>>(
+    fn get_handlers_for_type<E: Event>(
         &self,
     ) -> Vec<(Uuid, BoxedHandler<E>)> {
         let handlers = self.handlers.read().unwrap();
@@ -57,30 +409,49 @@ impl InMemoryEventBus {
 impl EventBus for InMemoryEventBus {
     /// 이벤트 발행
     fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
-        let type_id = TypeId::of::<E>();
-        let handlers = self.handlers.read().unwrap();
+        if let Some(schemas) = &self.schemas {
+            schemas.validate(&event)?;
+        }
         
-        if let Some(type_handlers) = handlers.get(&type_id) {
-            // 각 핸들러에게 이벤트 전달
-            for (_, handler) in type_handlers {
-                // 핸들러를 E 타입에 대한 함수로 다운캐스트
-                if let Some(typed_handler) = handler.downcast_ref::<BoxedHandler<E>>() {
-                    // 핸들러 실행
-                    if let Err(e) = typed_handler(&event) {
-                        tracing::error!("이벤트 핸들러 실행 중 오류: {:?}", e);
-                    }
+        // 이벤트가 맥락을 들고 있거나 처리 중인 이벤트가 있으면 그 흐름을 잇고, 없으면 이 이벤트가 새 흐름의 시작
+        let context = EventContext::of(&event);
+        let span = context.span(event.event_type(), *event.id());
+        let entered = span.enter();
+        self.metrics.record_published(&event);
+        
+        // 잠금을 해제한 뒤 핸들러를 실행하여, 핸들러 안에서의 구독/발행이 교착되지 않도록 함
+        let type_handlers = self.get_handlers_for_type::<E>();
+        
+        // 각 핸들러에게 이벤트 전달 (핸들러가 발행하는 이벤트의 원인은 이 이벤트)
+        let topic_count = {
+            let _handling = context.handling(*event.id()).enter();
+            for (id, handler) in &type_handlers {
+                let started = Instant::now();
+                let result = handler(&event);
+                self.record_handled(id, &result, started);
+                if let Err(e) = result {
+                    tracing::error!("이벤트 핸들러 실행 중 오류: {:?}", e);
+                    self.handle_failure(*id, &event, &context, e);
                 }
             }
-            
+            self.dispatch_topics(&event, &context)
+        };
+        
+        if !type_handlers.is_empty() || topic_count > 0 {
             tracing::debug!(
-                "이벤트 발행됨: {} (핸들러 {}개)",
+                "이벤트 발행됨: {} (핸들러 {}개, 토픽 구독 {}개)",
                 event.event_type(),
-                type_handlers.len()
+                type_handlers.len(),
+                topic_count
             );
         } else {
             tracing::debug!("이벤트 발행됨: {} (구독자 없음)", event.event_type());
         }
         
+        // 이번 이벤트로 선행 조건이 갖춰졌을 수 있으므로 기한이 된 재시도 실행
+        drop(entered);
+        self.process_retries();
+        
         Ok(())
     }
     
@@ -89,33 +460,29 @@ impl EventBus for InMemoryEventBus {
         &self,
         handler: H,
     ) -> Result<SubscriptionHandle> {
-        let type_id = TypeId::of::<E>();
-        let subscription_id = Uuid::new_v4();
-        
         // 클로저로 핸들러 래핑
         let handler = Arc::new(handler);
-        let boxed_handler: BoxedHandler<E> = Box::new(move |event: &E| {
+        let boxed_handler: BoxedHandler<E> = Arc::new(move |event: &E| {
             handler.handle(event)
         });
         
-        // Any로 변환하여 저장
-        let any_handler: AnyBoxedHandler = Box::new(boxed_handler);
-        
-        // 핸들러 맵에 추가
-        let mut handlers = self.handlers.write().unwrap();
-        handlers
-            .entry(type_id)
-            .or_insert_with(Vec::new)
-            .push((subscription_id, any_handler));
-        
-        tracing::debug!("이벤트 구독 등록됨: {:?}", type_id);
-        
-        // 구독 핸들 생성 및 반환
-        Ok(SubscriptionHandle::new::<E>(subscription_id))
+        Ok(self.register_handler(boxed_handler, std::any::type_name::<H>().to_string(), RetryPolicy::no_retry()))
     }
     
     /// 구독 취소
     fn unsubscribe(&self, handle: &SubscriptionHandle) -> Result<()> {
+        if handle.event_type_id == Self::topic_type_id() {
+            let mut topic_handlers = self.topic_handlers.write().unwrap();
+            let original_len = topic_handlers.len();
+            topic_handlers.retain(|(id, _, _)| *id != handle.id);
+            
+            if original_len != topic_handlers.len() {
+                self.metrics.unregister(&handle.id);
+                tracing::debug!("토픽 구독 취소됨: {}", handle.id);
+                return Ok(());
+            }
+        }
+        
         let mut handlers = self.handlers.write().unwrap();
         
         if let Some(type_handlers) = handlers.get_mut(&handle.event_type_id) {
@@ -124,6 +491,8 @@ impl EventBus for InMemoryEventBus {
             type_handlers.retain(|(id, _)| *id != handle.id);
             
             if original_len != type_handlers.len() {
+                self.deliveries.write().unwrap().remove(&handle.id);
+                self.metrics.unregister(&handle.id);
                 tracing::debug!("이벤트 구독 취소됨: {:?}", handle.event_type_id);
                 return Ok(());
             }
@@ -136,9 +505,112 @@ impl EventBus for InMemoryEventBus {
     }
 }
 
+impl TopicEventBus for InMemoryEventBus {
+    /// 패턴과 일치하는 모든 이벤트를 JSON 봉투로 구독
+    fn subscribe_topic<H: EnvelopeHandler>(
+        &self,
+        subscription: TopicSubscription,
+        handler: H,
+    ) -> Result<SubscriptionHandle> {
+        let subscription_id = Uuid::new_v4();
+        tracing::debug!("토픽 구독 등록됨: {}", subscription.pattern());
+        let pattern = subscription.pattern().to_string();
+        self.metrics.register_labeled(subscription_id, pattern.clone(), pattern);
+        
+        self.topic_handlers
+            .write()
+            .unwrap()
+            .push((subscription_id, subscription, Arc::new(handler)));
+        
+        Ok(SubscriptionHandle {
+            id: subscription_id,
+            event_type_id: Self::topic_type_id(),
+        })
+    }
+    
+    /// 구독 조건과 일치하는 `E` 타입 이벤트만 구독
+    fn subscribe_matching<E, H>(
+        &self,
+        subscription: TopicSubscription,
+        handler: H,
+    ) -> Result<SubscriptionHandle>
+    where
+        E: Event + Serialize + for<'de> Deserialize<'de>,
+        H: EventHandler<E>,
+    {
+        let source = self.source.clone();
+        let boxed_handler: BoxedHandler<E> = Arc::new(move |event: &E| {
+            // 필터가 없으면 토픽만 비교하여 직렬화를 생략
+            if subscription.filters().is_empty() {
+                if !subscription.pattern().matches(event.event_type()) {
+                    return Ok(());
+                }
+            } else if !subscription.matches(&envelope_of(event, &source)?) {
+                return Ok(());
+            }
+            handler.handle(event)
+        });
+        
+        Ok(self.register_handler(boxed_handler, std::any::type_name::<H>().to_string(), RetryPolicy::no_retry()))
+    }
+}
+
+impl DeadLetterQueue for InMemoryEventBus {
+    /// 데드 레터 목록
+    fn dead_letters(&self) -> Result<Vec<DeadLetter>> {
+        self.dead_letters.list()
+    }
+    
+    /// 데드 레터 조회
+    fn dead_letter(&self, id: &Uuid) -> Result<Option<DeadLetter>> {
+        self.dead_letters.get(id)
+    }
+    
+    /// 원래 구독으로 다시 전달
+    fn redrive(&self, id: &Uuid) -> Result<()> {
+        let mut letter = self
+            .dead_letters
+            .get(id)?
+            .ok_or_else(|| CoreError::NotFound(format!("데드 레터를 찾을 수 없음: {}", id)))?;
+        let delivery = self.delivery(&letter.subscription_id).ok_or_else(|| {
+            CoreError::NotFound(format!("구독이 취소되어 재전달할 수 없음: {}", letter.subscription))
+        })?;
+        
+        match Self::redeliver(&delivery, &letter.envelope) {
+            Ok(()) => {
+                self.dead_letters.remove(id)?;
+                self.metrics.record_delivery(DeliveryOutcome::Redriven);
+                tracing::info!(subscription = %letter.subscription, "데드 레터 재전달 성공: {}", id);
+                Ok(())
+            }
+            Err(e) => {
+                letter.attempts += 1;
+                letter.error = e.to_string();
+                letter.last_failed_at = self.clock.now();
+                self.dead_letters.put(letter)?;
+                self.metrics.record_delivery(DeliveryOutcome::RedriveFailed);
+                tracing::warn!("데드 레터 재전달 실패: {} - {:?}", id, e);
+                Err(e)
+            }
+        }
+    }
+    
+    /// 데드 레터 폐기
+    fn discard(&self, id: &Uuid) -> Result<DeadLetter> {
+        let letter = self
+            .dead_letters
+            .remove(id)?
+            .ok_or_else(|| CoreError::NotFound(format!("데드 레터를 찾을 수 없음: {}", id)))?;
+        self.metrics.record_delivery(DeliveryOutcome::Discarded);
+        tracing::info!(subscription = %letter.subscription, "데드 레터 폐기: {}", id);
+        Ok(letter)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::Ordering;
     use chrono::{DateTime, Utc};
     use cryptolytica_shared_kernel::events::Event;
     
@@ -197,9 +669,11 @@ mod tests {
         event_bus.publish(event).unwrap();
         
         // 결과 확인
-        let received = received.read().unwrap();
-        assert_eq!(received.len(), 1);
-        assert_eq!(received[0], "Hello, World!");
+        {
+            let received = received.read().unwrap();
+            assert_eq!(received.len(), 1);
+            assert_eq!(received[0], "Hello, World!");
+        }
         
         // 구독 취소
         event_bus.unsubscribe(&handle).unwrap();
@@ -217,4 +691,266 @@ mod tests {
         let received = received.read().unwrap();
         assert_eq!(received.len(), 1);
     }
+    
+    #[test]
+    fn test_publish_validates_schemas() {
+        let mut schemas = EventSchemaRegistry::new();
+        schemas.register("test.event", 1).unwrap();
+        let event_bus = InMemoryEventBus::new().with_schemas(Arc::new(schemas));
+        let received = Arc::new(RwLock::new(Vec::new()));
+        event_bus.subscribe(TestHandler { received: received.clone() }).unwrap();
+        
+        let event = TestEvent {
+            id: Uuid::new_v4(),
+            message: "registered".to_string(),
+            timestamp: Utc::now(),
+        };
+        event_bus.publish(event).unwrap();
+        assert_eq!(*received.read().unwrap(), ["registered"]);
+        
+        // 등록되지 않은 이벤트 타입은 핸들러에 전달되기 전에 거부
+        let result = event_bus.publish(OrderFilled::new("binance", "BTC/USDT"));
+        assert!(matches!(result, Err(CoreError::Validation(_))));
+        let snapshot = event_bus.metrics().snapshot();
+        assert_eq!(snapshot.event_type("exchange.order.filled").map_or(0, |m| m.published), 0);
+    }
+    
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct OrderFilled {
+        id: Uuid,
+        exchange: String,
+        symbol: String,
+        timestamp: DateTime<Utc>,
+    }
+    
+    impl OrderFilled {
+        fn new(exchange: &str, symbol: &str) -> Self {
+            Self {
+                id: Uuid::new_v4(),
+                exchange: exchange.to_string(),
+                symbol: symbol.to_string(),
+                timestamp: Utc::now(),
+            }
+        }
+    }
+    
+    impl Event for OrderFilled {
+        fn event_type(&self) -> &'static str {
+            "exchange.order.filled"
+        }
+        
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+        
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+    
+    struct FillRecorder(Arc<RwLock<Vec<String>>>);
+    
+    impl EventHandler<OrderFilled> for FillRecorder {
+        fn handle(&self, event: &OrderFilled) -> Result<()> {
+            self.0.write().unwrap().push(event.exchange.clone());
+            Ok(())
+        }
+    }
+    
+    #[test]
+    fn test_topic_subscriptions() {
+        use cryptolytica_shared_kernel::events::EventFilter;
+        use cryptolytica_shared_kernel::types::SymbolPair;
+        
+        let event_bus = InMemoryEventBus::new().with_source("test");
+        let topics = Arc::new(RwLock::new(Vec::new()));
+        
+        // `exchange.#` 봉투 구독
+        let sink = topics.clone();
+        let handle = event_bus
+            .subscribe_topic(TopicSubscription::new("exchange.#").unwrap(), move |envelope: &EventEnvelope<Value>| {
+                sink.write().unwrap().push(envelope.header.event_type.clone());
+                Ok(())
+            })
+            .unwrap();
+        
+        // 바이낸스 BTC/USDT 체결만 타입 그대로 구독
+        let fills = Arc::new(RwLock::new(Vec::new()));
+        let subscription = TopicSubscription::new("exchange.order.*")
+            .unwrap()
+            .with_filter(EventFilter::exchange("binance"))
+            .with_filter(EventFilter::symbol(SymbolPair::new("BTC", "USDT")));
+        event_bus
+            .subscribe_matching(subscription, FillRecorder(fills.clone()))
+            .unwrap();
+        
+        event_bus.publish(OrderFilled::new("Binance", "BTC/USDT")).unwrap();
+        event_bus.publish(OrderFilled::new("OKX", "BTC/USDT")).unwrap();
+        event_bus.publish(OrderFilled::new("Binance", "ETH/USDT")).unwrap();
+        event_bus.publish(TestEvent {
+            id: Uuid::new_v4(),
+            message: "ignored".to_string(),
+            timestamp: Utc::now(),
+        }).unwrap();
+        
+        assert_eq!(topics.read().unwrap().len(), 3);
+        assert_eq!(*fills.read().unwrap(), vec!["Binance".to_string()]);
+        
+        // 토픽 구독은 패턴, 타입 구독은 이벤트 타입으로 집계
+        let snapshot = event_bus.metrics().snapshot();
+        assert_eq!(snapshot.event_type("exchange.order.filled").unwrap().published, 3);
+        assert_eq!(snapshot.event_type("exchange.order.filled").unwrap().subscribers, 1);
+        assert_eq!(snapshot.subscription("exchange.#").unwrap().stats.delivered, 3);
+        
+        // 토픽 구독 취소
+        event_bus.unsubscribe(&handle).unwrap();
+        event_bus.publish(OrderFilled::new("Binance", "BTC/USDT")).unwrap();
+        assert_eq!(topics.read().unwrap().len(), 3);
+        assert_eq!(fills.read().unwrap().len(), 2);
+        assert!(event_bus.unsubscribe(&handle).is_err());
+    }
+    
+    /// 선행 조건(`ready`)이 갖춰지기 전에는 실패하는 핸들러
+    struct OrderingHandler {
+        ready: Arc<std::sync::atomic::AtomicBool>,
+        received: Arc<RwLock<Vec<String>>>,
+    }
+    
+    impl EventHandler<TestEvent> for OrderingHandler {
+        fn handle(&self, event: &TestEvent) -> Result<()> {
+            if !self.ready.load(Ordering::SeqCst) {
+                return Err(CoreError::NotFound("주문 생성 이벤트가 아직 도착하지 않음".to_string()));
+            }
+            self.received.write().unwrap().push(event.message.clone());
+            Ok(())
+        }
+    }
+    
+    #[test]
+    fn test_retry_dead_letter_and_redrive() {
+        use chrono::TimeZone;
+        use cryptolytica_shared_kernel::clock::SimulatedClock;
+        use cryptolytica_shared_kernel::resilience::{ExponentialBackoff, Jitter};
+        use std::sync::atomic::AtomicBool;
+        use std::time::Duration;
+        
+        let clock = Arc::new(SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()));
+        let event_bus = InMemoryEventBus::new().with_clock(clock.clone());
+        let ready = Arc::new(AtomicBool::new(false));
+        let received = Arc::new(RwLock::new(Vec::new()));
+        let policy = RetryPolicy::new(3).with_backoff(
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(10)).with_jitter(Jitter::None),
+        );
+        event_bus
+            .subscribe_with_retry(
+                OrderingHandler { ready: ready.clone(), received: received.clone() },
+                "order-cache",
+                policy,
+            )
+            .unwrap();
+        let event = |message: &str| TestEvent {
+            id: Uuid::new_v4(),
+            message: message.to_string(),
+            timestamp: Utc::now(),
+        };
+        
+        // 첫 실패 후 1초 뒤 재시도에서 성공
+        event_bus.publish(event("fill-1")).unwrap();
+        assert_eq!(event_bus.pending_retries(), 1);
+        assert_eq!(event_bus.process_retries(), 0);
+        ready.store(true, Ordering::SeqCst);
+        clock.advance(chrono::Duration::seconds(1));
+        assert_eq!(event_bus.process_retries(), 1);
+        assert_eq!(*received.read().unwrap(), vec!["fill-1".to_string()]);
+        
+        // 시도 3회를 소진하면 데드 레터로 이동
+        ready.store(false, Ordering::SeqCst);
+        event_bus.publish(event("fill-2")).unwrap();
+        clock.advance(chrono::Duration::seconds(1));
+        event_bus.process_retries();
+        clock.advance(chrono::Duration::seconds(2));
+        event_bus.process_retries();
+        assert_eq!(event_bus.pending_retries(), 0);
+        
+        let letters = event_bus.dead_letters().unwrap();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].subscription, "order-cache");
+        assert_eq!(letters[0].attempts, 3);
+        assert_eq!(letters[0].envelope.payload["message"], "fill-2");
+        
+        // 재전달 실패 시 시도 횟수만 늘고 보관, 성공하면 제거
+        let id = letters[0].id;
+        assert!(event_bus.redrive(&id).is_err());
+        assert_eq!(event_bus.dead_letter(&id).unwrap().unwrap().attempts, 4);
+        ready.store(true, Ordering::SeqCst);
+        event_bus.redrive(&id).unwrap();
+        assert!(event_bus.dead_letter(&id).unwrap().is_none());
+        assert_eq!(received.read().unwrap().last().unwrap(), "fill-2");
+        
+        // 재시도 정책이 없는 구독은 바로 데드 레터로 이동하며, 폐기할 수 있음
+        let plain = InMemoryEventBus::new();
+        plain.subscribe(OrderingHandler { ready: Arc::new(AtomicBool::new(false)), received }).unwrap();
+        plain.publish(event("fill-3")).unwrap();
+        let letter = plain.dead_letters().unwrap().pop().unwrap();
+        assert_eq!(letter.attempts, 1);
+        plain.discard(&letter.id).unwrap();
+        assert!(plain.dead_letters().unwrap().is_empty());
+        
+        let metrics = event_bus.delivery_metrics();
+        assert_eq!(metrics.failed, 4);
+        assert_eq!(metrics.retried, 3);
+        assert_eq!(metrics.recovered, 1);
+        assert_eq!(metrics.dead_lettered, 1);
+        assert_eq!(metrics.redrive_failed, 1);
+        assert_eq!(metrics.redriven, 1);
+        assert_eq!(plain.delivery_metrics().discarded, 1);
+        
+        // 지표 엔드포인트로 내보내는 스냅숏에도 같은 값
+        assert_eq!(event_bus.metrics().snapshot().delivery, metrics);
+    }
+    
+    /// 받은 이벤트마다 체결 이벤트를 발행하는 핸들러 (주문 → 체결 흐름 흉내)
+    struct FillPublisher(Arc<InMemoryEventBus>);
+    
+    impl EventHandler<TestEvent> for FillPublisher {
+        fn handle(&self, _: &TestEvent) -> Result<()> {
+            self.0.publish(OrderFilled::new("Binance", "BTC/USDT"))
+        }
+    }
+    
+    #[test]
+    fn test_causation_propagates_through_handlers() {
+        use cryptolytica_shared_kernel::events::CausalityIndex;
+        
+        let event_bus = Arc::new(InMemoryEventBus::new());
+        let index = Arc::new(CausalityIndex::new());
+        let recorder = index.clone();
+        event_bus
+            .subscribe_topic(TopicSubscription::new("#").unwrap(), move |envelope: &EventEnvelope<Value>| {
+                recorder.record(&envelope.header);
+                Ok(())
+            })
+            .unwrap();
+        event_bus.subscribe(FillPublisher(event_bus.clone())).unwrap();
+        
+        let order = TestEvent {
+            id: Uuid::new_v4(),
+            message: "order".to_string(),
+            timestamp: Utc::now(),
+        };
+        event_bus.publish(order.clone()).unwrap();
+        assert!(EventContext::current().is_none());
+        
+        let chain = index.chain(&order.id);
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].link.event_id, order.id);
+        assert_eq!(chain[0].link.causation_id, None);
+        assert_eq!(chain[1].depth, 1);
+        assert_eq!(chain[1].link.event_type, "exchange.order.filled");
+        assert_eq!(chain[1].link.causation_id, Some(order.id));
+        assert_eq!(chain[1].link.correlation_id, order.id);
+        
+        // 체결 이벤트에서 거슬러 올라가도 같은 흐름
+        assert_eq!(index.chain(&chain[1].link.event_id), chain);
+    }
 } 
\ No newline at end of file
@@ -3,5 +3,43 @@
 // 이벤트 인프라스트럭처 모듈
 
 pub mod memory_event_bus;
+pub mod async_event_bus;
+pub mod broker;
+pub mod checkpoint_store;
+pub mod codec;
+pub mod dead_letter;
+pub mod dedup_store;
+pub mod embedded_broker;
+pub mod event_log;
+pub mod factory;
+pub mod snapshot_store;
+pub mod sqlite_outbox;
+#[cfg(feature = "amqp")]
+pub mod amqp;
+#[cfg(feature = "kafka")]
+pub mod kafka;
 
-pub use memory_event_bus::InMemoryEventBus; 
\ No newline at end of file
+pub use memory_event_bus::InMemoryEventBus;
+pub use async_event_bus::AsyncInMemoryEventBus;
+pub use broker::{
+    Acknowledger, BrokerConsumer, BrokerDelivery, BrokerEventBus, BrokerTransport, ConsumerBinding,
+    OutgoingMessage,
+};
+pub use checkpoint_store::FileCheckpointStore;
+pub use codec::{BinaryCodec, MessagePackCodec, WireCodec};
+pub use dead_letter::InMemoryDeadLetterStore;
+pub use dedup_store::FileDedupStore;
+pub use embedded_broker::EmbeddedBroker;
+pub use event_log::{
+    EventLogConfig, EventLogError, EventLogReader, FileEventLog, LogRecord, ReplayFrom, RetentionPolicy,
+    RetentionReport,
+}; 
+pub use factory::{
+    AmqpConfig, ConfiguredEventBus, EventBusConfig, EventBusFactory, EventBusKind, KafkaConfig,
+};
+pub use snapshot_store::FileSnapshotStore;
+pub use sqlite_outbox::{SqliteOutboxStore, OUTBOX_MIGRATION};
+#[cfg(feature = "amqp")]
+pub use amqp::AmqpTransport;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaTransport;
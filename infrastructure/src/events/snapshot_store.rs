@@ -0,0 +1,231 @@
+// snapshot_store.rs
+//
+// 파일 기반 스냅숏 저장소
+// 읽기 모델마다 디렉터리를 두고 스냅숏을 `{오프셋:020}.snap` 파일로 저장함
+//
+// 파일 형식: [매직 "CLSN"][형식 버전 u32][crc32 u32][길이 u64][스냅숏 JSON]
+// 임시 파일에 쓰고 동기화한 뒤 이름을 바꾸므로 쓰는 도중 종료되어도 이전 스냅숏은 온전하며,
+// 체크섬이나 길이가 맞지 않는 파일은 load에서 오류로 돌려줘 이전 스냅숏으로 물러나게 함
+
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{Snapshot, SnapshotStore};
+use cryptolytica_shared_kernel::types::Result;
+
+/// 스냅숏 파일 확장자
+const SNAPSHOT_EXTENSION: &str = "snap";
+
+/// 파일 시작 표시
+const MAGIC: &[u8; 4] = b"CLSN";
+
+/// 파일 형식 버전
+const FORMAT_VERSION: u32 = 1;
+
+/// 헤더 크기 (매직 + 형식 버전 + crc32 + 길이)
+const HEADER_LEN: usize = 4 + 4 + 4 + 8;
+
+/// 파일 기반 스냅숏 저장소
+#[derive(Debug, Clone)]
+pub struct FileSnapshotStore {
+    dir: PathBuf,
+}
+
+impl FileSnapshotStore {
+    /// 디렉터리를 열어 저장소 생성 (없으면 생성)
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// 저장소 디렉터리
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 읽기 모델의 스냅숏 디렉터리 (경로에 쓸 수 없는 문자는 `_`로 바꿈)
+    fn name_dir(&self, name: &str) -> PathBuf {
+        let safe: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+            .collect();
+        self.dir.join(safe)
+    }
+
+    /// 스냅숏 파일 경로
+    pub fn path(&self, name: &str, offset: u64) -> PathBuf {
+        self.name_dir(name).join(format!("{:020}.{}", offset, SNAPSHOT_EXTENSION))
+    }
+
+    fn encode(snapshot: &Snapshot) -> Result<Vec<u8>> {
+        let body = serde_json::to_vec(snapshot)?;
+        let mut bytes = Vec::with_capacity(HEADER_LEN + body.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&crc32fast::hash(&body).to_le_bytes());
+        bytes.extend_from_slice(&(body.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&body);
+        Ok(bytes)
+    }
+
+    fn decode(path: &Path, bytes: &[u8]) -> Result<Snapshot> {
+        let corrupt = |reason: &str| CoreError::Data(format!("손상된 스냅숏 {}: {}", path.display(), reason));
+
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(corrupt("헤더 없음"));
+        }
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != FORMAT_VERSION {
+            return Err(corrupt(&format!("지원하지 않는 형식 버전 {}", version)));
+        }
+        let checksum = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let len = u64::from_le_bytes(bytes[12..20].try_into().unwrap());
+        let body = &bytes[HEADER_LEN..];
+        if body.len() as u64 != len {
+            return Err(corrupt(&format!("길이 불일치 ({} / {})", body.len(), len)));
+        }
+        if crc32fast::hash(body) != checksum {
+            return Err(corrupt("체크섬 불일치"));
+        }
+        serde_json::from_slice(body).map_err(|e| corrupt(&e.to_string()))
+    }
+}
+
+impl SnapshotStore for FileSnapshotStore {
+    fn save(&self, snapshot: &Snapshot) -> Result<()> {
+        let dir = self.name_dir(&snapshot.name);
+        fs::create_dir_all(&dir)?;
+
+        let path = self.path(&snapshot.name, snapshot.offset);
+        let temp = path.with_extension("tmp");
+        {
+            let mut file = File::create(&temp)?;
+            file.write_all(&Self::encode(snapshot)?)?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp, &path)?;
+        // 이름 변경을 디스크에 반영 (지원하지 않는 플랫폼은 무시)
+        if let Ok(dir) = File::open(&dir) {
+            let _ = dir.sync_all();
+        }
+        Ok(())
+    }
+
+    fn offsets(&self, name: &str) -> Result<Vec<u64>> {
+        let dir = self.name_dir(name);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut offsets: Vec<u64> = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(SNAPSHOT_EXTENSION) {
+                continue;
+            }
+            if let Some(offset) = path.file_stem().and_then(|stem| stem.to_str()?.parse().ok()) {
+                offsets.push(offset);
+            }
+        }
+        offsets.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(offsets)
+    }
+
+    fn load(&self, name: &str, offset: u64) -> Result<Snapshot> {
+        let path = self.path(name, offset);
+        let mut bytes = Vec::new();
+        File::open(&path)?.read_to_end(&mut bytes)?;
+
+        let snapshot = Self::decode(&path, &bytes)?;
+        if snapshot.name != name || snapshot.offset != offset {
+            return Err(CoreError::Data(format!(
+                "스냅숏 내용이 파일 위치와 다름: {} ({}@{})",
+                path.display(),
+                snapshot.name,
+                snapshot.offset
+            )));
+        }
+        Ok(snapshot)
+    }
+
+    fn prune(&self, name: &str, keep: usize) -> Result<usize> {
+        let offsets = self.offsets(name)?;
+        let mut removed = 0;
+        for offset in offsets.into_iter().skip(keep) {
+            fs::remove_file(self.path(name, offset))?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use chrono::Utc;
+    use serde_json::json;
+    use cryptolytica_shared_kernel::events::{SnapshotPolicy, Snapshottable, Snapshotter};
+
+    fn snapshot(offset: u64, total: i64) -> Snapshot {
+        Snapshot {
+            name: "trading.order_cache".to_string(),
+            version: 1,
+            offset,
+            created_at: Utc::now(),
+            state: json!({ "total": total }),
+        }
+    }
+
+    struct Total(std::sync::Mutex<i64>);
+
+    impl Snapshottable for Total {
+        fn snapshot_name(&self) -> &str {
+            "trading.order_cache"
+        }
+
+        fn snapshot_state(&self) -> Result<serde_json::Value> {
+            Ok(json!({ "total": *self.0.lock().unwrap() }))
+        }
+
+        fn restore_state(&self, state: serde_json::Value) -> Result<()> {
+            *self.0.lock().unwrap() = state["total"].as_i64().unwrap_or_default();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_corrupt_snapshot_falls_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileSnapshotStore::open(dir.path()).unwrap();
+        for (offset, total) in [(10, 1), (20, 2), (30, 3), (40, 4)] {
+            store.save(&snapshot(offset, total)).unwrap();
+        }
+        assert_eq!(store.prune("trading.order_cache", 3).unwrap(), 1);
+        assert_eq!(store.offsets("trading.order_cache").unwrap(), [40, 30, 20]);
+        assert_eq!(store.load("trading.order_cache", 40).unwrap().state, json!({ "total": 4 }));
+
+        // 최신 스냅숏의 한 바이트를 바꾸고, 그다음 스냅숏은 잘라냄
+        let latest = store.path("trading.order_cache", 40);
+        let mut bytes = fs::read(&latest).unwrap();
+        let last = bytes.len() - 2;
+        bytes[last] ^= 0xff;
+        fs::write(&latest, bytes).unwrap();
+        let torn = store.path("trading.order_cache", 30);
+        let bytes = fs::read(&torn).unwrap();
+        fs::write(&torn, &bytes[..bytes.len() / 2]).unwrap();
+
+        assert!(store.load("trading.order_cache", 40).is_err());
+        assert!(store.load("trading.order_cache", 30).is_err());
+
+        let total = Total(std::sync::Mutex::new(0));
+        let snapshotter = Snapshotter::new(Arc::new(store), SnapshotPolicy::every_events(10));
+        let report = snapshotter.recover(&total, |from| Ok(45 - from)).unwrap();
+        assert_eq!(report.restored_offset, Some(20));
+        assert_eq!(report.skipped, 2);
+        assert_eq!(report.replayed, 25);
+        assert_eq!(*total.0.lock().unwrap(), 2);
+    }
+}
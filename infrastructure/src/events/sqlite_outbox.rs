@@ -0,0 +1,283 @@
+// sqlite_outbox.rs
+//
+// SQLite 기반 아웃박스 저장소
+// 리포지토리가 상태를 쓰는 트랜잭션 안에서 `enqueue_in`으로 이벤트를 함께 기록하고,
+// 커밋된 메시지만 `OutboxRelay`가 읽어 발행함 (롤백되면 이벤트도 남지 않음)
+// 시각은 정렬·비교가 쉽도록 유닉스 밀리초 정수로 저장함
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use sqlx::sqlite::{SqliteConnection, SqlitePool, SqlitePoolOptions, SqliteRow};
+use sqlx::Row;
+use cryptolytica_shared_kernel::clock::{system_clock, SharedClock};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{EventEnvelope, OutboxBatch, OutboxMessage, OutboxStatus, OutboxStore};
+use cryptolytica_shared_kernel::types::Result;
+use crate::database::db_error;
+
+/// 아웃박스 테이블 마이그레이션
+pub const OUTBOX_MIGRATION: &str = r#"
+CREATE TABLE IF NOT EXISTS event_outbox (
+    sequence     INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id     TEXT    NOT NULL UNIQUE,
+    event_type   TEXT    NOT NULL,
+    envelope     TEXT    NOT NULL,
+    status       TEXT    NOT NULL DEFAULT 'pending',
+    enqueued_at  INTEGER NOT NULL,
+    attempts     INTEGER NOT NULL DEFAULT 0,
+    last_error   TEXT,
+    published_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS idx_event_outbox_status ON event_outbox (status, sequence);
+"#;
+
+/// SQLite 기반 아웃박스 저장소
+#[derive(Clone)]
+pub struct SqliteOutboxStore {
+    pool: SqlitePool,
+    clock: SharedClock,
+}
+
+impl SqliteOutboxStore {
+    /// 기존 풀로 저장소 생성 (마이그레이션은 `migrate`로 별도 실행)
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            clock: system_clock(),
+        }
+    }
+
+    /// 데이터베이스 URL로 연결하고 마이그레이션까지 실행
+    pub async fn connect(url: &str) -> Result<Self> {
+        let pool = SqlitePoolOptions::new().connect(url).await.map_err(db_error)?;
+        let store = Self::new(pool);
+        store.migrate().await?;
+        Ok(store)
+    }
+
+    /// 시계 설정
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 연결 풀 (리포지토리가 같은 데이터베이스에서 트랜잭션을 열 때 사용)
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// 아웃박스 테이블 생성
+    pub async fn migrate(&self) -> Result<()> {
+        sqlx::raw_sql(OUTBOX_MIGRATION).execute(&self.pool).await.map_err(db_error)?;
+        Ok(())
+    }
+
+    /// 호출자의 트랜잭션 안에서 이벤트 기록 (커밋해야 릴레이에 보임)
+    pub async fn enqueue_in(&self, conn: &mut SqliteConnection, batch: &OutboxBatch) -> Result<Vec<u64>> {
+        let enqueued_at = self.clock.now().timestamp_millis();
+        let mut sequences = Vec::with_capacity(batch.len());
+        for envelope in batch.envelopes() {
+            let row = sqlx::query(
+                "INSERT INTO event_outbox (event_id, event_type, envelope, status, enqueued_at) \
+                 VALUES (?, ?, ?, ?, ?) RETURNING sequence",
+            )
+            .bind(envelope.header.id.to_string())
+            .bind(&envelope.header.event_type)
+            .bind(serde_json::to_string(envelope)?)
+            .bind(OutboxStatus::Pending.as_str())
+            .bind(enqueued_at)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error)?;
+            sequences.push(row.try_get::<i64, _>("sequence").map_err(db_error)? as u64);
+        }
+        Ok(sequences)
+    }
+}
+
+#[async_trait]
+impl OutboxStore for SqliteOutboxStore {
+    async fn enqueue(&self, batch: &OutboxBatch) -> Result<Vec<u64>> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let sequences = self.enqueue_in(&mut tx, batch).await?;
+        tx.commit().await.map_err(db_error)?;
+        Ok(sequences)
+    }
+
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxMessage>> {
+        let rows = sqlx::query(
+            "SELECT sequence, envelope, status, enqueued_at, attempts, last_error, published_at \
+             FROM event_outbox WHERE status = ? ORDER BY sequence LIMIT ?",
+        )
+        .bind(OutboxStatus::Pending.as_str())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.iter().map(decode_message).collect()
+    }
+
+    async fn mark_published(&self, sequences: &[u64], at: DateTime<Utc>) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        for sequence in sequences {
+            sqlx::query("UPDATE event_outbox SET status = ?, published_at = ? WHERE sequence = ?")
+                .bind(OutboxStatus::Published.as_str())
+                .bind(at.timestamp_millis())
+                .bind(*sequence as i64)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn mark_failed(&self, sequence: u64, error: &str, dead: bool) -> Result<()> {
+        let status = if dead { OutboxStatus::Dead } else { OutboxStatus::Pending };
+        let result = sqlx::query(
+            "UPDATE event_outbox SET attempts = attempts + 1, last_error = ?, status = ? WHERE sequence = ?",
+        )
+        .bind(error)
+        .bind(status.as_str())
+        .bind(sequence as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(CoreError::NotFound(format!("아웃박스 메시지를 찾을 수 없음: {}", sequence)));
+        }
+        Ok(())
+    }
+
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<usize> {
+        let result = sqlx::query("DELETE FROM event_outbox WHERE status = ? AND published_at < ?")
+            .bind(OutboxStatus::Published.as_str())
+            .bind(before.timestamp_millis())
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+/// 행을 아웃박스 메시지로 복원
+fn decode_message(row: &SqliteRow) -> Result<OutboxMessage> {
+    let sequence: i64 = row.try_get("sequence").map_err(db_error)?;
+    let envelope: String = row.try_get("envelope").map_err(db_error)?;
+    let status: String = row.try_get("status").map_err(db_error)?;
+    let enqueued_at: i64 = row.try_get("enqueued_at").map_err(db_error)?;
+    let attempts: i64 = row.try_get("attempts").map_err(db_error)?;
+    let published_at: Option<i64> = row.try_get("published_at").map_err(db_error)?;
+
+    let envelope: EventEnvelope<serde_json::Value> = serde_json::from_str(&envelope)
+        .map_err(|e| CoreError::Data(format!("손상된 아웃박스 메시지 {}: {}", sequence, e)))?;
+    Ok(OutboxMessage {
+        sequence: sequence as u64,
+        envelope,
+        status: OutboxStatus::parse(&status)
+            .ok_or_else(|| CoreError::Data(format!("알 수 없는 아웃박스 상태: {}", status)))?,
+        enqueued_at: from_millis(enqueued_at)?,
+        attempts: attempts as u32,
+        last_error: row.try_get("last_error").map_err(db_error)?,
+        published_at: published_at.map(from_millis).transpose()?,
+    })
+}
+
+fn from_millis(millis: i64) -> Result<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .ok_or_else(|| CoreError::Data(format!("잘못된 타임스탬프: {}", millis)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+    use cryptolytica_shared_kernel::events::{Event, EventBus, EventHandler, OutboxRelay, OutboxRoutes};
+    use crate::events::memory_event_bus::InMemoryEventBus;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct OrderSaved {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+        order_id: String,
+    }
+
+    impl Event for OrderSaved {
+        fn event_type(&self) -> &'static str {
+            "exchange.order.saved"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    struct Counter(Arc<AtomicUsize>);
+
+    impl EventHandler<OrderSaved> for Counter {
+        fn handle(&self, _event: &OrderSaved) -> Result<()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn saved(order_id: &str) -> OrderSaved {
+        OrderSaved {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            order_id: order_id.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_outbox_rows_follow_transaction_and_relay_publishes() {
+        // 설정: 메모리 데이터베이스는 연결마다 따로 생기므로 연결 하나만 사용
+        let pool = SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+        let store = Arc::new(SqliteOutboxStore::new(pool));
+        store.migrate().await.unwrap();
+
+        // 롤백한 트랜잭션의 이벤트는 남지 않음
+        let mut tx = store.pool().begin().await.unwrap();
+        store
+            .enqueue_in(&mut tx, &OutboxBatch::new("exchange-domain").with(&saved("rolled-back")).unwrap())
+            .await
+            .unwrap();
+        tx.rollback().await.unwrap();
+        assert!(store.pending(10).await.unwrap().is_empty());
+
+        // 커밋한 이벤트는 순서대로 대기
+        let mut tx = store.pool().begin().await.unwrap();
+        let batch = OutboxBatch::new("exchange-domain")
+            .with(&saved("o-1"))
+            .unwrap()
+            .with(&saved("o-2"))
+            .unwrap();
+        let sequences = store.enqueue_in(&mut tx, &batch).await.unwrap();
+        tx.commit().await.unwrap();
+        assert_eq!(sequences.len(), 2);
+        assert!(sequences[0] < sequences[1]);
+
+        // 릴레이가 버스로 발행하고 발행 완료로 표시
+        let bus = Arc::new(InMemoryEventBus::new());
+        let received = Arc::new(AtomicUsize::new(0));
+        bus.subscribe(Counter(received.clone())).unwrap();
+        let relay = OutboxRelay::new(
+            store.clone(),
+            OutboxRoutes::new().route::<OrderSaved, _>("exchange.order.saved", bus.clone()),
+        );
+        let report = relay.relay_once().await.unwrap();
+
+        assert_eq!(report.published, 2);
+        assert_eq!(received.load(Ordering::SeqCst), 2);
+        assert!(store.pending(10).await.unwrap().is_empty());
+        assert_eq!(store.purge_published(Utc::now() + chrono::Duration::seconds(1)).await.unwrap(), 2);
+    }
+}
@@ -3,13 +3,17 @@
 //! 이 모듈은 도메인 인터페이스의 구현체를 제공합니다.
 //! 데이터베이스, 메시징, HTTP 클라이언트 등 외부 시스템과의 연동을 담당합니다.
 
+pub mod database;
 pub mod events;
+pub mod monitoring;
 pub mod repositories;
 pub mod services;
 pub mod adapters;
 
 // 공개 타입
-pub use events::InMemoryEventBus;
+pub use events::{AsyncInMemoryEventBus, BrokerEventBus, EventBusFactory, FileEventLog, InMemoryEventBus};
+pub use repositories::{PostgresRepositories, SqliteRepositories};
+pub use monitoring::MetricsServer;
 
 /// 인프라스트럭처 모듈 버전
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -37,10 +41,6 @@ pub mod tests {
 }
 
 // 아직 구현되지 않은 모듈 스텁
-pub mod repositories {
-    //! 저장소 구현체 모듈
-}
-
 pub mod services {
     //! 서비스 구현체 모듈
 }
@@ -0,0 +1,209 @@
+// metrics_endpoint.rs
+//
+// 지표 HTTP 엔드포인트
+// 설정의 `monitoring` 항목에 따라, 등록된 이벤트 버스 지표를 `metrics_path`에 Prometheus 텍스트
+// 형식으로, `{metrics_path}/events`에 관리 도구용 JSON 스냅숏으로 내보냄
+//
+// Prometheus 수집을 끄면(`prometheus_enabled: false`) 텍스트 경로만 빠지고 JSON 스냅숏은 남음
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::EventMetricsRegistry;
+use cryptolytica_shared_kernel::types::Result;
+
+/// 설정 파일에서 모니터링 설정을 읽는 키
+pub const MONITORING_CONFIG_KEY: &str = "monitoring";
+
+/// Prometheus 텍스트 형식의 콘텐츠 타입
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_metrics_path() -> String {
+    "/metrics".to_string()
+}
+
+fn default_listen_address() -> String {
+    "0.0.0.0:9090".to_string()
+}
+
+/// 모니터링 설정
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitoringConfig {
+    /// Prometheus 텍스트 엔드포인트 사용 여부
+    #[serde(default = "default_enabled")]
+    pub prometheus_enabled: bool,
+    /// 지표 경로
+    #[serde(default = "default_metrics_path")]
+    pub metrics_path: String,
+    /// 추적 내보내기 사용 여부
+    #[serde(default)]
+    pub tracing_enabled: bool,
+    /// 지표 서버 주소
+    #[serde(default = "default_listen_address")]
+    pub listen_address: String,
+}
+
+impl Default for MonitoringConfig {
+    fn default() -> Self {
+        Self {
+            prometheus_enabled: default_enabled(),
+            metrics_path: default_metrics_path(),
+            tracing_enabled: false,
+            listen_address: default_listen_address(),
+        }
+    }
+}
+
+impl MonitoringConfig {
+    /// 설정에서 `monitoring` 항목 읽기 (없으면 기본값)
+    pub fn from_config(config: &config::Config) -> Result<Self> {
+        match config.get::<Self>(MONITORING_CONFIG_KEY) {
+            Ok(settings) => Ok(settings),
+            Err(config::ConfigError::NotFound(_)) => Ok(Self::default()),
+            Err(e) => Err(CoreError::Configuration(format!("모니터링 설정 오류: {}", e))),
+        }
+    }
+
+    /// JSON 스냅숏 경로
+    pub fn snapshot_path(&self) -> String {
+        format!("{}/events", self.metrics_path.trim_end_matches('/'))
+    }
+}
+
+async fn prometheus_metrics(State(registry): State<Arc<EventMetricsRegistry>>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], registry.render_prometheus())
+}
+
+async fn metrics_snapshot(State(registry): State<Arc<EventMetricsRegistry>>) -> impl IntoResponse {
+    Json(registry.snapshot())
+}
+
+/// 지표 라우터 (API 서버에 합치거나 `MetricsServer`로 따로 띄움)
+pub fn metrics_router(config: &MonitoringConfig, registry: Arc<EventMetricsRegistry>) -> Router {
+    let mut router = Router::new().route(&config.snapshot_path(), get(metrics_snapshot));
+    if config.prometheus_enabled {
+        router = router.route(&config.metrics_path, get(prometheus_metrics));
+    }
+    router.with_state(registry)
+}
+
+/// 지표 전용 HTTP 서버
+pub struct MetricsServer {
+    local_addr: SocketAddr,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl MetricsServer {
+    /// `listen_address`에 지표 서버 시작
+    pub async fn start(config: &MonitoringConfig, registry: Arc<EventMetricsRegistry>) -> Result<Self> {
+        let listener = tokio::net::TcpListener::bind(&config.listen_address).await?;
+        let local_addr = listener.local_addr()?;
+        let (shutdown, stopped) = oneshot::channel();
+
+        let router = metrics_router(config, registry);
+        let task = tokio::spawn(async move {
+            let served = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = stopped.await;
+                })
+                .await;
+            if let Err(e) = served {
+                tracing::error!("지표 서버 오류: {}", e);
+            }
+        });
+
+        tracing::info!("지표 서버 시작: http://{}{}", local_addr, config.metrics_path);
+        Ok(Self {
+            local_addr,
+            shutdown,
+            task,
+        })
+    }
+
+    /// 실제로 바인딩된 주소 (포트 0으로 시작한 경우 확인용)
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// 처리 중인 요청을 마친 뒤 종료
+    pub async fn shutdown(self) -> Result<()> {
+        let _ = self.shutdown.send(());
+        self.task
+            .await
+            .map_err(|e| CoreError::Unknown(format!("지표 서버 태스크 실패: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use cryptolytica_shared_kernel::events::{AsyncEventBus, EventBusMetricsSnapshot, SubscriptionOptions};
+    use crate::events::broker::tests::{eventually, filled, Recorder};
+    use crate::events::AsyncInMemoryEventBus;
+
+    async fn get(addr: SocketAddr, path: &str) -> (String, String) {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path);
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.to_string(), body.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_serves_prometheus_text_and_snapshot() {
+        let bus = AsyncInMemoryEventBus::new();
+        let recorder = Recorder::default();
+        bus.subscribe(recorder.clone(), SubscriptionOptions::new("audit")).unwrap();
+        bus.publish(filled("order-1")).await.unwrap();
+        eventually(|| recorder.orders() == ["order-1"]).await;
+
+        let registry = Arc::new(EventMetricsRegistry::new());
+        registry.register(bus.metrics().clone());
+        let config = MonitoringConfig {
+            listen_address: "127.0.0.1:0".to_string(),
+            ..MonitoringConfig::default()
+        };
+        let server = MetricsServer::start(&config, registry).await.unwrap();
+
+        let (head, body) = get(server.local_addr(), "/metrics").await;
+        assert!(head.starts_with("HTTP/1.1 200"));
+        assert!(head.contains(PROMETHEUS_CONTENT_TYPE));
+        assert!(body.contains(
+            "cryptolytica_event_published_total{bus=\"in_memory\",event_type=\"exchange.order.filled\"} 1\n"
+        ));
+
+        let (_, body) = get(server.local_addr(), "/metrics/events").await;
+        let snapshots: Vec<EventBusMetricsSnapshot> = serde_json::from_str(&body).unwrap();
+        assert_eq!(snapshots[0].subscription("audit").unwrap().stats.delivered, 1);
+
+        server.shutdown().await.unwrap();
+        bus.shutdown(Duration::from_secs(1)).await.unwrap();
+
+        // Prometheus 수집을 끄면 텍스트 경로는 없음
+        let disabled = MonitoringConfig {
+            prometheus_enabled: false,
+            ..config
+        };
+        let server = MetricsServer::start(&disabled, Arc::new(EventMetricsRegistry::new())).await.unwrap();
+        let (head, _) = get(server.local_addr(), "/metrics").await;
+        assert!(head.starts_with("HTTP/1.1 404"));
+        server.shutdown().await.unwrap();
+    }
+}
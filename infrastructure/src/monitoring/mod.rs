@@ -0,0 +1,7 @@
+// monitoring/mod.rs
+//
+// 모니터링 인프라스트럭처 모듈
+
+pub mod metrics_endpoint;
+
+pub use metrics_endpoint::{metrics_router, MetricsServer, MonitoringConfig};
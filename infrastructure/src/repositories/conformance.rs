@@ -0,0 +1,407 @@
+// conformance.rs
+//
+// 리포지토리 적합성 테스트
+// 모든 리포지토리 구현체가 지켜야 하는 동작을 한곳에 정의함
+// - 벌크 저장은 전부 반영되거나 전부 반영되지 않음
+// - 거래소 주문 ID·체결 ID·심볼은 거래소 안에서 고유하며 중복은 `CoreError::Validation`
+// - 거래소별 조회는 최신순이며 `limit`은 개수 상한 (`None`은 전체, `Some(0)`은 빈 결과)
+// - `*_with_events`는 상태와 이벤트를 함께 기록함
+// - 캔들은 (거래소, 심볼, 타임프레임, 시작 시각) 키로 upsert되며 완성 캔들은 미완성 값으로 덮이지 않음
+// - 캔들 커서 페이지는 빠짐·중복 없이 이어지고, 페이지 사이에 추가된 캔들도 순서대로 이어서 읽힘
+// - 아웃박스는 기록 순서대로 대기 메시지를 돌려주고, 죽은·발행된 메시지는 대기 목록에서 빠짐
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use cryptolytica_exchange_domain::domain::model::{
+    Exchange, ExchangeId, ExchangeStatus, ExchangeType, Market, Order, OrderSide, OrderType, Trade,
+};
+use cryptolytica_exchange_domain::domain::repository::{
+    ExchangeRepository, MarketRepository, OrderRepository, TradeRepository,
+};
+use cryptolytica_market_domain::model::{Candle, MarketDataType};
+use cryptolytica_market_domain::repository::{CandleKey, CandleRepository, CandleWrite, MarketDataFilter};
+use cryptolytica_shared_kernel::clock::SimulatedClock;
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{Event, OutboxBatch, OutboxMessage, OutboxStatus, OutboxStore};
+use cryptolytica_shared_kernel::types::{Decimal, ExchangeId as MarketExchangeId, SymbolPair, Timeframe};
+
+/// 적합성 테스트용 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Stored {
+    id: Uuid,
+    timestamp: DateTime<Utc>,
+    entity_id: Uuid,
+}
+
+impl Event for Stored {
+    fn event_type(&self) -> &'static str {
+        "exchange.test.stored"
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn id(&self) -> &Uuid {
+        &self.id
+    }
+}
+
+fn events_for(entity_id: Uuid) -> OutboxBatch {
+    let event = Stored {
+        id: Uuid::new_v4(),
+        timestamp: Utc::now(),
+        entity_id,
+    };
+    OutboxBatch::new("exchange-domain").with(&event).unwrap()
+}
+
+fn test_clock() -> SimulatedClock {
+    SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+}
+
+fn order_at(clock: &SimulatedClock, exchange: &str) -> Order {
+    clock.advance(Duration::minutes(1));
+    Order::new(
+        ExchangeId::new(exchange),
+        SymbolPair::new("BTC", "USDT"),
+        OrderType::Limit,
+        OrderSide::Buy,
+        Decimal::new(15, 1),
+        Some(Decimal::new(5000012, 2)),
+        clock,
+    )
+}
+
+fn trade_at(clock: &SimulatedClock, exchange: &str, exchange_trade_id: &str) -> Trade {
+    let executed_at = clock.advance(Duration::seconds(10));
+    Trade::new(
+        ExchangeId::new(exchange),
+        exchange_trade_id,
+        SymbolPair::new("BTC", "USDT"),
+        OrderSide::Sell,
+        Decimal::new(5000012, 2),
+        Decimal::new(1, 3),
+        executed_at,
+    )
+}
+
+/// 거래소 리포지토리 적합성
+pub(crate) async fn exchange_repository_conformance<R: ExchangeRepository>(repo: &R) {
+    let clock = test_clock();
+    let binance = Exchange::new(
+        ExchangeId::new("binance"),
+        "Binance",
+        ExchangeType::Centralized,
+        "https://api.binance.com",
+        &clock,
+    );
+    let mut upbit = Exchange::new(ExchangeId::new("upbit"), "Upbit", ExchangeType::Centralized, "https://api.upbit.com", &clock);
+    repo.save(&upbit).await.unwrap();
+    repo.save(&binance).await.unwrap();
+
+    // 조회와 정렬 (거래소 코드순)
+    assert_eq!(repo.find_by_id(binance.id).await.unwrap().unwrap().name, "Binance");
+    assert_eq!(repo.find_by_exchange_id(&ExchangeId::new("upbit")).await.unwrap().unwrap().id, upbit.id);
+    let codes: Vec<_> = repo.find_all().await.unwrap().into_iter().map(|e| e.exchange_id).collect();
+    assert_eq!(codes, vec![ExchangeId::new("binance"), ExchangeId::new("upbit")]);
+
+    // 같은 ID로 저장하면 갱신
+    upbit.update_status(ExchangeStatus::Maintenance, &clock);
+    repo.save(&upbit).await.unwrap();
+    let active = repo.find_active().await.unwrap();
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].id, binance.id);
+
+    // 다른 ID로 같은 거래소 코드를 저장하면 거부
+    let duplicate = Exchange::new(ExchangeId::new("binance"), "Binance 2", ExchangeType::Centralized, "https://x", &clock);
+    assert!(matches!(repo.save(&duplicate).await, Err(CoreError::Validation(_))));
+
+    // 삭제 (없는 거래소 삭제는 NotFound)
+    repo.delete(upbit.id).await.unwrap();
+    assert!(repo.find_by_id(upbit.id).await.unwrap().is_none());
+    assert!(matches!(repo.delete(upbit.id).await, Err(CoreError::NotFound(_))));
+}
+
+/// 주문 리포지토리 적합성
+pub(crate) async fn order_repository_conformance<R: OrderRepository>(repo: &R, outbox: &dyn OutboxStore) {
+    let clock = test_clock();
+    let binance = ExchangeId::new("binance");
+    let upbit = ExchangeId::new("upbit");
+
+    // 벌크 저장
+    let mut orders = vec![order_at(&clock, "binance"), order_at(&clock, "binance"), order_at(&clock, "binance")];
+    orders[0].accept("B-1", &clock);
+    orders[1].accept("B-2", &clock);
+    let saved = repo.save_batch(&orders).await.unwrap();
+    assert_eq!(saved, orders);
+    repo.save(&order_at(&clock, "upbit")).await.unwrap();
+    assert_eq!(repo.find_by_id(orders[2].id).await.unwrap(), Some(orders[2].clone()));
+
+    // 거래소별 조회: 최신순, limit 처리
+    let all = repo.find_by_exchange(&binance, None).await.unwrap();
+    assert_eq!(all.iter().map(|o| o.id).collect::<Vec<_>>(), vec![orders[2].id, orders[1].id, orders[0].id]);
+    let limited = repo.find_by_exchange(&binance, Some(2)).await.unwrap();
+    assert_eq!(limited.iter().map(|o| o.id).collect::<Vec<_>>(), vec![orders[2].id, orders[1].id]);
+    assert!(repo.find_by_exchange(&binance, Some(0)).await.unwrap().is_empty());
+    assert_eq!(repo.find_by_exchange(&binance, Some(10)).await.unwrap().len(), 3);
+    assert_eq!(repo.find_by_exchange(&upbit, None).await.unwrap().len(), 1);
+
+    // 거래소 주문 ID 조회 (거래소 범위 안에서만)
+    let found = repo.find_by_exchange_order_id(&binance, "B-1").await.unwrap().unwrap();
+    assert_eq!(found.id, orders[0].id);
+    assert!(repo.find_by_exchange_order_id(&upbit, "B-1").await.unwrap().is_none());
+    assert!(repo.find_by_exchange_order_id(&binance, "missing").await.unwrap().is_none());
+
+    // 갱신하면 새 거래소 주문 ID로도 조회됨
+    orders[2].accept("B-3", &clock);
+    orders[2].record_fill(Decimal::new(15, 1), Decimal::new(50000, 0), &clock);
+    repo.save(&orders[2]).await.unwrap();
+    assert_eq!(repo.find_by_exchange_order_id(&binance, "B-3").await.unwrap(), Some(orders[2].clone()));
+    assert_eq!(repo.find_by_exchange(&binance, None).await.unwrap().len(), 3);
+
+    // 중복된 거래소 주문 ID가 섞인 벌크 저장은 전부 거부
+    let fresh = order_at(&clock, "binance");
+    let mut conflicting = order_at(&clock, "binance");
+    conflicting.accept("B-1", &clock);
+    let result = repo.save_batch(&[fresh.clone(), conflicting]).await;
+    assert!(matches!(result, Err(CoreError::Validation(_))));
+    assert!(repo.find_by_id(fresh.id).await.unwrap().is_none());
+
+    // 이벤트와 함께 저장
+    let before = outbox.pending(100).await.unwrap().len();
+    let with_events = order_at(&clock, "binance");
+    let batch = events_for(with_events.id);
+    repo.save_with_events(&with_events, &batch).await.unwrap();
+    assert!(repo.find_by_id(with_events.id).await.unwrap().is_some());
+    let pending = outbox.pending(100).await.unwrap();
+    assert_eq!(pending.len(), before + 1);
+    assert_eq!(pending.last().unwrap().envelope.header.id, batch.envelopes()[0].header.id);
+
+    // 거부된 저장은 이벤트도 남기지 않음
+    let mut rejected = order_at(&clock, "binance");
+    rejected.accept("B-2", &clock);
+    let result = repo.save_batch_with_events(&[rejected], &events_for(Uuid::new_v4())).await;
+    assert!(matches!(result, Err(CoreError::Validation(_))));
+    assert_eq!(outbox.pending(100).await.unwrap().len(), before + 1);
+}
+
+/// 거래 리포지토리 적합성
+pub(crate) async fn trade_repository_conformance<R: TradeRepository>(repo: &R, outbox: &dyn OutboxStore) {
+    let clock = test_clock();
+    let binance = ExchangeId::new("binance");
+    let order_id = Uuid::new_v4();
+
+    // 벌크 저장
+    let trades = vec![
+        trade_at(&clock, "binance", "T-1").with_order(order_id),
+        trade_at(&clock, "binance", "T-2").with_fee(Decimal::new(5, 2), "USDT"),
+        trade_at(&clock, "binance", "T-3").with_order(order_id),
+    ];
+    assert_eq!(repo.save_batch(&trades).await.unwrap(), trades);
+    repo.save(&trade_at(&clock, "upbit", "T-1")).await.unwrap();
+    assert_eq!(repo.find_by_id(trades[1].id).await.unwrap(), Some(trades[1].clone()));
+
+    // 주문별 조회는 체결 시간순
+    let by_order = repo.find_by_order_id(order_id).await.unwrap();
+    assert_eq!(by_order.iter().map(|t| t.id).collect::<Vec<_>>(), vec![trades[0].id, trades[2].id]);
+
+    // 거래소별 조회: 최신순, limit 처리
+    let latest = repo.find_by_exchange(&binance, Some(1)).await.unwrap();
+    assert_eq!(latest.iter().map(|t| t.id).collect::<Vec<_>>(), vec![trades[2].id]);
+    assert_eq!(repo.find_by_exchange(&binance, None).await.unwrap().len(), 3);
+    assert!(repo.find_by_exchange(&binance, Some(0)).await.unwrap().is_empty());
+
+    // 같은 거래소의 체결 ID 중복은 전부 거부
+    let fresh = trade_at(&clock, "binance", "T-4");
+    let duplicate = trade_at(&clock, "binance", "T-2");
+    let result = repo.save_batch(&[fresh.clone(), duplicate]).await;
+    assert!(matches!(result, Err(CoreError::Validation(_))));
+    assert!(repo.find_by_id(fresh.id).await.unwrap().is_none());
+
+    // 이벤트와 함께 저장
+    let before = outbox.pending(100).await.unwrap().len();
+    repo.save_batch_with_events(std::slice::from_ref(&fresh), &events_for(fresh.id)).await.unwrap();
+    assert!(repo.find_by_id(fresh.id).await.unwrap().is_some());
+    assert_eq!(outbox.pending(100).await.unwrap().len(), before + 1);
+}
+
+/// 마켓 리포지토리 적합성
+pub(crate) async fn market_repository_conformance<R: MarketRepository>(repo: &R) {
+    let clock = test_clock();
+    let binance = ExchangeId::new("binance");
+    let markets = vec![
+        Market::new(binance.clone(), SymbolPair::new("ETH", "USDT"), "ETHUSDT", 2, 4, &clock),
+        Market::new(binance.clone(), SymbolPair::new("BTC", "USDT"), "BTCUSDT", 2, 6, &clock),
+        Market::new(ExchangeId::new("upbit"), SymbolPair::new("BTC", "KRW"), "KRW-BTC", 0, 8, &clock),
+    ];
+    assert_eq!(repo.save_batch(&markets).await.unwrap(), markets);
+
+    // 표준 표기와 거래소 고유 심볼 모두로 조회
+    let by_standard = repo.find_by_exchange_and_symbol(&binance, "BTC/USDT").await.unwrap().unwrap();
+    let by_native = repo.find_by_exchange_and_symbol(&binance, "BTCUSDT").await.unwrap().unwrap();
+    assert_eq!(by_standard.id, markets[1].id);
+    assert_eq!(by_native.id, markets[1].id);
+    assert!(repo.find_by_exchange_and_symbol(&binance, "KRW-BTC").await.unwrap().is_none());
+
+    // 정렬 (거래소, 심볼순)
+    let symbols: Vec<_> = repo.find_by_exchange(&binance).await.unwrap().into_iter().map(|m| m.exchange_symbol).collect();
+    assert_eq!(symbols, vec!["BTCUSDT", "ETHUSDT"]);
+    assert_eq!(repo.find_all().await.unwrap().len(), 3);
+
+    // 같은 거래소의 같은 심볼을 다른 ID로 저장하면 전부 거부
+    let fresh = Market::new(binance.clone(), SymbolPair::new("SOL", "USDT"), "SOLUSDT", 2, 2, &clock);
+    let duplicate = Market::new(binance.clone(), SymbolPair::new("BTC", "USDT"), "BTCUSDT", 2, 6, &clock);
+    assert!(matches!(repo.save_batch(&[fresh.clone(), duplicate]).await, Err(CoreError::Validation(_))));
+    assert!(repo.find_by_id(fresh.id).await.unwrap().is_none());
+
+    // 같은 ID로 저장하면 갱신
+    let mut updated = markets[0].clone();
+    updated.set_active(false, &clock);
+    repo.save(&updated).await.unwrap();
+    assert!(!repo.find_by_id(updated.id).await.unwrap().unwrap().active);
+}
+
+fn candle_at(exchange: &str, symbol: &SymbolPair, minute: i64, close: i64, is_complete: bool) -> Candle {
+    Candle::new(
+        symbol.clone(),
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::minutes(minute),
+        Decimal::new(100, 0),
+        Decimal::new(110, 0),
+        Decimal::new(90, 0),
+        Decimal::new(close, 0),
+        Decimal::new(25, 1),
+        MarketExchangeId::new(exchange),
+        Timeframe::Minute1,
+        Some(Decimal::new(2505, 1)),
+        is_complete,
+    )
+}
+
+/// 캔들 리포지토리 적합성
+pub(crate) async fn candle_repository_conformance<R: CandleRepository>(repo: &R) {
+    let btc = SymbolPair::new("BTC", "USDT");
+    let eth = SymbolPair::new("ETH", "USDT");
+    let mut batch: Vec<_> = (0..5).map(|minute| candle_at("binance", &btc, minute, 100 + minute, true)).collect();
+    batch.extend((0..5).map(|minute| candle_at("upbit", &btc, minute, 200, true)));
+    batch.extend((0..5).map(|minute| candle_at("binance", &eth, minute, 10, true)));
+    let summary = repo.upsert_batch(&batch).await.unwrap();
+    assert_eq!((summary.inserted, summary.replaced, summary.ignored), (15, 0, 0));
+
+    // 진행 중인 캔들은 계속 대체되다가 완성 캔들로 확정되고, 이후의 미완성 값은 무시됨
+    assert_eq!(repo.upsert(&candle_at("binance", &btc, 5, 101, false)).await.unwrap(), CandleWrite::Insert);
+    assert_eq!(repo.upsert(&candle_at("binance", &btc, 5, 102, false)).await.unwrap(), CandleWrite::Replace);
+    assert_eq!(repo.upsert(&candle_at("binance", &btc, 5, 103, true)).await.unwrap(), CandleWrite::Replace);
+    let late = repo.upsert_batch(&[candle_at("binance", &btc, 5, 104, false)]).await.unwrap();
+    assert_eq!(late.ignored, 1);
+    let key = CandleKey::new(&MarketExchangeId::new("binance"), &btc, &Timeframe::Minute1, candle_at("binance", &btc, 5, 0, true).timestamp);
+    let settled = repo.find(&key).await.unwrap().unwrap();
+    assert_eq!(settled.close, Decimal::new(103, 0));
+    assert!(settled.is_complete);
+    let latest = repo.latest(&MarketExchangeId::new("binance"), &btc, &Timeframe::Minute1).await.unwrap().unwrap();
+    assert_eq!(latest.timestamp, settled.timestamp);
+
+    // 필터 + 커서 페이지네이션 (시각, 거래소순)
+    let filter = MarketDataFilter::new()
+        .with_symbol(btc.clone())
+        .with_timeframe(Timeframe::Minute1)
+        .with_range(batch[1].timestamp, batch[0].timestamp + Duration::minutes(10))
+        .with_limit(3);
+    let first = repo.query(&filter, None).await.unwrap();
+    let closes: Vec<_> = first.items.iter().map(|c| (c.exchange.0.clone(), c.close)).collect();
+    assert_eq!(
+        closes,
+        vec![
+            ("binance".to_string(), Decimal::new(101, 0)),
+            ("upbit".to_string(), Decimal::new(200, 0)),
+            ("binance".to_string(), Decimal::new(102, 0)),
+        ]
+    );
+
+    // 페이지 사이에 이미 읽은 구간과 아직 읽지 않은 구간에 캔들이 추가됨
+    repo.upsert(&candle_at("bybit", &btc, 1, 300, true)).await.unwrap();
+    repo.upsert(&candle_at("bybit", &btc, 4, 300, true)).await.unwrap();
+
+    let mut seen = first.items.clone();
+    let mut cursor = first.next_cursor.clone();
+    while let Some(next) = cursor {
+        let page = repo.query(&filter, Some(&next)).await.unwrap();
+        assert!(page.items.len() <= 3);
+        seen.extend(page.items);
+        cursor = page.next_cursor;
+    }
+    let keys: Vec<_> = seen.iter().map(CandleKey::of).collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(keys, sorted);
+    // binance 5개(1~5분) + upbit 4개(1~4분) + 커서 뒤에 추가된 bybit 4분
+    assert_eq!(keys.len(), 10);
+
+    // 내림차순, 거래소 필터, 빈 결과
+    let descending = repo
+        .query(&MarketDataFilter::new().with_exchange(MarketExchangeId::new("binance")).with_ascending(false).with_limit(2), None)
+        .await
+        .unwrap();
+    assert_eq!(descending.items[0].timestamp, settled.timestamp);
+    assert!(descending.items[0].timestamp >= descending.items[1].timestamp);
+    let rest = repo
+        .query(&MarketDataFilter::new().with_exchange(MarketExchangeId::new("binance")).with_ascending(false), descending.next_cursor.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(descending.items.len() + rest.items.len(), 11);
+    assert!(rest.next_cursor.is_none());
+    let none = repo.query(&MarketDataFilter::new().with_exchange(MarketExchangeId::new("okx")), None).await.unwrap();
+    assert!(none.items.is_empty() && none.next_cursor.is_none());
+    let trades = repo.query(&MarketDataFilter::new().with_data_type(MarketDataType::Trade), None).await.unwrap();
+    assert!(trades.items.is_empty() && trades.next_cursor.is_none());
+}
+
+/// 아웃박스 저장소 적합성
+pub(crate) async fn outbox_store_conformance(store: &dyn OutboxStore) {
+    let mut batch = OutboxBatch::new("exchange-domain");
+    for _ in 0..3 {
+        batch
+            .push(&Stored {
+                id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+                entity_id: Uuid::new_v4(),
+            })
+            .unwrap();
+    }
+    let sequences = store.enqueue(&batch).await.unwrap();
+    assert_eq!(sequences.len(), 3);
+    assert!(sequences.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // 다른 적합성 테스트와 저장소를 공유할 수 있으므로 이 묶음의 메시지만 봄
+    let ours = |messages: Vec<OutboxMessage>| -> Vec<OutboxMessage> {
+        messages.into_iter().filter(|message| sequences.contains(&message.sequence)).collect()
+    };
+
+    // 기록 순서대로, 봉투 그대로
+    let pending = ours(store.pending(1000).await.unwrap());
+    assert_eq!(pending.iter().map(|message| message.sequence).collect::<Vec<_>>(), sequences);
+    for (message, envelope) in pending.iter().zip(batch.envelopes()) {
+        assert_eq!(message.envelope.header.id, envelope.header.id);
+        assert_eq!(message.envelope.payload, envelope.payload);
+        assert_eq!((message.status, message.attempts, message.published_at), (OutboxStatus::Pending, 0, None));
+    }
+    assert_eq!(store.pending(1).await.unwrap().len(), 1);
+
+    // 재시도할 실패는 대기로 남고, 죽은 메시지는 빠짐
+    store.mark_failed(sequences[0], "timeout", false).await.unwrap();
+    store.mark_failed(sequences[1], "no route", true).await.unwrap();
+    let pending = ours(store.pending(1000).await.unwrap());
+    assert_eq!(pending.iter().map(|message| message.sequence).collect::<Vec<_>>(), [sequences[0], sequences[2]]);
+    assert_eq!((pending[0].attempts, pending[0].last_error.as_deref()), (1, Some("timeout")));
+    let missing = store.mark_failed(i64::MAX as u64, "missing", false).await;
+    assert!(matches!(missing, Err(CoreError::NotFound(_))));
+
+    // 발행 완료는 대기에서 빠지고, 기준 시각 이전에 발행된 것만 삭제
+    let published_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    store.mark_published(&[sequences[0], sequences[2]], published_at).await.unwrap();
+    assert!(ours(store.pending(1000).await.unwrap()).is_empty());
+    assert_eq!(store.purge_published(published_at).await.unwrap(), 0);
+    assert_eq!(store.purge_published(published_at + Duration::seconds(1)).await.unwrap(), 2);
+}
@@ -0,0 +1,474 @@
+// memory.rs
+//
+// 인메모리 리포지토리 구현체 (테스트용)
+// 테이블마다 잠금 하나로 보호하며, 벌크 저장은 잠금을 쥔 채로 먼저 전부 검증한 뒤 반영함
+// `*_with_events`는 검증 후 아웃박스 기록까지 성공해야 상태를 반영하므로
+// 둘 중 하나만 남는 경우가 없음 (기본 아웃박스는 `InMemoryOutboxStore`)
+// 캔들은 (시작 시각, 거래소, 심볼, 타임프레임) 순서의 정렬 맵으로 보관해 커서 페이지를 범위 탐색으로 읽음
+
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use cryptolytica_exchange_domain::domain::model::{Exchange, ExchangeId, Market, Order, Trade};
+use cryptolytica_exchange_domain::domain::repository::{
+    ExchangeRepository, MarketRepository, OrderRepository, TradeRepository,
+};
+use cryptolytica_market_domain::model::Candle;
+use cryptolytica_market_domain::repository::{
+    CandleCursor, CandleKey, CandlePage, CandleRepository, CandleWrite, MarketDataFilter, UpsertSummary,
+};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{InMemoryOutboxStore, OutboxBatch, OutboxStore};
+use cryptolytica_shared_kernel::types::{ExchangeId as MarketExchangeId, Result, SymbolPair, Timeframe};
+
+/// 최신순 정렬 후 개수 제한
+fn newest_first<T, K: Ord>(mut items: Vec<T>, key: impl Fn(&T) -> K, limit: Option<usize>) -> Vec<T> {
+    items.sort_by_key(|item| std::cmp::Reverse(key(item)));
+    if let Some(limit) = limit {
+        items.truncate(limit);
+    }
+    items
+}
+
+/// 같은 키를 다른 ID가 이미 쓰고 있거나 같은 묶음 안에서 겹치면 유효성 오류
+fn check_unique<K: Eq + std::hash::Hash + std::fmt::Debug>(
+    what: &str,
+    claims: impl IntoIterator<Item = (Uuid, K)>,
+    existing: impl Fn(&K) -> Option<Uuid>,
+) -> Result<()> {
+    let mut seen: HashMap<K, Uuid> = HashMap::new();
+    for (id, key) in claims {
+        let owner = seen.get(&key).copied().or_else(|| existing(&key));
+        if owner.is_some_and(|owner| owner != id) {
+            return Err(CoreError::Validation(format!("{} 중복: {:?}", what, key)));
+        }
+        seen.insert(key, id);
+    }
+    Ok(())
+}
+
+/// 인메모리 거래소 리포지토리
+#[derive(Default)]
+pub struct InMemoryExchangeRepository {
+    exchanges: RwLock<HashMap<Uuid, Exchange>>,
+}
+
+impl InMemoryExchangeRepository {
+    /// 빈 리포지토리 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ExchangeRepository for InMemoryExchangeRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Exchange>> {
+        Ok(self.exchanges.read().await.get(&id).cloned())
+    }
+
+    async fn find_by_exchange_id(&self, exchange_id: &ExchangeId) -> Result<Option<Exchange>> {
+        Ok(self
+            .exchanges
+            .read()
+            .await
+            .values()
+            .find(|exchange| &exchange.exchange_id == exchange_id)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Exchange>> {
+        let mut exchanges: Vec<_> = self.exchanges.read().await.values().cloned().collect();
+        exchanges.sort_by(|a, b| a.exchange_id.value().cmp(b.exchange_id.value()));
+        Ok(exchanges)
+    }
+
+    async fn find_active(&self) -> Result<Vec<Exchange>> {
+        Ok(self.find_all().await?.into_iter().filter(Exchange::is_active).collect())
+    }
+
+    async fn save(&self, exchange: &Exchange) -> Result<Exchange> {
+        let mut exchanges = self.exchanges.write().await;
+        check_unique("거래소 코드", [(exchange.id, exchange.exchange_id.clone())], |code| {
+            exchanges.values().find(|e| &e.exchange_id == code).map(|e| e.id)
+        })?;
+        exchanges.insert(exchange.id, exchange.clone());
+        Ok(exchange.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        self.exchanges
+            .write()
+            .await
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| CoreError::NotFound(format!("거래소를 찾을 수 없음: {}", id)))
+    }
+}
+
+/// 인메모리 주문 리포지토리
+pub struct InMemoryOrderRepository {
+    orders: RwLock<HashMap<Uuid, Order>>,
+    outbox: Arc<dyn OutboxStore>,
+}
+
+impl InMemoryOrderRepository {
+    /// 빈 리포지토리 생성 (자체 인메모리 아웃박스 사용)
+    pub fn new() -> Self {
+        Self::with_outbox(Arc::new(InMemoryOutboxStore::new()))
+    }
+
+    /// 이벤트를 기록할 아웃박스 지정
+    pub fn with_outbox(outbox: Arc<dyn OutboxStore>) -> Self {
+        Self {
+            orders: RwLock::new(HashMap::new()),
+            outbox,
+        }
+    }
+
+    /// 이벤트가 기록되는 아웃박스
+    pub fn outbox(&self) -> Arc<dyn OutboxStore> {
+        self.outbox.clone()
+    }
+}
+
+impl Default for InMemoryOrderRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OrderRepository for InMemoryOrderRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Order>> {
+        Ok(self.orders.read().await.get(&id).cloned())
+    }
+
+    async fn find_by_exchange_order_id(&self, exchange_id: &ExchangeId, exchange_order_id: &str) -> Result<Option<Order>> {
+        Ok(self
+            .orders
+            .read()
+            .await
+            .values()
+            .find(|order| &order.exchange_id == exchange_id && order.exchange_order_id.as_deref() == Some(exchange_order_id))
+            .cloned())
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId, limit: Option<usize>) -> Result<Vec<Order>> {
+        let orders = self
+            .orders
+            .read()
+            .await
+            .values()
+            .filter(|order| &order.exchange_id == exchange_id)
+            .cloned()
+            .collect();
+        Ok(newest_first(orders, |order| (order.created_at, order.id), limit))
+    }
+
+    async fn save(&self, order: &Order) -> Result<Order> {
+        self.save_batch_with_events(std::slice::from_ref(order), &OutboxBatch::default())
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, orders: &[Order]) -> Result<Vec<Order>> {
+        self.save_batch_with_events(orders, &OutboxBatch::default()).await
+    }
+
+    async fn save_with_events(&self, order: &Order, events: &OutboxBatch) -> Result<Order> {
+        self.save_batch_with_events(std::slice::from_ref(order), events)
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch_with_events(&self, orders: &[Order], events: &OutboxBatch) -> Result<Vec<Order>> {
+        let mut table = self.orders.write().await;
+        let claims = orders.iter().filter_map(|order| {
+            order
+                .exchange_order_id
+                .clone()
+                .map(|exchange_order_id| (order.id, (order.exchange_id.clone(), exchange_order_id)))
+        });
+        check_unique("거래소 주문 ID", claims, |(exchange_id, exchange_order_id)| {
+            table
+                .values()
+                .find(|o| &o.exchange_id == exchange_id && o.exchange_order_id.as_ref() == Some(exchange_order_id))
+                .map(|o| o.id)
+        })?;
+
+        if !events.is_empty() {
+            self.outbox.enqueue(events).await?;
+        }
+        for order in orders {
+            table.insert(order.id, order.clone());
+        }
+        Ok(orders.to_vec())
+    }
+}
+
+/// 인메모리 거래 리포지토리
+pub struct InMemoryTradeRepository {
+    trades: RwLock<HashMap<Uuid, Trade>>,
+    outbox: Arc<dyn OutboxStore>,
+}
+
+impl InMemoryTradeRepository {
+    /// 빈 리포지토리 생성 (자체 인메모리 아웃박스 사용)
+    pub fn new() -> Self {
+        Self::with_outbox(Arc::new(InMemoryOutboxStore::new()))
+    }
+
+    /// 이벤트를 기록할 아웃박스 지정
+    pub fn with_outbox(outbox: Arc<dyn OutboxStore>) -> Self {
+        Self {
+            trades: RwLock::new(HashMap::new()),
+            outbox,
+        }
+    }
+
+    /// 이벤트가 기록되는 아웃박스
+    pub fn outbox(&self) -> Arc<dyn OutboxStore> {
+        self.outbox.clone()
+    }
+}
+
+impl Default for InMemoryTradeRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TradeRepository for InMemoryTradeRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Trade>> {
+        Ok(self.trades.read().await.get(&id).cloned())
+    }
+
+    async fn find_by_order_id(&self, order_id: Uuid) -> Result<Vec<Trade>> {
+        let mut trades: Vec<_> = self
+            .trades
+            .read()
+            .await
+            .values()
+            .filter(|trade| trade.order_id == Some(order_id))
+            .cloned()
+            .collect();
+        trades.sort_by_key(|trade| (trade.executed_at, trade.id));
+        Ok(trades)
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId, limit: Option<usize>) -> Result<Vec<Trade>> {
+        let trades = self
+            .trades
+            .read()
+            .await
+            .values()
+            .filter(|trade| &trade.exchange_id == exchange_id)
+            .cloned()
+            .collect();
+        Ok(newest_first(trades, |trade| (trade.executed_at, trade.id), limit))
+    }
+
+    async fn save(&self, trade: &Trade) -> Result<Trade> {
+        self.save_batch(std::slice::from_ref(trade))
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, trades: &[Trade]) -> Result<Vec<Trade>> {
+        self.save_batch_with_events(trades, &OutboxBatch::default()).await
+    }
+
+    async fn save_batch_with_events(&self, trades: &[Trade], events: &OutboxBatch) -> Result<Vec<Trade>> {
+        let mut table = self.trades.write().await;
+        let claims = trades
+            .iter()
+            .map(|trade| (trade.id, (trade.exchange_id.clone(), trade.exchange_trade_id.clone())));
+        check_unique("거래소 체결 ID", claims, |(exchange_id, exchange_trade_id)| {
+            table
+                .values()
+                .find(|t| &t.exchange_id == exchange_id && &t.exchange_trade_id == exchange_trade_id)
+                .map(|t| t.id)
+        })?;
+
+        if !events.is_empty() {
+            self.outbox.enqueue(events).await?;
+        }
+        for trade in trades {
+            table.insert(trade.id, trade.clone());
+        }
+        Ok(trades.to_vec())
+    }
+}
+
+/// 인메모리 마켓 리포지토리
+#[derive(Default)]
+pub struct InMemoryMarketRepository {
+    markets: RwLock<HashMap<Uuid, Market>>,
+}
+
+impl InMemoryMarketRepository {
+    /// 빈 리포지토리 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MarketRepository for InMemoryMarketRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Market>> {
+        Ok(self.markets.read().await.get(&id).cloned())
+    }
+
+    async fn find_by_exchange_and_symbol(&self, exchange_id: &ExchangeId, symbol: &str) -> Result<Option<Market>> {
+        Ok(self
+            .markets
+            .read()
+            .await
+            .values()
+            .find(|market| &market.exchange_id == exchange_id && market.matches_symbol(symbol))
+            .cloned())
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId) -> Result<Vec<Market>> {
+        Ok(self
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|market| &market.exchange_id == exchange_id)
+            .collect())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Market>> {
+        let mut markets: Vec<_> = self.markets.read().await.values().cloned().collect();
+        markets.sort_by_key(|market| (market.exchange_id.value().to_string(), market.symbol.to_standard_notation()));
+        Ok(markets)
+    }
+
+    async fn save(&self, market: &Market) -> Result<Market> {
+        self.save_batch(std::slice::from_ref(market))
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, markets: &[Market]) -> Result<Vec<Market>> {
+        let mut table = self.markets.write().await;
+        let claims = markets
+            .iter()
+            .map(|market| (market.id, (market.exchange_id.clone(), market.symbol.to_standard_notation())));
+        check_unique("마켓 심볼", claims, |(exchange_id, symbol)| {
+            table
+                .values()
+                .find(|m| &m.exchange_id == exchange_id && &m.symbol.to_standard_notation() == symbol)
+                .map(|m| m.id)
+        })?;
+
+        for market in markets {
+            table.insert(market.id, market.clone());
+        }
+        Ok(markets.to_vec())
+    }
+}
+
+/// 인메모리 캔들 리포지토리
+#[derive(Default)]
+pub struct InMemoryCandleRepository {
+    candles: RwLock<BTreeMap<CandleKey, Candle>>,
+}
+
+impl InMemoryCandleRepository {
+    /// 빈 리포지토리 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CandleRepository for InMemoryCandleRepository {
+    async fn upsert(&self, candle: &Candle) -> Result<CandleWrite> {
+        let mut table = self.candles.write().await;
+        let key = CandleKey::of(candle);
+        let write = CandleWrite::decide(table.get(&key), candle);
+        if write != CandleWrite::Ignore {
+            table.insert(key, candle.clone());
+        }
+        Ok(write)
+    }
+
+    async fn upsert_batch(&self, candles: &[Candle]) -> Result<UpsertSummary> {
+        let mut table = self.candles.write().await;
+        let mut summary = UpsertSummary::default();
+        for candle in candles {
+            let key = CandleKey::of(candle);
+            let write = CandleWrite::decide(table.get(&key), candle);
+            if write != CandleWrite::Ignore {
+                table.insert(key, candle.clone());
+            }
+            summary.record(write);
+        }
+        Ok(summary)
+    }
+
+    async fn find(&self, key: &CandleKey) -> Result<Option<Candle>> {
+        Ok(self.candles.read().await.get(key).cloned())
+    }
+
+    async fn query(&self, filter: &MarketDataFilter, cursor: Option<&CandleCursor>) -> Result<CandlePage> {
+        let page_size = filter.page_size();
+        if !filter.selects_candles() {
+            return Ok(CandlePage::from_candidates(Vec::new(), page_size));
+        }
+        let table = self.candles.read().await;
+        let after = cursor.map_or(Bound::Unbounded, |cursor| Bound::Excluded(cursor.0.clone()));
+        let candidates: Vec<Candle> = if filter.is_ascending() {
+            table
+                .range((after, Bound::Unbounded))
+                .map(|(_, candle)| candle)
+                .filter(|candle| filter.matches(candle))
+                .take(page_size + 1)
+                .cloned()
+                .collect()
+        } else {
+            table
+                .range((Bound::Unbounded, after))
+                .rev()
+                .map(|(_, candle)| candle)
+                .filter(|candle| filter.matches(candle))
+                .take(page_size + 1)
+                .cloned()
+                .collect()
+        };
+        Ok(CandlePage::from_candidates(candidates, page_size))
+    }
+
+    async fn latest(&self, exchange: &MarketExchangeId, symbol: &SymbolPair, timeframe: &Timeframe) -> Result<Option<Candle>> {
+        Ok(self
+            .candles
+            .read()
+            .await
+            .values()
+            .rev()
+            .find(|candle| &candle.exchange == exchange && &candle.symbol == symbol && &candle.timeframe == timeframe)
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::conformance;
+
+    #[tokio::test]
+    async fn test_in_memory_repositories_conform() {
+        let outbox = Arc::new(InMemoryOutboxStore::new());
+        conformance::exchange_repository_conformance(&InMemoryExchangeRepository::new()).await;
+        conformance::order_repository_conformance(&InMemoryOrderRepository::with_outbox(outbox.clone()), outbox.as_ref()).await;
+        conformance::trade_repository_conformance(&InMemoryTradeRepository::with_outbox(outbox.clone()), outbox.as_ref()).await;
+        conformance::market_repository_conformance(&InMemoryMarketRepository::new()).await;
+        conformance::candle_repository_conformance(&InMemoryCandleRepository::new()).await;
+        conformance::outbox_store_conformance(outbox.as_ref()).await;
+    }
+}
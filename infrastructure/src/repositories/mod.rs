@@ -0,0 +1,24 @@
+// repositories/mod.rs
+//
+// 거래소·시장 데이터 도메인 리포지토리 구현체 모듈
+// 인메모리 구현은 테스트용, SQLite 구현은 단일 노드 배포용, PostgreSQL 구현은 운영용이며
+// 모든 구현이 같은 적합성 테스트(`conformance`)를 통과해야 함
+
+pub mod memory;
+pub mod postgres;
+pub mod sqlite;
+#[cfg(test)]
+pub(crate) mod conformance;
+
+pub use memory::{
+    InMemoryCandleRepository, InMemoryExchangeRepository, InMemoryMarketRepository, InMemoryOrderRepository,
+    InMemoryTradeRepository,
+};
+pub use postgres::{
+    PostgresCandleRepository, PostgresExchangeRepository, PostgresMarketRepository, PostgresOrderRepository,
+    PostgresOutboxStore, PostgresRepositories, PostgresTradeRepository,
+};
+pub use sqlite::{
+    SqliteExchangeRepository, SqliteMarketRepository, SqliteOrderRepository, SqliteRepositories,
+    SqliteTradeRepository,
+};
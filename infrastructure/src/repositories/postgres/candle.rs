@@ -0,0 +1,283 @@
+// candle.rs
+//
+// 캔들 리포지토리의 PostgreSQL 구현체
+// (거래소, 심볼, 타임프레임, 시작 시각)이 키이며, 완성 캔들은 미완성 값으로 덮지 않는 조건부 upsert를 사용함
+// 커서 페이지는 (시작 시각, 거래소, 심볼, 타임프레임) 행 비교로 이어 읽으며,
+// 문자열은 `COLLATE "C"`로 비교해 도메인의 `CandleKey` 순서(바이트 순)와 맞춤
+// TimescaleDB가 있으면 candles 테이블은 시작 시각 기준 하이퍼테이블임
+
+use chrono::{DateTime, Utc};
+use async_trait::async_trait;
+use sqlx::postgres::{PgConnection, PgPool, PgRow};
+use sqlx::types::Json;
+use sqlx::{Postgres, QueryBuilder, Row};
+use cryptolytica_market_domain::model::Candle;
+use cryptolytica_market_domain::repository::{
+    CandleCursor, CandleKey, CandlePage, CandleRepository, CandleWrite, MarketDataFilter, UpsertSummary,
+};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::types::{ExchangeId, Instrument, Result, SymbolPair, Timeframe};
+use crate::database::db_error;
+
+/// PostgreSQL 캔들 리포지토리
+#[derive(Clone)]
+pub struct PostgresCandleRepository {
+    pool: PgPool,
+}
+
+impl PostgresCandleRepository {
+    /// 풀로 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 구간 조회 (`from` 이상 `to` 미만, 시작 시각순)
+    pub async fn find_range(
+        &self,
+        exchange: &ExchangeId,
+        symbol: &SymbolPair,
+        timeframe: &Timeframe,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Candle>> {
+        let rows = sqlx::query(
+            "SELECT * FROM candles \
+             WHERE exchange = $1 AND symbol = $2 AND timeframe = $3 AND open_time >= $4 AND open_time < $5 \
+             ORDER BY open_time LIMIT $6",
+        )
+        .bind(&exchange.0)
+        .bind(symbol.to_standard_notation())
+        .bind(timeframe.to_code())
+        .bind(from)
+        .bind(to)
+        .bind(limit.map(|limit| limit as i64))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.iter().map(decode_candle).collect()
+    }
+}
+
+/// 조건부 upsert 한 건 (완성 캔들은 완성 캔들로만 대체)
+async fn upsert_in(conn: &mut PgConnection, candle: &Candle) -> Result<CandleWrite> {
+    let inserted: Option<bool> = sqlx::query_scalar(
+        "INSERT INTO candles \
+             (exchange, symbol, timeframe, open_time, id, open, high, low, close, volume, \
+              quote_volume, is_complete, instrument) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+         ON CONFLICT (exchange, symbol, timeframe, open_time) DO UPDATE SET \
+             id = EXCLUDED.id, open = EXCLUDED.open, high = EXCLUDED.high, low = EXCLUDED.low, \
+             close = EXCLUDED.close, volume = EXCLUDED.volume, quote_volume = EXCLUDED.quote_volume, \
+             is_complete = EXCLUDED.is_complete, instrument = EXCLUDED.instrument \
+         WHERE NOT candles.is_complete OR EXCLUDED.is_complete \
+         RETURNING (xmax = 0)",
+    )
+    .bind(&candle.exchange.0)
+    .bind(candle.symbol.to_standard_notation())
+    .bind(candle.timeframe.to_code())
+    .bind(candle.timestamp)
+    .bind(candle.id)
+    .bind(candle.open)
+    .bind(candle.high)
+    .bind(candle.low)
+    .bind(candle.close)
+    .bind(candle.volume)
+    .bind(candle.quote_volume)
+    .bind(candle.is_complete)
+    .bind(candle.instrument.as_ref().map(Json))
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(db_error)?;
+    // 행이 돌아오지 않으면 WHERE 조건에 걸려 기존 완성 캔들을 유지한 것
+    Ok(match inserted {
+        Some(true) => CandleWrite::Insert,
+        Some(false) => CandleWrite::Replace,
+        None => CandleWrite::Ignore,
+    })
+}
+
+#[async_trait]
+impl CandleRepository for PostgresCandleRepository {
+    async fn upsert(&self, candle: &Candle) -> Result<CandleWrite> {
+        let mut conn = self.pool.acquire().await.map_err(db_error)?;
+        upsert_in(&mut conn, candle).await
+    }
+
+    async fn upsert_batch(&self, candles: &[Candle]) -> Result<UpsertSummary> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let mut summary = UpsertSummary::default();
+        for candle in candles {
+            summary.record(upsert_in(&mut tx, candle).await?);
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(summary)
+    }
+
+    async fn find(&self, key: &CandleKey) -> Result<Option<Candle>> {
+        let row = sqlx::query(
+            "SELECT * FROM candles WHERE exchange = $1 AND symbol = $2 AND timeframe = $3 AND open_time = $4",
+        )
+        .bind(&key.exchange)
+        .bind(&key.symbol)
+        .bind(&key.timeframe)
+        .bind(key.timestamp)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+        row.as_ref().map(decode_candle).transpose()
+    }
+
+    async fn query(&self, filter: &MarketDataFilter, cursor: Option<&CandleCursor>) -> Result<CandlePage> {
+        let page_size = filter.page_size();
+        if !filter.selects_candles() {
+            return Ok(CandlePage::from_candidates(Vec::new(), page_size));
+        }
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM candles WHERE TRUE");
+        if let Some(exchanges) = &filter.exchanges {
+            let exchanges: Vec<String> = exchanges.iter().map(|exchange| exchange.0.clone()).collect();
+            query.push(" AND exchange = ANY(").push_bind(exchanges).push(")");
+        }
+        if let Some(symbols) = &filter.symbols {
+            let symbols: Vec<String> = symbols.iter().map(SymbolPair::to_standard_notation).collect();
+            query.push(" AND symbol = ANY(").push_bind(symbols).push(")");
+        }
+        if let Some(timeframe) = &filter.timeframe {
+            query.push(" AND timeframe = ").push_bind(timeframe.to_code());
+        }
+        if let Some(start) = filter.start_time {
+            query.push(" AND open_time >= ").push_bind(start);
+        }
+        if let Some(end) = filter.end_time {
+            query.push(" AND open_time < ").push_bind(end);
+        }
+
+        let direction = if filter.is_ascending() { "ASC" } else { "DESC" };
+        if let Some(CandleCursor(key)) = cursor {
+            query
+                .push(r#" AND (open_time, exchange COLLATE "C", symbol COLLATE "C", timeframe COLLATE "C") "#)
+                .push(if filter.is_ascending() { ">" } else { "<" })
+                .push(" (")
+                .push_bind(key.timestamp)
+                .push(", ")
+                .push_bind(key.exchange.clone())
+                .push(", ")
+                .push_bind(key.symbol.clone())
+                .push(", ")
+                .push_bind(key.timeframe.clone())
+                .push(")");
+        }
+        query.push(format!(
+            r#" ORDER BY open_time {d}, exchange COLLATE "C" {d}, symbol COLLATE "C" {d}, timeframe COLLATE "C" {d} LIMIT "#,
+            d = direction
+        ));
+        query.push_bind(page_size as i64 + 1);
+
+        let rows = query.build().fetch_all(&self.pool).await.map_err(db_error)?;
+        let candidates = rows.iter().map(decode_candle).collect::<Result<Vec<_>>>()?;
+        Ok(CandlePage::from_candidates(candidates, page_size))
+    }
+
+    async fn latest(&self, exchange: &ExchangeId, symbol: &SymbolPair, timeframe: &Timeframe) -> Result<Option<Candle>> {
+        let row = sqlx::query(
+            "SELECT * FROM candles WHERE exchange = $1 AND symbol = $2 AND timeframe = $3 \
+             ORDER BY open_time DESC LIMIT 1",
+        )
+        .bind(&exchange.0)
+        .bind(symbol.to_standard_notation())
+        .bind(timeframe.to_code())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+        row.as_ref().map(decode_candle).transpose()
+    }
+}
+
+/// 캔들 행 복원
+fn decode_candle(row: &PgRow) -> Result<Candle> {
+    let symbol: String = row.try_get("symbol").map_err(db_error)?;
+    let timeframe: String = row.try_get("timeframe").map_err(db_error)?;
+    let instrument: Option<Json<Instrument>> = row
+        .try_get("instrument")
+        .map_err(|e| CoreError::Data(format!("손상된 캔들 상품 정보: {}", e)))?;
+    Ok(Candle {
+        id: row.try_get("id").map_err(db_error)?,
+        symbol: symbol
+            .parse()
+            .map_err(|e| CoreError::Data(format!("잘못된 캔들 심볼 {}: {}", symbol, e)))?,
+        timestamp: row.try_get("open_time").map_err(db_error)?,
+        open: row.try_get("open").map_err(db_error)?,
+        high: row.try_get("high").map_err(db_error)?,
+        low: row.try_get("low").map_err(db_error)?,
+        close: row.try_get("close").map_err(db_error)?,
+        volume: row.try_get("volume").map_err(db_error)?,
+        exchange: ExchangeId::new(row.try_get::<String, _>("exchange").map_err(db_error)?),
+        timeframe: timeframe
+            .parse()
+            .map_err(|e| CoreError::Data(format!("잘못된 캔들 타임프레임 {}: {}", timeframe, e)))?,
+        quote_volume: row.try_get("quote_volume").map_err(db_error)?,
+        is_complete: row.try_get("is_complete").map_err(db_error)?,
+        instrument: instrument.map(|Json(instrument)| instrument),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use std::str::FromStr;
+    use cryptolytica_shared_kernel::types::Decimal;
+    use crate::repositories::conformance;
+    use crate::repositories::postgres::tests::isolated_repositories;
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_postgres_candles_conform() {
+        let repositories = isolated_repositories().await;
+        conformance::candle_repository_conformance(&repositories.candles()).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_candles_upsert_and_range() {
+        let repositories = isolated_repositories().await;
+        let candles = repositories.candles();
+        let exchange = ExchangeId::new("binance");
+        let symbol = SymbolPair::new("BTC", "USDT");
+        let timeframe = Timeframe::from_str("1m").unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let candle_at = |minute: i64, close: i64, is_complete: bool| {
+            Candle::new(
+                symbol.clone(),
+                start + Duration::minutes(minute),
+                Decimal::new(100, 0),
+                Decimal::new(110, 0),
+                Decimal::new(90, 0),
+                Decimal::new(close, 0),
+                Decimal::new(15, 1),
+                exchange.clone(),
+                timeframe,
+                Some(Decimal::new(1505, 1)),
+                is_complete,
+            )
+        };
+        let batch: Vec<_> = (0..5).map(|minute| candle_at(minute, 100 + minute, minute < 4)).collect();
+        assert_eq!(candles.upsert_batch(&batch).await.unwrap().inserted, 5);
+
+        // 진행 중인 캔들은 같은 키의 완성 캔들로 대체됨
+        assert_eq!(candles.upsert_batch(&[candle_at(4, 200, true)]).await.unwrap().replaced, 1);
+
+        let range = candles
+            .find_range(&exchange, &symbol, &timeframe, start + Duration::minutes(1), start + Duration::minutes(5), Some(10))
+            .await
+            .unwrap();
+        assert_eq!(range.len(), 4);
+        assert_eq!(range[0], batch[1]);
+        let latest = candles.latest(&exchange, &symbol, &timeframe).await.unwrap().unwrap();
+        assert_eq!(latest.close, Decimal::new(200, 0));
+        assert!(latest.is_complete);
+        let limited = candles.find_range(&exchange, &symbol, &timeframe, start, start + Duration::hours(1), Some(2)).await.unwrap();
+        assert_eq!(limited.len(), 2);
+    }
+}
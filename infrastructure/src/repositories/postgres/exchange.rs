@@ -0,0 +1,537 @@
+// exchange.rs
+//
+// 거래소 도메인 리포지토리의 PostgreSQL 구현체
+// 거래소·마켓·주문은 JSONB 문서로, 체결은 하이퍼테이블에 맞게 열로 저장함
+// 거래소 체결 ID는 일반 테이블에서는 (exchange_id, exchange_trade_id) 고유 인덱스가 막지만,
+// 하이퍼테이블의 고유 인덱스는 시간 열을 포함해야 하므로 중복을 트랜잭션 안에서도 따로 검사함
+// 검사 전에 체결 ID별 권고 잠금을 잡아 동시 저장이 검사를 함께 통과하지 못하게 함
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use sqlx::postgres::{PgConnection, PgPool, PgRow};
+use sqlx::types::Json;
+use sqlx::Row;
+use uuid::Uuid;
+use cryptolytica_exchange_domain::domain::model::{Exchange, ExchangeId, Market, Order, OrderSide, Trade};
+use cryptolytica_exchange_domain::domain::repository::{
+    ExchangeRepository, MarketRepository, OrderRepository, TradeRepository,
+};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::OutboxBatch;
+use cryptolytica_shared_kernel::types::{Result, SymbolPair};
+use crate::database::db_error;
+use super::outbox::PostgresOutboxStore;
+
+/// `data` 열의 JSONB 문서를 엔티티로 복원
+fn decode<T: DeserializeOwned>(row: &PgRow) -> Result<T> {
+    let Json(value): Json<T> = row
+        .try_get("data")
+        .map_err(|e| CoreError::Data(format!("손상된 레코드: {}", e)))?;
+    Ok(value)
+}
+
+fn decode_all<T: DeserializeOwned>(rows: Vec<PgRow>) -> Result<Vec<T>> {
+    rows.iter().map(decode).collect()
+}
+
+/// `LIMIT` 값 (NULL은 제한 없음)
+fn sql_limit(limit: Option<usize>) -> Option<i64> {
+    limit.map(|limit| limit as i64)
+}
+
+/// PostgreSQL 거래소 리포지토리
+#[derive(Clone)]
+pub struct PostgresExchangeRepository {
+    pool: PgPool,
+}
+
+impl PostgresExchangeRepository {
+    /// 풀로 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExchangeRepository for PostgresExchangeRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Exchange>> {
+        let row = sqlx::query("SELECT data FROM exchanges WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange_id(&self, exchange_id: &ExchangeId) -> Result<Option<Exchange>> {
+        let row = sqlx::query("SELECT data FROM exchanges WHERE exchange_id = $1")
+            .bind(exchange_id.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Exchange>> {
+        let rows = sqlx::query("SELECT data FROM exchanges ORDER BY exchange_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn find_active(&self) -> Result<Vec<Exchange>> {
+        let rows = sqlx::query("SELECT data FROM exchanges WHERE active ORDER BY exchange_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, exchange: &Exchange) -> Result<Exchange> {
+        sqlx::query(
+            "INSERT INTO exchanges (id, exchange_id, active, data) VALUES ($1, $2, $3, $4) \
+             ON CONFLICT (id) DO UPDATE SET \
+                 exchange_id = EXCLUDED.exchange_id, active = EXCLUDED.active, data = EXCLUDED.data",
+        )
+        .bind(exchange.id)
+        .bind(exchange.exchange_id.value())
+        .bind(exchange.is_active())
+        .bind(Json(exchange))
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(exchange.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        let result = sqlx::query("DELETE FROM exchanges WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(CoreError::NotFound(format!("거래소를 찾을 수 없음: {}", id)));
+        }
+        Ok(())
+    }
+}
+
+/// PostgreSQL 주문 리포지토리
+#[derive(Clone)]
+pub struct PostgresOrderRepository {
+    outbox: PostgresOutboxStore,
+}
+
+impl PostgresOrderRepository {
+    /// 아웃박스와 같은 데이터베이스를 쓰는 리포지토리 생성
+    pub fn new(outbox: PostgresOutboxStore) -> Self {
+        Self { outbox }
+    }
+
+    async fn upsert(conn: &mut PgConnection, order: &Order) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO orders (id, exchange_id, exchange_order_id, created_at, data) VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (id) DO UPDATE SET \
+                 exchange_id = EXCLUDED.exchange_id, exchange_order_id = EXCLUDED.exchange_order_id, \
+                 created_at = EXCLUDED.created_at, data = EXCLUDED.data",
+        )
+        .bind(order.id)
+        .bind(order.exchange_id.value())
+        .bind(order.exchange_order_id.as_deref())
+        .bind(order.created_at)
+        .bind(Json(order))
+        .execute(conn)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderRepository for PostgresOrderRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Order>> {
+        let row = sqlx::query("SELECT data FROM orders WHERE id = $1")
+            .bind(id)
+            .fetch_optional(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange_order_id(&self, exchange_id: &ExchangeId, exchange_order_id: &str) -> Result<Option<Order>> {
+        let row = sqlx::query("SELECT data FROM orders WHERE exchange_id = $1 AND exchange_order_id = $2")
+            .bind(exchange_id.value())
+            .bind(exchange_order_id)
+            .fetch_optional(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId, limit: Option<usize>) -> Result<Vec<Order>> {
+        let rows = sqlx::query(
+            "SELECT data FROM orders WHERE exchange_id = $1 ORDER BY created_at DESC, id DESC LIMIT $2",
+        )
+        .bind(exchange_id.value())
+        .bind(sql_limit(limit))
+        .fetch_all(self.outbox.pool())
+        .await
+        .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, order: &Order) -> Result<Order> {
+        self.save_batch_with_events(std::slice::from_ref(order), &OutboxBatch::default())
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, orders: &[Order]) -> Result<Vec<Order>> {
+        self.save_batch_with_events(orders, &OutboxBatch::default()).await
+    }
+
+    async fn save_with_events(&self, order: &Order, events: &OutboxBatch) -> Result<Order> {
+        self.save_batch_with_events(std::slice::from_ref(order), events)
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch_with_events(&self, orders: &[Order], events: &OutboxBatch) -> Result<Vec<Order>> {
+        let mut tx = self.outbox.pool().begin().await.map_err(db_error)?;
+        for order in orders {
+            Self::upsert(&mut tx, order).await?;
+        }
+        if !events.is_empty() {
+            self.outbox.enqueue_in(&mut tx, events).await?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(orders.to_vec())
+    }
+}
+
+/// PostgreSQL 거래 리포지토리
+#[derive(Clone)]
+pub struct PostgresTradeRepository {
+    outbox: PostgresOutboxStore,
+}
+
+impl PostgresTradeRepository {
+    /// 아웃박스와 같은 데이터베이스를 쓰는 리포지토리 생성
+    pub fn new(outbox: PostgresOutboxStore) -> Self {
+        Self { outbox }
+    }
+
+    /// 거래소 체결 ID별 트랜잭션 범위 권고 잠금
+    ///
+    /// 하이퍼테이블에서는 중복 검사가 조회 후 삽입이므로, 같은 체결 ID를 동시에 저장하는 트랜잭션이
+    /// 둘 다 검사를 통과하지 않도록 잠금을 잡고 검사함. 교착을 피하려고 정렬된 순서로 잡음
+    async fn lock_exchange_trade_ids(conn: &mut PgConnection, trades: &[Trade]) -> Result<()> {
+        let mut keys: Vec<_> = trades
+            .iter()
+            .map(|trade| (trade.exchange_id.value(), trade.exchange_trade_id.as_str()))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        for (exchange_id, exchange_trade_id) in keys {
+            sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1), hashtext($2))")
+                .bind(exchange_id)
+                .bind(exchange_trade_id)
+                .execute(&mut *conn)
+                .await
+                .map_err(db_error)?;
+        }
+        Ok(())
+    }
+
+    async fn upsert(conn: &mut PgConnection, trade: &Trade) -> Result<()> {
+        let owner: Option<Uuid> = sqlx::query_scalar(
+            "SELECT id FROM trades WHERE exchange_id = $1 AND exchange_trade_id = $2 AND id <> $3 LIMIT 1",
+        )
+        .bind(trade.exchange_id.value())
+        .bind(&trade.exchange_trade_id)
+        .bind(trade.id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(db_error)?;
+        if owner.is_some() {
+            return Err(CoreError::Validation(format!(
+                "거래소 체결 ID 중복: {}/{}",
+                trade.exchange_id, trade.exchange_trade_id
+            )));
+        }
+
+        // 체결 시간이 바뀐 경우 이전 청크의 행을 지움
+        sqlx::query("DELETE FROM trades WHERE id = $1 AND executed_at <> $2")
+            .bind(trade.id)
+            .bind(trade.executed_at)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error)?;
+        sqlx::query(
+            "INSERT INTO trades \
+                 (id, exchange_id, exchange_trade_id, order_id, symbol, side, price, quantity, fee, fee_asset, executed_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) \
+             ON CONFLICT (id, executed_at) DO UPDATE SET \
+                 exchange_id = EXCLUDED.exchange_id, exchange_trade_id = EXCLUDED.exchange_trade_id, \
+                 order_id = EXCLUDED.order_id, symbol = EXCLUDED.symbol, side = EXCLUDED.side, \
+                 price = EXCLUDED.price, quantity = EXCLUDED.quantity, fee = EXCLUDED.fee, \
+                 fee_asset = EXCLUDED.fee_asset",
+        )
+        .bind(trade.id)
+        .bind(trade.exchange_id.value())
+        .bind(&trade.exchange_trade_id)
+        .bind(trade.order_id)
+        .bind(trade.symbol.to_standard_notation())
+        .bind(trade.side.to_string())
+        .bind(trade.price)
+        .bind(trade.quantity)
+        .bind(trade.fee)
+        .bind(trade.fee_asset.as_deref())
+        .bind(trade.executed_at)
+        .execute(&mut *conn)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+/// 체결 행 복원
+fn decode_trade(row: &PgRow) -> Result<Trade> {
+    let symbol: String = row.try_get("symbol").map_err(db_error)?;
+    let side: String = row.try_get("side").map_err(db_error)?;
+    Ok(Trade {
+        id: row.try_get("id").map_err(db_error)?,
+        exchange_id: ExchangeId::new(row.try_get::<String, _>("exchange_id").map_err(db_error)?),
+        exchange_trade_id: row.try_get("exchange_trade_id").map_err(db_error)?,
+        order_id: row.try_get("order_id").map_err(db_error)?,
+        symbol: symbol
+            .parse::<SymbolPair>()
+            .map_err(|e| CoreError::Data(format!("잘못된 체결 심볼 {}: {}", symbol, e)))?,
+        side: match side.as_str() {
+            "buy" => OrderSide::Buy,
+            "sell" => OrderSide::Sell,
+            other => return Err(CoreError::Data(format!("알 수 없는 체결 방향: {}", other))),
+        },
+        price: row.try_get("price").map_err(db_error)?,
+        quantity: row.try_get("quantity").map_err(db_error)?,
+        fee: row.try_get("fee").map_err(db_error)?,
+        fee_asset: row.try_get("fee_asset").map_err(db_error)?,
+        executed_at: row.try_get("executed_at").map_err(db_error)?,
+    })
+}
+
+#[async_trait]
+impl TradeRepository for PostgresTradeRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Trade>> {
+        let row = sqlx::query("SELECT * FROM trades WHERE id = $1 LIMIT 1")
+            .bind(id)
+            .fetch_optional(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode_trade).transpose()
+    }
+
+    async fn find_by_order_id(&self, order_id: Uuid) -> Result<Vec<Trade>> {
+        let rows = sqlx::query("SELECT * FROM trades WHERE order_id = $1 ORDER BY executed_at, id")
+            .bind(order_id)
+            .fetch_all(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        rows.iter().map(decode_trade).collect()
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId, limit: Option<usize>) -> Result<Vec<Trade>> {
+        let rows = sqlx::query(
+            "SELECT * FROM trades WHERE exchange_id = $1 ORDER BY executed_at DESC, id DESC LIMIT $2",
+        )
+        .bind(exchange_id.value())
+        .bind(sql_limit(limit))
+        .fetch_all(self.outbox.pool())
+        .await
+        .map_err(db_error)?;
+        rows.iter().map(decode_trade).collect()
+    }
+
+    async fn save(&self, trade: &Trade) -> Result<Trade> {
+        self.save_batch(std::slice::from_ref(trade))
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, trades: &[Trade]) -> Result<Vec<Trade>> {
+        self.save_batch_with_events(trades, &OutboxBatch::default()).await
+    }
+
+    async fn save_batch_with_events(&self, trades: &[Trade], events: &OutboxBatch) -> Result<Vec<Trade>> {
+        let mut tx = self.outbox.pool().begin().await.map_err(db_error)?;
+        Self::lock_exchange_trade_ids(&mut tx, trades).await?;
+        for trade in trades {
+            Self::upsert(&mut tx, trade).await?;
+        }
+        if !events.is_empty() {
+            self.outbox.enqueue_in(&mut tx, events).await?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(trades.to_vec())
+    }
+}
+
+/// PostgreSQL 마켓 리포지토리
+#[derive(Clone)]
+pub struct PostgresMarketRepository {
+    pool: PgPool,
+}
+
+impl PostgresMarketRepository {
+    /// 풀로 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MarketRepository for PostgresMarketRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Market>> {
+        let row = sqlx::query("SELECT data FROM markets WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange_and_symbol(&self, exchange_id: &ExchangeId, symbol: &str) -> Result<Option<Market>> {
+        let row = sqlx::query(
+            "SELECT data FROM markets WHERE exchange_id = $1 AND (symbol = $2 OR exchange_symbol = $3) LIMIT 1",
+        )
+        .bind(exchange_id.value())
+        .bind(symbol.to_uppercase())
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId) -> Result<Vec<Market>> {
+        let rows = sqlx::query("SELECT data FROM markets WHERE exchange_id = $1 ORDER BY symbol")
+            .bind(exchange_id.value())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn find_all(&self) -> Result<Vec<Market>> {
+        let rows = sqlx::query("SELECT data FROM markets ORDER BY exchange_id, symbol")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, market: &Market) -> Result<Market> {
+        self.save_batch(std::slice::from_ref(market))
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, markets: &[Market]) -> Result<Vec<Market>> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        for market in markets {
+            sqlx::query(
+                "INSERT INTO markets (id, exchange_id, symbol, exchange_symbol, data) VALUES ($1, $2, $3, $4, $5) \
+                 ON CONFLICT (id) DO UPDATE SET \
+                     exchange_id = EXCLUDED.exchange_id, symbol = EXCLUDED.symbol, \
+                     exchange_symbol = EXCLUDED.exchange_symbol, data = EXCLUDED.data",
+            )
+            .bind(market.id)
+            .bind(market.exchange_id.value())
+            .bind(market.symbol.to_standard_notation())
+            .bind(&market.exchange_symbol)
+            .bind(Json(market))
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(markets.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use cryptolytica_shared_kernel::types::Decimal;
+    use crate::repositories::conformance;
+    use crate::repositories::postgres::tests::isolated_repositories;
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_postgres_repositories_conform() {
+        let repositories = isolated_repositories().await;
+
+        conformance::exchange_repository_conformance(&repositories.exchanges()).await;
+        conformance::order_repository_conformance(&repositories.orders(), repositories.outbox()).await;
+        conformance::trade_repository_conformance(&repositories.trades(), repositories.outbox()).await;
+        conformance::market_repository_conformance(&repositories.markets()).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_trade_key_excludes_execution_time() {
+        let repositories = isolated_repositories().await;
+        let executed_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let insert = |executed_at| {
+            sqlx::query(
+                "INSERT INTO trades (id, exchange_id, exchange_trade_id, symbol, side, price, quantity, executed_at) \
+                 VALUES ($1, 'binance', 'T-1', 'BTC/USDT', 'buy', 1, 1, $2)",
+            )
+            .bind(Uuid::new_v4())
+            .bind(executed_at)
+        };
+        insert(executed_at).execute(repositories.pool()).await.unwrap();
+
+        // 일반 테이블은 체결 시각만 다른 같은 체결 ID도 고유 인덱스가 거부
+        // 하이퍼테이블은 인덱스에 시간 열이 들어가므로 같은 시각의 중복만 데이터베이스가 거부
+        let duplicate_at = if repositories.timescale_enabled() {
+            executed_at
+        } else {
+            executed_at + Duration::seconds(1)
+        };
+        let duplicate = insert(duplicate_at).execute(repositories.pool()).await;
+        assert!(matches!(duplicate.map_err(db_error), Err(CoreError::Validation(_))));
+    }
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_concurrent_saves_keep_exchange_trade_id_unique() {
+        let repositories = isolated_repositories().await;
+        let trades = repositories.trades();
+        let executed_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        // 시각만 다른 같은 체결 ID를 동시에 저장하면 하나만 성공
+        let saves = (0..8).map(|i| {
+            let trade = Trade::new(
+                ExchangeId::new("binance"),
+                "T-1",
+                SymbolPair::new("BTC", "USDT"),
+                OrderSide::Buy,
+                Decimal::ONE,
+                Decimal::ONE,
+                executed_at + Duration::seconds(i),
+            );
+            let trades = trades.clone();
+            async move { trades.save(&trade).await }
+        });
+        let results = futures::future::join_all(saves).await;
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+        assert!(results
+            .iter()
+            .filter_map(|result| result.as_ref().err())
+            .all(|error| matches!(error, CoreError::Validation(_))));
+        assert_eq!(trades.find_by_exchange(&ExchangeId::new("binance"), None).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,260 @@
+// outbox.rs
+//
+// PostgreSQL 아웃박스 저장소
+// SQLite 아웃박스와 같은 규칙을 따르며, 리포지토리 트랜잭션 안에서 `enqueue_in`으로 기록함
+// 테이블은 `migrations/postgres` 마이그레이션이 생성함
+//
+// BIGSERIAL 순번은 커밋이 아니라 INSERT 시점에 정해지므로, 동시에 기록하는 트랜잭션들이 순번과 다른
+// 순서로 커밋하면 릴레이가 뒤 순번을 먼저 발행할 수 있음. SQLite는 쓰기를 직렬화하지만 PostgreSQL은
+// 그렇지 않으므로, 기록 전에 트랜잭션 범위 권고 잠금을 잡아 순번 순서와 커밋 순서를 일치시킴
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::postgres::{PgConnection, PgPool, PgRow};
+use sqlx::types::Json;
+use sqlx::Row;
+use cryptolytica_shared_kernel::clock::{system_clock, SharedClock};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::{EventEnvelope, OutboxBatch, OutboxMessage, OutboxStatus, OutboxStore};
+use cryptolytica_shared_kernel::types::Result;
+use crate::database::db_error;
+
+/// 아웃박스 기록을 직렬화하는 권고 잠금 키
+const OUTBOX_LOCK_KEY: i64 = 0x6f75_7462_6f78; // "outbox"
+
+/// PostgreSQL 아웃박스 저장소
+#[derive(Clone)]
+pub struct PostgresOutboxStore {
+    pool: PgPool,
+    clock: SharedClock,
+}
+
+impl PostgresOutboxStore {
+    /// 풀로 저장소 생성
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            clock: system_clock(),
+        }
+    }
+
+    /// 시계 설정
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 연결 풀
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// 호출자의 트랜잭션 안에서 이벤트 기록 (커밋해야 릴레이에 보임)
+    ///
+    /// 트랜잭션이 끝날 때까지 다른 기록 트랜잭션을 기다리게 하므로, 기록 후에는 빨리 커밋해야 합니다.
+    pub async fn enqueue_in(&self, conn: &mut PgConnection, batch: &OutboxBatch) -> Result<Vec<u64>> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(OUTBOX_LOCK_KEY)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error)?;
+
+        let enqueued_at = self.clock.now();
+        let mut sequences = Vec::with_capacity(batch.len());
+        for envelope in batch.envelopes() {
+            let sequence: i64 = sqlx::query_scalar(
+                "INSERT INTO event_outbox (event_id, event_type, envelope, status, enqueued_at) \
+                 VALUES ($1, $2, $3, $4, $5) RETURNING sequence",
+            )
+            .bind(envelope.header.id)
+            .bind(&envelope.header.event_type)
+            .bind(Json(envelope))
+            .bind(OutboxStatus::Pending.as_str())
+            .bind(enqueued_at)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error)?;
+            sequences.push(sequence as u64);
+        }
+        Ok(sequences)
+    }
+}
+
+#[async_trait]
+impl OutboxStore for PostgresOutboxStore {
+    async fn enqueue(&self, batch: &OutboxBatch) -> Result<Vec<u64>> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        let sequences = self.enqueue_in(&mut tx, batch).await?;
+        tx.commit().await.map_err(db_error)?;
+        Ok(sequences)
+    }
+
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxMessage>> {
+        let rows = sqlx::query(
+            "SELECT sequence, envelope, status, enqueued_at, attempts, last_error, published_at \
+             FROM event_outbox WHERE status = $1 ORDER BY sequence LIMIT $2",
+        )
+        .bind(OutboxStatus::Pending.as_str())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+        rows.iter().map(decode_message).collect()
+    }
+
+    async fn mark_published(&self, sequences: &[u64], at: DateTime<Utc>) -> Result<()> {
+        let sequences: Vec<i64> = sequences.iter().map(|sequence| *sequence as i64).collect();
+        sqlx::query("UPDATE event_outbox SET status = $1, published_at = $2 WHERE sequence = ANY($3)")
+            .bind(OutboxStatus::Published.as_str())
+            .bind(at)
+            .bind(&sequences)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    async fn mark_failed(&self, sequence: u64, error: &str, dead: bool) -> Result<()> {
+        let status = if dead { OutboxStatus::Dead } else { OutboxStatus::Pending };
+        let result = sqlx::query(
+            "UPDATE event_outbox SET attempts = attempts + 1, last_error = $1, status = $2 WHERE sequence = $3",
+        )
+        .bind(error)
+        .bind(status.as_str())
+        .bind(sequence as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(CoreError::NotFound(format!("아웃박스 메시지를 찾을 수 없음: {}", sequence)));
+        }
+        Ok(())
+    }
+
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<usize> {
+        let result = sqlx::query("DELETE FROM event_outbox WHERE status = $1 AND published_at < $2")
+            .bind(OutboxStatus::Published.as_str())
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+/// 행을 아웃박스 메시지로 복원
+fn decode_message(row: &PgRow) -> Result<OutboxMessage> {
+    let sequence: i64 = row.try_get("sequence").map_err(db_error)?;
+    let Json(envelope): Json<EventEnvelope<Value>> = row
+        .try_get("envelope")
+        .map_err(|e| CoreError::Data(format!("손상된 아웃박스 메시지 {}: {}", sequence, e)))?;
+    let status: String = row.try_get("status").map_err(db_error)?;
+    let attempts: i32 = row.try_get("attempts").map_err(db_error)?;
+    Ok(OutboxMessage {
+        sequence: sequence as u64,
+        envelope,
+        status: OutboxStatus::parse(&status)
+            .ok_or_else(|| CoreError::Data(format!("알 수 없는 아웃박스 상태: {}", status)))?,
+        enqueued_at: row.try_get("enqueued_at").map_err(db_error)?,
+        attempts: attempts as u32,
+        last_error: row.try_get("last_error").map_err(db_error)?,
+        published_at: row.try_get("published_at").map_err(db_error)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+    use cryptolytica_shared_kernel::events::Event;
+    use crate::repositories::conformance;
+    use crate::repositories::postgres::tests::isolated_repositories;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct OrderSaved {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+    }
+
+    impl Event for OrderSaved {
+        fn event_type(&self) -> &'static str {
+            "exchange.order.saved"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    fn saved() -> OrderSaved {
+        OrderSaved {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_postgres_outbox_conforms() {
+        let repositories = isolated_repositories().await;
+        conformance::outbox_store_conformance(repositories.outbox()).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_rolled_back_transaction_leaves_no_rows() {
+        let repositories = isolated_repositories().await;
+        let store = repositories.outbox();
+        let saved = saved();
+        let batch = OutboxBatch::new("exchange-domain").with(&saved).unwrap();
+
+        let mut tx = store.pool().begin().await.unwrap();
+        store.enqueue_in(&mut tx, &batch).await.unwrap();
+        tx.rollback().await.unwrap();
+        assert!(store.pending(10).await.unwrap().is_empty());
+
+        let mut tx = store.pool().begin().await.unwrap();
+        store.enqueue_in(&mut tx, &batch).await.unwrap();
+        tx.commit().await.unwrap();
+        let pending = store.pending(10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].envelope.header.id, saved.id);
+    }
+
+    #[tokio::test]
+    #[ignore = "로컬 Postgres 컨테이너 필요 (CRYPTOLYTICA_TEST_DATABASE_URL)"]
+    async fn test_concurrent_writers_commit_in_sequence_order() {
+        let repositories = isolated_repositories().await;
+        let store = repositories.outbox().clone();
+
+        let mut first = store.pool().begin().await.unwrap();
+        let first_sequences = store
+            .enqueue_in(&mut first, &OutboxBatch::new("exchange-domain").with(&saved()).unwrap())
+            .await
+            .unwrap();
+
+        // 먼저 기록한 트랜잭션이 끝나기 전에는 다음 기록이 순번을 받지 못하므로 추월할 수 없음
+        let second = tokio::spawn({
+            let store = store.clone();
+            async move { store.enqueue(&OutboxBatch::new("exchange-domain").with(&saved()).unwrap()).await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        assert!(!second.is_finished());
+        assert!(store.pending(10).await.unwrap().is_empty());
+
+        first.commit().await.unwrap();
+        let second_sequences = second.await.unwrap().unwrap();
+        assert!(first_sequences[0] < second_sequences[0]);
+        let pending: Vec<_> = store.pending(10).await.unwrap().iter().map(|message| message.sequence).collect();
+        assert_eq!(pending, [first_sequences[0], second_sequences[0]]);
+    }
+}
@@ -0,0 +1,474 @@
+// sqlite.rs
+//
+// SQLite 리포지토리 구현체 (단일 노드 배포용)
+// 엔티티 전체는 JSON 문서로 저장하고, 조회·고유성 검사에 쓰는 값만 열로 꺼내 인덱스를 둠
+// 벌크 저장은 한 트랜잭션이며, `*_with_events`는 같은 트랜잭션에서 아웃박스에 이벤트를 기록함
+// 스키마는 `migrations/sqlite`의 마이그레이션을 바이너리에 포함해 적용함
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection, SqlitePool, SqlitePoolOptions, SqliteRow};
+use sqlx::Row;
+use std::str::FromStr;
+use uuid::Uuid;
+use cryptolytica_exchange_domain::domain::model::{Exchange, ExchangeId, Market, Order, Trade};
+use cryptolytica_exchange_domain::domain::repository::{
+    ExchangeRepository, MarketRepository, OrderRepository, TradeRepository,
+};
+use cryptolytica_shared_kernel::error::CoreError;
+use cryptolytica_shared_kernel::events::OutboxBatch;
+use cryptolytica_shared_kernel::types::Result;
+use crate::database::db_error;
+use crate::events::sqlite_outbox::SqliteOutboxStore;
+
+/// 포함된 SQLite 마이그레이션
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+/// 엔티티를 JSON 문서로 직렬화
+fn encode<T: Serialize>(value: &T) -> Result<String> {
+    Ok(serde_json::to_string(value)?)
+}
+
+/// `data` 열의 JSON 문서를 엔티티로 복원
+fn decode<T: DeserializeOwned>(row: &SqliteRow) -> Result<T> {
+    let data: String = row.try_get("data").map_err(db_error)?;
+    serde_json::from_str(&data).map_err(|e| CoreError::Data(format!("손상된 레코드: {}", e)))
+}
+
+fn decode_all<T: DeserializeOwned>(rows: Vec<SqliteRow>) -> Result<Vec<T>> {
+    rows.iter().map(decode).collect()
+}
+
+/// `LIMIT` 값 (SQLite에서 음수는 제한 없음)
+fn sql_limit(limit: Option<usize>) -> i64 {
+    limit.map_or(-1, |limit| limit as i64)
+}
+
+/// SQLite 리포지토리 묶음 (풀과 아웃박스를 공유)
+#[derive(Clone)]
+pub struct SqliteRepositories {
+    pool: SqlitePool,
+    outbox: SqliteOutboxStore,
+}
+
+impl SqliteRepositories {
+    /// 기존 풀로 생성 (마이그레이션은 `migrate`로 별도 실행)
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            outbox: SqliteOutboxStore::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// 데이터베이스 파일을 열고(없으면 생성) 마이그레이션까지 실행
+    pub async fn connect(url: &str) -> Result<Self> {
+        let options = SqliteConnectOptions::from_str(url).map_err(db_error)?.create_if_missing(true);
+        let pool = SqlitePoolOptions::new().connect_with(options).await.map_err(db_error)?;
+        let repositories = Self::new(pool);
+        repositories.migrate().await?;
+        Ok(repositories)
+    }
+
+    /// 리포지토리 테이블과 아웃박스 테이블 생성
+    pub async fn migrate(&self) -> Result<()> {
+        MIGRATOR
+            .run(&self.pool)
+            .await
+            .map_err(|e| CoreError::Data(format!("SQLite 마이그레이션 실패: {}", e)))?;
+        self.outbox.migrate().await
+    }
+
+    /// 연결 풀
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// 이벤트가 기록되는 아웃박스 (`OutboxRelay`에 연결)
+    pub fn outbox(&self) -> &SqliteOutboxStore {
+        &self.outbox
+    }
+
+    /// 거래소 리포지토리
+    pub fn exchanges(&self) -> SqliteExchangeRepository {
+        SqliteExchangeRepository::new(self.pool.clone())
+    }
+
+    /// 주문 리포지토리
+    pub fn orders(&self) -> SqliteOrderRepository {
+        SqliteOrderRepository::new(self.outbox.clone())
+    }
+
+    /// 거래 리포지토리
+    pub fn trades(&self) -> SqliteTradeRepository {
+        SqliteTradeRepository::new(self.outbox.clone())
+    }
+
+    /// 마켓 리포지토리
+    pub fn markets(&self) -> SqliteMarketRepository {
+        SqliteMarketRepository::new(self.pool.clone())
+    }
+}
+
+/// SQLite 거래소 리포지토리
+#[derive(Clone)]
+pub struct SqliteExchangeRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteExchangeRepository {
+    /// 풀로 생성
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExchangeRepository for SqliteExchangeRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Exchange>> {
+        let row = sqlx::query("SELECT data FROM exchanges WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange_id(&self, exchange_id: &ExchangeId) -> Result<Option<Exchange>> {
+        let row = sqlx::query("SELECT data FROM exchanges WHERE exchange_id = ?")
+            .bind(exchange_id.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Exchange>> {
+        let rows = sqlx::query("SELECT data FROM exchanges ORDER BY exchange_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn find_active(&self) -> Result<Vec<Exchange>> {
+        let rows = sqlx::query("SELECT data FROM exchanges WHERE active = 1 ORDER BY exchange_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, exchange: &Exchange) -> Result<Exchange> {
+        sqlx::query(
+            "INSERT INTO exchanges (id, exchange_id, active, data) VALUES (?, ?, ?, ?) \
+             ON CONFLICT (id) DO UPDATE SET \
+                 exchange_id = excluded.exchange_id, active = excluded.active, data = excluded.data",
+        )
+        .bind(exchange.id.to_string())
+        .bind(exchange.exchange_id.value())
+        .bind(exchange.is_active())
+        .bind(encode(exchange)?)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(exchange.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        let result = sqlx::query("DELETE FROM exchanges WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected() == 0 {
+            return Err(CoreError::NotFound(format!("거래소를 찾을 수 없음: {}", id)));
+        }
+        Ok(())
+    }
+}
+
+/// SQLite 주문 리포지토리
+#[derive(Clone)]
+pub struct SqliteOrderRepository {
+    outbox: SqliteOutboxStore,
+}
+
+impl SqliteOrderRepository {
+    /// 아웃박스와 같은 데이터베이스를 쓰는 리포지토리 생성
+    pub fn new(outbox: SqliteOutboxStore) -> Self {
+        Self { outbox }
+    }
+
+    async fn upsert(conn: &mut SqliteConnection, order: &Order) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO orders (id, exchange_id, exchange_order_id, created_at, data) VALUES (?, ?, ?, ?, ?) \
+             ON CONFLICT (id) DO UPDATE SET \
+                 exchange_id = excluded.exchange_id, exchange_order_id = excluded.exchange_order_id, \
+                 created_at = excluded.created_at, data = excluded.data",
+        )
+        .bind(order.id.to_string())
+        .bind(order.exchange_id.value())
+        .bind(order.exchange_order_id.as_deref())
+        .bind(order.created_at.timestamp_millis())
+        .bind(encode(order)?)
+        .execute(conn)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderRepository for SqliteOrderRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Order>> {
+        let row = sqlx::query("SELECT data FROM orders WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange_order_id(&self, exchange_id: &ExchangeId, exchange_order_id: &str) -> Result<Option<Order>> {
+        let row = sqlx::query("SELECT data FROM orders WHERE exchange_id = ? AND exchange_order_id = ?")
+            .bind(exchange_id.value())
+            .bind(exchange_order_id)
+            .fetch_optional(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId, limit: Option<usize>) -> Result<Vec<Order>> {
+        let rows = sqlx::query(
+            "SELECT data FROM orders WHERE exchange_id = ? ORDER BY created_at DESC, id DESC LIMIT ?",
+        )
+        .bind(exchange_id.value())
+        .bind(sql_limit(limit))
+        .fetch_all(self.outbox.pool())
+        .await
+        .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, order: &Order) -> Result<Order> {
+        self.save_batch_with_events(std::slice::from_ref(order), &OutboxBatch::default())
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, orders: &[Order]) -> Result<Vec<Order>> {
+        self.save_batch_with_events(orders, &OutboxBatch::default()).await
+    }
+
+    async fn save_with_events(&self, order: &Order, events: &OutboxBatch) -> Result<Order> {
+        self.save_batch_with_events(std::slice::from_ref(order), events)
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch_with_events(&self, orders: &[Order], events: &OutboxBatch) -> Result<Vec<Order>> {
+        let mut tx = self.outbox.pool().begin().await.map_err(db_error)?;
+        for order in orders {
+            Self::upsert(&mut tx, order).await?;
+        }
+        if !events.is_empty() {
+            self.outbox.enqueue_in(&mut tx, events).await?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(orders.to_vec())
+    }
+}
+
+/// SQLite 거래 리포지토리
+#[derive(Clone)]
+pub struct SqliteTradeRepository {
+    outbox: SqliteOutboxStore,
+}
+
+impl SqliteTradeRepository {
+    /// 아웃박스와 같은 데이터베이스를 쓰는 리포지토리 생성
+    pub fn new(outbox: SqliteOutboxStore) -> Self {
+        Self { outbox }
+    }
+
+    async fn upsert(conn: &mut SqliteConnection, trade: &Trade) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO trades (id, exchange_id, exchange_trade_id, order_id, executed_at, data) \
+             VALUES (?, ?, ?, ?, ?, ?) \
+             ON CONFLICT (id) DO UPDATE SET \
+                 exchange_id = excluded.exchange_id, exchange_trade_id = excluded.exchange_trade_id, \
+                 order_id = excluded.order_id, executed_at = excluded.executed_at, data = excluded.data",
+        )
+        .bind(trade.id.to_string())
+        .bind(trade.exchange_id.value())
+        .bind(&trade.exchange_trade_id)
+        .bind(trade.order_id.map(|id| id.to_string()))
+        .bind(trade.executed_at.timestamp_millis())
+        .bind(encode(trade)?)
+        .execute(conn)
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TradeRepository for SqliteTradeRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Trade>> {
+        let row = sqlx::query("SELECT data FROM trades WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_order_id(&self, order_id: Uuid) -> Result<Vec<Trade>> {
+        let rows = sqlx::query("SELECT data FROM trades WHERE order_id = ? ORDER BY executed_at, id")
+            .bind(order_id.to_string())
+            .fetch_all(self.outbox.pool())
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId, limit: Option<usize>) -> Result<Vec<Trade>> {
+        let rows = sqlx::query(
+            "SELECT data FROM trades WHERE exchange_id = ? ORDER BY executed_at DESC, id DESC LIMIT ?",
+        )
+        .bind(exchange_id.value())
+        .bind(sql_limit(limit))
+        .fetch_all(self.outbox.pool())
+        .await
+        .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, trade: &Trade) -> Result<Trade> {
+        self.save_batch(std::slice::from_ref(trade))
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, trades: &[Trade]) -> Result<Vec<Trade>> {
+        self.save_batch_with_events(trades, &OutboxBatch::default()).await
+    }
+
+    async fn save_batch_with_events(&self, trades: &[Trade], events: &OutboxBatch) -> Result<Vec<Trade>> {
+        let mut tx = self.outbox.pool().begin().await.map_err(db_error)?;
+        for trade in trades {
+            Self::upsert(&mut tx, trade).await?;
+        }
+        if !events.is_empty() {
+            self.outbox.enqueue_in(&mut tx, events).await?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(trades.to_vec())
+    }
+}
+
+/// SQLite 마켓 리포지토리
+#[derive(Clone)]
+pub struct SqliteMarketRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMarketRepository {
+    /// 풀로 생성
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MarketRepository for SqliteMarketRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Market>> {
+        let row = sqlx::query("SELECT data FROM markets WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange_and_symbol(&self, exchange_id: &ExchangeId, symbol: &str) -> Result<Option<Market>> {
+        let row = sqlx::query(
+            "SELECT data FROM markets WHERE exchange_id = ? AND (symbol = ? OR exchange_symbol = ?) LIMIT 1",
+        )
+        .bind(exchange_id.value())
+        .bind(symbol.to_uppercase())
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+        row.as_ref().map(decode).transpose()
+    }
+
+    async fn find_by_exchange(&self, exchange_id: &ExchangeId) -> Result<Vec<Market>> {
+        let rows = sqlx::query("SELECT data FROM markets WHERE exchange_id = ? ORDER BY symbol")
+            .bind(exchange_id.value())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn find_all(&self) -> Result<Vec<Market>> {
+        let rows = sqlx::query("SELECT data FROM markets ORDER BY exchange_id, symbol")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        decode_all(rows)
+    }
+
+    async fn save(&self, market: &Market) -> Result<Market> {
+        self.save_batch(std::slice::from_ref(market))
+            .await
+            .map(|mut saved| saved.remove(0))
+    }
+
+    async fn save_batch(&self, markets: &[Market]) -> Result<Vec<Market>> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+        for market in markets {
+            sqlx::query(
+                "INSERT INTO markets (id, exchange_id, symbol, exchange_symbol, data) VALUES (?, ?, ?, ?, ?) \
+                 ON CONFLICT (id) DO UPDATE SET \
+                     exchange_id = excluded.exchange_id, symbol = excluded.symbol, \
+                     exchange_symbol = excluded.exchange_symbol, data = excluded.data",
+            )
+            .bind(market.id.to_string())
+            .bind(market.exchange_id.value())
+            .bind(market.symbol.to_standard_notation())
+            .bind(&market.exchange_symbol)
+            .bind(encode(market)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+        tx.commit().await.map_err(db_error)?;
+        Ok(markets.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::conformance;
+
+    #[tokio::test]
+    async fn test_sqlite_repositories_conform() {
+        // 메모리 데이터베이스는 연결마다 따로 생기므로 연결 하나만 사용
+        let pool = SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+        let repositories = SqliteRepositories::new(pool);
+        repositories.migrate().await.unwrap();
+
+        conformance::exchange_repository_conformance(&repositories.exchanges()).await;
+        conformance::order_repository_conformance(&repositories.orders(), repositories.outbox()).await;
+        conformance::trade_repository_conformance(&repositories.trades(), repositories.outbox()).await;
+        conformance::market_repository_conformance(&repositories.markets()).await;
+        conformance::outbox_store_conformance(repositories.outbox()).await;
+    }
+}
@@ -1,7 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use cryptolytica_market_domain::*;
 use cryptolytica_shared_kernel::types::SymbolPair;
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 
 fn time_series_benchmark(c: &mut Criterion) {
     let pair = SymbolPair::new("BTC", "USDT");
@@ -0,0 +1,203 @@
+//! 도메인 모델과 Polars DataFrame 간 변환
+//!
+//! 아카이브 파일의 열 구성(스키마)을 정의합니다. 가격과 수량은 `Decimal`의 십진 문자열(`Utf8`)로
+//! 저장해 쓴 값을 자릿수까지 그대로 읽어 옵니다. `Decimal`의 소수 자릿수는 값마다 0~28로 달라
+//! 고정 자릿수의 `Decimal128` 열에는 손실 없이 담을 수 없기 때문입니다. 분석할 때는
+//! `col("close").cast(DataType::Float64)`처럼 필요한 열만 숫자로 변환해 씁니다.
+//! 시각은 마이크로초 단위 `Datetime`(UTC 기준, 시간대 표기 없음)으로, 거래소 체결 시각을 잘라내지 않아
+//! 다시 읽은 시각이 병합 키와 그대로 일치합니다.
+
+use chrono::{DateTime, TimeZone, Utc};
+use polars::prelude::*;
+use uuid::Uuid;
+use crate::model::{Candle, Trade};
+use crate::shared::error::CoreError;
+use crate::shared::types::{Decimal, ExchangeId, Instrument, OrderSide, Result, SymbolPair, Timeframe};
+
+/// 시각 열 이름
+pub const TIMESTAMP_COLUMN: &str = "timestamp";
+
+/// 시각 열 자료형
+pub fn timestamp_dtype() -> DataType {
+    DataType::Datetime(TimeUnit::Microseconds, None)
+}
+
+/// Polars 오류 변환
+pub(crate) fn polars_error(error: PolarsError) -> CoreError {
+    CoreError::Data(format!("아카이브 데이터 처리 실패: {}", error))
+}
+
+/// 캔들을 DataFrame으로 변환
+pub fn candles_to_frame(candles: &[Candle]) -> Result<DataFrame> {
+    let instruments = candles
+        .iter()
+        .map(|candle| candle.instrument.as_ref().map(serde_json::to_string).transpose())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    DataFrame::new(vec![
+        Series::new("exchange", candles.iter().map(|c| c.exchange.0.clone()).collect::<Vec<_>>()),
+        Series::new("symbol", candles.iter().map(|c| c.symbol.to_standard_notation()).collect::<Vec<_>>()),
+        Series::new("timeframe", candles.iter().map(|c| c.timeframe.to_code()).collect::<Vec<_>>()),
+        timestamps(candles.iter().map(|c| c.timestamp))?,
+        Series::new("open", decimals(candles.iter().map(|c| c.open))),
+        Series::new("high", decimals(candles.iter().map(|c| c.high))),
+        Series::new("low", decimals(candles.iter().map(|c| c.low))),
+        Series::new("close", decimals(candles.iter().map(|c| c.close))),
+        Series::new("volume", decimals(candles.iter().map(|c| c.volume))),
+        Series::new(
+            "quote_volume",
+            candles.iter().map(|c| c.quote_volume.map(|v| v.to_string())).collect::<Vec<_>>(),
+        ),
+        Series::new("is_complete", candles.iter().map(|c| c.is_complete).collect::<Vec<_>>()),
+        Series::new("id", candles.iter().map(|c| c.id.to_string()).collect::<Vec<_>>()),
+        Series::new("instrument", instruments),
+    ])
+    .map_err(polars_error)
+}
+
+/// DataFrame을 캔들로 변환
+pub fn frame_to_candles(frame: &DataFrame) -> Result<Vec<Candle>> {
+    let exchange = strings(frame, "exchange")?;
+    let symbol = strings(frame, "symbol")?;
+    let timeframe = strings(frame, "timeframe")?;
+    let timestamp = read_timestamps(frame)?;
+    let open = strings(frame, "open")?;
+    let high = strings(frame, "high")?;
+    let low = strings(frame, "low")?;
+    let close = strings(frame, "close")?;
+    let volume = strings(frame, "volume")?;
+    let quote_volume = optional_strings(frame, "quote_volume")?;
+    let is_complete = frame.column("is_complete").and_then(|c| c.bool().cloned()).map_err(polars_error)?;
+    let id = strings(frame, "id")?;
+    let instrument = optional_strings(frame, "instrument")?;
+
+    (0..frame.height())
+        .map(|i| {
+            Ok(Candle {
+                id: parse_uuid(&id[i])?,
+                symbol: parse_symbol(&symbol[i])?,
+                timestamp: timestamp[i],
+                open: parse_decimal("open", &open[i])?,
+                high: parse_decimal("high", &high[i])?,
+                low: parse_decimal("low", &low[i])?,
+                close: parse_decimal("close", &close[i])?,
+                volume: parse_decimal("volume", &volume[i])?,
+                exchange: ExchangeId::new(exchange[i].clone()),
+                timeframe: timeframe[i]
+                    .parse::<Timeframe>()
+                    .map_err(|e| CoreError::Data(format!("잘못된 타임프레임 {}: {}", timeframe[i], e)))?,
+                quote_volume: quote_volume[i]
+                    .as_deref()
+                    .map(|v| parse_decimal("quote_volume", v))
+                    .transpose()?,
+                is_complete: is_complete.get(i).unwrap_or(false),
+                instrument: instrument[i]
+                    .as_deref()
+                    .map(serde_json::from_str::<Instrument>)
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
+/// 체결을 DataFrame으로 변환
+pub fn trades_to_frame(trades: &[Trade]) -> Result<DataFrame> {
+    DataFrame::new(vec![
+        Series::new("exchange", trades.iter().map(|t| t.exchange.0.clone()).collect::<Vec<_>>()),
+        Series::new("symbol", trades.iter().map(|t| t.symbol.to_standard_notation()).collect::<Vec<_>>()),
+        Series::new("trade_id", trades.iter().map(|t| t.trade_id.clone()).collect::<Vec<_>>()),
+        timestamps(trades.iter().map(|t| t.timestamp))?,
+        Series::new("price", decimals(trades.iter().map(|t| t.price))),
+        Series::new("quantity", decimals(trades.iter().map(|t| t.quantity))),
+        Series::new("side", trades.iter().map(|t| t.side.to_string()).collect::<Vec<_>>()),
+        Series::new("id", trades.iter().map(|t| t.id.to_string()).collect::<Vec<_>>()),
+    ])
+    .map_err(polars_error)
+}
+
+/// DataFrame을 체결로 변환
+pub fn frame_to_trades(frame: &DataFrame) -> Result<Vec<Trade>> {
+    let exchange = strings(frame, "exchange")?;
+    let symbol = strings(frame, "symbol")?;
+    let trade_id = strings(frame, "trade_id")?;
+    let timestamp = read_timestamps(frame)?;
+    let price = strings(frame, "price")?;
+    let quantity = strings(frame, "quantity")?;
+    let side = strings(frame, "side")?;
+    let id = strings(frame, "id")?;
+
+    (0..frame.height())
+        .map(|i| {
+            Ok(Trade {
+                id: parse_uuid(&id[i])?,
+                exchange: ExchangeId::new(exchange[i].clone()),
+                symbol: parse_symbol(&symbol[i])?,
+                trade_id: trade_id[i].clone(),
+                price: parse_decimal("price", &price[i])?,
+                quantity: parse_decimal("quantity", &quantity[i])?,
+                side: match side[i].as_str() {
+                    "buy" => OrderSide::Buy,
+                    "sell" => OrderSide::Sell,
+                    other => return Err(CoreError::Data(format!("알 수 없는 체결 방향: {}", other))),
+                },
+                timestamp: timestamp[i],
+            })
+        })
+        .collect()
+}
+
+fn timestamps(values: impl Iterator<Item = DateTime<Utc>>) -> Result<Series> {
+    Series::new(TIMESTAMP_COLUMN, values.map(|t| t.timestamp_micros()).collect::<Vec<_>>())
+        .cast(&timestamp_dtype())
+        .map_err(polars_error)
+}
+
+fn decimals(values: impl Iterator<Item = Decimal>) -> Vec<String> {
+    values.map(|value| value.to_string()).collect()
+}
+
+fn read_timestamps(frame: &DataFrame) -> Result<Vec<DateTime<Utc>>> {
+    let micros = frame
+        .column(TIMESTAMP_COLUMN)
+        .and_then(|c| c.cast(&timestamp_dtype()))
+        .and_then(|c| c.cast(&DataType::Int64))
+        .map_err(polars_error)?;
+    let micros = micros.i64().map_err(polars_error)?;
+    micros
+        .into_iter()
+        .map(|value| {
+            value
+                .and_then(|micros| Utc.timestamp_micros(micros).single())
+                .ok_or_else(|| CoreError::Data(format!("잘못된 시각 값: {:?}", value)))
+        })
+        .collect()
+}
+
+fn strings(frame: &DataFrame, name: &str) -> Result<Vec<String>> {
+    optional_strings(frame, name)?
+        .into_iter()
+        .map(|value| value.ok_or_else(|| CoreError::Data(format!("{} 열에 빈 값이 있음", name))))
+        .collect()
+}
+
+fn optional_strings(frame: &DataFrame, name: &str) -> Result<Vec<Option<String>>> {
+    let column = frame.column(name).map_err(polars_error)?;
+    let column = column.str().map_err(polars_error)?;
+    Ok(column.into_iter().map(|value| value.map(str::to_string)).collect())
+}
+
+fn parse_decimal(name: &str, value: &str) -> Result<Decimal> {
+    value
+        .parse()
+        .map_err(|e| CoreError::Data(format!("{} 값을 Decimal로 변환할 수 없음: {} ({})", name, value, e)))
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).map_err(|e| CoreError::Data(format!("잘못된 ID {}: {}", value, e)))
+}
+
+fn parse_symbol(value: &str) -> Result<SymbolPair> {
+    value
+        .parse()
+        .map_err(|e| CoreError::Data(format!("잘못된 심볼 {}: {}", value, e)))
+}
@@ -0,0 +1,561 @@
+//! 시장 데이터 아카이브 (Parquet / Arrow IPC)
+//!
+//! 캔들과 체결을 하이브(hive) 형식 디렉터리로 분할해 파일로 보관합니다.
+//! 연구용 노트북과 백테스트가 같은 데이터셋을 Polars `LazyFrame`으로 읽을 수 있습니다.
+//!
+//! ```text
+//! <루트>/candles/exchange=<거래소>/symbol=<BASE-QUOTE>/timeframe=<간격>/date=<YYYY-MM-DD>/data.parquet
+//! <루트>/trades/exchange=<거래소>/symbol=<BASE-QUOTE>/date=<YYYY-MM-DD>/data.parquet
+//! ```
+//!
+//! 조회는 두 단계로 걸러냅니다. 디렉터리 이름으로 파티션을 먼저 고르고(파티션 가지치기),
+//! 남은 파일에는 시간 범위 조건을 `LazyFrame`에 걸어 Parquet 행 그룹 통계로 읽을 범위를 줄입니다.
+//! 같은 파티션에 다시 쓰면 기존 파일과 병합해 원자적으로 교체합니다 (캔들은 시작 시각, 체결은 체결 시각과 체결 ID 기준).
+
+pub mod frame;
+
+pub use frame::{candles_to_frame, frame_to_candles, frame_to_trades, trades_to_frame};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use polars::prelude::*;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use crate::model::{Candle, Trade};
+use crate::shared::types::{ExchangeId, Result, SymbolPair, Timeframe};
+use self::frame::{polars_error, timestamp_dtype, TIMESTAMP_COLUMN};
+
+/// Parquet 행 그룹 크기 (1분 캔들 약 1주일치, 시간 범위 통계의 단위)
+const ROW_GROUP_SIZE: usize = 10_080;
+
+/// 아카이브 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Apache Parquet (압축, 행 그룹 통계로 조건 푸시다운)
+    Parquet,
+    /// Arrow IPC (Feather v2, 메모리 매핑으로 빠르게 읽음)
+    Ipc,
+}
+
+impl ArchiveFormat {
+    /// 파티션 파일 이름
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Parquet => "data.parquet",
+            ArchiveFormat::Ipc => "data.arrow",
+        }
+    }
+}
+
+/// 아카이브 조회 조건 (지정하지 않은 항목은 전체)
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveQuery {
+    /// 거래소
+    pub exchange: Option<ExchangeId>,
+    /// 심볼
+    pub symbol: Option<SymbolPair>,
+    /// 캔들 타임프레임 (체결 조회에서는 무시)
+    pub timeframe: Option<Timeframe>,
+    /// 시작 시각 (포함)
+    pub from: Option<DateTime<Utc>>,
+    /// 종료 시각 (제외)
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl ArchiveQuery {
+    /// 전체 조회
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// 거래소 지정
+    pub fn with_exchange(mut self, exchange: ExchangeId) -> Self {
+        self.exchange = Some(exchange);
+        self
+    }
+
+    /// 심볼 지정
+    pub fn with_symbol(mut self, symbol: SymbolPair) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    /// 타임프레임 지정
+    pub fn with_timeframe(mut self, timeframe: Timeframe) -> Self {
+        self.timeframe = Some(timeframe);
+        self
+    }
+
+    /// 시간 범위 지정 (`from` 이상 `to` 미만)
+    pub fn with_range(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self.to = Some(to);
+        self
+    }
+
+    /// 날짜 파티션이 시간 범위와 겹치는지 여부
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        let after_start = self.from.is_none_or(|from| date >= from.date_naive());
+        let before_end = self
+            .to
+            .is_none_or(|to| date <= (to - chrono::Duration::microseconds(1)).date_naive());
+        after_start && before_end
+    }
+
+    /// 시간 범위 조건식
+    fn time_predicate(&self) -> Option<Expr> {
+        let bound = |time: DateTime<Utc>| lit(time.timestamp_micros()).cast(timestamp_dtype());
+        let from = self.from.map(|from| col(TIMESTAMP_COLUMN).gt_eq(bound(from)));
+        let to = self.to.map(|to| col(TIMESTAMP_COLUMN).lt(bound(to)));
+        match (from, to) {
+            (Some(from), Some(to)) => Some(from.and(to)),
+            (from, to) => from.or(to),
+        }
+    }
+}
+
+/// 시계열 종류별 파티션 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dataset {
+    Candles,
+    Trades,
+}
+
+impl Dataset {
+    fn directory(&self) -> &'static str {
+        match self {
+            Dataset::Candles => "candles",
+            Dataset::Trades => "trades",
+        }
+    }
+
+    /// 날짜 위의 파티션 단계 이름
+    fn levels(&self) -> &'static [&'static str] {
+        match self {
+            Dataset::Candles => &["exchange", "symbol", "timeframe"],
+            Dataset::Trades => &["exchange", "symbol"],
+        }
+    }
+}
+
+/// Parquet / Arrow IPC 시장 데이터 아카이브
+#[derive(Debug, Clone)]
+pub struct MarketDataArchive {
+    root: PathBuf,
+    format: ArchiveFormat,
+}
+
+impl MarketDataArchive {
+    /// 루트 디렉터리와 파일 형식으로 생성
+    pub fn new(root: impl Into<PathBuf>, format: ArchiveFormat) -> Self {
+        Self {
+            root: root.into(),
+            format,
+        }
+    }
+
+    /// 루트 디렉터리
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 파일 형식
+    pub fn format(&self) -> ArchiveFormat {
+        self.format
+    }
+
+    /// 캔들 기록 (파티션별로 기존 파일과 병합, 같은 시작 시각은 새 값으로 대체)
+    ///
+    /// 기록한 파티션 파일 경로를 반환합니다.
+    pub fn write_candles(&self, candles: &[Candle]) -> Result<Vec<PathBuf>> {
+        let mut partitions: BTreeMap<PathBuf, Vec<Candle>> = BTreeMap::new();
+        for candle in candles {
+            let values = [
+                candle.exchange.0.clone(),
+                symbol_directory(&candle.symbol),
+                candle.timeframe.to_code(),
+            ];
+            let path = self.partition_file(Dataset::Candles, &values, candle.timestamp.date_naive());
+            partitions.entry(path).or_default().push(candle.clone());
+        }
+
+        let mut written = Vec::with_capacity(partitions.len());
+        for (path, incoming) in partitions {
+            let mut merged: BTreeMap<DateTime<Utc>, Candle> = BTreeMap::new();
+            if path.exists() {
+                for candle in frame_to_candles(&self.read_file(&path)?)? {
+                    merged.insert(candle.timestamp, candle);
+                }
+            }
+            for candle in incoming {
+                merged.insert(candle.timestamp, candle);
+            }
+            let candles: Vec<_> = merged.into_values().collect();
+            self.write_file(&path, &mut candles_to_frame(&candles)?)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    /// 체결 기록 (파티션별로 기존 파일과 병합, 같은 시각·체결 ID는 새 값으로 대체)
+    pub fn write_trades(&self, trades: &[Trade]) -> Result<Vec<PathBuf>> {
+        let mut partitions: BTreeMap<PathBuf, Vec<Trade>> = BTreeMap::new();
+        for trade in trades {
+            let values = [trade.exchange.0.clone(), symbol_directory(&trade.symbol)];
+            let path = self.partition_file(Dataset::Trades, &values, trade.timestamp.date_naive());
+            partitions.entry(path).or_default().push(trade.clone());
+        }
+
+        let mut written = Vec::with_capacity(partitions.len());
+        for (path, incoming) in partitions {
+            let mut merged: BTreeMap<(DateTime<Utc>, String), Trade> = BTreeMap::new();
+            if path.exists() {
+                for trade in frame_to_trades(&self.read_file(&path)?)? {
+                    merged.insert((trade.timestamp, trade.trade_id.clone()), trade);
+                }
+            }
+            for trade in incoming {
+                merged.insert((trade.timestamp, trade.trade_id.clone()), trade);
+            }
+            let trades: Vec<_> = merged.into_values().collect();
+            self.write_file(&path, &mut trades_to_frame(&trades)?)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    /// 조건에 맞는 캔들 파티션을 `LazyFrame`으로 스캔 (시간 범위 조건 포함)
+    pub fn scan_candles(&self, query: &ArchiveQuery) -> Result<LazyFrame> {
+        let empty = candles_to_frame(&[])?;
+        self.scan(Dataset::Candles, query, empty)
+    }
+
+    /// 조건에 맞는 체결 파티션을 `LazyFrame`으로 스캔 (시간 범위 조건 포함)
+    pub fn scan_trades(&self, query: &ArchiveQuery) -> Result<LazyFrame> {
+        let empty = trades_to_frame(&[])?;
+        self.scan(Dataset::Trades, query, empty)
+    }
+
+    /// 캔들 조회 (거래소, 심볼, 타임프레임, 시작 시각순)
+    pub fn read_candles(&self, query: &ArchiveQuery) -> Result<Vec<Candle>> {
+        let frame = self.scan_candles(query)?.collect().map_err(polars_error)?;
+        let mut candles = frame_to_candles(&frame)?;
+        candles.sort_by(|a, b| {
+            (&a.exchange.0, a.symbol.to_standard_notation(), a.timeframe.to_code(), a.timestamp).cmp(&(
+                &b.exchange.0,
+                b.symbol.to_standard_notation(),
+                b.timeframe.to_code(),
+                b.timestamp,
+            ))
+        });
+        Ok(candles)
+    }
+
+    /// 체결 조회 (거래소, 심볼, 체결 시각순)
+    pub fn read_trades(&self, query: &ArchiveQuery) -> Result<Vec<Trade>> {
+        let frame = self.scan_trades(query)?.collect().map_err(polars_error)?;
+        let mut trades = frame_to_trades(&frame)?;
+        trades.sort_by(|a, b| {
+            (&a.exchange.0, a.symbol.to_standard_notation(), a.timestamp, &a.trade_id).cmp(&(
+                &b.exchange.0,
+                b.symbol.to_standard_notation(),
+                b.timestamp,
+                &b.trade_id,
+            ))
+        });
+        Ok(trades)
+    }
+
+    /// 파티션을 골라 스캔하고 시간 조건을 붙임
+    fn scan(&self, dataset: Dataset, query: &ArchiveQuery, empty: DataFrame) -> Result<LazyFrame> {
+        let files = self.partition_files(dataset, query)?;
+        let frame = if files.is_empty() {
+            empty.lazy()
+        } else {
+            let files: Arc<[PathBuf]> = files.into();
+            match self.format {
+                ArchiveFormat::Parquet => {
+                    let args = ScanArgsParquet {
+                        use_statistics: true,
+                        ..Default::default()
+                    };
+                    LazyFrame::scan_parquet_files(files, args)
+                }
+                ArchiveFormat::Ipc => LazyFrame::scan_ipc_files(files, ScanArgsIpc::default()),
+            }
+            .map_err(polars_error)?
+        };
+        Ok(match query.time_predicate() {
+            Some(predicate) => frame.filter(predicate),
+            None => frame,
+        })
+    }
+
+    /// 조건에 맞는 파티션 파일 목록 (디렉터리 이름만으로 가지치기)
+    fn partition_files(&self, dataset: Dataset, query: &ArchiveQuery) -> Result<Vec<PathBuf>> {
+        let wanted = |level: &str| -> Option<String> {
+            match level {
+                "exchange" => query.exchange.as_ref().map(|exchange| exchange.0.clone()),
+                "symbol" => query.symbol.as_ref().map(symbol_directory),
+                "timeframe" => query.timeframe.as_ref().map(Timeframe::to_code),
+                _ => None,
+            }
+        };
+
+        let mut directories = vec![self.root.join(dataset.directory())];
+        for level in dataset.levels() {
+            let wanted = wanted(level);
+            let mut next = Vec::new();
+            for directory in &directories {
+                for (value, path) in partition_directories(directory, level)? {
+                    if wanted.as_ref().is_none_or(|wanted| *wanted == value) {
+                        next.push(path);
+                    }
+                }
+            }
+            directories = next;
+        }
+
+        let mut files = Vec::new();
+        for directory in &directories {
+            for (value, path) in partition_directories(directory, "date")? {
+                let date = match NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+                    Ok(date) => date,
+                    Err(_) => continue,
+                };
+                let file = path.join(self.format.file_name());
+                if query.matches_date(date) && file.exists() {
+                    files.push(file);
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// 파티션 파일 경로
+    fn partition_file(&self, dataset: Dataset, values: &[String], date: NaiveDate) -> PathBuf {
+        let mut path = self.root.join(dataset.directory());
+        for (level, value) in dataset.levels().iter().zip(values) {
+            path.push(format!("{}={}", level, value));
+        }
+        path.push(format!("date={}", date.format("%Y-%m-%d")));
+        path.push(self.format.file_name());
+        path
+    }
+
+    /// 파일 하나를 즉시 읽음
+    fn read_file(&self, path: &Path) -> Result<DataFrame> {
+        let file = File::open(path)?;
+        match self.format {
+            ArchiveFormat::Parquet => ParquetReader::new(file).finish(),
+            ArchiveFormat::Ipc => IpcReader::new(file).finish(),
+        }
+        .map_err(polars_error)
+    }
+
+    /// 임시 파일에 쓰고 이름을 바꿔 교체 (중간에 멈춰도 이전 파일이 남음)
+    fn write_file(&self, path: &Path, frame: &mut DataFrame) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary = path.with_extension("tmp");
+        let mut file = File::create(&temporary)?;
+        match self.format {
+            ArchiveFormat::Parquet => ParquetWriter::new(&mut file)
+                .with_statistics(true)
+                .with_row_group_size(Some(ROW_GROUP_SIZE))
+                .finish(frame)
+                .map(|_| ()),
+            ArchiveFormat::Ipc => IpcWriter::new(&mut file).finish(frame),
+        }
+        .map_err(polars_error)?;
+        file.sync_all()?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    }
+}
+
+/// 심볼 디렉터리 이름 (`BTC/USDT` → `BTC-USDT`)
+fn symbol_directory(symbol: &SymbolPair) -> String {
+    symbol.to_exchange_format(Some("-"))
+}
+
+/// `<단계>=<값>` 형식의 하위 디렉터리 목록 (없으면 빈 목록)
+fn partition_directories(directory: &Path, level: &str) -> Result<Vec<(String, PathBuf)>> {
+    if !directory.is_dir() {
+        return Ok(Vec::new());
+    }
+    let prefix = format!("{}=", level);
+    let mut partitions = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(value) = entry.file_name().to_str().and_then(|name| name.strip_prefix(&prefix)) {
+            partitions.push((value.to_string(), entry.path()));
+        }
+    }
+    Ok(partitions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use crate::shared::types::{Decimal, OrderSide};
+
+    fn candle(symbol: &SymbolPair, start: DateTime<Utc>, minute: i64, close: Decimal) -> Candle {
+        Candle::new(
+            symbol.clone(),
+            start + Duration::minutes(minute),
+            dec!(42000.1),
+            dec!(42100.25),
+            dec!(41900),
+            close,
+            dec!(1.5),
+            ExchangeId::new("binance"),
+            Timeframe::Minute1,
+            Some(dec!(63000.15)),
+            true,
+        )
+    }
+
+    fn archive_round_trip(format: ArchiveFormat) {
+        let root = tempfile::tempdir().unwrap();
+        let archive = MarketDataArchive::new(root.path(), format);
+        let btc = SymbolPair::new("BTC", "USDT");
+        let eth = SymbolPair::new("ETH", "USDT");
+        // 자정을 걸쳐 이틀치 파티션에 나뉘어 기록됨
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 23, 0, 0).unwrap();
+
+        let mut candles: Vec<_> = (0..120).map(|minute| candle(&btc, start, minute, dec!(42050.5))).collect();
+        candles.extend((0..10).map(|minute| candle(&eth, start, minute, dec!(2300.05))));
+        assert_eq!(archive.write_candles(&candles).unwrap().len(), 3);
+        // 같은 시작 시각으로 다시 쓰면 대체
+        archive.write_candles(&[candle(&btc, start, 119, dec!(43000))]).unwrap();
+
+        let query = ArchiveQuery::all()
+            .with_exchange(ExchangeId::new("binance"))
+            .with_symbol(btc.clone())
+            .with_timeframe(Timeframe::Minute1)
+            .with_range(start + Duration::minutes(30), start + Duration::minutes(120));
+        let read = archive.read_candles(&query).unwrap();
+        assert_eq!(read.len(), 90);
+        assert_eq!(read[0], candles[30]);
+        assert_eq!(read.last().unwrap().close, dec!(43000));
+
+        // 연구용 LazyFrame 스캔
+        let summary = archive
+            .scan_candles(&ArchiveQuery::all().with_range(start, start + Duration::minutes(5)))
+            .unwrap()
+            .group_by([col("symbol")])
+            .agg([col("close").count().alias("rows")])
+            .collect()
+            .unwrap();
+        assert_eq!(summary.height(), 2);
+
+        let trade = Trade::new(ExchangeId::new("upbit"), btc.clone(), "t-1", dec!(42000.5), dec!(0.01), OrderSide::Sell, start);
+        archive.write_trades(&[trade.clone(), trade.clone()]).unwrap();
+        let trades = archive.read_trades(&ArchiveQuery::all().with_symbol(btc)).unwrap();
+        assert_eq!(trades, vec![trade]);
+
+        assert!(archive.read_candles(&ArchiveQuery::all().with_symbol(SymbolPair::new("SOL", "USDT"))).unwrap().is_empty());
+    }
+
+    fn high_precision_round_trip(format: ArchiveFormat) {
+        let root = tempfile::tempdir().unwrap();
+        let archive = MarketDataArchive::new(root.path(), format);
+        let symbol = SymbolPair::new("SHIB", "KRW");
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+
+        // f64로는 표현할 수 없는 정밀도와 범위의 값
+        let mut candle = candle(&symbol, start, 0, dec!(12345678.123456789012345678));
+        candle.low = dec!(0.000000000000000000000000001);
+        candle.high = dec!(79228162514264337593543950335);
+        candle.volume = dec!(1.100000000000000000000000001);
+        candle.quote_volume = Some(dec!(-0.1234567890123456789012345678));
+        archive.write_candles(std::slice::from_ref(&candle)).unwrap();
+
+        let read = archive.read_candles(&ArchiveQuery::all().with_symbol(symbol.clone())).unwrap();
+        assert_eq!(read, vec![candle.clone()]);
+        assert_eq!(read[0].close.to_string(), "12345678.123456789012345678");
+        assert_eq!(read[0].low.to_string(), candle.low.to_string());
+        assert_eq!(read[0].quote_volume.unwrap().to_string(), "-0.1234567890123456789012345678");
+
+        let trade = Trade::new(
+            ExchangeId::new("upbit"),
+            symbol.clone(),
+            "t-precise",
+            dec!(0.00001234567890123456789),
+            dec!(98765432109876543210.5),
+            OrderSide::Buy,
+            start,
+        );
+        archive.write_trades(std::slice::from_ref(&trade)).unwrap();
+        let trades = archive.read_trades(&ArchiveQuery::all().with_symbol(symbol)).unwrap();
+        assert_eq!(trades, vec![trade.clone()]);
+        assert_eq!(trades[0].price.to_string(), trade.price.to_string());
+        assert_eq!(trades[0].quantity.to_string(), "98765432109876543210.5");
+    }
+
+    fn microsecond_rewrite(format: ArchiveFormat) {
+        let root = tempfile::tempdir().unwrap();
+        let archive = MarketDataArchive::new(root.path(), format);
+        let symbol = SymbolPair::new("BTC", "USDT");
+        let executed_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap() + Duration::microseconds(123_456);
+
+        // 마이크로초 시각의 체결을 다시 쓰면 행이 늘지 않고 대체됨
+        let first = Trade::new(ExchangeId::new("binance"), symbol.clone(), "t-us", dec!(60000), dec!(0.1), OrderSide::Buy, executed_at);
+        archive.write_trades(std::slice::from_ref(&first)).unwrap();
+        let mut corrected = first.clone();
+        corrected.quantity = dec!(0.2);
+        archive.write_trades(std::slice::from_ref(&corrected)).unwrap();
+
+        let trades = archive.read_trades(&ArchiveQuery::all().with_symbol(symbol.clone())).unwrap();
+        assert_eq!(trades, vec![corrected]);
+        assert_eq!(trades[0].timestamp, executed_at);
+
+        // 캔들 시각도 마이크로초까지 유지하고, 시간 범위 경계도 마이크로초 단위로 적용
+        let candle = candle(&symbol, executed_at, 0, dec!(60000));
+        archive.write_candles(std::slice::from_ref(&candle)).unwrap();
+        let read = archive
+            .read_candles(&ArchiveQuery::all().with_range(executed_at, executed_at + Duration::microseconds(1)))
+            .unwrap();
+        assert_eq!(read, vec![candle]);
+        let after = ArchiveQuery::all().with_range(executed_at + Duration::microseconds(1), executed_at + Duration::seconds(1));
+        assert!(archive.read_candles(&after).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parquet_archive_rewrites_microsecond_trade() {
+        microsecond_rewrite(ArchiveFormat::Parquet);
+    }
+
+    #[test]
+    fn test_ipc_archive_rewrites_microsecond_trade() {
+        microsecond_rewrite(ArchiveFormat::Ipc);
+    }
+
+    #[test]
+    fn test_parquet_archive_high_precision_round_trip() {
+        high_precision_round_trip(ArchiveFormat::Parquet);
+    }
+
+    #[test]
+    fn test_ipc_archive_high_precision_round_trip() {
+        high_precision_round_trip(ArchiveFormat::Ipc);
+    }
+
+    #[test]
+    fn test_parquet_archive_round_trip() {
+        archive_round_trip(ArchiveFormat::Parquet);
+    }
+
+    #[test]
+    fn test_ipc_archive_round_trip() {
+        archive_round_trip(ArchiveFormat::Ipc);
+    }
+}
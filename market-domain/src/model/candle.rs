@@ -5,7 +5,8 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use crate::shared::types::{SymbolPair, ExchangeId, Timeframe};
+use crate::shared::id::new_id_at;
+use crate::shared::types::{Decimal, Instrument, SymbolPair, ExchangeId, Timeframe};
 
 /// OHLCV 캔들스틱 데이터를 표현하는 도메인 모델
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -20,19 +21,19 @@ pub struct Candle {
     pub timestamp: DateTime<Utc>,
     
     /// 시가 (period의 첫 거래 가격)
-    pub open: f64,
+    pub open: Decimal,
     
     /// 고가 (period 내 최고 가격)
-    pub high: f64,
+    pub high: Decimal,
     
     /// 저가 (period 내 최저 가격)
-    pub low: f64,
+    pub low: Decimal,
     
     /// 종가 (period의 마지막 거래 가격)
-    pub close: f64,
+    pub close: Decimal,
     
     /// 거래량 (period 내 거래된 총량)
-    pub volume: f64,
+    pub volume: Decimal,
     
     /// 데이터 소스(거래소)
     pub exchange: ExchangeId,
@@ -41,29 +42,34 @@ pub struct Candle {
     pub timeframe: Timeframe,
     
     /// 거래대금 (거래량 * 평균가격)
-    pub quote_volume: Option<f64>,
+    pub quote_volume: Option<Decimal>,
     
     /// 캔들 완성 여부 (false일 경우 현재 진행 중인 캔들)
     pub is_complete: bool,
+    
+    /// 거래 상품 (파생상품 캔들의 계약 정보, 현물은 생략 가능)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<Instrument>,
 }
 
 impl Candle {
     /// 새로운 캔들 생성
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         symbol: SymbolPair,
         timestamp: DateTime<Utc>,
-        open: f64,
-        high: f64,
-        low: f64, 
-        close: f64,
-        volume: f64,
+        open: Decimal,
+        high: Decimal,
+        low: Decimal,
+        close: Decimal,
+        volume: Decimal,
         exchange: ExchangeId,
         timeframe: Timeframe,
-        quote_volume: Option<f64>,
+        quote_volume: Option<Decimal>,
         is_complete: bool,
     ) -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id: new_id_at(timestamp),
             symbol,
             timestamp,
             open,
@@ -75,16 +81,24 @@ impl Candle {
             timeframe,
             quote_volume,
             is_complete,
+            instrument: None,
         }
     }
     
+    /// 거래 상품 지정
+    pub fn with_instrument(mut self, instrument: Instrument) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+    
     /// 가격 변화(%) 계산
-    pub fn price_change_percent(&self) -> f64 {
-        if self.open == 0.0 {
-            return 0.0;
-        }
-        
-        ((self.close - self.open) / self.open) * 100.0
+    ///
+    /// 시가가 0이거나 오버플로가 발생하면 `None`을 반환합니다.
+    pub fn price_change_percent(&self) -> Option<Decimal> {
+        self.close
+            .checked_sub(self.open)?
+            .checked_div(self.open)?
+            .checked_mul(Decimal::ONE_HUNDRED)
     }
     
     /// 캔들이 상승 캔들인지 확인
@@ -98,17 +112,17 @@ impl Candle {
     }
     
     /// 캔들 가격 범위 계산 (고가 - 저가)
-    pub fn range(&self) -> f64 {
+    pub fn range(&self) -> Decimal {
         self.high - self.low
     }
     
     /// 캔들 몸통 크기 계산 (종가 - 시가의 절대값)
-    pub fn body_size(&self) -> f64 {
+    pub fn body_size(&self) -> Decimal {
         (self.close - self.open).abs()
     }
     
     /// 위 그림자 크기 계산
-    pub fn upper_shadow(&self) -> f64 {
+    pub fn upper_shadow(&self) -> Decimal {
         if self.is_bullish() {
             self.high - self.close
         } else {
@@ -117,7 +131,7 @@ impl Candle {
     }
     
     /// 아래 그림자 크기 계산
-    pub fn lower_shadow(&self) -> f64 {
+    pub fn lower_shadow(&self) -> Decimal {
         if self.is_bullish() {
             self.open - self.low
         } else {
@@ -126,19 +140,12 @@ impl Candle {
     }
     
     /// 캔들 종료 시간 계산
+    ///
+    /// 시작 시간에 타임프레임 하나를 더한 시각이며, 월봉은 달력 기준으로 계산합니다.
     pub fn end_time(&self) -> DateTime<Utc> {
-        match self.timeframe {
-            Timeframe::Minute1 => self.timestamp + chrono::Duration::minutes(1),
-            Timeframe::Minute5 => self.timestamp + chrono::Duration::minutes(5),
-            Timeframe::Minute15 => self.timestamp + chrono::Duration::minutes(15),
-            Timeframe::Minute30 => self.timestamp + chrono::Duration::minutes(30),
-            Timeframe::Hour1 => self.timestamp + chrono::Duration::hours(1),
-            Timeframe::Hour4 => self.timestamp + chrono::Duration::hours(4),
-            Timeframe::Hour12 => self.timestamp + chrono::Duration::hours(12),
-            Timeframe::Day1 => self.timestamp + chrono::Duration::days(1),
-            Timeframe::Week1 => self.timestamp + chrono::Duration::weeks(1),
-            Timeframe::Month1 => self.timestamp + chrono::Duration::days(30), // 근사치
-        }
+        self.timeframe
+            .add_intervals(self.timestamp, 1)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
     }
 }
 
@@ -146,19 +153,20 @@ impl Candle {
 mod tests {
     use super::*;
     use chrono::Utc;
+    use rust_decimal_macros::dec;
     
     fn create_sample_candle() -> Candle {
         Candle::new(
             SymbolPair::new("BTC", "USDT"),
             Utc::now(),
-            100.0,
-            110.0,
-            90.0,
-            105.0,
-            10.0,
+            dec!(100),
+            dec!(110),
+            dec!(90),
+            dec!(105),
+            dec!(10),
             ExchangeId::new("binance"),
             Timeframe::Hour1,
-            Some(1000.0),
+            Some(dec!(1000)),
             true,
         )
     }
@@ -167,30 +175,58 @@ mod tests {
     fn test_candle_properties() {
         let candle = create_sample_candle();
         
-        assert_eq!(candle.price_change_percent(), 5.0);
+        assert_eq!(candle.price_change_percent(), Some(dec!(5)));
         assert!(candle.is_bullish());
         assert!(!candle.is_bearish());
-        assert_eq!(candle.range(), 20.0);
-        assert_eq!(candle.body_size(), 5.0);
-        assert_eq!(candle.upper_shadow(), 5.0);
-        assert_eq!(candle.lower_shadow(), 10.0);
+        assert_eq!(candle.range(), dec!(20));
+        assert_eq!(candle.body_size(), dec!(5));
+        assert_eq!(candle.upper_shadow(), dec!(5));
+        assert_eq!(candle.lower_shadow(), dec!(10));
     }
     
     #[test]
     fn test_bearish_candle() {
         let mut candle = create_sample_candle();
-        candle.open = 110.0;
-        candle.close = 90.0;
+        candle.open = dec!(110);
+        candle.close = dec!(90);
         
-        let price_change = candle.price_change_percent();
-        assert!(price_change > -18.19 && price_change < -18.18, 
+        let price_change = candle.price_change_percent().unwrap();
+        assert!(price_change > dec!(-18.19) && price_change < dec!(-18.18), 
                "가격 변화율: {}, 기대 범위: -18.19 ~ -18.18", price_change);
         
         assert!(!candle.is_bullish());
         assert!(candle.is_bearish());
-        assert_eq!(candle.body_size(), 20.0);
-        assert_eq!(candle.upper_shadow(), 0.0);
-        assert_eq!(candle.lower_shadow(), 0.0);
+        assert_eq!(candle.body_size(), dec!(20));
+        assert_eq!(candle.upper_shadow(), dec!(0));
+        assert_eq!(candle.lower_shadow(), dec!(0));
+    }
+    
+    #[test]
+    fn test_decimal_prices_are_exact() {
+        let mut candle = create_sample_candle();
+        candle.open = dec!(0.1);
+        candle.high = dec!(0.3);
+        candle.low = dec!(0.1);
+        candle.close = dec!(0.3);
+        
+        // f64에서는 0.3 - 0.1 != 0.2
+        assert_eq!(candle.range(), dec!(0.2));
+        assert_eq!(candle.body_size(), dec!(0.2));
+        
+        let json = serde_json::to_value(&candle).unwrap();
+        assert_eq!(json["close"], "0.3");
+    }
+    
+    #[test]
+    fn test_price_change_percent_without_valid_open() {
+        let mut candle = create_sample_candle();
+        candle.open = Decimal::ZERO;
+        assert_eq!(candle.price_change_percent(), None);
+        
+        // 변화율 × 100이 표현 범위를 넘으면 오버플로 대신 None
+        candle.open = dec!(0.0000000000000000000000000001);
+        candle.close = Decimal::MAX;
+        assert_eq!(candle.price_change_percent(), None);
     }
     
     #[test]
@@ -199,18 +235,43 @@ mod tests {
         let candle = Candle::new(
             SymbolPair::new("BTC", "USDT"),
             now,
-            100.0,
-            110.0,
-            90.0,
-            105.0,
-            10.0,
+            dec!(100),
+            dec!(110),
+            dec!(90),
+            dec!(105),
+            dec!(10),
             ExchangeId::new("binance"),
             Timeframe::Hour1,
-            Some(1000.0),
+            Some(dec!(1000)),
             true,
         );
         
         let expected_end = now + chrono::Duration::hours(1);
         assert_eq!(candle.end_time().timestamp(), expected_end.timestamp());
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_monthly_end_time() {
+        let mut candle = create_sample_candle();
+        candle.timeframe = Timeframe::Month1;
+        candle.timestamp = DateTime::parse_from_rfc3339("2024-02-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        
+        // 30일 근사가 아닌 실제 다음 달 1일
+        let expected_end = DateTime::parse_from_rfc3339("2024-03-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(candle.end_time(), expected_end);
+    }
+    
+    #[test]
+    fn test_candle_instrument() {
+        use crate::shared::types::ContractMargin;
+        
+        let candle = create_sample_candle();
+        let json = serde_json::to_value(&candle).unwrap();
+        assert!(json.get("instrument").is_none());
+        
+        let perpetual = Instrument::perpetual(SymbolPair::new("BTC", "USDT"), ContractMargin::Linear);
+        let candle = candle.with_instrument(perpetual.clone());
+        let parsed: Candle = serde_json::from_value(serde_json::to_value(&candle).unwrap()).unwrap();
+        assert_eq!(parsed.instrument, Some(perpetual));
+    }
+}
@@ -0,0 +1,24 @@
+//! 시장 데이터 종류 정의
+//!
+//! 이 모듈은 조회·수집 대상이 되는 시장 데이터의 종류를 정의합니다.
+
+use serde::{Deserialize, Serialize};
+
+/// 시장 데이터 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketDataType {
+    #[serde(rename = "candle")]
+    Candle,
+    #[serde(rename = "tick")]
+    Tick,
+    #[serde(rename = "order_book")]
+    OrderBook,
+    #[serde(rename = "trade")]
+    Trade,
+    #[serde(rename = "funding_rate")]
+    FundingRate,
+    #[serde(rename = "open_interest")]
+    OpenInterest,
+    #[serde(rename = "liquidation")]
+    Liquidation,
+}
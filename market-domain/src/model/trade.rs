@@ -0,0 +1,87 @@
+//! 체결 모델 정의
+//!
+//! 이 모듈은 거래소에서 공개적으로 발생한 개별 체결(시장 체결 내역)을 모델링합니다.
+
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::shared::id::new_id_at;
+use crate::shared::types::{Decimal, ExchangeId, OrderSide, SymbolPair};
+
+/// 시장 체결 내역을 표현하는 도메인 모델
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trade {
+    /// 체결 고유 식별자
+    pub id: Uuid,
+
+    /// 데이터 소스(거래소)
+    pub exchange: ExchangeId,
+
+    /// 해당 심볼(거래 쌍)
+    pub symbol: SymbolPair,
+
+    /// 거래소가 부여한 체결 ID
+    pub trade_id: String,
+
+    /// 체결 가격
+    pub price: Decimal,
+
+    /// 체결 수량
+    pub quantity: Decimal,
+
+    /// 테이커 방향
+    pub side: OrderSide,
+
+    /// 체결 시간
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Trade {
+    /// 새로운 체결 생성
+    pub fn new(
+        exchange: ExchangeId,
+        symbol: SymbolPair,
+        trade_id: impl Into<String>,
+        price: Decimal,
+        quantity: Decimal,
+        side: OrderSide,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: new_id_at(timestamp),
+            exchange,
+            symbol,
+            trade_id: trade_id.into(),
+            price,
+            quantity,
+            side,
+            timestamp,
+        }
+    }
+
+    /// 체결 대금 (가격 * 수량)
+    pub fn notional(&self) -> Decimal {
+        self.price * self.quantity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_trade_notional() {
+        let trade = Trade::new(
+            ExchangeId::new("binance"),
+            SymbolPair::new("BTC", "USDT"),
+            "12345",
+            dec!(42000.5),
+            dec!(0.02),
+            OrderSide::Buy,
+            Utc::now(),
+        );
+        assert_eq!(trade.notional(), dec!(840.01));
+        assert_eq!(trade.trade_id, "12345");
+    }
+}
@@ -0,0 +1,277 @@
+//! 시장 데이터 조회 필터와 커서 페이지네이션
+//!
+//! `MarketDataFilter`는 시장 데이터 조회 조건의 유일한 정의이며, `market_data_core`도 이 타입을
+//! 그대로 다시 내보냅니다.
+//!
+//! 캔들 목록은 (시작 시각, 거래소, 심볼, 타임프레임) 순서로 정렬되며, 커서는 마지막으로 받은
+//! 캔들의 키입니다. 다음 페이지는 커서 키 "다음"부터 읽으므로 페이지 사이에 캔들이 추가되거나
+//! 갱신되어도 항목이 빠지거나 중복되지 않습니다.
+
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use crate::model::{Candle, MarketDataType};
+use crate::shared::error::CoreError;
+use crate::shared::types::{ExchangeId, Result, SymbolPair, Timeframe};
+
+/// 페이지 크기를 지정하지 않았을 때의 기본값
+pub const DEFAULT_PAGE_SIZE: u32 = 500;
+
+/// 한 페이지의 최대 크기
+pub const MAX_PAGE_SIZE: u32 = 5_000;
+
+/// 시장 데이터 쿼리 필터
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketDataFilter {
+    /// 심볼 필터
+    pub symbols: Option<Vec<SymbolPair>>,
+    /// 거래소 필터
+    pub exchanges: Option<Vec<ExchangeId>>,
+    /// 시작 시간 필터 (포함)
+    pub start_time: Option<DateTime<Utc>>,
+    /// 종료 시간 필터 (제외)
+    pub end_time: Option<DateTime<Utc>>,
+    /// 타임프레임 필터
+    pub timeframe: Option<Timeframe>,
+    /// 데이터 타입 필터 (캔들 저장소는 캔들 외의 타입이면 빈 결과)
+    pub data_type: Option<MarketDataType>,
+    /// 페이지당 최대 결과 수량 (기본 500, 최대 5000)
+    pub limit: Option<u32>,
+    /// 정렬 방향 (기본 오름차순)
+    pub ascending: Option<bool>,
+}
+
+impl MarketDataFilter {
+    /// 조건 없는 필터
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 심볼 조건 추가
+    pub fn with_symbol(mut self, symbol: SymbolPair) -> Self {
+        self.symbols.get_or_insert_with(Vec::new).push(symbol);
+        self
+    }
+
+    /// 거래소 조건 추가
+    pub fn with_exchange(mut self, exchange: ExchangeId) -> Self {
+        self.exchanges.get_or_insert_with(Vec::new).push(exchange);
+        self
+    }
+
+    /// 시간 범위 지정 (`start` 이상 `end` 미만)
+    pub fn with_range(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.start_time = Some(start);
+        self.end_time = Some(end);
+        self
+    }
+
+    /// 타임프레임 지정
+    pub fn with_timeframe(mut self, timeframe: Timeframe) -> Self {
+        self.timeframe = Some(timeframe);
+        self
+    }
+
+    /// 데이터 타입 지정
+    pub fn with_data_type(mut self, data_type: MarketDataType) -> Self {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    /// 페이지 크기 지정
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// 정렬 방향 지정
+    pub fn with_ascending(mut self, ascending: bool) -> Self {
+        self.ascending = Some(ascending);
+        self
+    }
+
+    /// 오름차순 여부
+    pub fn is_ascending(&self) -> bool {
+        self.ascending.unwrap_or(true)
+    }
+
+    /// 실제 페이지 크기 (1 ~ `MAX_PAGE_SIZE`)
+    pub fn page_size(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE) as usize
+    }
+
+    /// 캔들을 조회하는 필터인지 여부 (데이터 타입 조건이 없거나 캔들)
+    pub fn selects_candles(&self) -> bool {
+        self.data_type.is_none_or(|data_type| data_type == MarketDataType::Candle)
+    }
+
+    /// 캔들이 조건을 만족하는지 여부
+    pub fn matches(&self, candle: &Candle) -> bool {
+        self.selects_candles()
+            && self.symbols.as_ref().is_none_or(|symbols| symbols.contains(&candle.symbol))
+            && self.exchanges.as_ref().is_none_or(|exchanges| exchanges.contains(&candle.exchange))
+            && self.timeframe.is_none_or(|timeframe| timeframe == candle.timeframe)
+            && self.start_time.is_none_or(|start| candle.timestamp >= start)
+            && self.end_time.is_none_or(|end| candle.timestamp < end)
+    }
+
+    /// 키가 커서보다 뒤(정렬 방향 기준)에 있는지 여부
+    pub fn is_after(&self, key: &CandleKey, cursor: &CandleCursor) -> bool {
+        if self.is_ascending() {
+            *key > cursor.0
+        } else {
+            *key < cursor.0
+        }
+    }
+}
+
+/// 캔들 식별 키 (거래소, 심볼, 타임프레임, 시작 시각)
+///
+/// 필드 순서가 곧 페이지 정렬 순서입니다. 심볼은 표준 표기, 타임프레임은 코드 문자열로 비교합니다.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CandleKey {
+    /// 캔들 시작 시각
+    pub timestamp: DateTime<Utc>,
+    /// 거래소
+    pub exchange: String,
+    /// 심볼 (표준 표기)
+    pub symbol: String,
+    /// 타임프레임 코드
+    pub timeframe: String,
+}
+
+impl CandleKey {
+    /// 구성 요소로 생성
+    pub fn new(exchange: &ExchangeId, symbol: &SymbolPair, timeframe: &Timeframe, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            timestamp,
+            exchange: exchange.0.clone(),
+            symbol: symbol.to_standard_notation(),
+            timeframe: timeframe.to_code(),
+        }
+    }
+
+    /// 캔들의 키
+    pub fn of(candle: &Candle) -> Self {
+        Self::new(&candle.exchange, &candle.symbol, &candle.timeframe, candle.timestamp)
+    }
+}
+
+/// 페이지 커서 (마지막으로 받은 캔들의 키)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CandleCursor(pub CandleKey);
+
+/// 커서 문자열 안의 구분자
+const CURSOR_SEPARATOR: char = '\u{1f}';
+
+impl CandleCursor {
+    /// 캔들 다음부터 읽는 커서
+    pub fn after(candle: &Candle) -> Self {
+        Self(CandleKey::of(candle))
+    }
+
+    /// API로 주고받는 불투명 문자열로 인코딩
+    pub fn encode(&self) -> String {
+        let raw = format!(
+            "{}{sep}{}{sep}{}{sep}{}",
+            self.0.timestamp.timestamp_micros(),
+            self.0.exchange,
+            self.0.symbol,
+            self.0.timeframe,
+            sep = CURSOR_SEPARATOR
+        );
+        raw.bytes().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// 커서 문자열 디코딩
+    pub fn decode(encoded: &str) -> Result<Self> {
+        let invalid = || CoreError::Validation(format!("잘못된 페이지 커서: {}", encoded));
+        if !encoded.len().is_multiple_of(2) || !encoded.is_ascii() {
+            return Err(invalid());
+        }
+        let bytes = (0..encoded.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&encoded[i..i + 2], 16))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+        let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+        let mut parts = raw.split(CURSOR_SEPARATOR);
+        match (parts.next(), parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(micros), Some(exchange), Some(symbol), Some(timeframe), None) => {
+                let timestamp = micros
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(DateTime::from_timestamp_micros)
+                    .ok_or_else(invalid)?;
+                Ok(Self(CandleKey {
+                    timestamp,
+                    exchange: exchange.to_string(),
+                    symbol: symbol.to_string(),
+                    timeframe: timeframe.to_string(),
+                }))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// 캔들 조회 결과 한 페이지
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandlePage {
+    /// 이 페이지의 캔들
+    pub items: Vec<Candle>,
+    /// 다음 페이지 커서 (마지막 페이지면 None)
+    pub next_cursor: Option<CandleCursor>,
+}
+
+impl CandlePage {
+    /// 정렬·필터가 끝난 후보에서 페이지를 만듦 (`page_size`보다 하나 더 받아 다음 페이지 유무를 판단)
+    pub fn from_candidates(mut candidates: Vec<Candle>, page_size: usize) -> Self {
+        let has_more = candidates.len() > page_size;
+        candidates.truncate(page_size);
+        let next_cursor = if has_more {
+            candidates.last().map(CandleCursor::after)
+        } else {
+            None
+        };
+        Self {
+            items: candidates,
+            next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_cursor_round_trip_and_ordering() {
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 1, 0).unwrap();
+        let key = CandleKey::new(&ExchangeId::new("binance"), &SymbolPair::new("BTC", "USDT"), &Timeframe::Minute1, timestamp);
+        let cursor = CandleCursor(key.clone());
+        assert_eq!(CandleCursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert!(matches!(CandleCursor::decode("zz"), Err(CoreError::Validation(_))));
+
+        // 같은 시각이면 거래소·심볼 순으로 이어짐
+        let next = CandleKey::new(&ExchangeId::new("upbit"), &SymbolPair::new("BTC", "USDT"), &Timeframe::Minute1, timestamp);
+        let filter = MarketDataFilter::new();
+        assert!(filter.is_after(&next, &cursor));
+        assert!(!filter.clone().with_ascending(false).is_after(&next, &cursor));
+        assert_eq!(filter.with_limit(0).page_size(), 1);
+    }
+
+    #[test]
+    fn test_data_type_selects_candles() {
+        assert!(MarketDataFilter::new().selects_candles());
+        assert!(MarketDataFilter::new().with_data_type(MarketDataType::Candle).selects_candles());
+        assert!(!MarketDataFilter::new().with_data_type(MarketDataType::Trade).selects_candles());
+
+        // 이전 형식(데이터 타입 없음)의 직렬화 필터도 읽음
+        let filter: MarketDataFilter = serde_json::from_str(r#"{"limit": 10}"#).unwrap();
+        assert_eq!(filter, MarketDataFilter::new().with_limit(10));
+        let filter: MarketDataFilter = serde_json::from_str(r#"{"data_type": "tick"}"#).unwrap();
+        assert_eq!(filter.data_type, Some(MarketDataType::Tick));
+    }
+}
@@ -0,0 +1,116 @@
+//! 시장 데이터 리포지토리 인터페이스
+//!
+//! 이 모듈은 시장 데이터 도메인에서 사용하는 리포지토리 인터페이스를 정의합니다.
+//! 캔들은 (거래소, 심볼, 타임프레임, 시작 시각)을 키로 upsert되며,
+//! 진행 중인 캔들(`is_complete = false`)은 같은 키의 새 값으로 계속 대체되다가
+//! 완성된 캔들이 도착하면 확정됩니다. 확정된 캔들은 늦게 도착한 미완성 값으로 되돌아가지 않습니다.
+
+pub mod filter;
+
+pub use filter::{CandleCursor, CandleKey, CandlePage, MarketDataFilter, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+use async_trait::async_trait;
+use crate::model::Candle;
+use crate::shared::types::{ExchangeId, Result, SymbolPair, Timeframe};
+
+/// 같은 키의 캔들을 저장할 때의 처리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleWrite {
+    /// 새 키로 추가
+    Insert,
+    /// 기존 캔들을 대체
+    Replace,
+    /// 기존 완성 캔들을 유지하고 미완성 값은 버림
+    Ignore,
+}
+
+impl CandleWrite {
+    /// 기존 캔들과 새 캔들로 처리 방식을 결정
+    ///
+    /// 미완성 캔들은 언제든 대체되고, 완성 캔들은 완성 캔들(정정값)로만 대체됩니다.
+    pub fn decide(existing: Option<&Candle>, incoming: &Candle) -> Self {
+        match existing {
+            None => CandleWrite::Insert,
+            Some(existing) if !existing.is_complete || incoming.is_complete => CandleWrite::Replace,
+            Some(_) => CandleWrite::Ignore,
+        }
+    }
+}
+
+/// upsert 결과 집계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpsertSummary {
+    /// 새로 추가된 캔들 수
+    pub inserted: usize,
+    /// 대체된 캔들 수
+    pub replaced: usize,
+    /// 완성 캔들이 이미 있어 버려진 미완성 캔들 수
+    pub ignored: usize,
+}
+
+impl UpsertSummary {
+    /// 처리 결과 하나를 더함
+    pub fn record(&mut self, write: CandleWrite) {
+        match write {
+            CandleWrite::Insert => self.inserted += 1,
+            CandleWrite::Replace => self.replaced += 1,
+            CandleWrite::Ignore => self.ignored += 1,
+        }
+    }
+
+    /// 실제로 기록된 캔들 수
+    pub fn written(&self) -> usize {
+        self.inserted + self.replaced
+    }
+}
+
+/// 캔들 리포지토리 인터페이스
+#[async_trait]
+pub trait CandleRepository: Send + Sync {
+    /// 캔들 하나 upsert
+    async fn upsert(&self, candle: &Candle) -> Result<CandleWrite>;
+
+    /// 캔들 벌크 upsert (한 트랜잭션, 배치 안에서 같은 키는 뒤의 값이 우선)
+    async fn upsert_batch(&self, candles: &[Candle]) -> Result<UpsertSummary>;
+
+    /// 키로 조회
+    async fn find(&self, key: &CandleKey) -> Result<Option<Candle>>;
+
+    /// 필터 조건으로 한 페이지 조회 (`cursor`가 있으면 그 다음부터)
+    async fn query(&self, filter: &MarketDataFilter, cursor: Option<&CandleCursor>) -> Result<CandlePage>;
+
+    /// 가장 최근 캔들
+    async fn latest(&self, exchange: &ExchangeId, symbol: &SymbolPair, timeframe: &Timeframe) -> Result<Option<Candle>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_incomplete_candles_are_replaced_until_final() {
+        let partial = Candle::new(
+            SymbolPair::new("BTC", "USDT"),
+            Utc::now(),
+            dec!(100),
+            dec!(101),
+            dec!(99),
+            dec!(100.5),
+            dec!(1),
+            ExchangeId::new("binance"),
+            Timeframe::Minute1,
+            None,
+            false,
+        );
+        let mut finished = partial.clone();
+        finished.is_complete = true;
+
+        assert_eq!(CandleWrite::decide(None, &partial), CandleWrite::Insert);
+        assert_eq!(CandleWrite::decide(Some(&partial), &partial), CandleWrite::Replace);
+        assert_eq!(CandleWrite::decide(Some(&partial), &finished), CandleWrite::Replace);
+        assert_eq!(CandleWrite::decide(Some(&finished), &finished), CandleWrite::Replace);
+        assert_eq!(CandleWrite::decide(Some(&finished), &partial), CandleWrite::Ignore);
+    }
+}
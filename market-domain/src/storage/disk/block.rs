@@ -0,0 +1,510 @@
+//! 열 지향 블록 형식
+//!
+//! 세그먼트 파일은 블록의 연속입니다. 블록은 고정 길이 헤더와 압축된 열 페이로드로 이루어지며,
+//! 헤더에 담긴 시간 범위로 블록 인덱스를 만들고 CRC32로 잘린 쓰기와 손상을 감지합니다.
+//!
+//! 헤더 (리틀 엔디언, 36바이트):
+//! `magic(4) | version(1) | kind(1) | reserved(2) | rows(4) | min_ts(8) | max_ts(8) | payload_len(4) | crc32(4)`
+
+use chrono::{DateTime, TimeZone, Utc};
+use uuid::Uuid;
+
+use super::codec::{BitReader, BitWriter, DecimalDecoder, DecimalEncoder, TimestampDecoder, TimestampEncoder};
+use crate::model::{Candle, Trade};
+use crate::shared::error::CoreError;
+use crate::shared::types::{Decimal, ExchangeId, Instrument, OrderSide, Result, SymbolPair, Timeframe};
+
+/// 블록 헤더 식별자
+pub const BLOCK_MAGIC: [u8; 4] = *b"CLTB";
+
+/// 블록 형식 버전
+pub const BLOCK_VERSION: u8 = 1;
+
+/// 블록 헤더 길이
+pub const HEADER_LEN: usize = 36;
+
+/// 블록에 담긴 시계열 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeriesKind {
+    /// OHLCV 캔들
+    Candles,
+    /// 체결 내역
+    Trades,
+}
+
+impl SeriesKind {
+    /// 디렉터리 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeriesKind::Candles => "candles",
+            SeriesKind::Trades => "trades",
+        }
+    }
+
+    /// 디렉터리 이름에서 복원
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "candles" => Some(SeriesKind::Candles),
+            "trades" => Some(SeriesKind::Trades),
+            _ => None,
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            SeriesKind::Candles => 1,
+            SeriesKind::Trades => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(SeriesKind::Candles),
+            2 => Some(SeriesKind::Trades),
+            _ => None,
+        }
+    }
+}
+
+/// 블록 헤더
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockHeader {
+    /// 시계열 종류
+    pub kind: SeriesKind,
+    /// 행 수
+    pub rows: u32,
+    /// 가장 이른 타임스탬프 (밀리초)
+    pub min_timestamp: i64,
+    /// 가장 늦은 타임스탬프 (밀리초)
+    pub max_timestamp: i64,
+    /// 페이로드 길이
+    pub payload_len: u32,
+    /// 페이로드 CRC32
+    pub checksum: u32,
+}
+
+impl BlockHeader {
+    /// 헤더 직렬화
+    pub fn to_bytes(&self) -> [u8; HEADER_LEN] {
+        let mut bytes = [0u8; HEADER_LEN];
+        bytes[0..4].copy_from_slice(&BLOCK_MAGIC);
+        bytes[4] = BLOCK_VERSION;
+        bytes[5] = self.kind.tag();
+        bytes[8..12].copy_from_slice(&self.rows.to_le_bytes());
+        bytes[12..20].copy_from_slice(&self.min_timestamp.to_le_bytes());
+        bytes[20..28].copy_from_slice(&self.max_timestamp.to_le_bytes());
+        bytes[28..32].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes[32..36].copy_from_slice(&self.checksum.to_le_bytes());
+        bytes
+    }
+
+    /// 헤더 역직렬화
+    pub fn from_bytes(bytes: &[u8; HEADER_LEN]) -> Result<Self> {
+        if bytes[0..4] != BLOCK_MAGIC {
+            return Err(CoreError::Data("블록 헤더 식별자가 맞지 않음".to_string()));
+        }
+        if bytes[4] != BLOCK_VERSION {
+            return Err(CoreError::Data(format!("지원하지 않는 블록 버전: {}", bytes[4])));
+        }
+        let kind = SeriesKind::from_tag(bytes[5])
+            .ok_or_else(|| CoreError::Data(format!("알 수 없는 시계열 종류: {}", bytes[5])))?;
+        Ok(Self {
+            kind,
+            rows: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+            min_timestamp: i64::from_le_bytes(bytes[12..20].try_into().unwrap()),
+            max_timestamp: i64::from_le_bytes(bytes[20..28].try_into().unwrap()),
+            payload_len: u32::from_le_bytes(bytes[28..32].try_into().unwrap()),
+            checksum: u32::from_le_bytes(bytes[32..36].try_into().unwrap()),
+        })
+    }
+
+    /// 페이로드가 헤더의 체크섬과 일치하는지 확인
+    pub fn verify(&self, payload: &[u8]) -> bool {
+        payload.len() == self.payload_len as usize && crc32fast::hash(payload) == self.checksum
+    }
+}
+
+/// 블록 안에서 비교할 행의 키와 시각
+pub trait SeriesRow: Clone {
+    /// 시계열 종류
+    const KIND: SeriesKind;
+
+    /// 타임스탬프 (밀리초)
+    fn timestamp_millis(&self) -> i64;
+
+    /// 같은 행인지 판단하는 키 (캔들은 시작 시각, 체결은 시각과 체결 ID)
+    fn same_row(&self, other: &Self) -> bool;
+}
+
+impl SeriesRow for Candle {
+    const KIND: SeriesKind = SeriesKind::Candles;
+
+    fn timestamp_millis(&self) -> i64 {
+        self.timestamp.timestamp_millis()
+    }
+
+    fn same_row(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp
+    }
+}
+
+impl SeriesRow for Trade {
+    const KIND: SeriesKind = SeriesKind::Trades;
+
+    fn timestamp_millis(&self) -> i64 {
+        self.timestamp.timestamp_millis()
+    }
+
+    fn same_row(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp && self.trade_id == other.trade_id
+    }
+}
+
+/// 블록을 만들고 읽는 열 코덱
+pub trait BlockCodec: SeriesRow + Sized {
+    /// 시각순으로 정렬된 행들을 열 페이로드로 인코딩
+    fn encode_columns(rows: &[Self]) -> Vec<u8>;
+
+    /// 페이로드를 행으로 디코딩 (파티션 키의 거래소·심볼·타임프레임을 채움)
+    fn decode_columns(payload: &[u8], rows: usize, key: &RowContext<'_>) -> Result<Vec<Self>>;
+}
+
+/// 블록에 저장하지 않고 파티션 키에서 복원하는 값
+#[derive(Debug, Clone, Copy)]
+pub struct RowContext<'a> {
+    /// 거래소 식별자
+    pub exchange: &'a ExchangeId,
+    /// 심볼
+    pub symbol: &'a SymbolPair,
+    /// 캔들 타임프레임 (체결은 없음)
+    pub timeframe: Option<&'a Timeframe>,
+}
+
+/// 행들을 하나의 블록(헤더 + 페이로드)으로 인코딩
+pub fn encode_block<R: BlockCodec>(rows: &[R]) -> Vec<u8> {
+    let payload = R::encode_columns(rows);
+    let header = BlockHeader {
+        kind: R::KIND,
+        rows: rows.len() as u32,
+        min_timestamp: rows.iter().map(R::timestamp_millis).min().unwrap_or(0),
+        max_timestamp: rows.iter().map(R::timestamp_millis).max().unwrap_or(0),
+        payload_len: payload.len() as u32,
+        checksum: crc32fast::hash(&payload),
+    };
+    let mut block = Vec::with_capacity(HEADER_LEN + payload.len());
+    block.extend_from_slice(&header.to_bytes());
+    block.extend_from_slice(&payload);
+    block
+}
+
+/// 길이가 붙은 열들을 이어 붙임
+fn join_columns(columns: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(columns.iter().map(|column| column.len() + 4).sum());
+    for column in columns {
+        payload.extend_from_slice(&(column.len() as u32).to_le_bytes());
+        payload.extend_from_slice(&column);
+    }
+    payload
+}
+
+/// 길이가 붙은 열들을 나눔
+fn split_columns(payload: &[u8], expected: usize) -> Result<Vec<&[u8]>> {
+    let mut columns = Vec::with_capacity(expected);
+    let mut rest = payload;
+    while !rest.is_empty() {
+        if rest.len() < 4 {
+            return Err(CoreError::Data("열 길이가 잘림".to_string()));
+        }
+        let len = u32::from_le_bytes(rest[0..4].try_into().unwrap()) as usize;
+        let column = rest
+            .get(4..4 + len)
+            .ok_or_else(|| CoreError::Data("열 데이터가 잘림".to_string()))?;
+        columns.push(column);
+        rest = &rest[4 + len..];
+    }
+    if columns.len() != expected {
+        return Err(CoreError::Data(format!(
+            "열 개수 불일치: 예상 {}, 실제 {}",
+            expected,
+            columns.len()
+        )));
+    }
+    Ok(columns)
+}
+
+/// 십진수 열 인코딩
+fn encode_decimals(values: impl Iterator<Item = Decimal>) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut encoder = DecimalEncoder::new();
+    values.for_each(|value| encoder.encode(&mut writer, value));
+    writer.into_bytes()
+}
+
+/// 십진수 열 디코딩
+fn decode_decimals(column: &[u8], rows: usize) -> Result<Vec<Decimal>> {
+    let mut reader = BitReader::new(column);
+    let mut decoder = DecimalDecoder::new();
+    (0..rows).map(|_| decoder.decode(&mut reader)).collect()
+}
+
+/// 없을 수도 있는 십진수 열 인코딩 (존재 비트 뒤에 있는 값만 차이로 기록)
+fn encode_optional_decimals(values: impl Iterator<Item = Option<Decimal>>) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut encoder = DecimalEncoder::new();
+    for value in values {
+        writer.write_bit(value.is_some());
+        if let Some(value) = value {
+            encoder.encode(&mut writer, value);
+        }
+    }
+    writer.into_bytes()
+}
+
+/// 없을 수도 있는 십진수 열 디코딩
+fn decode_optional_decimals(column: &[u8], rows: usize) -> Result<Vec<Option<Decimal>>> {
+    let mut reader = BitReader::new(column);
+    let mut decoder = DecimalDecoder::new();
+    (0..rows)
+        .map(|_| match reader.read_bit()? {
+            true => decoder.decode(&mut reader).map(Some),
+            false => Ok(None),
+        })
+        .collect()
+}
+
+/// 타임스탬프 열 인코딩
+fn encode_timestamps(values: impl Iterator<Item = i64>) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut encoder = TimestampEncoder::new();
+    values.for_each(|value| encoder.encode(&mut writer, value));
+    writer.into_bytes()
+}
+
+/// 타임스탬프 열 디코딩
+fn decode_timestamps(column: &[u8], rows: usize) -> Result<Vec<DateTime<Utc>>> {
+    let mut reader = BitReader::new(column);
+    let mut decoder = TimestampDecoder::new();
+    (0..rows)
+        .map(|_| {
+            let millis = decoder.decode(&mut reader)?;
+            Utc.timestamp_millis_opt(millis)
+                .single()
+                .ok_or_else(|| CoreError::Data(format!("범위를 벗어난 타임스탬프: {}", millis)))
+        })
+        .collect()
+}
+
+/// 식별자 열 인코딩 (16바이트씩)
+fn encode_ids(values: impl Iterator<Item = Uuid>) -> Vec<u8> {
+    values.flat_map(|id| id.into_bytes()).collect()
+}
+
+/// 식별자 열 디코딩
+fn decode_ids(column: &[u8], rows: usize) -> Result<Vec<Uuid>> {
+    if column.len() != rows * 16 {
+        return Err(CoreError::Data(format!("식별자 열 길이 불일치: {}바이트, {}행", column.len(), rows)));
+    }
+    Ok(column
+        .chunks_exact(16)
+        .map(|bytes| Uuid::from_bytes(bytes.try_into().unwrap()))
+        .collect())
+}
+
+/// 길이가 붙은 바이트 문자열 열 인코딩 (없는 값은 길이 `u32::MAX`)
+fn encode_bytes<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<u8> {
+    let mut column = Vec::new();
+    for value in values {
+        match value {
+            Some(bytes) => {
+                column.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+                column.extend_from_slice(bytes);
+            }
+            None => column.extend_from_slice(&u32::MAX.to_le_bytes()),
+        }
+    }
+    column
+}
+
+/// 길이가 붙은 바이트 문자열 열 디코딩
+fn decode_bytes(mut column: &[u8], rows: usize) -> Result<Vec<Option<&[u8]>>> {
+    let truncated = || CoreError::Data("바이트 문자열 열이 잘림".to_string());
+    let mut values = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let len = u32::from_le_bytes(column.get(0..4).ok_or_else(truncated)?.try_into().unwrap());
+        column = &column[4..];
+        if len == u32::MAX {
+            values.push(None);
+            continue;
+        }
+        let len = len as usize;
+        values.push(Some(column.get(..len).ok_or_else(truncated)?));
+        column = &column[len..];
+    }
+    Ok(values)
+}
+
+impl BlockCodec for Candle {
+    fn encode_columns(rows: &[Self]) -> Vec<u8> {
+        let mut complete = BitWriter::new();
+        for candle in rows {
+            complete.write_bit(candle.is_complete);
+        }
+        // 파생상품 계약 조건은 드물고 구조가 다양하므로 JSON으로 보관
+        let instruments: Vec<Option<Vec<u8>>> = rows
+            .iter()
+            .map(|candle| candle.instrument.as_ref().and_then(|instrument| serde_json::to_vec(instrument).ok()))
+            .collect();
+        join_columns(vec![
+            encode_ids(rows.iter().map(|candle| candle.id)),
+            encode_timestamps(rows.iter().map(SeriesRow::timestamp_millis)),
+            encode_decimals(rows.iter().map(|candle| candle.open)),
+            encode_decimals(rows.iter().map(|candle| candle.high)),
+            encode_decimals(rows.iter().map(|candle| candle.low)),
+            encode_decimals(rows.iter().map(|candle| candle.close)),
+            encode_decimals(rows.iter().map(|candle| candle.volume)),
+            encode_optional_decimals(rows.iter().map(|candle| candle.quote_volume)),
+            complete.into_bytes(),
+            encode_bytes(instruments.iter().map(Option::as_deref)),
+        ])
+    }
+
+    fn decode_columns(payload: &[u8], rows: usize, key: &RowContext<'_>) -> Result<Vec<Self>> {
+        let timeframe = key
+            .timeframe
+            .ok_or_else(|| CoreError::Data("캔들 파티션에 타임프레임이 없음".to_string()))?;
+        let columns = split_columns(payload, 10)?;
+        let ids = decode_ids(columns[0], rows)?;
+        let timestamps = decode_timestamps(columns[1], rows)?;
+        let open = decode_decimals(columns[2], rows)?;
+        let high = decode_decimals(columns[3], rows)?;
+        let low = decode_decimals(columns[4], rows)?;
+        let close = decode_decimals(columns[5], rows)?;
+        let volume = decode_decimals(columns[6], rows)?;
+        let quote_volume = decode_optional_decimals(columns[7], rows)?;
+        let mut complete = BitReader::new(columns[8]);
+        let instruments = decode_bytes(columns[9], rows)?;
+
+        let mut candles = Vec::with_capacity(rows);
+        for i in 0..rows {
+            let instrument = instruments[i]
+                .map(serde_json::from_slice::<Instrument>)
+                .transpose()
+                .map_err(|e| CoreError::Data(format!("잘못된 거래 상품 열: {}", e)))?;
+            candles.push(Candle {
+                id: ids[i],
+                symbol: key.symbol.clone(),
+                timestamp: timestamps[i],
+                open: open[i],
+                high: high[i],
+                low: low[i],
+                close: close[i],
+                volume: volume[i],
+                exchange: key.exchange.clone(),
+                timeframe: *timeframe,
+                quote_volume: quote_volume[i],
+                is_complete: complete.read_bit()?,
+                instrument,
+            });
+        }
+        Ok(candles)
+    }
+}
+
+impl BlockCodec for Trade {
+    fn encode_columns(rows: &[Self]) -> Vec<u8> {
+        let mut sides = BitWriter::new();
+        for trade in rows {
+            sides.write_bit(trade.side == OrderSide::Sell);
+        }
+        join_columns(vec![
+            encode_ids(rows.iter().map(|trade| trade.id)),
+            encode_timestamps(rows.iter().map(SeriesRow::timestamp_millis)),
+            encode_decimals(rows.iter().map(|trade| trade.price)),
+            encode_decimals(rows.iter().map(|trade| trade.quantity)),
+            sides.into_bytes(),
+            encode_bytes(rows.iter().map(|trade| Some(trade.trade_id.as_bytes()))),
+        ])
+    }
+
+    fn decode_columns(payload: &[u8], rows: usize, key: &RowContext<'_>) -> Result<Vec<Self>> {
+        let columns = split_columns(payload, 6)?;
+        let ids = decode_ids(columns[0], rows)?;
+        let timestamps = decode_timestamps(columns[1], rows)?;
+        let prices = decode_decimals(columns[2], rows)?;
+        let quantities = decode_decimals(columns[3], rows)?;
+        let mut sides = BitReader::new(columns[4]);
+        let trade_ids = decode_bytes(columns[5], rows)?;
+
+        let mut trades = Vec::with_capacity(rows);
+        for i in 0..rows {
+            let trade_id = trade_ids[i].ok_or_else(|| CoreError::Data("체결 ID가 없음".to_string()))?;
+            let trade_id = String::from_utf8(trade_id.to_vec())
+                .map_err(|e| CoreError::Data(format!("잘못된 체결 ID: {}", e)))?;
+            trades.push(Trade {
+                id: ids[i],
+                exchange: key.exchange.clone(),
+                symbol: key.symbol.clone(),
+                trade_id,
+                price: prices[i],
+                quantity: quantities[i],
+                side: if sides.read_bit()? { OrderSide::Sell } else { OrderSide::Buy },
+                timestamp: timestamps[i],
+            });
+        }
+        Ok(trades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_candle_block_round_trip() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let exchange = ExchangeId::new("binance");
+        let symbol = SymbolPair::new("BTC", "USDT");
+        let candles: Vec<Candle> = (0..100)
+            .map(|i| {
+                let candle = Candle::new(
+                    symbol.clone(),
+                    start + chrono::Duration::minutes(i),
+                    dec!(42000) + Decimal::from(i),
+                    dec!(42010.5) + Decimal::from(i),
+                    dec!(41990.25),
+                    dec!(12345678.123456789) + Decimal::from(i % 3),
+                    dec!(1.5) * Decimal::from(i),
+                    exchange.clone(),
+                    Timeframe::Minute1,
+                    (i % 2 == 0).then(|| dec!(63000.000001) * Decimal::from(i)),
+                    i != 99,
+                );
+                match i % 10 {
+                    0 => candle.with_instrument(Instrument::spot(symbol.clone())),
+                    _ => candle,
+                }
+            })
+            .collect();
+
+        let block = encode_block(&candles);
+        let header = BlockHeader::from_bytes(block[..HEADER_LEN].try_into().unwrap()).unwrap();
+        assert_eq!(header.kind, SeriesKind::Candles);
+        assert_eq!(header.rows, 100);
+        assert_eq!(header.min_timestamp, start.timestamp_millis());
+        assert!(header.verify(&block[HEADER_LEN..]));
+
+        let timeframe = Timeframe::Minute1;
+        let context = RowContext { exchange: &exchange, symbol: &symbol, timeframe: Some(&timeframe) };
+        let decoded = Candle::decode_columns(&block[HEADER_LEN..], 100, &context).unwrap();
+        // 십진수 가격과 식별자·완성 여부·상품까지 그대로 복원
+        assert_eq!(decoded, candles);
+        assert_eq!(decoded[0].close.to_string(), "12345678.123456789");
+        assert!(decoded[0].instrument.is_some() && decoded[1].instrument.is_none());
+
+        // 페이로드가 한 바이트라도 바뀌면 체크섬으로 감지
+        let mut corrupted = block.clone();
+        corrupted[HEADER_LEN + 5] ^= 0xff;
+        assert!(!header.verify(&corrupted[HEADER_LEN..]));
+    }
+}
@@ -0,0 +1,356 @@
+//! 시계열 열 압축 코덱
+//!
+//! 블록의 각 열을 비트 단위로 압축합니다. 타임스탬프는 delta-of-delta로 (Facebook Gorilla 방식),
+//! 십진수 값은 직전 값과의 가수(mantissa) 차이로 인코딩합니다.
+//! 간격이 일정한 1분 캔들은 타임스탬프 하나에 1비트, 변화 없는 값도 1비트만 차지합니다.
+//! 십진수는 가수와 소수 자릿수를 그대로 보존하므로 읽은 값이 쓴 값과 정확히 같습니다.
+
+use crate::shared::error::CoreError;
+use crate::shared::types::{Decimal, Result};
+
+/// 비트 단위 쓰기 버퍼 (상위 비트부터 채움)
+#[derive(Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    /// 마지막 바이트에서 이미 사용한 비트 수 (0이면 새 바이트 필요)
+    used: u8,
+}
+
+impl BitWriter {
+    /// 빈 버퍼 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 비트 하나 쓰기
+    pub fn write_bit(&mut self, bit: bool) {
+        if self.used == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= 1 << (7 - self.used);
+        }
+        self.used = (self.used + 1) % 8;
+    }
+
+    /// `value`의 하위 `count`비트를 상위 비트부터 쓰기
+    pub fn write_bits(&mut self, value: u64, count: u8) {
+        for shift in (0..count).rev() {
+            self.write_bit((value >> shift) & 1 == 1);
+        }
+    }
+
+    /// 지금까지 쓴 바이트 (마지막 바이트의 남은 비트는 0)
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// 비트 단위 읽기 커서
+#[derive(Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// 바이트 슬라이스로 생성
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// 비트 하나 읽기
+    pub fn read_bit(&mut self) -> Result<bool> {
+        let byte = self
+            .bytes
+            .get(self.position / 8)
+            .ok_or_else(|| CoreError::Data("압축 열이 예상보다 짧음".to_string()))?;
+        let bit = (byte >> (7 - self.position % 8)) & 1 == 1;
+        self.position += 1;
+        Ok(bit)
+    }
+
+    /// `count`비트를 읽어 하위 비트에 채움
+    pub fn read_bits(&mut self, count: u8) -> Result<u64> {
+        let mut value = 0u64;
+        for _ in 0..count {
+            value = (value << 1) | self.read_bit()? as u64;
+        }
+        Ok(value)
+    }
+}
+
+/// 부호 있는 정수를 작은 절댓값일수록 작은 부호 없는 정수로 변환
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// `zigzag`의 역변환
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// delta-of-delta 구간 (접두 비트 수, 접두 값, 값 비트 수)
+const DOD_BUCKETS: &[(u8, u64, u8)] = &[(2, 0b10, 7), (3, 0b110, 9), (4, 0b1110, 12)];
+
+/// 타임스탬프(밀리초) delta-of-delta 인코더
+#[derive(Debug, Default)]
+pub struct TimestampEncoder {
+    previous: i64,
+    previous_delta: i64,
+    count: usize,
+}
+
+impl TimestampEncoder {
+    /// 새 인코더
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 타임스탬프 하나 인코딩
+    pub fn encode(&mut self, writer: &mut BitWriter, timestamp: i64) {
+        if self.count == 0 {
+            writer.write_bits(timestamp as u64, 64);
+        } else {
+            let delta = timestamp.wrapping_sub(self.previous);
+            let dod = zigzag(delta.wrapping_sub(self.previous_delta));
+            if dod == 0 {
+                writer.write_bit(false);
+            } else {
+                match DOD_BUCKETS.iter().find(|(_, _, bits)| dod < 1 << bits) {
+                    Some((prefix_bits, prefix, bits)) => {
+                        writer.write_bits(*prefix, *prefix_bits);
+                        writer.write_bits(dod, *bits);
+                    }
+                    None => {
+                        writer.write_bits(0b1111, 4);
+                        writer.write_bits(dod, 64);
+                    }
+                }
+            }
+            self.previous_delta = delta;
+        }
+        self.previous = timestamp;
+        self.count += 1;
+    }
+}
+
+/// 타임스탬프 delta-of-delta 디코더
+#[derive(Debug, Default)]
+pub struct TimestampDecoder {
+    previous: i64,
+    previous_delta: i64,
+    count: usize,
+}
+
+impl TimestampDecoder {
+    /// 새 디코더
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 타임스탬프 하나 디코딩
+    pub fn decode(&mut self, reader: &mut BitReader<'_>) -> Result<i64> {
+        if self.count == 0 {
+            self.previous = reader.read_bits(64)? as i64;
+        } else {
+            let mut dod = 0;
+            if reader.read_bit()? {
+                let mut bits = 64;
+                for (_, _, bucket_bits) in DOD_BUCKETS {
+                    if !reader.read_bit()? {
+                        bits = *bucket_bits;
+                        break;
+                    }
+                }
+                dod = unzigzag(reader.read_bits(bits)?);
+            }
+            self.previous_delta = self.previous_delta.wrapping_add(dod);
+            self.previous = self.previous.wrapping_add(self.previous_delta);
+        }
+        self.count += 1;
+        Ok(self.previous)
+    }
+}
+
+/// 가수 차이 구간 (접두 비트 수, 접두 값, 값 비트 수)
+///
+/// 가수는 96비트이므로 차이의 zigzag 값은 98비트를 넘지 않으며, 마지막 구간은 128비트로 씁니다.
+const MANTISSA_BUCKETS: &[(u8, u64, u8)] = &[(2, 0b10, 16), (3, 0b110, 32), (4, 0b1110, 64)];
+
+/// 소수 자릿수 비트 수 (`Decimal::MAX_SCALE` = 28)
+const SCALE_BITS: u8 = 5;
+
+/// 부호 있는 128비트 정수의 zigzag 변환
+fn zigzag128(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+/// `zigzag128`의 역변환
+fn unzigzag128(value: u128) -> i128 {
+    ((value >> 1) as i128) ^ -((value & 1) as i128)
+}
+
+/// 십진수 가수 차이 인코더
+///
+/// 소수 자릿수가 직전 값과 같으면 1비트, 다르면 1비트 뒤에 5비트로 기록하고,
+/// 가수는 직전 가수와의 차이를 zigzag 변환해 크기별 구간으로 씁니다.
+/// 같은 호가 단위의 가격은 대부분 16비트 구간에 들어갑니다.
+#[derive(Debug, Default)]
+pub struct DecimalEncoder {
+    previous_mantissa: i128,
+    previous_scale: u32,
+}
+
+impl DecimalEncoder {
+    /// 새 인코더
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 값 하나 인코딩
+    pub fn encode(&mut self, writer: &mut BitWriter, value: Decimal) {
+        let scale = value.scale();
+        if scale == self.previous_scale {
+            writer.write_bit(false);
+        } else {
+            writer.write_bit(true);
+            writer.write_bits(scale as u64, SCALE_BITS);
+        }
+
+        let mantissa = value.mantissa();
+        let delta = zigzag128(mantissa.wrapping_sub(self.previous_mantissa));
+        if delta == 0 {
+            writer.write_bit(false);
+        } else {
+            match MANTISSA_BUCKETS.iter().find(|(_, _, bits)| delta < 1u128 << bits) {
+                Some((prefix_bits, prefix, bits)) => {
+                    writer.write_bits(*prefix, *prefix_bits);
+                    writer.write_bits(delta as u64, *bits);
+                }
+                None => {
+                    writer.write_bits(0b1111, 4);
+                    writer.write_bits((delta >> 64) as u64, 64);
+                    writer.write_bits(delta as u64, 64);
+                }
+            }
+        }
+        self.previous_mantissa = mantissa;
+        self.previous_scale = scale;
+    }
+}
+
+/// 십진수 가수 차이 디코더
+#[derive(Debug, Default)]
+pub struct DecimalDecoder {
+    previous_mantissa: i128,
+    previous_scale: u32,
+}
+
+impl DecimalDecoder {
+    /// 새 디코더
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 값 하나 디코딩
+    pub fn decode(&mut self, reader: &mut BitReader<'_>) -> Result<Decimal> {
+        if reader.read_bit()? {
+            self.previous_scale = reader.read_bits(SCALE_BITS)? as u32;
+        }
+
+        let mut delta = 0u128;
+        if reader.read_bit()? {
+            let mut bits = None;
+            for (_, _, bucket_bits) in MANTISSA_BUCKETS {
+                if !reader.read_bit()? {
+                    bits = Some(*bucket_bits);
+                    break;
+                }
+            }
+            delta = match bits {
+                Some(bits) => reader.read_bits(bits)? as u128,
+                None => ((reader.read_bits(64)? as u128) << 64) | reader.read_bits(64)? as u128,
+            };
+        }
+        self.previous_mantissa = self.previous_mantissa.wrapping_add(unzigzag128(delta));
+
+        Decimal::try_from_i128_with_scale(self.previous_mantissa, self.previous_scale).map_err(|e| {
+            CoreError::Data(format!(
+                "잘못된 십진수 열 (가수 {}, 자릿수 {}): {}",
+                self.previous_mantissa, self.previous_scale, e
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_timestamps_and_decimals_round_trip() {
+        let start = 1_704_067_200_000i64;
+        let mut timestamps: Vec<i64> = (0..500).map(|i| start + i * 60_000).collect();
+        // 누락 구간, 역행, 큰 점프도 복원되어야 함
+        timestamps.extend([start + 600 * 60_000, start + 599 * 60_000, start + 10_000 * 60_000, -5, i64::MAX]);
+        let decimals: Vec<Decimal> = (0..timestamps.len())
+            .map(|i| match i % 7 {
+                0 => dec!(42000.5),
+                1 => dec!(42000.50),
+                2 => dec!(42001.25) + Decimal::from(i),
+                3 => Decimal::ZERO,
+                4 => dec!(-0.000000001),
+                5 => Decimal::MAX,
+                _ => dec!(12345678.123456789) * Decimal::from(i),
+            })
+            .collect();
+
+        let mut timestamp_writer = BitWriter::new();
+        let mut decimal_writer = BitWriter::new();
+        let mut timestamp_encoder = TimestampEncoder::new();
+        let mut decimal_encoder = DecimalEncoder::new();
+        for (timestamp, value) in timestamps.iter().zip(&decimals) {
+            timestamp_encoder.encode(&mut timestamp_writer, *timestamp);
+            decimal_encoder.encode(&mut decimal_writer, *value);
+        }
+        let timestamp_bytes = timestamp_writer.into_bytes();
+        let decimal_bytes = decimal_writer.into_bytes();
+
+        let mut timestamp_reader = BitReader::new(&timestamp_bytes);
+        let mut decimal_reader = BitReader::new(&decimal_bytes);
+        let mut timestamp_decoder = TimestampDecoder::new();
+        let mut decimal_decoder = DecimalDecoder::new();
+        for (timestamp, value) in timestamps.iter().zip(&decimals) {
+            assert_eq!(timestamp_decoder.decode(&mut timestamp_reader).unwrap(), *timestamp);
+            let decoded = decimal_decoder.decode(&mut decimal_reader).unwrap();
+            // 값뿐 아니라 소수 자릿수(42000.5와 42000.50)도 보존
+            assert_eq!(decoded, *value);
+            assert_eq!(decoded.scale(), value.scale());
+        }
+    }
+
+    #[test]
+    fn test_unchanged_decimals_use_two_bits() {
+        let mut writer = BitWriter::new();
+        let mut encoder = DecimalEncoder::new();
+        for _ in 0..1_000 {
+            encoder.encode(&mut writer, dec!(42000.25));
+        }
+        // 첫 값 (자릿수 6비트 + 가수 구간) 이후 999개는 2비트씩
+        assert!(writer.into_bytes().len() < 8 + 999 * 2 / 8 + 2);
+    }
+
+    #[test]
+    fn test_regular_timestamps_use_one_bit() {
+        let mut writer = BitWriter::new();
+        let mut encoder = TimestampEncoder::new();
+        for i in 0..1_000i64 {
+            encoder.encode(&mut writer, 1_704_067_200_000 + i * 60_000);
+        }
+        // 첫 값 64비트 + 첫 간격 + 나머지 998개는 1비트씩
+        assert!(writer.into_bytes().len() < 8 + 9 + 998 / 8 + 2);
+    }
+}
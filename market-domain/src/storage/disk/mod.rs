@@ -0,0 +1,346 @@
+//! 디스크 시계열 엔진
+//!
+//! 수집기 설정의 `StorageType::Disk`가 사용하는 엔진입니다. 캔들과 체결을 (거래소, 심볼, 타임프레임)별
+//! 파티션에 열 지향 압축 블록으로 추가 기록하고, 블록 인덱스로 시간 구간을 찾아 읽습니다.
+//! 가격과 수량은 `Decimal` 그대로 저장하므로 읽은 값이 쓴 값과 정확히 같습니다.
+//!
+//! 디렉터리 구조: `<루트>/<candles|trades>/<거래소>/<심볼>/<타임프레임|tick>/data.seg`
+//!
+//! - 쓰기: 배치를 블록으로 인코딩해 파일 끝에 붙이고 `fsync`한 뒤 반환 (충돌 시 마지막 배치의 손상된 블록부터 유실)
+//! - 읽기: 블록 헤더의 시간 범위로 후보 블록만 읽고 디코딩
+//! - 압축: 작은 블록과 겹치는 블록을 모아 꽉 찬 블록으로 다시 씀 (`spawn_compactor`로 주기 실행)
+
+pub mod block;
+pub mod codec;
+pub mod partition;
+
+pub use block::{BlockHeader, SeriesKind};
+pub use partition::{BlockIndexEntry, CompactionStats, PartitionKey};
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use self::block::BlockCodec;
+use self::partition::{Partition, SEGMENT_FILE};
+use crate::model::{Candle, Trade};
+use crate::shared::types::{ExchangeId, Result, SymbolPair, Timeframe};
+
+/// 디스크 엔진 설정
+#[derive(Debug, Clone)]
+pub struct DiskEngineOptions {
+    /// 블록당 최대 행 수 (1분 캔들 기준 약 하루치)
+    pub block_rows: usize,
+    /// 절반도 차지 않은 블록이 이만큼 쌓이면 압축 대상
+    pub compaction_small_blocks: usize,
+}
+
+impl Default for DiskEngineOptions {
+    fn default() -> Self {
+        Self {
+            block_rows: 1440,
+            compaction_small_blocks: 8,
+        }
+    }
+}
+
+/// 열 지향 디스크 시계열 엔진
+#[derive(Debug)]
+pub struct DiskEngine {
+    root: PathBuf,
+    options: DiskEngineOptions,
+    partitions: RwLock<HashMap<PartitionKey, Arc<Mutex<Partition>>>>,
+}
+
+impl DiskEngine {
+    /// 기본 설정으로 루트 디렉터리의 엔진 열기
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        Self::open_with_options(root, DiskEngineOptions::default())
+    }
+
+    /// 설정을 지정해 엔진 열기
+    ///
+    /// 기존 파티션을 모두 열어 블록 인덱스를 만들고 끊긴 쓰기를 복구합니다.
+    pub fn open_with_options(root: impl Into<PathBuf>, options: DiskEngineOptions) -> Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+
+        let mut partitions = HashMap::new();
+        for key in discover_partitions(&root)? {
+            let partition = Partition::open(&root, key.clone())?;
+            partitions.insert(key, Arc::new(Mutex::new(partition)));
+        }
+        tracing::info!("디스크 엔진 열림: {:?} (파티션 {}개)", root, partitions.len());
+
+        Ok(Self {
+            root,
+            options,
+            partitions: RwLock::new(partitions),
+        })
+    }
+
+    /// 루트 디렉터리
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 열린 파티션 키 목록
+    pub fn partitions(&self) -> Vec<PartitionKey> {
+        self.partitions.read().unwrap().keys().cloned().collect()
+    }
+
+    /// 캔들 추가 (파티션별로 나눠 기록, 같은 시작 시각은 나중 값으로 대체됨)
+    pub fn append_candles(&self, candles: &[Candle]) -> Result<usize> {
+        self.append_grouped(candles, |candle| {
+            PartitionKey::candles(&candle.exchange, &candle.symbol, &candle.timeframe)
+        })
+    }
+
+    /// 체결 추가 (같은 시각·체결 ID는 나중 값으로 대체됨)
+    pub fn append_trades(&self, trades: &[Trade]) -> Result<usize> {
+        self.append_grouped(trades, |trade| PartitionKey::trades(&trade.exchange, &trade.symbol))
+    }
+
+    /// `[from, to)` 구간의 캔들을 시각순으로 조회
+    pub fn candles(
+        &self,
+        exchange: &ExchangeId,
+        symbol: &SymbolPair,
+        timeframe: &Timeframe,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Candle>> {
+        self.scan(&PartitionKey::candles(exchange, symbol, timeframe), from, to)
+    }
+
+    /// `[from, to)` 구간의 체결을 시각순으로 조회
+    pub fn trades(
+        &self,
+        exchange: &ExchangeId,
+        symbol: &SymbolPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Trade>> {
+        self.scan(&PartitionKey::trades(exchange, symbol), from, to)
+    }
+
+    /// 필요한 파티션을 모두 압축
+    pub fn compact(&self) -> Result<CompactionStats> {
+        let partitions: Vec<_> = self.partitions.read().unwrap().values().cloned().collect();
+        let mut total = CompactionStats::default();
+        for partition in partitions {
+            let mut partition = partition.lock().unwrap();
+            if !partition.needs_compaction(self.options.block_rows, self.options.compaction_small_blocks) {
+                continue;
+            }
+            let stats = match partition.key().kind {
+                SeriesKind::Candles => partition.compact::<Candle>(self.options.block_rows)?,
+                SeriesKind::Trades => partition.compact::<Trade>(self.options.block_rows)?,
+            };
+            tracing::debug!("{:?} 압축: {:?}", partition.key(), stats);
+            total.partitions += stats.partitions;
+            total.blocks_before += stats.blocks_before;
+            total.blocks_after += stats.blocks_after;
+            total.rows_removed += stats.rows_removed;
+            total.bytes_reclaimed += stats.bytes_reclaimed;
+        }
+        Ok(total)
+    }
+
+    /// 주기적으로 압축하는 백그라운드 작업 시작 (반환된 핸들을 abort하면 중단)
+    pub fn spawn_compactor(self: &Arc<Self>, period: Duration) -> tokio::task::JoinHandle<()> {
+        let engine = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let engine = Arc::clone(&engine);
+                match tokio::task::spawn_blocking(move || engine.compact()).await {
+                    Ok(Ok(stats)) if stats.partitions > 0 => {
+                        tracing::info!("디스크 엔진 압축 완료: {:?}", stats);
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(error)) => tracing::error!("디스크 엔진 압축 실패: {}", error),
+                    Err(error) => tracing::error!("디스크 엔진 압축 작업 중단: {}", error),
+                }
+            }
+        })
+    }
+
+    /// 행을 파티션별로 묶어 기록
+    fn append_grouped<R: BlockCodec>(
+        &self,
+        rows: &[R],
+        key_of: impl Fn(&R) -> PartitionKey,
+    ) -> Result<usize> {
+        let mut groups: HashMap<PartitionKey, Vec<R>> = HashMap::new();
+        for row in rows {
+            groups.entry(key_of(row)).or_default().push(row.clone());
+        }
+
+        let mut written = 0;
+        for (key, rows) in groups {
+            let partition = self.partition(key)?;
+            written += partition.lock().unwrap().append(&rows, self.options.block_rows)?;
+        }
+        Ok(written)
+    }
+
+    /// 구간 조회 (없는 파티션은 빈 결과)
+    fn scan<R: BlockCodec>(
+        &self,
+        key: &PartitionKey,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<R>> {
+        let partition = match self.partitions.read().unwrap().get(key) {
+            Some(partition) => Arc::clone(partition),
+            None => return Ok(Vec::new()),
+        };
+        let mut partition = partition.lock().unwrap();
+        partition.scan(from.timestamp_millis(), to.timestamp_millis())
+    }
+
+    /// 파티션을 찾거나 새로 엶
+    fn partition(&self, key: PartitionKey) -> Result<Arc<Mutex<Partition>>> {
+        if let Some(partition) = self.partitions.read().unwrap().get(&key) {
+            return Ok(Arc::clone(partition));
+        }
+        let mut partitions = self.partitions.write().unwrap();
+        if let Some(partition) = partitions.get(&key) {
+            return Ok(Arc::clone(partition));
+        }
+        let partition = Arc::new(Mutex::new(Partition::open(&self.root, key.clone())?));
+        partitions.insert(key, Arc::clone(&partition));
+        Ok(partition)
+    }
+}
+
+/// 루트 아래의 세그먼트 파일을 찾아 파티션 키로 복원
+fn discover_partitions(root: &Path) -> Result<Vec<PartitionKey>> {
+    let mut keys = Vec::new();
+    for kind in subdirectories(root)? {
+        for exchange in subdirectories(&kind)? {
+            for symbol in subdirectories(&exchange)? {
+                for timeframe in subdirectories(&symbol)? {
+                    if !timeframe.join(SEGMENT_FILE).exists() {
+                        continue;
+                    }
+                    let name = |path: &Path| path.file_name().and_then(|name| name.to_str()).map(str::to_string);
+                    let key = match (name(&kind), name(&exchange), name(&symbol), name(&timeframe)) {
+                        (Some(kind), Some(exchange), Some(symbol), Some(timeframe)) => {
+                            PartitionKey::from_components(&kind, &exchange, &symbol, &timeframe)
+                        }
+                        _ => None,
+                    };
+                    match key {
+                        Some(key) => keys.push(key),
+                        None => tracing::warn!("알 수 없는 파티션 디렉터리 무시: {:?}", timeframe),
+                    }
+                }
+            }
+        }
+    }
+    Ok(keys)
+}
+
+/// 하위 디렉터리 목록
+fn subdirectories(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut directories = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            directories.push(entry.path());
+        }
+    }
+    Ok(directories)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::types::{Decimal, OrderSide};
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn binance() -> ExchangeId {
+        ExchangeId::new("binance")
+    }
+
+    fn candle(symbol: &SymbolPair, minute: i64, close: Decimal) -> Candle {
+        Candle::new(
+            symbol.clone(),
+            start() + chrono::Duration::minutes(minute),
+            close - dec!(1),
+            close + dec!(2),
+            close - dec!(2),
+            close,
+            dec!(3.5),
+            binance(),
+            Timeframe::Minute1,
+            Some(close * dec!(3.5)),
+            true,
+        )
+    }
+
+    #[test]
+    fn test_append_scan_reopen_and_compact() {
+        let root = tempfile::tempdir().unwrap();
+        let options = DiskEngineOptions {
+            block_rows: 60,
+            compaction_small_blocks: 2,
+        };
+        let btc = SymbolPair::new("BTC", "USDT");
+        let eth = SymbolPair::new("ETH", "USDT");
+        {
+            let engine = DiskEngine::open_with_options(root.path(), options.clone()).unwrap();
+            // 수집기처럼 작은 배치로 나눠 기록하고, 마지막 캔들은 갱신됨
+            for hour in 0..3 {
+                let batch: Vec<_> = (hour * 60..hour * 60 + 60)
+                    .flat_map(|minute| [candle(&btc, minute, dec!(100) + Decimal::from(minute)), candle(&eth, minute, dec!(10))])
+                    .collect();
+                for chunk in batch.chunks(25) {
+                    engine.append_candles(chunk).unwrap();
+                }
+            }
+            engine.append_candles(&[candle(&btc, 179, dec!(999.123456789))]).unwrap();
+            engine
+                .append_trades(&[Trade::new(binance(), btc.clone(), "t-1", dec!(42000.01), dec!(0.5), OrderSide::Sell, start())])
+                .unwrap();
+        }
+
+        let engine = DiskEngine::open_with_options(root.path(), options).unwrap();
+        assert_eq!(engine.partitions().len(), 3);
+        let range = engine
+            .candles(&binance(), &btc, &Timeframe::Minute1, start() + chrono::Duration::minutes(30), start() + chrono::Duration::minutes(180))
+            .unwrap();
+        assert_eq!(range.len(), 150);
+        assert_eq!(range[0].close, dec!(130));
+        assert_eq!(range.last().unwrap().close, dec!(999.123456789));
+        assert_eq!(range[0].symbol, btc);
+
+        let stats = engine.compact().unwrap();
+        assert_eq!(stats.partitions, 2);
+        assert_eq!(stats.rows_removed, 1);
+        let compacted = engine
+            .candles(&binance(), &btc, &Timeframe::Minute1, start(), start() + chrono::Duration::days(1))
+            .unwrap();
+        assert_eq!(compacted.len(), 180);
+        assert_eq!(compacted.last().unwrap().close, dec!(999.123456789));
+        assert_eq!(engine.compact().unwrap().partitions, 0);
+
+        let trades = engine.trades(&binance(), &btc, start(), start() + chrono::Duration::seconds(1)).unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!((trades[0].side, trades[0].price, trades[0].trade_id.as_str()), (OrderSide::Sell, dec!(42000.01), "t-1"));
+        let sol = SymbolPair::new("SOL", "USDT");
+        assert!(engine.candles(&binance(), &sol, &Timeframe::Minute1, start(), start() + chrono::Duration::days(1)).unwrap().is_empty());
+    }
+}
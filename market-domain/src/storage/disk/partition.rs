@@ -0,0 +1,564 @@
+//! 파티션 세그먼트 파일
+//!
+//! 파티션은 (종류, 거래소, 심볼, 타임프레임)마다 하나의 디렉터리와 세그먼트 파일(`data.seg`)을 가집니다.
+//! 추가 쓰기는 한 배치의 블록들을 파일 끝에 붙이고 `fsync`한 뒤에야 성공으로 보고합니다.
+//! 충돌로 끊긴 쓰기는 배치 안의 어느 블록이든 손상시킬 수 있으므로, 열 때 모든 블록의 체크섬을
+//! 확인하고 처음으로 손상된 블록부터 잘라냅니다.
+//! 압축(compaction)은 새 파일을 다 쓴 뒤 원자적으로 이름을 바꿔 교체합니다.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use super::block::{encode_block, BlockCodec, BlockHeader, RowContext, SeriesKind, HEADER_LEN};
+use crate::shared::error::CoreError;
+use crate::shared::types::{ExchangeId, Result, SymbolPair, Timeframe};
+
+/// 세그먼트 파일 이름
+pub const SEGMENT_FILE: &str = "data.seg";
+
+/// 압축 중 임시 파일 이름
+const COMPACTING_FILE: &str = "data.seg.compacting";
+
+/// 체결 파티션의 타임프레임 디렉터리 이름
+const TICK_DIRECTORY: &str = "tick";
+
+/// 파티션 키
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartitionKey {
+    /// 시계열 종류
+    pub kind: SeriesKind,
+    /// 거래소 식별자
+    pub exchange: ExchangeId,
+    /// 심볼
+    pub symbol: SymbolPair,
+    /// 캔들 타임프레임 (체결은 없음)
+    pub timeframe: Option<Timeframe>,
+}
+
+impl PartitionKey {
+    /// 캔들 파티션 키
+    pub fn candles(exchange: &ExchangeId, symbol: &SymbolPair, timeframe: &Timeframe) -> Self {
+        Self {
+            kind: SeriesKind::Candles,
+            exchange: exchange.clone(),
+            symbol: symbol.clone(),
+            timeframe: Some(*timeframe),
+        }
+    }
+
+    /// 체결 파티션 키
+    pub fn trades(exchange: &ExchangeId, symbol: &SymbolPair) -> Self {
+        Self {
+            kind: SeriesKind::Trades,
+            exchange: exchange.clone(),
+            symbol: symbol.clone(),
+            timeframe: None,
+        }
+    }
+
+    /// 루트 아래의 파티션 디렉터리 (`<종류>/<거래소>/<BASE/QUOTE>/<타임프레임|tick>`)
+    pub fn directory(&self, root: &Path) -> PathBuf {
+        let timeframe = match &self.timeframe {
+            Some(timeframe) => timeframe.to_code(),
+            None => TICK_DIRECTORY.to_string(),
+        };
+        root.join(self.kind.as_str())
+            .join(escape_component(&self.exchange.0))
+            .join(escape_component(&self.symbol.to_string()))
+            .join(escape_component(&timeframe))
+    }
+
+    /// 디렉터리 이름들에서 복원
+    pub fn from_components(kind: &str, exchange: &str, symbol: &str, timeframe: &str) -> Option<Self> {
+        let kind = SeriesKind::parse(kind)?;
+        let timeframe = match (kind, unescape_component(timeframe)?) {
+            (SeriesKind::Trades, timeframe) if timeframe == TICK_DIRECTORY => None,
+            (SeriesKind::Candles, timeframe) => Some(timeframe.parse().ok()?),
+            _ => return None,
+        };
+        Some(Self {
+            kind,
+            exchange: ExchangeId::new(unescape_component(exchange)?),
+            symbol: unescape_component(symbol)?.parse().ok()?,
+            timeframe,
+        })
+    }
+
+    fn context(&self) -> RowContext<'_> {
+        RowContext {
+            exchange: &self.exchange,
+            symbol: &self.symbol,
+            timeframe: self.timeframe.as_ref(),
+        }
+    }
+}
+
+/// 경로 구성 요소로 쓸 수 없는 문자를 `%XX`로 바꿈
+fn escape_component(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_') {
+            escaped.push(byte as char);
+        } else {
+            escaped.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    escaped
+}
+
+/// `escape_component`의 역변환
+fn unescape_component(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(0..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// 블록 인덱스 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockIndexEntry {
+    /// 파일 안의 헤더 위치
+    pub offset: u64,
+    /// 블록 헤더
+    pub header: BlockHeader,
+}
+
+impl BlockIndexEntry {
+    /// 블록이 `[from, to)` 구간과 겹치는지 여부
+    fn overlaps(&self, from: i64, to: i64) -> bool {
+        self.header.min_timestamp < to && self.header.max_timestamp >= from
+    }
+}
+
+/// 압축 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionStats {
+    /// 압축한 파티션 수
+    pub partitions: usize,
+    /// 압축 전 블록 수
+    pub blocks_before: usize,
+    /// 압축 후 블록 수
+    pub blocks_after: usize,
+    /// 중복으로 제거된 행 수
+    pub rows_removed: usize,
+    /// 압축 전후 파일 크기 차이 (바이트)
+    pub bytes_reclaimed: u64,
+}
+
+/// 열린 파티션
+#[derive(Debug)]
+pub struct Partition {
+    key: PartitionKey,
+    directory: PathBuf,
+    file: File,
+    /// 파일 순서대로의 블록 인덱스
+    index: Vec<BlockIndexEntry>,
+    /// 블록들이 시각순으로 겹치지 않고 정렬되어 있으면 이진 탐색 가능
+    sorted: bool,
+    len: u64,
+}
+
+impl Partition {
+    /// 파티션을 열거나 만들고, 헤더를 훑어 블록 인덱스를 구성
+    ///
+    /// 블록이 잘렸거나 체크섬이 맞지 않으면 충돌로 끊긴 쓰기로 보고 그 블록부터 잘라냅니다.
+    pub fn open(root: &Path, key: PartitionKey) -> Result<Self> {
+        let directory = key.directory(root);
+        fs::create_dir_all(&directory)?;
+        // 압축 도중 멈췄다면 원본이 그대로 남아 있으므로 임시 파일만 지움
+        let leftover = directory.join(COMPACTING_FILE);
+        if leftover.exists() {
+            fs::remove_file(&leftover)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(directory.join(SEGMENT_FILE))?;
+        let file_len = file.metadata()?.len();
+
+        let mut index = Vec::new();
+        let mut offset = 0u64;
+        while offset < file_len {
+            let entry = match read_header(&mut file, offset, file_len)? {
+                Some(header) if offset + (HEADER_LEN as u64) + header.payload_len as u64 <= file_len => {
+                    BlockIndexEntry { offset, header }
+                }
+                _ => break,
+            };
+            // 한 번의 fsync로 쓴 배치 안에서는 앞 블록도 손상될 수 있으므로 모든 페이로드를 확인
+            if !entry.header.verify(&read_payload(&mut file, &entry)?) {
+                break;
+            }
+            index.push(entry);
+            offset += HEADER_LEN as u64 + entry.header.payload_len as u64;
+        }
+        if offset < file_len {
+            tracing::warn!(
+                "{:?} 파티션의 끊긴 쓰기 {}바이트를 잘라냄",
+                directory,
+                file_len - offset
+            );
+            file.set_len(offset)?;
+            file.sync_all()?;
+        }
+
+        let sorted = is_sorted(&index);
+        Ok(Self {
+            key,
+            directory,
+            file,
+            index,
+            sorted,
+            len: offset,
+        })
+    }
+
+    /// 파티션 키
+    pub fn key(&self) -> &PartitionKey {
+        &self.key
+    }
+
+    /// 블록 인덱스
+    pub fn index(&self) -> &[BlockIndexEntry] {
+        &self.index
+    }
+
+    /// 세그먼트 파일 크기
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// 행들을 블록 단위로 추가하고 디스크에 반영될 때까지 기다림
+    pub fn append<R: BlockCodec>(&mut self, rows: &[R], block_rows: usize) -> Result<usize> {
+        // 한 배치 안의 같은 행도 마지막 값만 남김
+        let (rows, _) = merge_rows(rows.to_vec());
+
+        let mut buffer = Vec::new();
+        let mut entries = Vec::new();
+        for chunk in rows.chunks(block_rows.max(1)) {
+            let block = encode_block(chunk);
+            let header = BlockHeader::from_bytes(block[..HEADER_LEN].try_into().unwrap())?;
+            entries.push(BlockIndexEntry {
+                offset: self.len + buffer.len() as u64,
+                header,
+            });
+            buffer.extend_from_slice(&block);
+        }
+        if buffer.is_empty() {
+            return Ok(0);
+        }
+
+        if let Err(error) = self.file.write_all(&buffer).and_then(|_| self.file.sync_data()) {
+            // 일부만 쓰였을 수 있으므로 마지막으로 온전한 위치로 되돌림
+            let _ = self.file.set_len(self.len);
+            return Err(CoreError::Data(format!("{:?} 블록 쓰기 실패: {}", self.directory, error)));
+        }
+
+        for entry in entries {
+            self.sorted = self.sorted
+                && self
+                    .index
+                    .last()
+                    .is_none_or(|last| last.header.max_timestamp < entry.header.min_timestamp);
+            self.index.push(entry);
+        }
+        self.len += buffer.len() as u64;
+        Ok(rows.len())
+    }
+
+    /// `[from, to)` 구간의 행을 시각순으로 읽음
+    ///
+    /// 같은 행이 여러 블록에 있으면 나중에 쓴 값이 이깁니다 (캔들 갱신, 체결 재수집).
+    pub fn scan<R: BlockCodec>(&mut self, from: i64, to: i64) -> Result<Vec<R>> {
+        if R::KIND != self.key.kind {
+            return Err(CoreError::Validation(format!(
+                "{:?} 파티션을 {:?}로 읽을 수 없음",
+                self.key.kind,
+                R::KIND
+            )));
+        }
+
+        let candidates: Vec<BlockIndexEntry> = if self.sorted {
+            // 겹치지 않고 정렬된 블록은 이진 탐색으로 시작 위치를 찾음
+            let start = self.index.partition_point(|entry| entry.header.max_timestamp < from);
+            self.index[start..]
+                .iter()
+                .take_while(|entry| entry.header.min_timestamp < to)
+                .copied()
+                .collect()
+        } else {
+            self.index.iter().filter(|entry| entry.overlaps(from, to)).copied().collect()
+        };
+
+        let rows = self.read_blocks::<R>(&candidates, from, to)?;
+        if self.sorted {
+            Ok(rows)
+        } else {
+            Ok(merge_rows(rows).0)
+        }
+    }
+
+    /// 블록들을 파일 순서대로 읽어 `[from, to)` 구간의 행만 모음 (중복 제거 전)
+    fn read_blocks<R: BlockCodec>(
+        &mut self,
+        entries: &[BlockIndexEntry],
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<R>> {
+        let context = self.key.context();
+        let mut rows = Vec::new();
+        for entry in entries {
+            let payload = read_payload(&mut self.file, entry)?;
+            if !entry.header.verify(&payload) {
+                return Err(CoreError::Data(format!(
+                    "{:?} 파티션 {}번 위치 블록의 체크섬 불일치",
+                    self.directory, entry.offset
+                )));
+            }
+            let decoded = R::decode_columns(&payload, entry.header.rows as usize, &context)?;
+            rows.extend(
+                decoded
+                    .into_iter()
+                    .filter(|row| (from..to).contains(&row.timestamp_millis())),
+            );
+        }
+        Ok(rows)
+    }
+
+    /// 압축이 필요한지 여부 (블록이 겹치거나, 작은 블록이 `small_blocks`개 이상)
+    pub fn needs_compaction(&self, block_rows: usize, small_blocks: usize) -> bool {
+        let small = self
+            .index
+            .iter()
+            .filter(|entry| (entry.header.rows as usize) < block_rows / 2)
+            .count();
+        !self.sorted || small >= small_blocks.max(1)
+    }
+
+    /// 모든 블록을 읽어 정렬·중복 제거한 뒤 꽉 찬 블록들로 다시 씀
+    pub fn compact<R: BlockCodec>(&mut self, block_rows: usize) -> Result<CompactionStats> {
+        if R::KIND != self.key.kind {
+            return Err(CoreError::Validation(format!(
+                "{:?} 파티션을 {:?}로 압축할 수 없음",
+                self.key.kind,
+                R::KIND
+            )));
+        }
+        let entries = self.index.clone();
+        let rows = self.read_blocks::<R>(&entries, i64::MIN, i64::MAX)?;
+        let (rows, rows_removed) = merge_rows(rows);
+
+        let temporary = self.directory.join(COMPACTING_FILE);
+        let mut output = File::create(&temporary)?;
+        let mut index = Vec::new();
+        let mut len = 0u64;
+        for chunk in rows.chunks(block_rows.max(1)) {
+            let block = encode_block(chunk);
+            let header = BlockHeader::from_bytes(block[..HEADER_LEN].try_into().unwrap())?;
+            index.push(BlockIndexEntry { offset: len, header });
+            output.write_all(&block)?;
+            len += block.len() as u64;
+        }
+        output.sync_all()?;
+        drop(output);
+
+        let segment = self.directory.join(SEGMENT_FILE);
+        fs::rename(&temporary, &segment)?;
+        sync_directory(&self.directory)?;
+
+        let stats = CompactionStats {
+            partitions: 1,
+            blocks_before: self.index.len(),
+            blocks_after: index.len(),
+            rows_removed,
+            bytes_reclaimed: self.len.saturating_sub(len),
+        };
+        self.file = OpenOptions::new().read(true).append(true).open(&segment)?;
+        self.index = index;
+        self.sorted = true;
+        self.len = len;
+        Ok(stats)
+    }
+}
+
+/// 정렬된 인덱스인지 확인
+fn is_sorted(index: &[BlockIndexEntry]) -> bool {
+    index
+        .windows(2)
+        .all(|pair| pair[0].header.max_timestamp < pair[1].header.min_timestamp)
+}
+
+/// 시각순으로 안정 정렬하고 같은 행은 나중 것만 남김 (남은 행, 제거된 행 수)
+fn merge_rows<R: BlockCodec>(mut rows: Vec<R>) -> (Vec<R>, usize) {
+    rows.sort_by_key(R::timestamp_millis);
+    let before = rows.len();
+    let mut merged: Vec<R> = Vec::with_capacity(rows.len());
+    let mut run_start = 0;
+    for row in rows {
+        if merged.last().is_none_or(|last| last.timestamp_millis() != row.timestamp_millis()) {
+            run_start = merged.len();
+        }
+        // 같은 시각 구간 안에서만 같은 행을 찾으면 됨
+        match merged[run_start..].iter().position(|existing| existing.same_row(&row)) {
+            Some(position) => merged[run_start + position] = row,
+            None => merged.push(row),
+        }
+    }
+    let removed = before - merged.len();
+    (merged, removed)
+}
+
+/// `offset`의 블록 헤더 읽기 (헤더가 잘렸거나 식별자가 다르면 None)
+fn read_header(file: &mut File, offset: u64, file_len: u64) -> Result<Option<BlockHeader>> {
+    if offset + HEADER_LEN as u64 > file_len {
+        return Ok(None);
+    }
+    let mut bytes = [0u8; HEADER_LEN];
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(&mut bytes)?;
+    Ok(BlockHeader::from_bytes(&bytes).ok())
+}
+
+/// 블록 페이로드 읽기
+fn read_payload(file: &mut File, entry: &BlockIndexEntry) -> Result<Vec<u8>> {
+    let mut payload = vec![0u8; entry.header.payload_len as usize];
+    file.seek(SeekFrom::Start(entry.offset + HEADER_LEN as u64))?;
+    file.read_exact(&mut payload)?;
+    Ok(payload)
+}
+
+/// 이름 바꾸기가 디스크에 반영되도록 디렉터리를 동기화
+fn sync_directory(directory: &Path) -> Result<()> {
+    #[cfg(unix)]
+    File::open(directory)?.sync_all()?;
+    #[cfg(not(unix))]
+    let _ = directory;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Candle;
+    use crate::shared::types::Decimal;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal_macros::dec;
+
+    fn key() -> PartitionKey {
+        PartitionKey::candles(&ExchangeId::new("binance"), &SymbolPair::new("BTC", "USDT"), &Timeframe::Minute1)
+    }
+
+    fn candle(minute: i64, close: Decimal) -> Candle {
+        Candle::new(
+            SymbolPair::new("BTC", "USDT"),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::minutes(minute),
+            dec!(100),
+            dec!(110),
+            dec!(90),
+            close,
+            dec!(1),
+            ExchangeId::new("binance"),
+            Timeframe::Minute1,
+            None,
+            true,
+        )
+    }
+
+    #[test]
+    fn test_partition_key_directory_round_trip() {
+        let root = Path::new("/data");
+        let candles = key();
+        let directory = candles.directory(root);
+        assert_eq!(directory, root.join("candles/binance/BTC%2FUSDT/1m"));
+
+        let components: Vec<String> = directory
+            .strip_prefix(root)
+            .unwrap()
+            .iter()
+            .map(|component| component.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            PartitionKey::from_components(&components[0], &components[1], &components[2], &components[3]),
+            Some(candles)
+        );
+
+        let trades = PartitionKey::trades(&ExchangeId::new("upbit"), &SymbolPair::new("BTC", "KRW"));
+        assert_eq!(trades.directory(root), root.join("trades/upbit/BTC%2FKRW/tick"));
+        assert_eq!(PartitionKey::from_components("trades", "upbit", "BTC%2FKRW", "tick"), Some(trades));
+        assert_eq!(PartitionKey::from_components("candles", "upbit", "BTC%2FKRW", "tick"), None);
+    }
+
+    #[test]
+    fn test_torn_tail_is_truncated_on_open() {
+        let root = tempfile::tempdir().unwrap();
+        let key = key();
+        {
+            let mut partition = Partition::open(root.path(), key.clone()).unwrap();
+            let batch: Vec<_> = (0..10).map(|minute| candle(minute, dec!(100))).collect();
+            partition.append(&batch, 4).unwrap();
+            assert_eq!(partition.index().len(), 3);
+        }
+
+        // 마지막 블록의 중간에서 쓰기가 끊긴 상황을 흉내 냄
+        let segment = key.directory(root.path()).join(SEGMENT_FILE);
+        let full_len = fs::metadata(&segment).unwrap().len();
+        OpenOptions::new().write(true).open(&segment).unwrap().set_len(full_len - 3).unwrap();
+
+        let mut partition = Partition::open(root.path(), key).unwrap();
+        assert_eq!(partition.index().len(), 2);
+        let rows: Vec<Candle> = partition.scan(i64::MIN, i64::MAX).unwrap();
+        assert_eq!(rows.len(), 8);
+
+        // 잘라낸 뒤에도 이어서 쓸 수 있음
+        partition.append(&[candle(8, dec!(200))], 4).unwrap();
+        let rows: Vec<Candle> = partition.scan(i64::MIN, i64::MAX).unwrap();
+        assert_eq!(rows.len(), 9);
+        assert_eq!(rows[8].close, dec!(200));
+    }
+
+    #[test]
+    fn test_corrupted_block_inside_last_batch_is_truncated_on_open() {
+        let root = tempfile::tempdir().unwrap();
+        let key = key();
+        let first_batch_len;
+        {
+            let mut partition = Partition::open(root.path(), key.clone()).unwrap();
+            partition.append(&[candle(0, dec!(100))], 4).unwrap();
+            first_batch_len = partition.len();
+            // 한 번의 쓰기와 fsync로 블록 3개를 기록
+            let batch: Vec<_> = (1..11).map(|minute| candle(minute, dec!(100))).collect();
+            partition.append(&batch, 4).unwrap();
+            assert_eq!(partition.index().len(), 4);
+        }
+
+        // 배치의 첫 블록 페이로드가 손상되고 뒤 블록들은 온전한 상황을 흉내 냄
+        let segment = key.directory(root.path()).join(SEGMENT_FILE);
+        let mut bytes = fs::read(&segment).unwrap();
+        bytes[first_batch_len as usize + HEADER_LEN + 2] ^= 0xff;
+        fs::write(&segment, &bytes).unwrap();
+
+        let mut partition = Partition::open(root.path(), key).unwrap();
+        assert_eq!(partition.index().len(), 1);
+        assert_eq!(partition.len(), first_batch_len);
+        assert_eq!(fs::metadata(&segment).unwrap().len(), first_batch_len);
+        let rows: Vec<Candle> = partition.scan(i64::MIN, i64::MAX).unwrap();
+        assert_eq!(rows.len(), 1);
+    }
+}
@@ -0,0 +1,8 @@
+//! 시장 데이터 저장소
+//!
+//! 수집한 캔들과 체결을 로컬 디스크에 저장하는 엔진을 제공합니다.
+//! 연구용 파일 보관은 `archive`(Parquet/Arrow IPC)를, 실시간 수집 경로의 저장은 `disk`를 사용합니다.
+
+pub mod disk;
+
+pub use disk::{CompactionStats, DiskEngine, DiskEngineOptions, PartitionKey, SeriesKind};
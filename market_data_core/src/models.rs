@@ -7,7 +7,11 @@ use chrono::{DateTime, Utc};
 use polars::prelude::*;
 use uuid::Uuid;
 
-use cryptolytica_common_core::types::{SymbolPair, ExchangeId, Timeframe, Candle, Price};
+use cryptolytica_common_core::types::{SymbolPair, ExchangeId, Timeframe, Candle, Price, Decimal};
+
+// 시장 데이터 타입과 조회 필터는 market-domain의 정의를 그대로 사용 (필터 조건은 shared-kernel 타입)
+pub use cryptolytica_market_domain::model::MarketDataType;
+pub use cryptolytica_market_domain::repository::MarketDataFilter;
 
 /// 시장 데이터 컬렉션 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,47 +40,10 @@ pub struct MarketDataCollection {
     pub timeframe: Option<Timeframe>,
 }
 
-/// 시장 데이터 타입
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum MarketDataType {
-    #[serde(rename = "candle")]
-    Candle,
-    #[serde(rename = "tick")]
-    Tick,
-    #[serde(rename = "order_book")]
-    OrderBook,
-    #[serde(rename = "trade")]
-    Trade,
-    #[serde(rename = "funding_rate")]
-    FundingRate,
-    #[serde(rename = "open_interest")]
-    OpenInterest,
-    #[serde(rename = "liquidation")]
-    Liquidation,
-}
-
-/// 시장 데이터 쿼리 필터
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MarketDataFilter {
-    /// 심볼 필터
-    pub symbols: Option<Vec<SymbolPair>>,
-    /// 거래소 필터
-    pub exchanges: Option<Vec<ExchangeId>>,
-    /// 시작 시간 필터
-    pub start_time: Option<DateTime<Utc>>,
-    /// 종료 시간 필터
-    pub end_time: Option<DateTime<Utc>>,
-    /// 타임프레임 필터
-    pub timeframe: Option<Timeframe>,
-    /// 데이터 타입 필터
-    pub data_type: Option<MarketDataType>,
-    /// 최대 결과 수량
-    pub limit: Option<u32>,
-    /// 정렬 방향
-    pub ascending: Option<bool>,
-}
-
 /// 캔들 데이터프레임 변환 인터페이스
+///
+/// 가격·거래량 열은 `Decimal`의 십진 문자열로 담아 자릿수를 잃지 않습니다. 분석할 때는
+/// `col("close").cast(DataType::Float64)`처럼 필요한 열만 숫자로 변환해 씁니다.
 pub trait CandleDataFrame {
     /// 캔들 데이터를 Polars DataFrame으로 변환
     fn to_dataframe(candles: &[Candle]) -> Result<DataFrame, polars::error::PolarsError>;
@@ -112,11 +79,11 @@ impl CandleDataFrame for CandleDataFrameImpl {
         for candle in candles {
             timestamps.push(candle.timestamp.timestamp_millis());
             symbols.push(candle.symbol.to_string());
-            opens.push(candle.open);
-            highs.push(candle.high);
-            lows.push(candle.low);
-            closes.push(candle.close);
-            volumes.push(candle.volume);
+            opens.push(candle.open.to_string());
+            highs.push(candle.high.to_string());
+            lows.push(candle.low.to_string());
+            closes.push(candle.close.to_string());
+            volumes.push(candle.volume.to_string());
         }
         
         // DataFrame 생성
@@ -147,11 +114,11 @@ impl CandleDataFrame for CandleDataFrameImpl {
         
         let timestamp_col = df.column("timestamp").map_err(|e| e.to_string())?;
         let symbol_col = df.column("symbol").map_err(|e| e.to_string())?;
-        let open_col = df.column("open").map_err(|e| e.to_string())?;
-        let high_col = df.column("high").map_err(|e| e.to_string())?;
-        let low_col = df.column("low").map_err(|e| e.to_string())?;
-        let close_col = df.column("close").map_err(|e| e.to_string())?;
-        let volume_col = df.column("volume").map_err(|e| e.to_string())?;
+        let opens = decimal_column(df, "open")?;
+        let highs = decimal_column(df, "high")?;
+        let lows = decimal_column(df, "low")?;
+        let closes = decimal_column(df, "close")?;
+        let volumes = decimal_column(df, "volume")?;
         
         let mut candles = Vec::with_capacity(df.height());
         
@@ -176,39 +143,14 @@ impl CandleDataFrame for CandleDataFrameImpl {
             
             let symbol = SymbolPair::new(parts[0], parts[1]);
             
-            let open = open_col.get(i).map_err(|e| e.to_string())?;
-            let open = open
-                .try_extract::<f64>()
-                .map_err(|_| "시가가 f64 타입이 아닙니다".to_string())?;
-                
-            let high = high_col.get(i).map_err(|e| e.to_string())?;
-            let high = high
-                .try_extract::<f64>()
-                .map_err(|_| "고가가 f64 타입이 아닙니다".to_string())?;
-                
-            let low = low_col.get(i).map_err(|e| e.to_string())?;
-            let low = low
-                .try_extract::<f64>()
-                .map_err(|_| "저가가 f64 타입이 아닙니다".to_string())?;
-                
-            let close = close_col.get(i).map_err(|e| e.to_string())?;
-            let close = close
-                .try_extract::<f64>()
-                .map_err(|_| "종가가 f64 타입이 아닙니다".to_string())?;
-                
-            let volume = volume_col.get(i).map_err(|e| e.to_string())?;
-            let volume = volume
-                .try_extract::<f64>()
-                .map_err(|_| "거래량이 f64 타입이 아닙니다".to_string())?;
-                
             candles.push(Candle {
                 symbol,
                 timestamp: datetime,
-                open,
-                high,
-                low,
-                close,
-                volume,
+                open: opens[i],
+                high: highs[i],
+                low: lows[i],
+                close: closes[i],
+                volume: volumes[i],
             });
         }
         
@@ -237,10 +179,31 @@ impl CandleDataFrame for CandleDataFrameImpl {
     }
 }
 
+/// 가격·거래량 열을 `Decimal`로 읽기
+///
+/// CSV에서 불러와 숫자로 추론된 열도 문자열로 변환한 뒤 읽습니다.
+fn decimal_column(df: &DataFrame, name: &str) -> Result<Vec<Decimal>, String> {
+    let column = df
+        .column(name)
+        .and_then(|c| c.cast(&DataType::String))
+        .map_err(|e| e.to_string())?;
+    let column = column.str().map_err(|e| e.to_string())?;
+    column
+        .into_iter()
+        .map(|value| {
+            let value = value.ok_or_else(|| format!("{} 열에 빈 값이 있습니다", name))?;
+            value
+                .parse::<Decimal>()
+                .map_err(|e| format!("{} 값을 Decimal로 변환할 수 없습니다: {} ({})", name, value, e))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::TimeZone;
+    use rust_decimal_macros::dec;
     
     #[test]
     fn test_market_data_type() {
@@ -259,20 +222,20 @@ mod tests {
             Candle {
                 symbol: SymbolPair::new("BTC", "USDT"),
                 timestamp: now,
-                open: 40000.0,
-                high: 41000.0,
-                low: 39500.0,
-                close: 40500.0,
-                volume: 100.5,
+                open: dec!(40000.0),
+                high: dec!(41000.0),
+                low: dec!(39500.0),
+                close: dec!(40500.0),
+                volume: dec!(100.5),
             },
             Candle {
                 symbol: SymbolPair::new("BTC", "USDT"),
                 timestamp: Utc.timestamp_opt(now.timestamp() + 60, 0).unwrap(),
-                open: 40500.0,
-                high: 40800.0,
-                low: 40200.0,
-                close: 40700.0,
-                volume: 85.2,
+                open: dec!(40500.0),
+                high: dec!(40800.0),
+                low: dec!(40200.0),
+                close: dec!(40700.12345678),
+                volume: dec!(85.2),
             },
         ];
         
@@ -282,7 +245,8 @@ mod tests {
         
         let reconverted = CandleDataFrameImpl::from_dataframe(&df).unwrap();
         assert_eq!(reconverted.len(), 2);
-        assert_eq!(reconverted[0].open, 40000.0);
-        assert_eq!(reconverted[1].close, 40700.0);
+        assert_eq!(reconverted[0].open, dec!(40000.0));
+        // 소수 자릿수까지 그대로 복원
+        assert_eq!(reconverted[1].close.to_string(), "40700.12345678");
     }
 } 
\ No newline at end of file
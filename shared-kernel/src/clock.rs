@@ -0,0 +1,165 @@
+//! 시계(Clock) 모듈
+//!
+//! 도메인 코드가 `Utc::now()`를 직접 호출하지 않고 주입된 시계에서 현재 시각을
+//! 얻도록 하기 위한 추상화를 제공합니다. 실거래는 `SystemClock`, 백테스트는
+//! `SimulatedClock`, 이벤트 재생은 `ReplayClock`을 사용하면 같은 코드가
+//! 시뮬레이션 시간 위에서 재현 가능하게 동작합니다.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+use std::sync::{Arc, RwLock};
+
+use crate::events::{Event, EventEnvelope};
+
+/// 현재 시각을 제공하는 시계
+pub trait Clock: Send + Sync + Debug {
+    /// 현재 시각 (UTC)
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// 여러 서비스가 공유하는 시계 핸들
+pub type SharedClock = Arc<dyn Clock>;
+
+/// 시스템 시계를 공유 핸들로 생성
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// 시스템(벽시계) 시간
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 수동으로 진행시키는 시뮬레이션 시계
+///
+/// 복제본은 같은 시각을 공유하므로, 백테스트 엔진이 진행시킨 시간을
+/// 서비스들이 그대로 관찰합니다.
+#[derive(Debug, Clone)]
+pub struct SimulatedClock {
+    now: Arc<RwLock<DateTime<Utc>>>,
+}
+
+impl SimulatedClock {
+    /// 지정한 시각에서 시작하는 시계 생성
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(RwLock::new(start)),
+        }
+    }
+
+    /// 시각 설정
+    pub fn set(&self, time: DateTime<Utc>) {
+        *self.now.write().unwrap_or_else(|e| e.into_inner()) = time;
+    }
+
+    /// 시간 진행 후 변경된 시각 반환
+    pub fn advance(&self, duration: Duration) -> DateTime<Utc> {
+        let mut now = self.now.write().unwrap_or_else(|e| e.into_inner());
+        *now += duration;
+        *now
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 이벤트 타임스탬프로 구동되는 재생 시계
+///
+/// 재생 중인 이벤트의 타임스탬프를 관찰할 때마다 그 시각으로 이동합니다.
+/// 시간은 되돌아가지 않으므로 순서가 약간 뒤섞인 이벤트가 들어와도 단조 증가합니다.
+#[derive(Debug, Clone)]
+pub struct ReplayClock {
+    now: Arc<RwLock<DateTime<Utc>>>,
+}
+
+impl ReplayClock {
+    /// 재생 시작 시각으로 시계 생성
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(RwLock::new(start)),
+        }
+    }
+
+    /// 타임스탬프 관찰 (현재 시각보다 이후면 이동하고 `true` 반환)
+    pub fn observe(&self, timestamp: DateTime<Utc>) -> bool {
+        let mut now = self.now.write().unwrap_or_else(|e| e.into_inner());
+        if timestamp > *now {
+            *now = timestamp;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 이벤트 관찰
+    pub fn observe_event<E: Event>(&self, event: &E) -> bool {
+        self.observe(event.timestamp())
+    }
+
+    /// 저장된 이벤트 봉투 관찰 (헤더 타임스탬프 기준)
+    pub fn observe_envelope<T>(&self, envelope: &EventEnvelope<T>) -> bool
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.observe(envelope.header.timestamp)
+    }
+}
+
+impl Clock for ReplayClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_simulated_clock() {
+        let clock = SimulatedClock::new(start());
+        let shared: SharedClock = Arc::new(clock.clone());
+        assert_eq!(shared.now(), start());
+
+        // 복제본과 시각 공유
+        assert_eq!(clock.advance(Duration::minutes(5)), start() + Duration::minutes(5));
+        assert_eq!(shared.now(), start() + Duration::minutes(5));
+
+        clock.set(start());
+        assert_eq!(shared.now(), start());
+    }
+
+    #[test]
+    fn test_replay_clock_is_monotonic() {
+        let clock = ReplayClock::new(start());
+
+        assert!(clock.observe(start() + Duration::seconds(10)));
+        assert_eq!(clock.now(), start() + Duration::seconds(10));
+
+        // 과거 타임스탬프는 무시
+        assert!(!clock.observe(start() + Duration::seconds(5)));
+        assert_eq!(clock.now(), start() + Duration::seconds(10));
+    }
+
+    #[test]
+    fn test_system_clock() {
+        let before = Utc::now();
+        let now = system_clock().now();
+        assert!(now >= before);
+    }
+}
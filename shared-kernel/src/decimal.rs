@@ -0,0 +1,219 @@
+//! 고정소수점 십진수 모듈
+//!
+//! 가격, 수량, 수수료, 손익 등 금액 계산에 사용되는 십진수 타입과
+//! 명시적인 반올림 규칙을 제공합니다. `f64` 연산에서 발생하는 누적 오차를
+//! 피하기 위해 모든 금액 모델은 이 모듈의 `Decimal`을 사용합니다.
+//!
+//! `Decimal`은 serde 직렬화 시 문자열(`"0.1"`)로 표현됩니다.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+pub use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy;
+
+use crate::error::CoreError;
+use crate::types::Result;
+
+/// 반올림 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// 가장 가까운 짝수로 반올림 (은행가 반올림)
+    #[serde(rename = "half_even")]
+    HalfEven,
+    /// 0.5는 0에서 멀어지는 방향으로 반올림
+    #[serde(rename = "half_up")]
+    HalfUp,
+    /// 0 방향으로 버림 (절사)
+    #[serde(rename = "toward_zero")]
+    TowardZero,
+    /// 음의 무한대 방향으로 내림
+    #[serde(rename = "floor")]
+    Floor,
+    /// 양의 무한대 방향으로 올림
+    #[serde(rename = "ceiling")]
+    Ceiling,
+}
+
+impl RoundingMode {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::TowardZero => RoundingStrategy::ToZero,
+            RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+            RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+        }
+    }
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            RoundingMode::HalfEven => "half_even",
+            RoundingMode::HalfUp => "half_up",
+            RoundingMode::TowardZero => "toward_zero",
+            RoundingMode::Floor => "floor",
+            RoundingMode::Ceiling => "ceiling",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// `Decimal` 확장 기능
+///
+/// 오버플로와 0 나눗셈을 `CoreError`로 보고하는 검사 연산과
+/// 소수점 자릿수/호가 단위(tick)/수량 단위(step) 반올림을 제공합니다.
+pub trait DecimalExt: Sized {
+    /// 검사 덧셈
+    fn try_add(self, rhs: Decimal) -> Result<Decimal>;
+
+    /// 검사 뺄셈
+    fn try_sub(self, rhs: Decimal) -> Result<Decimal>;
+
+    /// 검사 곱셈
+    fn try_mul(self, rhs: Decimal) -> Result<Decimal>;
+
+    /// 검사 나눗셈 (0으로 나누면 오류)
+    fn try_div(self, rhs: Decimal) -> Result<Decimal>;
+
+    /// 지정한 소수점 자릿수로 반올림
+    fn round_with(self, decimal_places: u32, mode: RoundingMode) -> Decimal;
+
+    /// 지정한 단위의 배수로 반올림
+    ///
+    /// 가격은 호가 단위(tick size), 수량은 수량 단위(step size)를 넘겨 사용합니다.
+    /// 단위가 0 이하이면 오류를 반환합니다.
+    fn round_to_increment(self, increment: Decimal, mode: RoundingMode) -> Result<Decimal>;
+}
+
+impl DecimalExt for Decimal {
+    fn try_add(self, rhs: Decimal) -> Result<Decimal> {
+        self.checked_add(rhs).ok_or_else(|| overflow("덧셈", self, rhs))
+    }
+
+    fn try_sub(self, rhs: Decimal) -> Result<Decimal> {
+        self.checked_sub(rhs).ok_or_else(|| overflow("뺄셈", self, rhs))
+    }
+
+    fn try_mul(self, rhs: Decimal) -> Result<Decimal> {
+        self.checked_mul(rhs).ok_or_else(|| overflow("곱셈", self, rhs))
+    }
+
+    fn try_div(self, rhs: Decimal) -> Result<Decimal> {
+        if rhs.is_zero() {
+            return Err(CoreError::Data(format!("0으로 나눌 수 없습니다: {} / {}", self, rhs)));
+        }
+        self.checked_div(rhs).ok_or_else(|| overflow("나눗셈", self, rhs))
+    }
+
+    fn round_with(self, decimal_places: u32, mode: RoundingMode) -> Decimal {
+        self.round_dp_with_strategy(decimal_places, mode.strategy())
+    }
+
+    fn round_to_increment(self, increment: Decimal, mode: RoundingMode) -> Result<Decimal> {
+        if increment <= Decimal::ZERO {
+            return Err(CoreError::Validation(format!(
+                "반올림 단위는 0보다 커야 합니다: {}", increment
+            )));
+        }
+
+        let units = self.try_div(increment)?.round_dp_with_strategy(0, mode.strategy());
+        let rounded = units.try_mul(increment)?;
+
+        // 단위의 소수점 자릿수에 맞춰 표현을 정규화 (예: 0.10 단위 → 1.20)
+        Ok(rounded.round_dp(increment.scale()))
+    }
+}
+
+fn overflow(op: &str, lhs: Decimal, rhs: Decimal) -> CoreError {
+    CoreError::Data(format!("십진수 {} 오버플로: {}, {}", op, lhs, rhs))
+}
+
+/// 문자열을 `Decimal`로 변환
+///
+/// 거래소 API는 가격과 수량을 문자열로 내려주므로, `f64`를 거치지 않고
+/// 바로 십진수로 파싱합니다. 지수 표기(`1e-8`)도 허용합니다.
+pub fn parse_decimal(s: &str) -> Result<Decimal> {
+    let trimmed = s.trim();
+    Decimal::from_str(trimmed)
+        .or_else(|_| Decimal::from_scientific(trimmed))
+        .map_err(|e| CoreError::Data(format!("문자열 '{}' 십진수 파싱 실패: {}", s, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_serialize_as_string() {
+        let value = dec!(0.1) + dec!(0.2);
+        assert_eq!(value, dec!(0.3));
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "\"0.3\"");
+
+        let parsed: Decimal = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, dec!(0.3));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(dec!(1.5).try_add(dec!(2.25)).unwrap(), dec!(3.75));
+        assert_eq!(dec!(1.5).try_sub(dec!(2.25)).unwrap(), dec!(-0.75));
+        assert_eq!(dec!(1.5).try_mul(dec!(2)).unwrap(), dec!(3.0));
+        assert_eq!(dec!(3).try_div(dec!(4)).unwrap(), dec!(0.75));
+
+        assert!(dec!(1).try_div(Decimal::ZERO).is_err());
+        assert!(Decimal::MAX.try_add(dec!(1)).is_err());
+        assert!(Decimal::MAX.try_mul(dec!(2)).is_err());
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!(dec!(2.345).round_with(2, RoundingMode::HalfEven), dec!(2.34));
+        assert_eq!(dec!(2.355).round_with(2, RoundingMode::HalfEven), dec!(2.36));
+        assert_eq!(dec!(2.345).round_with(2, RoundingMode::HalfUp), dec!(2.35));
+        assert_eq!(dec!(2.349).round_with(2, RoundingMode::TowardZero), dec!(2.34));
+        assert_eq!(dec!(-2.349).round_with(2, RoundingMode::TowardZero), dec!(-2.34));
+        assert_eq!(dec!(-2.341).round_with(2, RoundingMode::Floor), dec!(-2.35));
+        assert_eq!(dec!(2.341).round_with(2, RoundingMode::Ceiling), dec!(2.35));
+    }
+
+    #[test]
+    fn test_round_to_increment() {
+        // 호가 단위 0.5
+        assert_eq!(
+            dec!(100.74).round_to_increment(dec!(0.5), RoundingMode::HalfEven).unwrap(),
+            dec!(100.5)
+        );
+        assert_eq!(
+            dec!(100.75).round_to_increment(dec!(0.5), RoundingMode::HalfEven).unwrap(),
+            dec!(101.0)
+        );
+
+        // 수량 단위 0.001 - 주문 수량은 항상 버림
+        let qty = dec!(0.123456).round_to_increment(dec!(0.001), RoundingMode::TowardZero).unwrap();
+        assert_eq!(qty, dec!(0.123));
+        assert_eq!(qty.to_string(), "0.123");
+
+        // 정수 단위 (예: 원화 호가 1000원)
+        assert_eq!(
+            dec!(51234567).round_to_increment(dec!(1000), RoundingMode::Floor).unwrap(),
+            dec!(51234000)
+        );
+
+        assert!(dec!(1).round_to_increment(Decimal::ZERO, RoundingMode::HalfEven).is_err());
+        assert!(dec!(1).round_to_increment(dec!(-0.1), RoundingMode::HalfEven).is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("123.45").unwrap(), dec!(123.45));
+        assert_eq!(parse_decimal(" 0.00000001 ").unwrap(), dec!(0.00000001));
+        assert_eq!(parse_decimal("1e-8").unwrap(), dec!(0.00000001));
+        assert!(parse_decimal("not a number").is_err());
+    }
+}
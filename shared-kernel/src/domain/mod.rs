@@ -3,8 +3,9 @@
 //! 이 모듈은 여러 바운디드 컨텍스트에서 공유되는 핵심 도메인 개념들을 정의합니다.
 //! Value Object, Entity, Domain Event 등 공통으로 사용되는 도메인 객체들이 포함됩니다.
 
-pub mod model;
-pub mod repository;
+// 아직 구현되지 않은 모듈은 주석 처리
+// pub mod model;
+// pub mod repository;
 pub mod service;
 pub mod value_object;
-pub mod aggregate; 
\ No newline at end of file
+// pub mod aggregate;
@@ -0,0 +1,317 @@
+//! 클라이언트 주문 ID 생성기
+//!
+//! 거래소마다 클라이언트 주문 ID의 길이와 허용 문자가 다릅니다(바이낸스 36자,
+//! OKX는 영숫자 32자 등). 생성기는 전략 태그, 생성 시각, 순번을 고정 폭 영숫자로
+//! 인코딩하여 각 거래소 규칙을 지키면서도 체결 보고서에서 전략을 되찾을 수 있게 합니다.
+//!
+//! 형식: `[접두사][전략 태그][시각 9자][순번 4자]` (시각과 순번은 36진수 소문자)
+
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicU32, Ordering};
+use thiserror::Error;
+
+use crate::clock::SharedClock;
+use crate::types::ExchangeId;
+
+/// 시각 필드 폭 (36진수 밀리초)
+const TIME_WIDTH: usize = 9;
+/// 순번 필드 폭 (36진수)
+const SEQUENCE_WIDTH: usize = 4;
+/// 순번 최댓값 + 1
+const SEQUENCE_MODULUS: u32 = 36 * 36 * 36 * 36;
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// 클라이언트 주문 ID 오류
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClientOrderIdError {
+    #[error("잘못된 전략 태그 (영숫자만 허용): '{0}'")]
+    InvalidStrategyTag(String),
+
+    #[error("거래소 허용 문자가 아닌 접두사: '{0}'")]
+    InvalidPrefix(String),
+
+    #[error("클라이언트 주문 ID 길이 초과: {length}자 (최대 {max}자)")]
+    TooLong { length: usize, max: usize },
+
+    #[error("해석할 수 없는 클라이언트 주문 ID: '{0}'")]
+    Malformed(String),
+}
+
+/// 거래소별 클라이언트 주문 ID 규칙
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientOrderIdRules {
+    /// 최대 길이
+    max_length: usize,
+    /// 영숫자 외에 허용되는 문자
+    extra_chars: &'static str,
+    /// 모든 ID 앞에 붙는 접두사 (브로커 태그 등)
+    prefix: String,
+}
+
+impl ClientOrderIdRules {
+    /// 규칙 생성
+    pub fn new(max_length: usize, extra_chars: &'static str) -> Self {
+        Self {
+            max_length,
+            extra_chars,
+            prefix: String::new(),
+        }
+    }
+
+    /// 바이낸스 (`^[.A-Z:/a-z0-9_-]{1,36}$`)
+    pub fn binance() -> Self {
+        Self::new(36, ".:/_-")
+    }
+
+    /// 바이빗 (영숫자, `-`, `_` 36자)
+    pub fn bybit() -> Self {
+        Self::new(36, "_-")
+    }
+
+    /// OKX (영숫자 32자)
+    pub fn okx() -> Self {
+        Self::new(32, "")
+    }
+
+    /// 거래소 ID에 해당하는 규칙 (알 수 없는 거래소는 영숫자 32자)
+    pub fn for_exchange(exchange: &ExchangeId) -> Self {
+        match exchange.0.to_ascii_lowercase().as_str() {
+            "binance" => Self::binance(),
+            "bybit" => Self::bybit(),
+            "okx" => Self::okx(),
+            _ => Self::default(),
+        }
+    }
+
+    /// 접두사 지정
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Result<Self, ClientOrderIdError> {
+        let prefix = prefix.into();
+        if !prefix.chars().all(|c| self.allows(c)) {
+            return Err(ClientOrderIdError::InvalidPrefix(prefix));
+        }
+        self.prefix = prefix;
+        Ok(self)
+    }
+
+    /// 최대 길이
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// 접두사
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// 전략 태그의 최대 길이
+    pub fn max_strategy_tag_length(&self) -> usize {
+        self.max_length
+            .saturating_sub(self.prefix.len() + TIME_WIDTH + SEQUENCE_WIDTH)
+    }
+
+    /// 거래소 규칙에 맞는 ID인지 여부
+    pub fn is_valid(&self, id: &str) -> bool {
+        !id.is_empty() && id.len() <= self.max_length && id.chars().all(|c| self.allows(c))
+    }
+
+    /// 생성기가 만든 ID를 해석
+    pub fn decode(&self, id: &str) -> Result<DecodedClientOrderId, ClientOrderIdError> {
+        let malformed = || ClientOrderIdError::Malformed(id.to_string());
+
+        let body = id.strip_prefix(self.prefix.as_str()).ok_or_else(malformed)?;
+        if !body.is_ascii() || body.len() <= TIME_WIDTH + SEQUENCE_WIDTH {
+            return Err(malformed());
+        }
+
+        let (strategy, rest) = body.split_at(body.len() - TIME_WIDTH - SEQUENCE_WIDTH);
+        let (time, sequence) = rest.split_at(TIME_WIDTH);
+        if !strategy.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(malformed());
+        }
+
+        let millis = decode_base36(time).ok_or_else(malformed)?;
+        let sequence = decode_base36(sequence).ok_or_else(malformed)?;
+        let timestamp = i64::try_from(millis)
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or_else(malformed)?;
+
+        Ok(DecodedClientOrderId {
+            strategy: strategy.to_string(),
+            timestamp,
+            sequence: sequence as u32,
+        })
+    }
+
+    fn allows(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || self.extra_chars.contains(c)
+    }
+}
+
+impl Default for ClientOrderIdRules {
+    fn default() -> Self {
+        Self::okx()
+    }
+}
+
+/// 해석된 클라이언트 주문 ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedClientOrderId {
+    /// 전략 태그
+    pub strategy: String,
+    /// 생성 시각 (밀리초 정밀도)
+    pub timestamp: DateTime<Utc>,
+    /// 생성기 순번
+    pub sequence: u32,
+}
+
+/// 전략별 클라이언트 주문 ID 생성기
+#[derive(Debug)]
+pub struct ClientOrderIdGenerator {
+    rules: ClientOrderIdRules,
+    strategy: String,
+    clock: SharedClock,
+    sequence: AtomicU32,
+}
+
+impl ClientOrderIdGenerator {
+    /// 생성기 생성
+    ///
+    /// 전략 태그는 영숫자여야 하며 거래소 규칙의 최대 길이 안에 들어가야 합니다.
+    pub fn new(
+        rules: ClientOrderIdRules,
+        strategy: impl Into<String>,
+        clock: SharedClock,
+    ) -> Result<Self, ClientOrderIdError> {
+        let strategy = strategy.into();
+        if strategy.is_empty() || !strategy.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(ClientOrderIdError::InvalidStrategyTag(strategy));
+        }
+
+        let length = rules.prefix.len() + strategy.len() + TIME_WIDTH + SEQUENCE_WIDTH;
+        if length > rules.max_length {
+            return Err(ClientOrderIdError::TooLong {
+                length,
+                max: rules.max_length,
+            });
+        }
+
+        Ok(Self {
+            rules,
+            strategy,
+            clock,
+            sequence: AtomicU32::new(0),
+        })
+    }
+
+    /// 거래소 규칙
+    pub fn rules(&self) -> &ClientOrderIdRules {
+        &self.rules
+    }
+
+    /// 전략 태그
+    pub fn strategy(&self) -> &str {
+        &self.strategy
+    }
+
+    /// 다음 클라이언트 주문 ID 생성
+    pub fn next_id(&self) -> String {
+        let millis = u64::try_from(self.clock.now().timestamp_millis()).unwrap_or(0);
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) % SEQUENCE_MODULUS;
+
+        let mut id = String::with_capacity(self.rules.max_length);
+        id.push_str(&self.rules.prefix);
+        id.push_str(&self.strategy);
+        encode_base36(&mut id, millis, TIME_WIDTH);
+        encode_base36(&mut id, u64::from(sequence), SEQUENCE_WIDTH);
+        id
+    }
+
+    /// 이 생성기 규칙으로 ID 해석
+    pub fn decode(&self, id: &str) -> Result<DecodedClientOrderId, ClientOrderIdError> {
+        self.rules.decode(id)
+    }
+}
+
+/// 고정 폭 36진수 인코딩 (폭을 넘는 상위 자리는 버림)
+fn encode_base36(out: &mut String, mut value: u64, width: usize) {
+    let mut buf = [b'0'; 16];
+    for slot in buf[..width].iter_mut().rev() {
+        *slot = DIGITS[(value % 36) as usize];
+        value /= 36;
+    }
+    out.extend(buf[..width].iter().map(|&b| b as char));
+}
+
+fn decode_base36(digits: &str) -> Option<u64> {
+    digits.chars().try_fold(0u64, |acc, c| {
+        let digit = c.to_digit(36)?;
+        acc.checked_mul(36)?.checked_add(u64::from(digit))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SimulatedClock;
+    use chrono::TimeZone;
+    use std::sync::Arc;
+
+    fn clock() -> SharedClock {
+        Arc::new(SimulatedClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()))
+    }
+
+    #[test]
+    fn test_ids_follow_exchange_rules() {
+        let binance = ClientOrderIdRules::binance().with_prefix("x-CLY").unwrap();
+        let generator = ClientOrderIdGenerator::new(binance.clone(), "macross", clock()).unwrap();
+
+        let first = generator.next_id();
+        let second = generator.next_id();
+        assert!(first.starts_with("x-CLYmacross"));
+        assert!(binance.is_valid(&first));
+        assert!(first < second);
+
+        let okx = ClientOrderIdRules::for_exchange(&ExchangeId::new("OKX"));
+        let generator = ClientOrderIdGenerator::new(okx.clone(), "grid01", clock()).unwrap();
+        let id = generator.next_id();
+        assert!(okx.is_valid(&id));
+        assert!(id.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        // OKX는 `-`를 허용하지 않음
+        assert!(matches!(
+            ClientOrderIdRules::okx().with_prefix("x-"),
+            Err(ClientOrderIdError::InvalidPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_roundtrip() {
+        let generator = ClientOrderIdGenerator::new(ClientOrderIdRules::bybit(), "arb7", clock()).unwrap();
+        generator.next_id();
+        let id = generator.next_id();
+
+        let decoded = generator.decode(&id).unwrap();
+        assert_eq!(decoded.strategy, "arb7");
+        assert_eq!(decoded.sequence, 1);
+        assert_eq!(decoded.timestamp, Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+
+        assert!(matches!(generator.decode("manual-order"), Err(ClientOrderIdError::Malformed(_))));
+    }
+
+    #[test]
+    fn test_strategy_tag_validation() {
+        let rules = ClientOrderIdRules::okx();
+        assert_eq!(rules.max_strategy_tag_length(), 19);
+
+        let too_long = "a".repeat(20);
+        assert_eq!(
+            ClientOrderIdGenerator::new(rules.clone(), too_long, clock()).unwrap_err(),
+            ClientOrderIdError::TooLong { length: 33, max: 32 }
+        );
+        assert!(matches!(
+            ClientOrderIdGenerator::new(rules, "ma_cross", clock()),
+            Err(ClientOrderIdError::InvalidStrategyTag(_))
+        ));
+    }
+}
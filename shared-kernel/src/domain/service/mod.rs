@@ -0,0 +1,11 @@
+//! 도메인 서비스 모듈
+//!
+//! 특정 값 객체나 엔티티에 속하지 않는 공통 도메인 로직을 정의합니다.
+
+pub mod client_order_id;
+pub mod symbol_registry;
+
+pub use client_order_id::{
+    ClientOrderIdError, ClientOrderIdGenerator, ClientOrderIdRules, DecodedClientOrderId,
+};
+pub use symbol_registry::{SymbolFormat, SymbolListing, SymbolRegistry};
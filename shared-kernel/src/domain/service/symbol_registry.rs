@@ -0,0 +1,483 @@
+//! 거래소 심볼 매핑 레지스트리
+//!
+//! 거래소마다 심볼 표기 방식이 다릅니다 (`BTCUSDT`, `KRW-BTC`, `XBTUSD` 등).
+//! 구분자 없는 문자열에서 견적 자산을 추측하면 `1000PEPEUSDC` 같은 심볼에서
+//! 틀리기 때문에, 거래소가 제공하는 심볼 목록으로 레지스트리를 구성하고
+//! 등록된 심볼에 대해서만 거래소 고유 심볼 ↔ 표준 거래 쌍 변환을 수행합니다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::decimal::Decimal;
+use crate::domain::value_object::symbol::{SymbolError, SymbolPair};
+use crate::types::ExchangeId;
+
+/// 거래소 고유 심볼 표기 형식
+///
+/// 심볼 제약 정보가 없는 거래 쌍의 거래소 고유 심볼을 만들 때 사용합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SymbolFormat {
+    /// 자산 사이 구분자 (없으면 이어붙임)
+    pub delimiter: Option<char>,
+    /// 견적 자산을 앞에 표기하는지 여부 (예: Upbit `KRW-BTC`)
+    pub quote_first: bool,
+}
+
+impl SymbolFormat {
+    /// 구분자 없는 형식 (예: `BTCUSDT`)
+    pub const fn concatenated() -> Self {
+        Self {
+            delimiter: None,
+            quote_first: false,
+        }
+    }
+
+    /// 구분자가 있는 형식 (예: `BTC-USDT`)
+    pub const fn delimited(delimiter: char) -> Self {
+        Self {
+            delimiter: Some(delimiter),
+            quote_first: false,
+        }
+    }
+
+    /// 견적 자산을 앞에 표기하도록 변경
+    pub const fn with_quote_first(mut self) -> Self {
+        self.quote_first = true;
+        self
+    }
+
+    /// 거래 쌍을 이 형식의 거래소 고유 심볼로 변환
+    pub fn format(&self, pair: &SymbolPair) -> String {
+        let (first, second) = if self.quote_first {
+            (pair.quote(), pair.base())
+        } else {
+            (pair.base(), pair.quote())
+        };
+
+        match self.delimiter {
+            Some(delimiter) => format!("{}{}{}", first, delimiter, second),
+            None => format!("{}{}", first, second),
+        }
+    }
+}
+
+/// 거래소에 상장된 심볼 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolListing {
+    /// 거래소 고유 심볼 (예: `XBTUSD`)
+    pub native: String,
+    /// 거래 쌍 (레지스트리 조회 결과에서는 자산 별칭이 정규화된 값)
+    pub pair: SymbolPair,
+    /// 호가 단위
+    pub tick_size: Option<Decimal>,
+    /// 수량 단위
+    pub step_size: Option<Decimal>,
+    /// 최소 주문 수량
+    pub min_quantity: Option<Decimal>,
+    /// 최소 주문 금액
+    pub min_notional: Option<Decimal>,
+}
+
+impl SymbolListing {
+    /// 새로운 상장 정보 생성
+    pub fn new(native: impl Into<String>, pair: SymbolPair) -> Self {
+        Self {
+            native: native.into(),
+            pair,
+            tick_size: None,
+            step_size: None,
+            min_quantity: None,
+            min_notional: None,
+        }
+    }
+
+    /// 호가 단위 설정
+    pub fn with_tick_size(mut self, tick_size: Decimal) -> Self {
+        self.tick_size = Some(tick_size);
+        self
+    }
+
+    /// 수량 단위 설정
+    pub fn with_step_size(mut self, step_size: Decimal) -> Self {
+        self.step_size = Some(step_size);
+        self
+    }
+
+    /// 최소 주문 수량 설정
+    pub fn with_min_quantity(mut self, min_quantity: Decimal) -> Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    /// 최소 주문 금액 설정
+    pub fn with_min_notional(mut self, min_notional: Decimal) -> Self {
+        self.min_notional = Some(min_notional);
+        self
+    }
+}
+
+/// 거래소별 심볼 색인
+#[derive(Debug, Clone)]
+struct ExchangeSymbols {
+    format: SymbolFormat,
+    /// 거래소가 제공한 원본 목록 (별칭 변경 시 재색인에 사용)
+    source: Vec<SymbolListing>,
+    /// 별칭이 정규화된 목록
+    listings: Vec<SymbolListing>,
+    by_native: HashMap<String, usize>,
+    by_pair: HashMap<SymbolPair, usize>,
+}
+
+/// 거래소 심볼 매핑 레지스트리
+///
+/// 거래소별로 등록된 상장 목록을 기준으로 거래소 고유 심볼과 표준 거래 쌍을
+/// 양방향으로 변환합니다. 등록되지 않은 심볼은 추측하지 않고 오류를 반환합니다.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolRegistry {
+    exchanges: HashMap<ExchangeId, ExchangeSymbols>,
+    asset_aliases: HashMap<String, String>,
+}
+
+impl SymbolRegistry {
+    /// 빈 레지스트리 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 널리 쓰이는 자산 별칭(XBT→BTC, XDG→DOGE)이 등록된 레지스트리 생성
+    pub fn with_common_aliases() -> Self {
+        let mut registry = Self::new();
+        registry.asset_aliases.insert("XBT".to_string(), "BTC".to_string());
+        registry.asset_aliases.insert("XDG".to_string(), "DOGE".to_string());
+        registry
+    }
+
+    /// 자산 별칭 추가
+    ///
+    /// 이미 등록된 거래소의 심볼도 새 별칭 기준으로 다시 색인합니다.
+    pub fn add_asset_alias(
+        &mut self,
+        alias: impl Into<String>,
+        canonical: impl Into<String>,
+    ) -> Result<(), SymbolError> {
+        let alias = alias.into().trim().to_uppercase();
+        let canonical = canonical.into().trim().to_uppercase();
+        let previous = self.asset_aliases.insert(alias.clone(), canonical);
+
+        if let Err(e) = self.reindex_all() {
+            // 충돌이 발생하면 별칭 추가 이전 상태로 되돌림
+            match previous {
+                Some(previous) => self.asset_aliases.insert(alias, previous),
+                None => self.asset_aliases.remove(&alias),
+            };
+            self.reindex_all()?;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// 자산 별칭을 표준 자산 코드로 변환
+    pub fn canonical_asset(&self, asset: &str) -> String {
+        let asset = asset.trim().to_uppercase();
+        self.asset_aliases.get(&asset).cloned().unwrap_or(asset)
+    }
+
+    /// 거래 쌍의 자산 별칭을 정규화
+    pub fn canonical_pair(&self, pair: &SymbolPair) -> SymbolPair {
+        SymbolPair::new(self.canonical_asset(pair.base()), self.canonical_asset(pair.quote()))
+    }
+
+    /// 거래소 심볼 목록 등록
+    ///
+    /// 같은 거래소가 이미 등록되어 있으면 목록 전체를 교체합니다.
+    /// 하나의 거래 쌍에 여러 고유 심볼이 있으면 먼저 등록된 심볼이
+    /// `to_native`의 결과가 되며, 모든 고유 심볼은 `to_canonical`로 조회할 수 있습니다.
+    pub fn register_exchange(
+        &mut self,
+        exchange: ExchangeId,
+        format: SymbolFormat,
+        listings: impl IntoIterator<Item = SymbolListing>,
+    ) -> Result<(), SymbolError> {
+        let symbols = self.index(&exchange, format, listings.into_iter().collect())?;
+        self.exchanges.insert(exchange, symbols);
+        Ok(())
+    }
+
+    /// 제약 정보 없이 거래 쌍 목록만으로 거래소 등록
+    ///
+    /// 거래소 고유 심볼은 `format`에 따라 생성합니다.
+    pub fn register_pairs(
+        &mut self,
+        exchange: ExchangeId,
+        format: SymbolFormat,
+        pairs: impl IntoIterator<Item = SymbolPair>,
+    ) -> Result<(), SymbolError> {
+        let listings = pairs
+            .into_iter()
+            .map(|pair| SymbolListing::new(format.format(&pair), pair));
+        self.register_exchange(exchange, format, listings)
+    }
+
+    /// 거래소 등록 여부
+    pub fn contains_exchange(&self, exchange: &ExchangeId) -> bool {
+        self.exchanges.contains_key(exchange)
+    }
+
+    /// 등록된 거래소의 심볼 표기 형식
+    pub fn format(&self, exchange: &ExchangeId) -> Result<SymbolFormat, SymbolError> {
+        Ok(self.exchange(exchange)?.format)
+    }
+
+    /// 거래소 고유 심볼을 표준 거래 쌍으로 변환
+    pub fn to_canonical(&self, exchange: &ExchangeId, native: &str) -> Result<SymbolPair, SymbolError> {
+        let symbols = self.exchange(exchange)?;
+        symbols
+            .by_native
+            .get(&native_key(native))
+            .map(|&index| symbols.listings[index].pair.clone())
+            .ok_or_else(|| SymbolError::UnknownSymbol {
+                exchange: exchange.clone(),
+                input: native.to_string(),
+            })
+    }
+
+    /// 표준 거래 쌍을 거래소 고유 심볼로 변환
+    pub fn to_native(&self, exchange: &ExchangeId, pair: &SymbolPair) -> Result<String, SymbolError> {
+        Ok(self.listing(exchange, pair)?.native.clone())
+    }
+
+    /// 거래 쌍의 상장 정보 조회
+    pub fn listing(&self, exchange: &ExchangeId, pair: &SymbolPair) -> Result<&SymbolListing, SymbolError> {
+        let symbols = self.exchange(exchange)?;
+        symbols
+            .by_pair
+            .get(&self.canonical_pair(pair))
+            .map(|&index| &symbols.listings[index])
+            .ok_or_else(|| SymbolError::UnknownSymbol {
+                exchange: exchange.clone(),
+                input: pair.to_string(),
+            })
+    }
+
+    /// 거래소의 전체 상장 목록
+    pub fn listings(&self, exchange: &ExchangeId) -> Result<&[SymbolListing], SymbolError> {
+        Ok(&self.exchange(exchange)?.listings)
+    }
+
+    fn exchange(&self, exchange: &ExchangeId) -> Result<&ExchangeSymbols, SymbolError> {
+        self.exchanges
+            .get(exchange)
+            .ok_or_else(|| SymbolError::UnknownExchange(exchange.clone()))
+    }
+
+    fn reindex_all(&mut self) -> Result<(), SymbolError> {
+        let mut rebuilt = HashMap::with_capacity(self.exchanges.len());
+        for (exchange, symbols) in &self.exchanges {
+            let reindexed = self.index(exchange, symbols.format, symbols.source.clone())?;
+            rebuilt.insert(exchange.clone(), reindexed);
+        }
+        self.exchanges = rebuilt;
+        Ok(())
+    }
+
+    fn index(
+        &self,
+        exchange: &ExchangeId,
+        format: SymbolFormat,
+        source: Vec<SymbolListing>,
+    ) -> Result<ExchangeSymbols, SymbolError> {
+        let mut listings: Vec<SymbolListing> = Vec::with_capacity(source.len());
+        let mut by_native = HashMap::with_capacity(source.len());
+        let mut by_pair = HashMap::with_capacity(source.len());
+
+        for listing in &source {
+            let mut listing = listing.clone();
+            listing.pair = self.canonical_pair(&listing.pair);
+            let key = native_key(&listing.native);
+
+            if let Some(&existing) = by_native.get(&key) {
+                let existing: &SymbolListing = &listings[existing];
+                if existing.pair == listing.pair {
+                    continue;
+                }
+                return Err(SymbolError::ConflictingListing {
+                    exchange: exchange.clone(),
+                    native: listing.native,
+                    existing: existing.pair.to_string(),
+                    requested: listing.pair.to_string(),
+                });
+            }
+
+            let index = listings.len();
+            by_native.insert(key, index);
+            by_pair.entry(listing.pair.clone()).or_insert(index);
+            listings.push(listing);
+        }
+
+        Ok(ExchangeSymbols {
+            format,
+            source,
+            listings,
+            by_native,
+            by_pair,
+        })
+    }
+}
+
+/// 거래소 고유 심볼 조회 키 (대소문자 무시)
+fn native_key(native: &str) -> String {
+    native.trim().to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn binance() -> ExchangeId {
+        ExchangeId::new("binance")
+    }
+
+    fn upbit() -> ExchangeId {
+        ExchangeId::new("upbit")
+    }
+
+    fn kraken() -> ExchangeId {
+        ExchangeId::new("kraken")
+    }
+
+    #[test]
+    fn test_concatenated_symbols_without_guessing() {
+        let mut registry = SymbolRegistry::new();
+        registry
+            .register_exchange(
+                binance(),
+                SymbolFormat::concatenated(),
+                vec![
+                    SymbolListing::new("1000PEPEUSDC", SymbolPair::new("1000PEPE", "USDC"))
+                        .with_tick_size(dec!(0.0000001))
+                        .with_step_size(dec!(1)),
+                    SymbolListing::new("BTCUSDT", SymbolPair::new("BTC", "USDT")),
+                ],
+            )
+            .unwrap();
+
+        let pair = registry.to_canonical(&binance(), "1000PEPEUSDC").unwrap();
+        assert_eq!(pair, SymbolPair::new("1000PEPE", "USDC"));
+        assert_eq!(registry.to_canonical(&binance(), "btcusdt").unwrap(), SymbolPair::new("BTC", "USDT"));
+        assert_eq!(registry.to_native(&binance(), &pair).unwrap(), "1000PEPEUSDC");
+
+        let listing = registry.listing(&binance(), &pair).unwrap();
+        assert_eq!(listing.tick_size, Some(dec!(0.0000001)));
+        assert_eq!(listing.step_size, Some(dec!(1)));
+
+        // 등록되지 않은 심볼은 추측하지 않음
+        assert!(registry.to_canonical(&binance(), "ETHUSDT").is_err());
+    }
+
+    #[test]
+    fn test_quote_first_format() {
+        let mut registry = SymbolRegistry::new();
+        let format = SymbolFormat::delimited('-').with_quote_first();
+        registry
+            .register_pairs(upbit(), format, vec![SymbolPair::new("BTC", "KRW"), SymbolPair::new("ETH", "BTC")])
+            .unwrap();
+
+        assert_eq!(registry.to_canonical(&upbit(), "KRW-BTC").unwrap(), SymbolPair::new("BTC", "KRW"));
+        assert_eq!(registry.to_canonical(&upbit(), "BTC-ETH").unwrap(), SymbolPair::new("ETH", "BTC"));
+        assert_eq!(registry.to_native(&upbit(), &SymbolPair::new("BTC", "KRW")).unwrap(), "KRW-BTC");
+        assert_eq!(registry.format(&upbit()).unwrap(), format);
+    }
+
+    #[test]
+    fn test_asset_aliases() {
+        let mut registry = SymbolRegistry::with_common_aliases();
+        registry
+            .register_exchange(
+                kraken(),
+                SymbolFormat::concatenated(),
+                vec![
+                    SymbolListing::new("XBTUSD", SymbolPair::new("XBT", "USD")),
+                    SymbolListing::new("XXBTZUSD", SymbolPair::new("XBT", "USD")),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(registry.to_canonical(&kraken(), "XBTUSD").unwrap(), SymbolPair::new("BTC", "USD"));
+        assert_eq!(registry.to_canonical(&kraken(), "XXBTZUSD").unwrap(), SymbolPair::new("BTC", "USD"));
+
+        // 표준 표기와 별칭 표기 모두 같은 고유 심볼로 변환
+        assert_eq!(registry.to_native(&kraken(), &SymbolPair::new("BTC", "USD")).unwrap(), "XBTUSD");
+        assert_eq!(registry.to_native(&kraken(), &SymbolPair::new("XBT", "USD")).unwrap(), "XBTUSD");
+    }
+
+    #[test]
+    fn test_alias_added_after_registration() {
+        let mut registry = SymbolRegistry::new();
+        registry
+            .register_pairs(kraken(), SymbolFormat::delimited('/'), vec![SymbolPair::new("XDG", "USD")])
+            .unwrap();
+        assert_eq!(registry.to_canonical(&kraken(), "XDG/USD").unwrap(), SymbolPair::new("XDG", "USD"));
+
+        registry.add_asset_alias("xdg", "doge").unwrap();
+        assert_eq!(registry.to_canonical(&kraken(), "XDG/USD").unwrap(), SymbolPair::new("DOGE", "USD"));
+        assert_eq!(registry.canonical_asset("XDG"), "DOGE");
+    }
+
+    #[test]
+    fn test_conflicting_listings() {
+        let mut registry = SymbolRegistry::new();
+        let result = registry.register_exchange(
+            binance(),
+            SymbolFormat::concatenated(),
+            vec![
+                SymbolListing::new("BTCUSDT", SymbolPair::new("BTC", "USDT")),
+                SymbolListing::new("btcusdt", SymbolPair::new("BTCU", "SDT")),
+            ],
+        );
+        assert!(matches!(result, Err(SymbolError::ConflictingListing { .. })));
+        assert!(!registry.contains_exchange(&binance()));
+
+        // 별칭으로 거래 쌍이 합쳐지면 먼저 등록된 고유 심볼이 우선
+        registry
+            .register_exchange(
+                binance(),
+                SymbolFormat::concatenated(),
+                vec![
+                    SymbolListing::new("WBTCUSDT", SymbolPair::new("WBTC", "USDT")),
+                    SymbolListing::new("BTCUSDT", SymbolPair::new("BTC", "USDT")),
+                ],
+            )
+            .unwrap();
+        assert!(registry.add_asset_alias("WBTC", "BTC").is_ok());
+        assert_eq!(registry.to_native(&binance(), &SymbolPair::new("BTC", "USDT")).unwrap(), "WBTCUSDT");
+    }
+
+    #[test]
+    fn test_error_names_exchange_and_input() {
+        let mut registry = SymbolRegistry::new();
+        registry
+            .register_pairs(upbit(), SymbolFormat::delimited('-').with_quote_first(), vec![SymbolPair::new("BTC", "KRW")])
+            .unwrap();
+
+        let err = registry.to_canonical(&upbit(), "KRW-DOGE").unwrap_err();
+        assert_eq!(
+            err,
+            SymbolError::UnknownSymbol {
+                exchange: upbit(),
+                input: "KRW-DOGE".to_string()
+            }
+        );
+        let message = err.to_string();
+        assert!(message.contains("upbit"));
+        assert!(message.contains("KRW-DOGE"));
+
+        let err = registry.to_native(&upbit(), &SymbolPair::new("ETH", "KRW")).unwrap_err();
+        assert!(err.to_string().contains("ETH/KRW"));
+
+        let err = registry.to_canonical(&binance(), "BTCUSDT").unwrap_err();
+        assert_eq!(err, SymbolError::UnknownExchange(binance()));
+        assert!(err.to_string().contains("binance"));
+    }
+}
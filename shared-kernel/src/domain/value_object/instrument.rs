@@ -0,0 +1,406 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::symbol::SymbolPair;
+use crate::decimal::{Decimal, DecimalExt};
+use crate::error::CoreError;
+use crate::types::{AssetType, Result};
+
+/// 파생상품 증거금/정산 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContractMargin {
+    /// 견적 자산으로 증거금과 손익을 정산 (예: USDT 무기한)
+    #[serde(rename = "linear")]
+    Linear,
+    /// 기본 자산으로 증거금과 손익을 정산 (예: BTC 마진 무기한)
+    #[serde(rename = "inverse")]
+    Inverse,
+}
+
+/// 옵션 유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OptionType {
+    #[serde(rename = "call")]
+    Call,
+    #[serde(rename = "put")]
+    Put,
+}
+
+/// 옵션 행사 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExerciseStyle {
+    /// 만기일에만 행사 가능
+    #[serde(rename = "european")]
+    European,
+    /// 만기 전 언제든 행사 가능
+    #[serde(rename = "american")]
+    American,
+}
+
+/// 상품 종류별 계약 조건
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InstrumentKind {
+    /// 현물
+    Spot,
+    /// 무기한 선물
+    Perpetual {
+        margin: ContractMargin,
+    },
+    /// 만기 선물
+    Future {
+        margin: ContractMargin,
+        expiry: DateTime<Utc>,
+    },
+    /// 옵션
+    Option {
+        margin: ContractMargin,
+        expiry: DateTime<Utc>,
+        strike: Decimal,
+        option_type: OptionType,
+        exercise_style: ExerciseStyle,
+    },
+}
+
+/// 거래 상품(Instrument) 값 객체
+///
+/// `SymbolPair`가 거래되는 두 자산만 표현한다면, `Instrument`는 현물/무기한/만기 선물/옵션의
+/// 계약 조건(계약 크기, 정산 통화, 기초 자산, 만기, 행사가)까지 포함합니다.
+///
+/// 표시 형식은 통합 심볼 표기를 따릅니다.
+/// - 현물: `BTC/USDT`
+/// - 무기한: `BTC/USDT:USDT`
+/// - 만기 선물: `BTC/USD:BTC-241227`
+/// - 옵션: `BTC/USD:BTC-241227-60000-C`
+///
+/// 역직렬화도 생성자를 거치므로 계약 크기와 행사가가 0 이하이면 거부됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "InstrumentFields")]
+pub struct Instrument {
+    /// 거래 쌍
+    pair: SymbolPair,
+    /// 상품 종류와 계약 조건
+    kind: InstrumentKind,
+    /// 기초 자산 (예: 'BTC')
+    underlying: String,
+    /// 정산 통화 (예: 'USDT', 'BTC')
+    settlement: String,
+    /// 계약 1개당 기초 자산 수량 (현물은 1)
+    contract_size: Decimal,
+}
+
+/// 역직렬화용 필드 (검증 전)
+#[derive(Deserialize)]
+struct InstrumentFields {
+    pair: SymbolPair,
+    kind: InstrumentKind,
+    underlying: String,
+    settlement: String,
+    contract_size: Decimal,
+}
+
+impl TryFrom<InstrumentFields> for Instrument {
+    type Error = CoreError;
+
+    fn try_from(fields: InstrumentFields) -> Result<Self> {
+        if let InstrumentKind::Option { strike, .. } = &fields.kind {
+            if *strike <= Decimal::ZERO {
+                return Err(CoreError::Validation(format!("행사가는 0보다 커야 합니다: {}", strike)));
+            }
+        }
+
+        Self::with_kind(fields.pair, fields.kind)
+            .with_underlying(fields.underlying)
+            .with_settlement(fields.settlement)
+            .with_contract_size(fields.contract_size)
+    }
+}
+
+impl Instrument {
+    fn with_kind(pair: SymbolPair, kind: InstrumentKind) -> Self {
+        let settlement = match &kind {
+            InstrumentKind::Spot => pair.quote(),
+            InstrumentKind::Perpetual { margin }
+            | InstrumentKind::Future { margin, .. }
+            | InstrumentKind::Option { margin, .. } => match margin {
+                ContractMargin::Linear => pair.quote(),
+                ContractMargin::Inverse => pair.base(),
+            },
+        }
+        .to_string();
+
+        Self {
+            underlying: pair.base().to_string(),
+            settlement,
+            pair,
+            kind,
+            contract_size: Decimal::ONE,
+        }
+    }
+
+    /// 현물 상품 생성
+    pub fn spot(pair: SymbolPair) -> Self {
+        Self::with_kind(pair, InstrumentKind::Spot)
+    }
+
+    /// 무기한 선물 생성
+    ///
+    /// 정산 통화는 선형이면 견적 자산, 역방향이면 기본 자산으로 설정됩니다.
+    pub fn perpetual(pair: SymbolPair, margin: ContractMargin) -> Self {
+        Self::with_kind(pair, InstrumentKind::Perpetual { margin })
+    }
+
+    /// 만기 선물 생성
+    pub fn future(pair: SymbolPair, margin: ContractMargin, expiry: DateTime<Utc>) -> Self {
+        Self::with_kind(pair, InstrumentKind::Future { margin, expiry })
+    }
+
+    /// 옵션 생성
+    pub fn option(
+        pair: SymbolPair,
+        margin: ContractMargin,
+        expiry: DateTime<Utc>,
+        strike: Decimal,
+        option_type: OptionType,
+        exercise_style: ExerciseStyle,
+    ) -> Self {
+        Self::with_kind(
+            pair,
+            InstrumentKind::Option {
+                margin,
+                expiry,
+                strike,
+                option_type,
+                exercise_style,
+            },
+        )
+    }
+
+    /// 계약 크기 설정 (0 이하이면 오류)
+    pub fn with_contract_size(mut self, contract_size: Decimal) -> Result<Self> {
+        if contract_size <= Decimal::ZERO {
+            return Err(CoreError::Validation(format!(
+                "계약 크기는 0보다 커야 합니다: {} ({})", contract_size, self
+            )));
+        }
+        self.contract_size = contract_size;
+        Ok(self)
+    }
+
+    /// 기초 자산 설정 (기본값은 기본 자산)
+    pub fn with_underlying(mut self, underlying: impl Into<String>) -> Self {
+        self.underlying = underlying.into().to_uppercase();
+        self
+    }
+
+    /// 정산 통화 설정 (기본값은 증거금 방식에 따라 결정)
+    pub fn with_settlement(mut self, settlement: impl Into<String>) -> Self {
+        self.settlement = settlement.into().to_uppercase();
+        self
+    }
+
+    /// 거래 쌍 조회
+    pub fn pair(&self) -> &SymbolPair {
+        &self.pair
+    }
+
+    /// 상품 종류 조회
+    pub fn kind(&self) -> &InstrumentKind {
+        &self.kind
+    }
+
+    /// 기초 자산 조회
+    pub fn underlying(&self) -> &str {
+        &self.underlying
+    }
+
+    /// 정산 통화 조회
+    pub fn settlement(&self) -> &str {
+        &self.settlement
+    }
+
+    /// 계약 크기 조회
+    pub fn contract_size(&self) -> Decimal {
+        self.contract_size
+    }
+
+    /// 자산 유형
+    pub fn asset_type(&self) -> AssetType {
+        match self.kind {
+            InstrumentKind::Spot => AssetType::Spot,
+            InstrumentKind::Perpetual { .. } | InstrumentKind::Future { .. } => AssetType::Futures,
+            InstrumentKind::Option { .. } => AssetType::Option,
+        }
+    }
+
+    /// 파생상품 여부
+    pub fn is_derivative(&self) -> bool {
+        !matches!(self.kind, InstrumentKind::Spot)
+    }
+
+    /// 증거금 방식 (현물은 `None`)
+    pub fn margin(&self) -> Option<ContractMargin> {
+        match self.kind {
+            InstrumentKind::Spot => None,
+            InstrumentKind::Perpetual { margin }
+            | InstrumentKind::Future { margin, .. }
+            | InstrumentKind::Option { margin, .. } => Some(margin),
+        }
+    }
+
+    /// 만기 (현물과 무기한은 `None`)
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        match self.kind {
+            InstrumentKind::Future { expiry, .. } | InstrumentKind::Option { expiry, .. } => Some(expiry),
+            _ => None,
+        }
+    }
+
+    /// 주어진 시각 기준 만기 도래 여부
+    pub fn is_expired(&self, at: DateTime<Utc>) -> bool {
+        self.expiry().is_some_and(|expiry| at >= expiry)
+    }
+
+    /// 명목 가치 계산 (정산 통화 기준)
+    ///
+    /// 역방향 선물은 `수량 × 계약 크기 / 가격`, 그 외는 `수량 × 계약 크기 × 가격`입니다.
+    /// 옵션의 `price`는 프리미엄으로 취급합니다.
+    pub fn notional_value(&self, price: Decimal, quantity: Decimal) -> Result<Decimal> {
+        let contracts = quantity.try_mul(self.contract_size)?;
+        match self.kind {
+            InstrumentKind::Perpetual { margin: ContractMargin::Inverse }
+            | InstrumentKind::Future { margin: ContractMargin::Inverse, .. } => contracts.try_div(price),
+            _ => contracts.try_mul(price),
+        }
+    }
+}
+
+impl From<SymbolPair> for Instrument {
+    fn from(pair: SymbolPair) -> Self {
+        Instrument::spot(pair)
+    }
+}
+
+impl fmt::Display for Instrument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pair)?;
+        if !self.is_derivative() {
+            return Ok(());
+        }
+
+        write!(f, ":{}", self.settlement)?;
+        if let Some(expiry) = self.expiry() {
+            write!(f, "-{}", expiry.format("%y%m%d"))?;
+        }
+        if let InstrumentKind::Option { strike, option_type, .. } = &self.kind {
+            let side = match option_type {
+                OptionType::Call => "C",
+                OptionType::Put => "P",
+            };
+            write!(f, "-{}-{}", strike.normalize(), side)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn expiry() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 12, 27, 8, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_spot_instrument() {
+        let instrument = Instrument::from(SymbolPair::new("BTC", "USDT"));
+        assert_eq!(instrument.to_string(), "BTC/USDT");
+        assert_eq!(instrument.asset_type(), AssetType::Spot);
+        assert_eq!(instrument.settlement(), "USDT");
+        assert_eq!(instrument.contract_size(), Decimal::ONE);
+        assert!(!instrument.is_derivative());
+        assert!(instrument.expiry().is_none());
+    }
+
+    #[test]
+    fn test_perpetuals() {
+        let linear = Instrument::perpetual(SymbolPair::new("BTC", "USDT"), ContractMargin::Linear)
+            .with_contract_size(dec!(0.001))
+            .unwrap();
+        assert_eq!(linear.to_string(), "BTC/USDT:USDT");
+        assert_eq!(linear.asset_type(), AssetType::Futures);
+        assert_eq!(linear.notional_value(dec!(60000), dec!(10)).unwrap(), dec!(600));
+
+        // 역방향 무기한: 계약 1개 = 100 USD, 정산은 BTC
+        let inverse = Instrument::perpetual(SymbolPair::new("BTC", "USD"), ContractMargin::Inverse)
+            .with_contract_size(dec!(100))
+            .unwrap();
+        assert_eq!(inverse.to_string(), "BTC/USD:BTC");
+        assert_eq!(inverse.settlement(), "BTC");
+        assert_eq!(inverse.notional_value(dec!(50000), dec!(10)).unwrap(), dec!(0.02));
+
+        assert!(linear.clone().with_contract_size(Decimal::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_dated_future() {
+        let future = Instrument::future(SymbolPair::new("BTC", "USD"), ContractMargin::Inverse, expiry());
+        assert_eq!(future.to_string(), "BTC/USD:BTC-241227");
+        assert_eq!(future.expiry(), Some(expiry()));
+        assert!(!future.is_expired(Utc.with_ymd_and_hms(2024, 12, 27, 7, 59, 59).unwrap()));
+        assert!(future.is_expired(expiry()));
+    }
+
+    #[test]
+    fn test_option() {
+        let option = Instrument::option(
+            SymbolPair::new("BTC", "USD"),
+            ContractMargin::Inverse,
+            expiry(),
+            dec!(60000.00),
+            OptionType::Call,
+            ExerciseStyle::European,
+        )
+        .with_underlying("btc_usd");
+
+        assert_eq!(option.to_string(), "BTC/USD:BTC-241227-60000-C");
+        assert_eq!(option.asset_type(), AssetType::Option);
+        assert_eq!(option.underlying(), "BTC_USD");
+        assert_eq!(option.margin(), Some(ContractMargin::Inverse));
+
+        // 옵션은 프리미엄 × 수량
+        assert_eq!(option.notional_value(dec!(0.05), dec!(2)).unwrap(), dec!(0.10));
+    }
+
+    #[test]
+    fn test_instrument_serialization() {
+        let option = Instrument::option(
+            SymbolPair::new("ETH", "USDT"),
+            ContractMargin::Linear,
+            expiry(),
+            dec!(3500),
+            OptionType::Put,
+            ExerciseStyle::American,
+        );
+
+        let json = serde_json::to_value(&option).unwrap();
+        assert_eq!(json["kind"]["type"], "option");
+        assert_eq!(json["kind"]["strike"], "3500");
+        assert_eq!(json["kind"]["option_type"], "put");
+
+        let parsed: Instrument = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed, option);
+
+        // 역직렬화도 생성자와 같은 검증을 거침
+        let mut zero_size = json.clone();
+        zero_size["contract_size"] = "0".into();
+        assert!(serde_json::from_value::<Instrument>(zero_size).is_err());
+
+        let mut negative_strike = json;
+        negative_strike["kind"]["strike"] = "-3500".into();
+        assert!(serde_json::from_value::<Instrument>(negative_strike).is_err());
+    }
+}
@@ -3,12 +3,15 @@
 //! 이 모듈은 여러 도메인에서 공유되는 값 객체들을 정의합니다.
 //! 값 객체는 식별자가 없고 속성에 의해 정의되는 불변 객체입니다.
 
-pub mod money;
+// 아직 구현되지 않은 모듈은 주석 처리
+// pub mod money;
+pub mod instrument;
 pub mod symbol;
 pub mod timeframe;
-pub mod timestamp;
+// pub mod timestamp;
 
-pub use money::Money;
-pub use symbol::SymbolPair;
-pub use timeframe::Timeframe;
-pub use timestamp::Timestamp; 
\ No newline at end of file
+// pub use money::Money;
+pub use instrument::{ContractMargin, ExerciseStyle, Instrument, InstrumentKind, OptionType};
+pub use symbol::{SymbolError, SymbolPair};
+pub use timeframe::{Timeframe, TimeframeError, TimeframeUnit};
+// pub use timestamp::Timestamp;
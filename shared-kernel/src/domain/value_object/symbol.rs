@@ -1,6 +1,41 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::types::ExchangeId;
+
+/// 심볼 변환 오류
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SymbolError {
+    #[error("잘못된 심볼 형식 (BASE/QUOTE 필요): '{0}'")]
+    InvalidFormat(String),
+
+    #[error("등록되지 않은 거래소: {0}")]
+    UnknownExchange(ExchangeId),
+
+    #[error("거래소 {exchange}에서 심볼 '{input}'을(를) 변환할 수 없습니다")]
+    UnknownSymbol {
+        exchange: ExchangeId,
+        input: String,
+    },
+
+    #[error("거래소 {exchange}의 심볼 '{native}'이(가) {existing}와 {requested}에 중복 매핑되었습니다")]
+    ConflictingListing {
+        exchange: ExchangeId,
+        native: String,
+        existing: String,
+        requested: String,
+    },
+
+    #[error("심볼 '{native}'의 소수 자릿수 {precision}이(가) 지원 범위(최대 {max})를 넘습니다")]
+    InvalidPrecision {
+        native: String,
+        precision: u32,
+        max: u32,
+    },
+}
 
 /// 암호화폐 거래 쌍(Symbol Pair) 값 객체
 ///
@@ -45,51 +80,23 @@ impl SymbolPair {
             None => format!("{}{}", self.base, self.quote),
         }
     }
+}
 
-    /// 문자열에서 심볼 쌍 파싱
-    /// 
-    /// `BTC/USDT`, `BTC-USDT`, `BTCUSDT` 등의 형식 지원
-    pub fn from_str(input: &str) -> Option<Self> {
-        let input = input.trim().to_uppercase();
-        
-        // 형식에 따라 분리
-        let (base, quote) = if input.contains('/') {
-            let parts: Vec<&str> = input.split('/').collect();
-            (parts.get(0)?.trim(), parts.get(1)?.trim())
-        } else if input.contains('-') {
-            let parts: Vec<&str> = input.split('-').collect();
-            (parts.get(0)?.trim(), parts.get(1)?.trim())
-        } else if input.contains('_') {
-            let parts: Vec<&str> = input.split('_').collect();
-            (parts.get(0)?.trim(), parts.get(1)?.trim())
-        } else {
-            // 구분자가 없을 경우 일반적으로 마지막 3-4 글자가 quote
-            if input.len() <= 4 {
-                return None;
-            }
-            
-            let common_quotes = ["USDT", "USD", "BTC", "ETH", "EUR", "JPY"];
-            
-            for quote in common_quotes.iter() {
-                if input.ends_with(quote) {
-                    let quote_len = quote.len();
-                    let base = &input[..input.len() - quote_len];
-                    if !base.is_empty() {
-                        return Some(SymbolPair::new(base, *quote));
-                    }
-                }
+/// 표준 표기(`BASE/QUOTE`) 파싱
+///
+/// 구분자가 없는 거래소 고유 심볼(`BTCUSDT`, `KRW-BTC` 등)은 형식만으로
+/// base/quote를 구분할 수 없으므로 `SymbolRegistry`를 통해 변환해야 합니다.
+impl FromStr for SymbolPair {
+    type Err = SymbolError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parts = input.trim().split('/');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(base), Some(quote), None) if !base.trim().is_empty() && !quote.trim().is_empty() => {
+                Ok(SymbolPair::new(base.trim(), quote.trim()))
             }
-            
-            // 기본값: 마지막 4글자를 quote로 가정
-            let split_at = input.len().saturating_sub(4);
-            (&input[..split_at], &input[split_at..])
-        };
-        
-        if base.is_empty() || quote.is_empty() {
-            return None;
+            _ => Err(SymbolError::InvalidFormat(input.to_string())),
         }
-        
-        Some(SymbolPair::new(base, quote))
     }
 }
 
@@ -147,20 +154,16 @@ mod tests {
     
     #[test]
     fn test_from_str_parsing() {
-        let test_cases = vec![
-            ("BTC/USDT", Some(SymbolPair::new("BTC", "USDT"))),
-            ("ETH-BTC", Some(SymbolPair::new("ETH", "BTC"))),
-            ("XRP_USD", Some(SymbolPair::new("XRP", "USD"))),
-            ("ADAUSDT", Some(SymbolPair::new("ADA", "USDT"))),
-            ("BTCETH", Some(SymbolPair::new("BTC", "ETH"))),
-            ("", None),
-            ("BTC", None),
-        ];
-        
-        for (input, expected) in test_cases {
-            let result = SymbolPair::from_str(input);
-            assert_eq!(result.as_ref().map(|p| p.base()), expected.as_ref().map(|p| p.base()));
-            assert_eq!(result.as_ref().map(|p| p.quote()), expected.as_ref().map(|p| p.quote()));
+        assert_eq!("BTC/USDT".parse::<SymbolPair>().unwrap(), SymbolPair::new("BTC", "USDT"));
+        assert_eq!(" eth / btc ".parse::<SymbolPair>().unwrap(), SymbolPair::new("ETH", "BTC"));
+        assert_eq!("1000PEPE/USDC".parse::<SymbolPair>().unwrap().base(), "1000PEPE");
+
+        // 거래소 고유 형식은 추측하지 않음 (SymbolRegistry 사용)
+        for input in ["", "BTC", "BTCUSDT", "KRW-BTC", "XRP_USD", "BTC/", "A/B/C"] {
+            assert_eq!(
+                input.parse::<SymbolPair>(),
+                Err(SymbolError::InvalidFormat(input.to_string()))
+            );
         }
     }
-} 
\ No newline at end of file
+}
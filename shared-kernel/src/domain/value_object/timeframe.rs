@@ -1,8 +1,8 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
 use std::time::Duration;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, Months, NaiveDate, TimeZone, Utc};
 use thiserror::Error;
 
 /// 타임프레임 파싱 오류
@@ -10,87 +10,236 @@ use thiserror::Error;
 pub enum TimeframeError {
     #[error("잘못된 타임프레임 형식: {0}")]
     InvalidFormat(String),
-    
+
     #[error("지원되지 않는 타임프레임: {0}")]
     UnsupportedTimeframe(String),
 }
 
+/// 타임프레임 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimeframeUnit {
+    /// 분 (`m`)
+    Minute,
+    /// 시간 (`h`)
+    Hour,
+    /// 일 (`d`)
+    Day,
+    /// 주 (`w`), 월요일 시작
+    Week,
+    /// 월 (`M`), 1일 시작
+    Month,
+}
+
+impl TimeframeUnit {
+    /// 코드 문자 (분 `m`과 월 `M`은 대소문자로 구분)
+    pub fn code(&self) -> char {
+        match self {
+            TimeframeUnit::Minute => 'm',
+            TimeframeUnit::Hour => 'h',
+            TimeframeUnit::Day => 'd',
+            TimeframeUnit::Week => 'w',
+            TimeframeUnit::Month => 'M',
+        }
+    }
+
+    /// 고정 길이 단위의 초 수 (월은 길이가 달라 `None`)
+    fn seconds(&self) -> Option<i64> {
+        match self {
+            TimeframeUnit::Minute => Some(60),
+            TimeframeUnit::Hour => Some(60 * 60),
+            TimeframeUnit::Day => Some(24 * 60 * 60),
+            TimeframeUnit::Week => Some(7 * 24 * 60 * 60),
+            TimeframeUnit::Month => None,
+        }
+    }
+}
+
+/// 1970-01-01(목요일) 기준 첫 월요일(1970-01-05)까지의 초
+const EPOCH_MONDAY_SECONDS: i64 = 4 * 24 * 60 * 60;
+
 /// 타임프레임 (차트 기간) 값 객체
 ///
-/// 금융 차트에서 사용되는 기간 단위
-/// 예: 1분, 5분, 1시간, 1일 등
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Timeframe {
+/// `N × 단위` 형태의 임의 기간을 표현합니다 (예: 3m, 2h, 6h, 3d, 2w, 3M).
+/// 버킷 경계는 기준 시간대(anchor) 의 현지 시각으로 계산하며, 주 단위는 월요일,
+/// 월 단위는 매월 1일에 정렬됩니다. 예를 들어 Upbit 일봉은 09:00 KST(00:00 UTC)에
+/// 시작하므로 기본값인 `Timeframe::Day1` 로 표현하고, 현지 자정(00:00 KST)에 시작하는
+/// 일봉은 `Timeframe::Day1.with_anchor(+09:00)` 으로 표현합니다.
+///
+/// 코드 형식은 `{N}{단위}` 이며 기준 시간대가 UTC가 아니면 `@±HH:MM` 이 붙습니다
+/// (예: `"15m"`, `"1d@+09:00"`). 직렬화 시에도 이 코드 문자열을 사용합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timeframe {
+    count: u32,
+    unit: TimeframeUnit,
+    /// 기준 시간대의 UTC 오프셋 (초)
+    anchor_seconds: i32,
+}
+
+#[allow(non_upper_case_globals)]
+impl Timeframe {
     /// 1분
-    Minute1,
+    pub const Minute1: Timeframe = Timeframe::utc(1, TimeframeUnit::Minute);
     /// 5분
-    Minute5,
+    pub const Minute5: Timeframe = Timeframe::utc(5, TimeframeUnit::Minute);
     /// 15분
-    Minute15,
+    pub const Minute15: Timeframe = Timeframe::utc(15, TimeframeUnit::Minute);
     /// 30분
-    Minute30,
+    pub const Minute30: Timeframe = Timeframe::utc(30, TimeframeUnit::Minute);
     /// 1시간
-    Hour1,
+    pub const Hour1: Timeframe = Timeframe::utc(1, TimeframeUnit::Hour);
     /// 4시간
-    Hour4,
+    pub const Hour4: Timeframe = Timeframe::utc(4, TimeframeUnit::Hour);
     /// 12시간
-    Hour12,
+    pub const Hour12: Timeframe = Timeframe::utc(12, TimeframeUnit::Hour);
     /// 1일
-    Day1,
+    pub const Day1: Timeframe = Timeframe::utc(1, TimeframeUnit::Day);
     /// 1주
-    Week1,
+    pub const Week1: Timeframe = Timeframe::utc(1, TimeframeUnit::Week);
     /// 1개월
-    Month1,
+    pub const Month1: Timeframe = Timeframe::utc(1, TimeframeUnit::Month);
 }
 
 impl Timeframe {
+    const fn utc(count: u32, unit: TimeframeUnit) -> Self {
+        Self {
+            count,
+            unit,
+            anchor_seconds: 0,
+        }
+    }
+
+    /// 새로운 타임프레임 생성 (UTC 기준)
+    pub fn new(count: u32, unit: TimeframeUnit) -> Result<Self, TimeframeError> {
+        if count == 0 {
+            return Err(TimeframeError::UnsupportedTimeframe(format!("0{}", unit.code())));
+        }
+        Ok(Self::utc(count, unit))
+    }
+
+    /// 버킷 경계 기준 시간대 지정
+    ///
+    /// 코드 형식(`@±HH:MM`)으로 표현할 수 없는 초 단위 오프셋은 거부합니다.
+    pub fn with_anchor(mut self, anchor: FixedOffset) -> Result<Self, TimeframeError> {
+        let offset = anchor.local_minus_utc();
+        if offset % 60 != 0 {
+            return Err(TimeframeError::UnsupportedTimeframe(format!(
+                "{}@{} (분 단위 오프셋만 지원)", self.to_code(), anchor
+            )));
+        }
+        self.anchor_seconds = offset;
+        Ok(self)
+    }
+
+    /// 단위 개수
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// 단위
+    pub fn unit(&self) -> TimeframeUnit {
+        self.unit
+    }
+
+    /// 버킷 경계 기준 시간대
+    pub fn anchor(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.anchor_seconds).unwrap_or_else(|| FixedOffset::east_opt(0).unwrap())
+    }
+
     /// 타임프레임의 기간(Duration)을 반환
-    pub fn duration(&self) -> Duration {
-        match self {
-            Timeframe::Minute1 => Duration::from_secs(60),
-            Timeframe::Minute5 => Duration::from_secs(5 * 60),
-            Timeframe::Minute15 => Duration::from_secs(15 * 60),
-            Timeframe::Minute30 => Duration::from_secs(30 * 60),
-            Timeframe::Hour1 => Duration::from_secs(60 * 60),
-            Timeframe::Hour4 => Duration::from_secs(4 * 60 * 60),
-            Timeframe::Hour12 => Duration::from_secs(12 * 60 * 60),
-            Timeframe::Day1 => Duration::from_secs(24 * 60 * 60),
-            Timeframe::Week1 => Duration::from_secs(7 * 24 * 60 * 60),
-            Timeframe::Month1 => Duration::from_secs(30 * 24 * 60 * 60), // 근사값
+    ///
+    /// 월 단위는 달마다 길이가 다르므로 `None`을 반환합니다.
+    /// 실제 캔들 경계는 `bucket_start`/`bucket_end`를 사용해야 합니다.
+    pub fn duration(&self) -> Option<Duration> {
+        self.fixed_seconds().map(|secs| Duration::from_secs(secs as u64))
+    }
+
+    /// 분 단위 길이 (월 단위는 `None`)
+    pub fn to_minutes(&self) -> Option<u64> {
+        self.fixed_seconds().map(|secs| secs as u64 / 60)
+    }
+
+    fn fixed_seconds(&self) -> Option<i64> {
+        self.unit.seconds().map(|secs| secs * self.count as i64)
+    }
+
+    /// 표준 코드 문자열로 변환 (예: "1m", "1h", "1d@+09:00")
+    pub fn to_code(&self) -> String {
+        if self.anchor_seconds == 0 {
+            format!("{}{}", self.count, self.unit.code())
+        } else {
+            format!("{}{}@{}", self.count, self.unit.code(), self.anchor())
         }
     }
-    
-    /// 표준 코드 문자열로 변환 (예: "1m", "1h")
-    pub fn to_code(&self) -> &'static str {
-        match self {
-            Timeframe::Minute1 => "1m",
-            Timeframe::Minute5 => "5m",
-            Timeframe::Minute15 => "15m",
-            Timeframe::Minute30 => "30m",
-            Timeframe::Hour1 => "1h",
-            Timeframe::Hour4 => "4h",
-            Timeframe::Hour12 => "12h",
-            Timeframe::Day1 => "1d",
-            Timeframe::Week1 => "1w",
-            Timeframe::Month1 => "1M",
+
+    /// 주어진 시각이 속한 캔들의 시작 시각
+    pub fn bucket_start(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let anchor = self.anchor_seconds as i64;
+
+        match self.fixed_seconds() {
+            Some(step) => {
+                // 주 단위는 월요일, 나머지는 1970-01-01 00:00 (현지 시각) 기준으로 정렬
+                let origin = if self.unit == TimeframeUnit::Week { EPOCH_MONDAY_SECONDS } else { 0 };
+                let local = time.timestamp() + anchor - origin;
+                let start = local.div_euclid(step) * step + origin - anchor;
+                DateTime::from_timestamp(start, 0).unwrap_or(time)
+            }
+            None => {
+                // 1970년 1월부터의 개월 수를 기준으로 정렬
+                let local = time.with_timezone(&self.anchor()).date_naive();
+                let months = (local.year() as i64 - 1970) * 12 + local.month0() as i64;
+                let aligned = months.div_euclid(self.count as i64) * self.count as i64;
+                let year = 1970 + aligned.div_euclid(12);
+                let month = aligned.rem_euclid(12) as u32 + 1;
+
+                NaiveDate::from_ymd_opt(year as i32, month, 1)
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .and_then(|start| self.anchor().from_local_datetime(&start).single())
+                    .map(|start| start.with_timezone(&Utc))
+                    .unwrap_or(time)
+            }
+        }
+    }
+
+    /// 주어진 시각이 속한 캔들의 종료 시각 (다음 캔들의 시작 시각)
+    pub fn bucket_end(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        self.add_intervals(self.bucket_start(time), 1)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// 시각에 타임프레임 n개를 더함 (음수면 뺌)
+    ///
+    /// 월 단위는 달력 기준으로 계산하며, 표현 범위를 벗어나면 `None`을 반환합니다.
+    pub fn add_intervals(&self, time: DateTime<Utc>, n: i64) -> Option<DateTime<Utc>> {
+        match self.fixed_seconds() {
+            Some(step) => {
+                let delta = chrono::Duration::try_seconds(step.checked_mul(n)?)?;
+                time.checked_add_signed(delta)
+            }
+            None => {
+                let months = u32::try_from((self.count as i64).checked_mul(n.abs())?).ok()?;
+                let local = time.with_timezone(&self.anchor());
+                let shifted = if n >= 0 {
+                    local.checked_add_months(Months::new(months))?
+                } else {
+                    local.checked_sub_months(Months::new(months))?
+                };
+                Some(shifted.with_timezone(&Utc))
+            }
         }
     }
-    
+
     /// 현재 시간을 기준으로 n개 이전의 캔들 시작시간 계산
     pub fn previous_candle_start(&self, n: usize, reference_time: Option<DateTime<Utc>>) -> DateTime<Utc> {
         let now = reference_time.unwrap_or_else(Utc::now);
-        let duration_millis = self.duration().as_millis() as i64;
-        let n_millis = (n as i64) * duration_millis;
-        
-        let timestamp_millis = now.timestamp_millis();
-        let current_candle_start_millis = (timestamp_millis / duration_millis) * duration_millis;
-        let previous_candle_start_millis = current_candle_start_millis - n_millis;
-        
-        DateTime::from_timestamp_millis(previous_candle_start_millis)
-            .unwrap_or_else(|| now)
-    }
-    
-    /// 모든 타임프레임 값 목록 반환
+        let current_candle_start = self.bucket_start(now);
+
+        i64::try_from(n)
+            .ok()
+            .and_then(|n| self.add_intervals(current_candle_start, -n))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// 자주 쓰이는 타임프레임 값 목록 반환
     pub fn all() -> Vec<Timeframe> {
         vec![
             Timeframe::Minute1,
@@ -109,103 +258,226 @@ impl Timeframe {
 
 impl FromStr for Timeframe {
     type Err = TimeframeError;
-    
+
+    /// `{N}{단위}[@±HH:MM]` 형식 파싱
+    ///
+    /// 분은 `m`/`min`, 월은 `M`/`mo`로 구분하며 나머지 단위는 대소문자를 구분하지 않습니다.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim().to_lowercase();
-        
-        match s.as_str() {
-            "1m" => Ok(Timeframe::Minute1),
-            "5m" => Ok(Timeframe::Minute5),
-            "15m" => Ok(Timeframe::Minute15),
-            "30m" => Ok(Timeframe::Minute30),
-            "1h" => Ok(Timeframe::Hour1),
-            "4h" => Ok(Timeframe::Hour4),
-            "12h" => Ok(Timeframe::Hour12),
-            "1d" => Ok(Timeframe::Day1),
-            "1w" => Ok(Timeframe::Week1),
-            "1M" | "1mo" => Ok(Timeframe::Month1),
-            _ => {
-                // 추가 파싱 시도: 숫자+단위 형식
-                if let Some(last_char) = s.chars().last() {
-                    let value = &s[0..s.len()-1];
-                    if let Ok(num) = value.parse::<u32>() {
-                        match last_char {
-                            'm' => match num {
-                                1 => Ok(Timeframe::Minute1),
-                                5 => Ok(Timeframe::Minute5),
-                                15 => Ok(Timeframe::Minute15),
-                                30 => Ok(Timeframe::Minute30),
-                                _ => Err(TimeframeError::UnsupportedTimeframe(s)),
-                            },
-                            'h' => match num {
-                                1 => Ok(Timeframe::Hour1),
-                                4 => Ok(Timeframe::Hour4),
-                                12 => Ok(Timeframe::Hour12),
-                                _ => Err(TimeframeError::UnsupportedTimeframe(s)),
-                            },
-                            'd' => match num {
-                                1 => Ok(Timeframe::Day1),
-                                _ => Err(TimeframeError::UnsupportedTimeframe(s)),
-                            },
-                            'w' => match num {
-                                1 => Ok(Timeframe::Week1),
-                                _ => Err(TimeframeError::UnsupportedTimeframe(s)),
-                            },
-                            'M' => match num {
-                                1 => Ok(Timeframe::Month1),
-                                _ => Err(TimeframeError::UnsupportedTimeframe(s)),
-                            },
-                            _ => Err(TimeframeError::InvalidFormat(s)),
-                        }
-                    } else {
-                        Err(TimeframeError::InvalidFormat(s))
-                    }
-                } else {
-                    Err(TimeframeError::InvalidFormat(s))
-                }
+        let s = s.trim();
+        let (interval, anchor) = match s.split_once('@') {
+            Some((interval, anchor)) => (interval, Some(anchor)),
+            None => (s, None),
+        };
+
+        let digits = interval.find(|c: char| !c.is_ascii_digit()).unwrap_or(interval.len());
+        let (count, unit) = interval.split_at(digits);
+        let count: u32 = count
+            .parse()
+            .map_err(|_| TimeframeError::InvalidFormat(s.to_string()))?;
+
+        let unit = match unit {
+            "m" | "min" => TimeframeUnit::Minute,
+            "M" | "mo" => TimeframeUnit::Month,
+            _ => match unit.to_lowercase().as_str() {
+                "h" => TimeframeUnit::Hour,
+                "d" => TimeframeUnit::Day,
+                "w" => TimeframeUnit::Week,
+                _ => return Err(TimeframeError::InvalidFormat(s.to_string())),
+            },
+        };
+
+        let timeframe = Timeframe::new(count, unit)
+            .map_err(|_| TimeframeError::UnsupportedTimeframe(s.to_string()))?;
+
+        match anchor {
+            Some(anchor) => {
+                let offset = parse_offset(anchor).ok_or_else(|| TimeframeError::InvalidFormat(s.to_string()))?;
+                timeframe.with_anchor(offset)
             }
+            None => Ok(timeframe),
         }
     }
 }
 
+/// `±HH:MM` 형식의 UTC 오프셋 파싱
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = rest.split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
 impl fmt::Display for Timeframe {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_code())
     }
 }
 
+impl Serialize for Timeframe {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timeframe {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn kst() -> FixedOffset {
+        FixedOffset::east_opt(9 * 3600).unwrap()
+    }
+
     #[test]
     fn test_timeframe_codes() {
         assert_eq!(Timeframe::Minute1.to_code(), "1m");
         assert_eq!(Timeframe::Hour1.to_code(), "1h");
         assert_eq!(Timeframe::Day1.to_code(), "1d");
+        assert_eq!(Timeframe::Month1.to_code(), "1M");
+        assert_eq!(Timeframe::Day1.with_anchor(kst()).unwrap().to_code(), "1d@+09:00");
     }
-    
+
     #[test]
     fn test_timeframe_durations() {
-        assert_eq!(Timeframe::Minute1.duration(), Duration::from_secs(60));
-        assert_eq!(Timeframe::Hour1.duration(), Duration::from_secs(60 * 60));
-        assert_eq!(Timeframe::Day1.duration(), Duration::from_secs(24 * 60 * 60));
+        assert_eq!(Timeframe::Minute1.duration(), Some(Duration::from_secs(60)));
+        assert_eq!(Timeframe::Hour1.duration(), Some(Duration::from_secs(60 * 60)));
+        assert_eq!(Timeframe::Day1.duration(), Some(Duration::from_secs(24 * 60 * 60)));
+        assert_eq!(Timeframe::Month1.duration(), None);
     }
-    
+
     #[test]
     fn test_timeframe_parsing() {
         assert_eq!(Timeframe::from_str("1m").unwrap(), Timeframe::Minute1);
         assert_eq!(Timeframe::from_str("5m").unwrap(), Timeframe::Minute5);
         assert_eq!(Timeframe::from_str("1h").unwrap(), Timeframe::Hour1);
         assert_eq!(Timeframe::from_str("1d").unwrap(), Timeframe::Day1);
-        
-        assert!(Timeframe::from_str("2h").is_err());
+        assert_eq!(Timeframe::from_str("1D").unwrap(), Timeframe::Day1);
+        assert_eq!(Timeframe::from_str("1M").unwrap(), Timeframe::Month1);
+        assert_eq!(Timeframe::from_str("1mo").unwrap(), Timeframe::Month1);
+        assert_eq!(Timeframe::from_str("2h").unwrap(), Timeframe::new(2, TimeframeUnit::Hour).unwrap());
+
+        assert!(Timeframe::from_str("0m").is_err());
+        assert!(Timeframe::from_str("1x").is_err());
+        assert!(Timeframe::from_str("1d@+9").is_err());
         assert!(Timeframe::from_str("invalid").is_err());
     }
-    
+
+    #[test]
+    fn test_code_round_trip() {
+        let mut timeframes = Timeframe::all();
+        for (count, unit) in [
+            (3, TimeframeUnit::Minute),
+            (2, TimeframeUnit::Hour),
+            (6, TimeframeUnit::Hour),
+            (3, TimeframeUnit::Day),
+            (2, TimeframeUnit::Week),
+            (3, TimeframeUnit::Month),
+        ] {
+            timeframes.push(Timeframe::new(count, unit).unwrap());
+        }
+        timeframes.push(Timeframe::Day1.with_anchor(kst()).unwrap());
+        timeframes.push(Timeframe::Hour4.with_anchor(FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()).unwrap());
+
+        for timeframe in timeframes {
+            let code = timeframe.to_code();
+            assert_eq!(code.parse::<Timeframe>().unwrap(), timeframe, "{}", code);
+
+            let json = serde_json::to_string(&timeframe).unwrap();
+            assert_eq!(json, format!("\"{}\"", code));
+            assert_eq!(serde_json::from_str::<Timeframe>(&json).unwrap(), timeframe);
+        }
+    }
+
+    #[test]
+    fn test_fixed_interval_buckets() {
+        let time = utc("2024-03-15T10:47:31Z");
+        let three_minutes = Timeframe::new(3, TimeframeUnit::Minute).unwrap();
+        assert_eq!(three_minutes.bucket_start(time), utc("2024-03-15T10:45:00Z"));
+        assert_eq!(three_minutes.bucket_end(time), utc("2024-03-15T10:48:00Z"));
+
+        let six_hours = Timeframe::new(6, TimeframeUnit::Hour).unwrap();
+        assert_eq!(six_hours.bucket_start(time), utc("2024-03-15T06:00:00Z"));
+
+        // 경계 시각은 새 캔들에 속함
+        assert_eq!(Timeframe::Hour1.bucket_start(utc("2024-03-15T11:00:00Z")), utc("2024-03-15T11:00:00Z"));
+    }
+
+    #[test]
+    fn test_calendar_buckets() {
+        // 2024-03-15는 금요일 → 주봉은 월요일(03-11) 시작
+        let time = utc("2024-03-15T10:47:31Z");
+        assert_eq!(Timeframe::Week1.bucket_start(time), utc("2024-03-11T00:00:00Z"));
+        assert_eq!(Timeframe::Week1.bucket_end(time), utc("2024-03-18T00:00:00Z"));
+
+        // 월봉은 달력 기준 (2월은 29일, 윤년)
+        assert_eq!(Timeframe::Month1.bucket_start(utc("2024-02-29T23:59:59Z")), utc("2024-02-01T00:00:00Z"));
+        assert_eq!(Timeframe::Month1.bucket_end(utc("2024-02-10T00:00:00Z")), utc("2024-03-01T00:00:00Z"));
+        assert_eq!(Timeframe::Month1.bucket_end(utc("2024-12-10T00:00:00Z")), utc("2025-01-01T00:00:00Z"));
+
+        // 분기봉
+        let quarter = Timeframe::new(3, TimeframeUnit::Month).unwrap();
+        assert_eq!(quarter.bucket_start(utc("2024-05-20T00:00:00Z")), utc("2024-04-01T00:00:00Z"));
+        assert_eq!(quarter.bucket_end(utc("2024-05-20T00:00:00Z")), utc("2024-07-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_sub_minute_anchor_rejected() {
+        let offset = FixedOffset::east_opt(9 * 3600 + 30).unwrap();
+        assert!(Timeframe::Day1.with_anchor(offset).is_err());
+        assert!(Timeframe::from_str("1d@+09:00:30").is_err());
+    }
+
+    #[test]
+    fn test_anchored_buckets() {
+        // Upbit 일봉은 09:00 KST (00:00 UTC) 시작이므로 UTC 기준 일봉과 같음
+        let upbit_daily = Timeframe::Day1;
+        assert_eq!(upbit_daily.bucket_start(utc("2024-03-15T23:30:00Z")), utc("2024-03-15T00:00:00Z"));
+        assert_eq!(
+            upbit_daily.bucket_start(utc("2024-03-15T23:30:00Z")).with_timezone(&kst()).to_rfc3339(),
+            "2024-03-15T09:00:00+09:00"
+        );
+
+        // KST 기준 일봉은 현지 자정 (15:00 UTC) 시작, 주봉/월봉도 KST 기준
+        let daily = Timeframe::Day1.with_anchor(kst()).unwrap();
+        assert_eq!(daily.bucket_start(utc("2024-03-15T23:30:00Z")), utc("2024-03-15T15:00:00Z"));
+        assert_eq!(daily.bucket_end(utc("2024-03-15T23:30:00Z")), utc("2024-03-16T15:00:00Z"));
+
+        let monthly = Timeframe::Month1.with_anchor(kst()).unwrap();
+        // 2024-03-31T16:00Z는 KST로 4월 1일 01:00
+        assert_eq!(monthly.bucket_start(utc("2024-03-31T16:00:00Z")), utc("2024-03-31T15:00:00Z"));
+    }
+
+    #[test]
+    fn test_previous_candle_start() {
+        let time = utc("2024-03-15T10:47:31Z");
+        assert_eq!(Timeframe::Hour1.previous_candle_start(2, Some(time)), utc("2024-03-15T08:00:00Z"));
+        assert_eq!(Timeframe::Month1.previous_candle_start(2, Some(time)), utc("2024-01-01T00:00:00Z"));
+    }
+
     #[test]
     fn test_timeframe_display() {
         assert_eq!(Timeframe::Minute5.to_string(), "5m");
         assert_eq!(Timeframe::Hour4.to_string(), "4h");
     }
-} 
\ No newline at end of file
+}
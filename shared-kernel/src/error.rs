@@ -3,7 +3,9 @@
 //! 이 모듈은 CryptoLytica 프로젝트에서 사용되는 오류 타입과 처리 메커니즘을 정의합니다.
 
 use thiserror::Error;
+use chrono::{DateTime, Utc};
 use std::fmt;
+use std::time::Duration;
 
 /// 핵심 오류 타입
 #[derive(Error, Debug)]
@@ -26,6 +28,27 @@ pub enum CoreError {
     #[error("타임아웃 오류: {0}")]
     Timeout(String),
 
+    #[error("요청 한도 초과: {message}")]
+    RateLimited {
+        message: String,
+        /// 서버가 알려준 재시도 대기 시간
+        retry_after: Option<Duration>,
+    },
+
+    #[error("서비스 일시 중단: {message}")]
+    Unavailable {
+        message: String,
+        /// 서버가 알려준 재시도 대기 시간
+        retry_after: Option<Duration>,
+    },
+
+    #[error("회로 차단기 열림: {endpoint}")]
+    CircuitOpen {
+        endpoint: String,
+        /// 반개방 상태로 전환되기까지 남은 시간
+        retry_after: Option<Duration>,
+    },
+
     #[error("인증 오류: {0}")]
     Authentication(String),
 
@@ -51,6 +74,52 @@ pub enum CoreError {
     Unknown(String),
 }
 
+impl CoreError {
+    /// 재시도하면 성공할 수 있는 일시적 오류인지 여부
+    ///
+    /// 타임아웃, IO 오류, 요청 한도 초과, 5xx 응답(501 제외), 열린 회로 차단기는
+    /// 일시적 오류로, 인증·권한·유효성 검사 등은 재시도해도 결과가 같은 영구 오류로 분류합니다.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            CoreError::Io(_)
+            | CoreError::Timeout(_)
+            | CoreError::RateLimited { .. }
+            | CoreError::Unavailable { .. }
+            | CoreError::CircuitOpen { .. } => true,
+            CoreError::Response { code, .. } => (500..=599).contains(code) && *code != 501,
+            _ => false,
+        }
+    }
+
+    /// 서버나 회로 차단기가 알려준 재시도 대기 시간
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            CoreError::RateLimited { retry_after, .. }
+            | CoreError::Unavailable { retry_after, .. }
+            | CoreError::CircuitOpen { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// 오류 코드
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            CoreError::Io(_) | CoreError::Request(_) | CoreError::Response { .. } => ErrorCode::NetworkError,
+            CoreError::Json(_) | CoreError::Data(_) => ErrorCode::DataError,
+            CoreError::Timeout(_) => ErrorCode::TimeoutError,
+            CoreError::RateLimited { .. } => ErrorCode::RateLimitError,
+            CoreError::Unavailable { .. } | CoreError::CircuitOpen { .. } => ErrorCode::UnavailableError,
+            CoreError::Authentication(_) => ErrorCode::AuthenticationError,
+            CoreError::Authorization(_) => ErrorCode::AuthorizationError,
+            CoreError::Configuration(_) => ErrorCode::ConfigurationError,
+            CoreError::Validation(_) => ErrorCode::ValidationError,
+            CoreError::Domain(_) => ErrorCode::DomainError,
+            CoreError::NotFound(_) => ErrorCode::NotFoundError,
+            CoreError::Unknown(_) => ErrorCode::GeneralError,
+        }
+    }
+}
+
 /// 오류 코드 열거형
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorCode {
@@ -66,6 +135,8 @@ pub enum ErrorCode {
     NotFoundError = 1800,
     DuplicateError = 1900,
     DomainError = 2000,
+    RateLimitError = 2100,
+    UnavailableError = 2200,
 }
 
 impl fmt::Display for ErrorCode {
@@ -76,10 +147,27 @@ impl fmt::Display for ErrorCode {
 
 /// HTTP 오류에서 CoreError 생성
 pub fn from_http_error(status: u16, body: &str) -> CoreError {
+    from_http_error_with_retry_after(status, body, None)
+}
+
+/// `Retry-After` 힌트를 포함하여 HTTP 오류에서 CoreError 생성
+pub fn from_http_error_with_retry_after(
+    status: u16,
+    body: &str,
+    retry_after: Option<Duration>,
+) -> CoreError {
     match status {
         401 | 403 => CoreError::Authentication(format!("HTTP {}: {}", status, body)),
         404 => CoreError::NotFound(format!("HTTP {}: {}", status, body)),
-        429 => CoreError::Timeout(format!("속도 제한 초과: HTTP {}: {}", status, body)),
+        408 => CoreError::Timeout(format!("HTTP {}: {}", status, body)),
+        429 => CoreError::RateLimited {
+            message: format!("HTTP {}: {}", status, body),
+            retry_after,
+        },
+        503 => CoreError::Unavailable {
+            message: format!("HTTP {}: {}", status, body),
+            retry_after,
+        },
         400..=499 => CoreError::Request(format!("HTTP {}: {}", status, body)),
         500..=599 => CoreError::Response {
             code: status,
@@ -89,6 +177,20 @@ pub fn from_http_error(status: u16, body: &str) -> CoreError {
     }
 }
 
+/// `Retry-After` 헤더 값 파싱
+///
+/// 초 단위 정수와 HTTP 날짜(RFC 2822) 형식을 모두 지원하며,
+/// 날짜가 `now`보다 과거면 대기 시간 0을 반환합니다.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((at - now).to_std().unwrap_or(Duration::ZERO))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +226,35 @@ mod tests {
             _ => panic!("잘못된 에러 타입"),
         }
     }
+
+    #[test]
+    fn test_http_error_retryability() {
+        let rate_limited = from_http_error_with_retry_after(429, "Too Many Requests", Some(Duration::from_secs(3)));
+        assert!(matches!(rate_limited, CoreError::RateLimited { .. }));
+        assert!(rate_limited.is_retryable());
+        assert_eq!(rate_limited.retry_after(), Some(Duration::from_secs(3)));
+
+        assert!(from_http_error(503, "Service Unavailable").is_retryable());
+        assert!(from_http_error(502, "Bad Gateway").is_retryable());
+        assert!(from_http_error(408, "Request Timeout").is_retryable());
+
+        assert!(!from_http_error(501, "Not Implemented").is_retryable());
+        assert!(!from_http_error(401, "Unauthorized").is_retryable());
+        assert!(!from_http_error(400, "Bad Request").is_retryable());
+        assert!(!CoreError::Validation("수량 오류".to_string()).is_retryable());
+        assert_eq!(from_http_error(401, "Unauthorized").code(), ErrorCode::AuthenticationError);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+
+        assert_eq!(parse_retry_after("120", now), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Mon, 01 Jan 2024 00:00:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after("Sun, 31 Dec 2023 23:59:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
 } 
\ No newline at end of file
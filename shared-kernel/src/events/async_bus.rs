@@ -0,0 +1,131 @@
+//! 비동기 이벤트 버스 계약
+//!
+//! 구독마다 독립된 유한 큐와 처리 태스크를 두어, 느린 핸들러가 다른 구독자나
+//! 발행자를 막지 않도록 합니다. 큐가 가득 찼을 때의 동작은 구독 단위의
+//! `BackpressurePolicy`로 정하며, 실제 구현은 infrastructure 모듈에서 수행합니다.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::types::Result;
+use super::{Event, EventHandler, SubscriptionHandle};
+
+/// 비동기 이벤트 핸들러
+#[async_trait]
+pub trait AsyncEventHandler<E: Event>: Send + Sync + 'static {
+    /// 이벤트 처리
+    async fn handle(&self, event: &E) -> Result<()>;
+}
+
+/// 동기 `EventHandler`를 비동기 버스에 구독시키기 위한 어댑터
+pub struct SyncHandler<H>(pub H);
+
+#[async_trait]
+impl<E: Event, H: EventHandler<E>> AsyncEventHandler<E> for SyncHandler<H> {
+    async fn handle(&self, event: &E) -> Result<()> {
+        self.0.handle(event)
+    }
+}
+
+/// 구독 큐가 가득 찼을 때의 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    /// 자리가 날 때까지 발행자를 대기시킴 (이벤트 유실 없음)
+    Block,
+    /// 가장 오래된 이벤트를 버리고 새 이벤트를 넣음
+    DropOldest,
+    /// 새 이벤트를 버림
+    DropNewest,
+    /// 같은 `Event::partition_key`의 대기 중인 이벤트를 최신 값으로 교체
+    ///
+    /// 가격 틱처럼 최신 값만 의미 있는 이벤트에 사용합니다. 큐가 가득 찼는데
+    /// 교체할 이벤트가 없으면 가장 오래된 이벤트를 버립니다.
+    CoalesceByKey,
+}
+
+/// 구독 옵션
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionOptions {
+    /// 구독 이름 (로그·지표용)
+    pub name: String,
+    /// 큐 용량
+    pub capacity: usize,
+    /// 배압 정책
+    pub policy: BackpressurePolicy,
+    /// 구독 타입의 이벤트 타입 ID (모든 토픽을 받는 브로커 구독이 다른 타입을 구분할 때 사용)
+    pub event_type: Option<String>,
+}
+
+impl SubscriptionOptions {
+    /// 이름을 지정하여 기본 옵션 생성 (용량 1024, 대기)
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            capacity: 1024,
+            policy: BackpressurePolicy::Block,
+            event_type: None,
+        }
+    }
+
+    /// 큐 용량 지정 (0은 1로 보정)
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// 배압 정책 지정
+    pub fn with_policy(mut self, policy: BackpressurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// 구독 타입의 이벤트 타입 ID 지정 (예: `exchange.order.filled`)
+    pub fn with_event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+}
+
+/// 구독 처리 통계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionStats {
+    /// 현재 큐에 대기 중인 이벤트 수
+    pub queued: usize,
+    /// 핸들러가 성공적으로 처리한 이벤트 수
+    pub delivered: u64,
+    /// 핸들러가 오류를 반환한 이벤트 수
+    pub failed: u64,
+    /// 핸들러가 패닉한 이벤트 수
+    pub panicked: u64,
+    /// 배압 정책으로 버려진 이벤트 수
+    pub dropped: u64,
+    /// 같은 키의 최신 이벤트로 교체된 이벤트 수
+    pub coalesced: u64,
+}
+
+/// 비동기 이벤트 버스
+#[async_trait]
+pub trait AsyncEventBus: Send + Sync {
+    /// 이벤트 발행
+    ///
+    /// 이벤트를 각 구독 큐에 넣은 뒤 반환하며, 핸들러 실행을 기다리지 않습니다.
+    /// `Block` 정책 구독의 큐가 가득 찬 경우에만 자리가 날 때까지 대기합니다.
+    async fn publish<E: Event + Serialize>(&self, event: E) -> Result<()>;
+
+    /// 이벤트 구독 (구독마다 전용 처리 태스크 생성)
+    fn subscribe<E, H>(&self, handler: H, options: SubscriptionOptions) -> Result<SubscriptionHandle>
+    where
+        E: Event + for<'de> Deserialize<'de>,
+        H: AsyncEventHandler<E>;
+
+    /// 구독 취소 (대기 중인 이벤트는 처리 후 태스크 종료)
+    fn unsubscribe(&self, handle: &SubscriptionHandle) -> Result<()>;
+
+    /// 구독 처리 통계
+    fn stats(&self, handle: &SubscriptionHandle) -> Option<SubscriptionStats>;
+
+    /// 새 발행을 막고, 대기 중인 이벤트를 제한 시간 안에 모두 처리한 뒤 종료
+    async fn shutdown(&self, timeout: Duration) -> Result<()>;
+}
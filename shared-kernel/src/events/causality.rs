@@ -0,0 +1,296 @@
+//! 인과 사슬 조회
+//!
+//! 봉투 헤더의 상관 ID와 원인 ID로 이벤트 간 인과 관계를 색인하여, 임의의 이벤트 ID에서
+//! 흐름 전체(신호 → 매매 결정 → 주문 → 체결 → 손익)를 재구성합니다. `EnvelopeHandler`를
+//! 구현하므로 토픽 구독(`#`)으로 실시간 색인하거나, 이벤트 로그 재생으로 다시 만들 수 있습니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::types::Result;
+use super::topic::EnvelopeHandler;
+use super::{EventEnvelope, EventHeader};
+
+/// 인과 사슬의 한 이벤트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CausalLink {
+    /// 이벤트 ID
+    pub event_id: Uuid,
+    /// 이벤트 타입
+    pub event_type: String,
+    /// 이벤트 발생 시간
+    pub timestamp: DateTime<Utc>,
+    /// 상관 ID
+    pub correlation_id: Uuid,
+    /// 원인 이벤트 ID
+    pub causation_id: Option<Uuid>,
+}
+
+impl CausalLink {
+    /// 헤더에서 생성
+    pub fn from_header(header: &EventHeader) -> Self {
+        Self {
+            event_id: header.id,
+            event_type: header.event_type.clone(),
+            timestamp: header.timestamp,
+            correlation_id: header.correlation_id.unwrap_or(header.id),
+            causation_id: header.causation_id,
+        }
+    }
+}
+
+/// 인과 트리의 노드 (깊이 0이 흐름의 시작)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CausalNode {
+    /// 뿌리로부터의 깊이
+    pub depth: usize,
+    /// 이벤트
+    pub link: CausalLink,
+}
+
+#[derive(Debug, Default)]
+struct IndexState {
+    links: HashMap<Uuid, CausalLink>,
+    children: HashMap<Uuid, Vec<Uuid>>,
+    /// 기록 순서 (용량 초과 시 오래된 이벤트부터 제거)
+    order: VecDeque<Uuid>,
+}
+
+/// 인메모리 인과 관계 색인
+#[derive(Debug)]
+pub struct CausalityIndex {
+    state: RwLock<IndexState>,
+    capacity: usize,
+}
+
+impl Default for CausalityIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CausalityIndex {
+    /// 기본 용량(100,000건)으로 생성
+    pub fn new() -> Self {
+        Self::with_capacity(100_000)
+    }
+
+    /// 용량을 지정하여 생성 (0은 1로 보정)
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            state: RwLock::new(IndexState::default()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 색인된 이벤트 수
+    pub fn len(&self) -> usize {
+        self.state.read().unwrap().links.len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 헤더 색인 (같은 이벤트는 한 번만 기록)
+    pub fn record(&self, header: &EventHeader) {
+        let link = CausalLink::from_header(header);
+        let mut state = self.state.write().unwrap();
+        if state.links.contains_key(&link.event_id) {
+            return;
+        }
+
+        if state.order.len() >= self.capacity {
+            if let Some(oldest) = state.order.pop_front() {
+                state.children.remove(&oldest);
+                if let Some(cause) = state.links.remove(&oldest).and_then(|removed| removed.causation_id) {
+                    // 색인되지 않은 원인(외부 이벤트 등)의 항목도 마지막 자식과 함께 제거되므로
+                    // 자식 목록 수는 색인된 이벤트 수를 넘지 않음
+                    if let Some(siblings) = state.children.get_mut(&cause) {
+                        siblings.retain(|id| *id != oldest);
+                        if siblings.is_empty() {
+                            state.children.remove(&cause);
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(cause) = link.causation_id {
+            state.children.entry(cause).or_default().push(link.event_id);
+        }
+        state.order.push_back(link.event_id);
+        state.links.insert(link.event_id, link);
+    }
+
+    /// 이벤트 조회
+    pub fn get(&self, event_id: &Uuid) -> Option<CausalLink> {
+        self.state.read().unwrap().links.get(event_id).cloned()
+    }
+
+    /// 흐름의 시작부터 이 이벤트까지의 원인 경로 (이 이벤트 포함)
+    pub fn ancestors(&self, event_id: &Uuid) -> Vec<CausalLink> {
+        let state = self.state.read().unwrap();
+        let mut path = Vec::new();
+        let mut current = state.links.get(event_id);
+        while let Some(link) = current {
+            // 잘못된 데이터로 순환이 생겨도 멈추도록 함
+            if path.len() > state.links.len() {
+                break;
+            }
+            path.push(link.clone());
+            current = link.causation_id.and_then(|cause| state.links.get(&cause));
+        }
+        path.reverse();
+        path
+    }
+
+    /// 이 이벤트가 직·간접적으로 일으킨 이벤트 (깊이 우선, 형제는 발생 시간 순)
+    pub fn descendants(&self, event_id: &Uuid) -> Vec<CausalNode> {
+        let state = self.state.read().unwrap();
+        let mut nodes = Vec::new();
+        Self::collect(&state, event_id, 1, &mut nodes);
+        nodes
+    }
+
+    /// 이 이벤트가 속한 흐름 전체를 뿌리부터 재구성
+    ///
+    /// 원인 이벤트가 색인에 없으면(용량 초과로 제거 등) 거슬러 올라갈 수 있는 가장 오래된 이벤트가 뿌리가 됩니다.
+    pub fn chain(&self, event_id: &Uuid) -> Vec<CausalNode> {
+        let Some(root) = self.ancestors(event_id).into_iter().next() else {
+            return Vec::new();
+        };
+
+        let state = self.state.read().unwrap();
+        let mut nodes = vec![CausalNode {
+            depth: 0,
+            link: root.clone(),
+        }];
+        Self::collect(&state, &root.event_id, 1, &mut nodes);
+        nodes
+    }
+
+    /// 같은 상관 ID의 모든 이벤트 (발생 시간 순)
+    pub fn correlated(&self, correlation_id: &Uuid) -> Vec<CausalLink> {
+        let state = self.state.read().unwrap();
+        let mut links: Vec<_> = state
+            .links
+            .values()
+            .filter(|link| link.correlation_id == *correlation_id)
+            .cloned()
+            .collect();
+        links.sort_by_key(|link| (link.timestamp, link.event_id));
+        links
+    }
+
+    fn collect(state: &IndexState, event_id: &Uuid, depth: usize, nodes: &mut Vec<CausalNode>) {
+        let Some(children) = state.children.get(event_id) else {
+            return;
+        };
+        let mut children: Vec<_> = children.iter().filter_map(|id| state.links.get(id)).collect();
+        children.sort_by_key(|link| (link.timestamp, link.event_id));
+
+        for link in children {
+            if nodes.len() > state.links.len() {
+                return;
+            }
+            nodes.push(CausalNode {
+                depth,
+                link: link.clone(),
+            });
+            Self::collect(state, &link.event_id, depth + 1, nodes);
+        }
+    }
+}
+
+impl EnvelopeHandler for CausalityIndex {
+    fn handle(&self, envelope: &EventEnvelope<Value>) -> Result<()> {
+        self.record(&envelope.header);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::create_event_header;
+
+    fn header(event_type: &str, cause: Option<&EventHeader>) -> EventHeader {
+        let mut header = create_event_header("trading-domain", event_type, None);
+        match cause {
+            Some(cause) => {
+                header.correlation_id = Some(cause.correlation_id.unwrap_or(cause.id));
+                header.causation_id = Some(cause.id);
+            }
+            None => header.correlation_id = Some(header.id),
+        }
+        header
+    }
+
+    #[test]
+    fn test_chain_from_any_event() {
+        let signal = header("trading.signal.generated", None);
+        let decision = header("trading.decision.made", Some(&signal));
+        let order = header("exchange.order.created", Some(&decision));
+        let fill_1 = header("exchange.order.filled", Some(&order));
+        let fill_2 = header("exchange.order.filled", Some(&order));
+        let pnl = header("portfolio.pnl.realized", Some(&fill_2));
+        let unrelated = header("market.price.updated", None);
+
+        let index = CausalityIndex::new();
+        for h in [&pnl, &fill_2, &unrelated, &signal, &order, &fill_1, &decision] {
+            index.record(h);
+        }
+
+        let path: Vec<_> = index.ancestors(&pnl.id).into_iter().map(|link| link.event_type).collect();
+        assert_eq!(
+            path,
+            [
+                "trading.signal.generated",
+                "trading.decision.made",
+                "exchange.order.created",
+                "exchange.order.filled",
+                "portfolio.pnl.realized"
+            ]
+        );
+
+        // 중간 이벤트에서도 흐름 전체를 재구성
+        let chain = index.chain(&order.id);
+        assert_eq!(chain.len(), 6);
+        assert_eq!(chain[0].link.event_id, signal.id);
+        assert_eq!(chain.iter().map(|node| node.depth).max(), Some(4));
+        assert!(chain.iter().all(|node| node.link.correlation_id == signal.id));
+
+        assert_eq!(index.descendants(&order.id).len(), 3);
+        assert_eq!(index.correlated(&signal.id).len(), 6);
+        assert_eq!(index.chain(&unrelated.id).len(), 1);
+    }
+
+    #[test]
+    fn test_eviction_bounds_children() {
+        let index = CausalityIndex::with_capacity(10);
+        let mut cause = header("trading.signal.generated", None);
+        for i in 0..1_000 {
+            // 색인된 원인의 자식과 색인되지 않은 외부 원인의 자식을 섞어 기록
+            let external = header("external.webhook.received", None);
+            let next = if i % 2 == 0 {
+                header("exchange.order.filled", Some(&cause))
+            } else {
+                header("exchange.order.filled", Some(&external))
+            };
+            index.record(&next);
+            cause = next;
+        }
+
+        let state = index.state.read().unwrap();
+        assert_eq!(state.links.len(), 10);
+        assert_eq!(state.order.len(), 10);
+        assert!(state.children.len() <= 10);
+        assert!(state.children.values().flatten().all(|id| state.links.contains_key(id)));
+    }
+}
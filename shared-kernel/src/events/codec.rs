@@ -0,0 +1,264 @@
+//! 이벤트 봉투 코덱
+//!
+//! 봉투를 전송·저장할 바이트로 바꾸는 방식을 코덱으로 분리합니다. JSON이 아닌 코덱의
+//! 프레임 앞에는 `[0xCE][코덱 ID][코덱 버전]` 3바이트를 붙여, 서로 다른 코덱을 쓰는 발행자가
+//! 섞여 있어도 수신자가 프레임만 보고 코덱을 고를 수 있게 합니다. JSON 프레임은 접두사 없이
+//! 두어 기존 소비자와 다른 언어의 소비자가 그대로 읽을 수 있습니다.
+//!
+//! 인코딩할 때 헤더의 `codec`에 `{코덱 이름}/{버전}`을 기록하고, 디코딩할 때는 프레임의
+//! 코덱으로 다시 채우므로, 봉투를 받은 쪽에서도 어떤 형식으로 전달되었는지 알 수 있습니다.
+//! MessagePack과 이진 코덱 구현은 infrastructure 모듈에서 수행합니다.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::error::CoreError;
+use crate::types::Result;
+use super::{EventEnvelope, EventHeader};
+
+/// 코덱 프레임 접두사의 시작 바이트 (JSON 프레임에는 붙이지 않음)
+pub const FRAME_MAGIC: u8 = 0xCE;
+
+/// 코덱 프레임 접두사 길이 (시작 바이트, 코덱 ID, 코덱 버전)
+pub const FRAME_PREFIX_LEN: usize = 3;
+
+/// 코덱 종류
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CodecKind {
+    /// JSON (접두사 없음)
+    #[default]
+    #[serde(rename = "json")]
+    Json,
+    /// MessagePack (필드 이름 포함, 필드 추가·순서 변경에 강함)
+    #[serde(rename = "msgpack")]
+    MessagePack,
+    /// 스키마 기반 이진 형식 (필드 이름 없이 순서로 구분, 양쪽이 같은 스키마를 알아야 함)
+    #[serde(rename = "binary")]
+    Binary,
+}
+
+impl CodecKind {
+    /// 프레임 접두사의 코덱 ID
+    pub fn id(self) -> u8 {
+        match self {
+            CodecKind::Json => 1,
+            CodecKind::MessagePack => 2,
+            CodecKind::Binary => 3,
+        }
+    }
+
+    /// 코덱 ID로 찾기
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(CodecKind::Json),
+            2 => Some(CodecKind::MessagePack),
+            3 => Some(CodecKind::Binary),
+            _ => None,
+        }
+    }
+
+    /// 코덱 이름 (헤더의 `codec`, 설정값)
+    pub fn name(self) -> &'static str {
+        match self {
+            CodecKind::Json => "json",
+            CodecKind::MessagePack => "msgpack",
+            CodecKind::Binary => "binary",
+        }
+    }
+
+    /// 브로커 메시지의 콘텐츠 타입
+    pub fn content_type(self) -> &'static str {
+        match self {
+            CodecKind::Json => "application/json",
+            CodecKind::MessagePack => "application/msgpack",
+            CodecKind::Binary => "application/x-cryptolytica-binary",
+        }
+    }
+}
+
+impl fmt::Display for CodecKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 헤더의 `codec`에 기록하는 값 (`{코덱 이름}/{버전}`)
+pub fn codec_label(kind: CodecKind, version: u8) -> String {
+    format!("{}/{}", kind.name(), version)
+}
+
+/// 프레임의 코덱, 코덱 버전, 본문 (접두사가 없으면 JSON 버전 1)
+pub fn split_frame(frame: &[u8]) -> Result<(CodecKind, u8, &[u8])> {
+    match frame.first() {
+        None => Err(CoreError::Data("빈 이벤트 프레임".to_string())),
+        Some(&FRAME_MAGIC) => {
+            if frame.len() < FRAME_PREFIX_LEN {
+                return Err(CoreError::Data("이벤트 프레임 접두사가 잘림".to_string()));
+            }
+            let kind = CodecKind::from_id(frame[1])
+                .ok_or_else(|| CoreError::Data(format!("알 수 없는 코덱 ID: {}", frame[1])))?;
+            Ok((kind, frame[2], &frame[FRAME_PREFIX_LEN..]))
+        }
+        Some(_) => Ok((CodecKind::Json, 1, frame)),
+    }
+}
+
+/// 페이로드를 복사하지 않고 `EventEnvelope`와 같은 형태로 직렬화하기 위한 봉투
+#[derive(Debug, Serialize)]
+pub struct EnvelopeRef<'a, T: Serialize> {
+    /// 이벤트 헤더
+    pub header: &'a EventHeader,
+    /// 이벤트 데이터
+    pub payload: &'a T,
+}
+
+/// 페이로드를 건너뛰고 헤더만 읽기 위한 봉투 (필드 이름이 있는 형식용)
+#[derive(Debug, Deserialize)]
+pub struct HeaderOnly {
+    /// 이벤트 헤더
+    pub header: EventHeader,
+}
+
+/// `EventEnvelope`와 같은 형태를 `Serialize` 제약 없이 읽기 위한 봉투 (필드 이름이 있는 형식용)
+#[derive(Debug, Deserialize)]
+pub struct OwnedEnvelope<T> {
+    /// 이벤트 헤더
+    pub header: EventHeader,
+    /// 이벤트 데이터
+    pub payload: T,
+}
+
+/// 이벤트 봉투 코덱
+///
+/// 구현체는 본문 형식만 정하고, 접두사와 헤더의 `codec` 기록은 기본 메서드가 처리합니다.
+/// 코덱 버전은 본문 형식을 바꿀 때 올리며, 디코더는 자신의 버전 이하의 프레임을 읽을 수 있어야 합니다.
+pub trait EnvelopeCodec: Send + Sync {
+    /// 코덱 종류
+    fn kind(&self) -> CodecKind;
+
+    /// 본문 형식 버전
+    fn version(&self) -> u8 {
+        1
+    }
+
+    /// 헤더와 페이로드를 본문으로 직렬화하여 `out`에 덧붙임
+    fn encode_body<T: Serialize>(&self, header: &EventHeader, payload: &T, out: &mut Vec<u8>) -> Result<()>;
+
+    /// 본문에서 헤더만 읽기 (라우팅·타입 확인용)
+    fn decode_header_body(&self, body: &[u8], version: u8) -> Result<EventHeader>;
+
+    /// 본문에서 헤더와 페이로드 읽기
+    fn decode_body<T: DeserializeOwned>(&self, body: &[u8], version: u8) -> Result<(EventHeader, T)>;
+
+    /// 헤더에 코덱을 기록하고 프레임으로 직렬화
+    fn encode<T: Serialize>(&self, header: &EventHeader, payload: &T) -> Result<Vec<u8>> {
+        let mut header = header.clone();
+        header.codec = Some(codec_label(self.kind(), self.version()));
+
+        let mut out = Vec::with_capacity(256);
+        if self.kind() != CodecKind::Json {
+            out.extend_from_slice(&[FRAME_MAGIC, self.kind().id(), self.version()]);
+        }
+        self.encode_body(&header, payload, &mut out)?;
+        Ok(out)
+    }
+
+    /// 봉투를 프레임으로 직렬화
+    fn encode_envelope<T: Serialize + DeserializeOwned>(&self, envelope: &EventEnvelope<T>) -> Result<Vec<u8>> {
+        self.encode(&envelope.header, &envelope.payload)
+    }
+
+    /// 프레임에서 헤더만 읽기
+    fn decode_header(&self, frame: &[u8]) -> Result<EventHeader> {
+        let (version, body) = checked_body(self.kind(), self.version(), frame)?;
+        let mut header = self.decode_header_body(body, version)?;
+        header.codec = Some(codec_label(self.kind(), version));
+        Ok(header)
+    }
+
+    /// 프레임에서 헤더와 페이로드 읽기 (페이로드가 `Serialize`가 아니어도 됨)
+    fn decode_parts<T: DeserializeOwned>(&self, frame: &[u8]) -> Result<(EventHeader, T)> {
+        let (version, body) = checked_body(self.kind(), self.version(), frame)?;
+        let (mut header, payload) = self.decode_body::<T>(body, version)?;
+        header.codec = Some(codec_label(self.kind(), version));
+        Ok((header, payload))
+    }
+
+    /// 프레임에서 봉투 읽기
+    fn decode<T: Serialize + DeserializeOwned>(&self, frame: &[u8]) -> Result<EventEnvelope<T>> {
+        let (header, payload) = self.decode_parts(frame)?;
+        Ok(EventEnvelope { header, payload })
+    }
+}
+
+/// 프레임이 이 코덱이 읽을 수 있는 것인지 확인하고 버전과 본문 반환
+fn checked_body(kind: CodecKind, supported: u8, frame: &[u8]) -> Result<(u8, &[u8])> {
+    let (frame_kind, version, body) = split_frame(frame)?;
+    if frame_kind != kind {
+        return Err(CoreError::Data(format!("{} 코덱으로 {} 프레임을 읽을 수 없음", kind, frame_kind)));
+    }
+    if version == 0 || version > supported {
+        return Err(CoreError::Data(format!(
+            "지원하지 않는 {} 코덱 버전: {} (최대 {})",
+            kind, version, supported
+        )));
+    }
+    Ok((version, body))
+}
+
+/// JSON 코덱
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl EnvelopeCodec for JsonCodec {
+    fn kind(&self) -> CodecKind {
+        CodecKind::Json
+    }
+
+    fn encode_body<T: Serialize>(&self, header: &EventHeader, payload: &T, out: &mut Vec<u8>) -> Result<()> {
+        serde_json::to_writer(out, &EnvelopeRef { header, payload })
+            .map_err(|e| CoreError::Data(format!("이벤트 직렬화 실패: {}", e)))
+    }
+
+    fn decode_header_body(&self, body: &[u8], _version: u8) -> Result<EventHeader> {
+        serde_json::from_slice::<HeaderOnly>(body)
+            .map(|envelope| envelope.header)
+            .map_err(|e| CoreError::Data(format!("이벤트 헤더 역직렬화 실패: {}", e)))
+    }
+
+    fn decode_body<T: DeserializeOwned>(&self, body: &[u8], _version: u8) -> Result<(EventHeader, T)> {
+        serde_json::from_slice::<OwnedEnvelope<T>>(body)
+            .map(|envelope| (envelope.header, envelope.payload))
+            .map_err(|e| CoreError::Data(format!("이벤트 역직렬화 실패: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::create_event_header;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_json_frames_stay_plain_and_record_codec() {
+        let header = create_event_header("market-data", "market.trade", None);
+        let frame = JsonCodec.encode(&header, &json!({ "price": "42000.5" })).unwrap();
+
+        // 접두사 없는 JSON이므로 기존 소비자도 그대로 읽음
+        let plain: EventEnvelope<Value> = serde_json::from_slice(&frame).unwrap();
+        assert_eq!(plain.header.codec.as_deref(), Some("json/1"));
+
+        // 코덱을 모르던 시절의 JSON 봉투도 읽음
+        let legacy = serde_json::to_vec(&EventEnvelope { header: header.clone(), payload: json!(1) }).unwrap();
+        let decoded: EventEnvelope<Value> = JsonCodec.decode(&legacy).unwrap();
+        assert_eq!(decoded.header.id, header.id);
+        assert_eq!(decoded.header.codec.as_deref(), Some("json/1"));
+        assert_eq!(JsonCodec.decode_header(&frame).unwrap().event_type, "market.trade");
+
+        // 다른 코덱의 프레임이나 더 새로운 버전은 거부
+        assert!(JsonCodec.decode_header(&[FRAME_MAGIC, CodecKind::Binary.id(), 1, 0]).is_err());
+        assert!(split_frame(&[FRAME_MAGIC, 9, 1]).is_err());
+        assert_eq!(split_frame(&[FRAME_MAGIC, 2, 7, 42]).unwrap(), (CodecKind::MessagePack, 7, &[42u8][..]));
+    }
+}
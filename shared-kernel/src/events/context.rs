@@ -0,0 +1,205 @@
+//! 이벤트 상관·인과 맥락 전파
+//!
+//! 신호 → 매매 결정 → 주문 → 체결 → 손익으로 이어지는 이벤트들을 추적하기 위해,
+//! 핸들러가 이벤트를 처리하는 동안의 맥락(상관 ID, 원인 이벤트 ID)을 스레드 로컬과
+//! 태스크 로컬에 보관합니다. 버스는 핸들러를 이 맥락 안에서 실행하므로, 핸들러가
+//! 새 이벤트를 발행하면 헤더에 상관 ID와 원인 ID가 자동으로 기록됩니다.
+//!
+//! - 상관 ID: 최초 이벤트(맥락 없이 발행된 이벤트)의 ID로, 같은 흐름의 모든 이벤트가 공유
+//! - 원인 ID: 이 이벤트를 발행한 핸들러가 처리하던 이벤트의 ID
+
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::future::Future;
+use uuid::Uuid;
+
+use super::{Event, EventHeader};
+
+thread_local! {
+    static CURRENT: Cell<Option<EventContext>> = const { Cell::new(None) };
+}
+
+tokio::task_local! {
+    static TASK_CURRENT: EventContext;
+}
+
+/// 이벤트 상관·인과 맥락
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EventContext {
+    /// 상관 ID (흐름의 최초 이벤트 ID)
+    pub correlation_id: Uuid,
+    /// 원인 이벤트 ID (최초 이벤트는 없음)
+    pub causation_id: Option<Uuid>,
+}
+
+impl EventContext {
+    /// 맥락 생성
+    pub fn new(correlation_id: Uuid, causation_id: Option<Uuid>) -> Self {
+        Self {
+            correlation_id,
+            causation_id,
+        }
+    }
+
+    /// 현재 맥락 (태스크 로컬 우선, 없으면 스레드 로컬)
+    pub fn current() -> Option<Self> {
+        TASK_CURRENT
+            .try_with(|context| *context)
+            .ok()
+            .or_else(|| CURRENT.with(Cell::get))
+    }
+
+    /// 지금 발행하는 이벤트의 헤더에 기록할 맥락
+    ///
+    /// 처리 중인 이벤트가 없으면 이 이벤트가 새 흐름의 시작이 됩니다.
+    pub fn for_new_event(event_id: Uuid) -> Self {
+        Self::current().unwrap_or(Self {
+            correlation_id: event_id,
+            causation_id: None,
+        })
+    }
+
+    /// 이벤트를 발행할 때 헤더에 기록할 맥락 (이벤트가 들고 있는 맥락 우선)
+    pub fn of<E: Event + ?Sized>(event: &E) -> Self {
+        event.context().unwrap_or_else(|| Self::for_new_event(*event.id()))
+    }
+
+    /// 헤더에 기록된 맥락 (상관 ID가 없는 이전 헤더는 이벤트 자신이 흐름의 시작)
+    pub fn from_header(header: &EventHeader) -> Self {
+        Self {
+            correlation_id: header.correlation_id.unwrap_or(header.id),
+            causation_id: header.causation_id,
+        }
+    }
+
+    /// 이 맥락을 가진 이벤트를 처리하는 동안의 맥락
+    pub fn handling(&self, event_id: Uuid) -> Self {
+        Self {
+            correlation_id: self.correlation_id,
+            causation_id: Some(event_id),
+        }
+    }
+
+    /// 헤더에 맥락 기록
+    pub fn apply_to(&self, header: &mut EventHeader) {
+        header.correlation_id = Some(self.correlation_id);
+        header.causation_id = self.causation_id;
+    }
+
+    /// 현재 스레드의 맥락으로 설정 (가드가 사라지면 이전 맥락으로 복원)
+    pub fn enter(self) -> EventContextGuard {
+        EventContextGuard {
+            previous: CURRENT.with(|current| current.replace(Some(self))),
+        }
+    }
+
+    /// 이 맥락 안에서 비동기 작업 실행 (스레드를 옮겨 다니는 태스크에도 유지)
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        TASK_CURRENT.scope(self, future).await
+    }
+
+    /// 이벤트 처리 추적 스팬
+    ///
+    /// 버스는 발행 시점의 스팬 아래에 이벤트 스팬을 만들므로, 스팬 계층이 인과 사슬과 같아집니다.
+    pub fn span(&self, event_type: &str, event_id: Uuid) -> tracing::Span {
+        tracing::info_span!(
+            "event",
+            event_type,
+            event_id = %event_id,
+            correlation_id = %self.correlation_id,
+            causation_id = ?self.causation_id,
+        )
+    }
+}
+
+/// 스레드 로컬 맥락 복원 가드
+#[must_use = "가드가 즉시 사라지면 맥락이 바로 복원됩니다"]
+#[derive(Debug)]
+pub struct EventContextGuard {
+    previous: Option<EventContext>,
+}
+
+impl Drop for EventContextGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    struct Decided {
+        id: Uuid,
+        context: Option<EventContext>,
+    }
+
+    impl Event for Decided {
+        fn event_type(&self) -> &'static str {
+            "trading.decision.made"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+
+        fn context(&self) -> Option<EventContext> {
+            self.context
+        }
+    }
+
+    #[test]
+    fn test_thread_local_propagation() {
+        let signal = Uuid::new_v4();
+        let root = EventContext::for_new_event(signal);
+        assert_eq!(root, EventContext::new(signal, None));
+
+        let decision = Uuid::new_v4();
+        {
+            let _guard = root.handling(signal).enter();
+            let stamped = EventContext::for_new_event(decision);
+            assert_eq!(stamped, EventContext::new(signal, Some(signal)));
+
+            // 중첩 처리 후 바깥 맥락으로 복원
+            {
+                let _inner = stamped.handling(decision).enter();
+                assert_eq!(EventContext::current().unwrap().causation_id, Some(decision));
+            }
+            assert_eq!(EventContext::current().unwrap().causation_id, Some(signal));
+        }
+        assert!(EventContext::current().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_task_local_scope() {
+        let context = EventContext::new(Uuid::new_v4(), Some(Uuid::new_v4()));
+        let seen = context
+            .scope(async {
+                tokio::task::yield_now().await;
+                EventContext::current()
+            })
+            .await;
+        assert_eq!(seen, Some(context));
+        assert!(EventContext::current().is_none());
+    }
+
+    #[test]
+    fn test_event_context_takes_precedence() {
+        let signal = Uuid::new_v4();
+        let carried = EventContext::new(signal, Some(signal));
+        let unrelated = EventContext::new(Uuid::new_v4(), Some(Uuid::new_v4()));
+        let _guard = unrelated.enter();
+
+        // 원인을 들고 있는 이벤트는 발행하는 곳의 처리 맥락과 무관하게 자기 흐름을 이음
+        let decided = Decided { id: Uuid::new_v4(), context: Some(carried) };
+        assert_eq!(EventContext::of(&decided), carried);
+
+        let plain = Decided { id: Uuid::new_v4(), context: None };
+        assert_eq!(EventContext::of(&plain), unrelated);
+    }
+}
@@ -0,0 +1,111 @@
+//! 데드 레터 큐 계약
+//!
+//! 구독 핸들러가 재시도 정책을 모두 소진하고도 처리하지 못한 이벤트를 봉투째로
+//! 보관합니다. 운영자는 데드 레터를 조회한 뒤 원인을 해결하고 같은 구독으로 다시
+//! 전달(re-drive)하거나 폐기할 수 있습니다. 실제 구현은 infrastructure 모듈에서 수행합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::types::Result;
+use super::EventEnvelope;
+
+/// 처리에 실패한 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// 데드 레터 ID
+    pub id: Uuid,
+    /// 실패한 구독 ID
+    pub subscription_id: Uuid,
+    /// 실패한 구독 이름
+    pub subscription: String,
+    /// 이벤트 봉투
+    pub envelope: EventEnvelope<Value>,
+    /// 마지막 오류 메시지
+    pub error: String,
+    /// 지금까지의 처리 시도 횟수 (재전달 포함)
+    pub attempts: u32,
+    /// 첫 실패 시각
+    pub first_failed_at: DateTime<Utc>,
+    /// 마지막 실패 시각
+    pub last_failed_at: DateTime<Utc>,
+}
+
+impl DeadLetter {
+    /// 이벤트 타입
+    pub fn event_type(&self) -> &str {
+        &self.envelope.header.event_type
+    }
+}
+
+/// 데드 레터 저장소
+pub trait DeadLetterStore: Send + Sync {
+    /// 데드 레터 추가 (같은 ID가 있으면 교체)
+    fn put(&self, letter: DeadLetter) -> Result<()>;
+
+    /// 모든 데드 레터 (첫 실패 시각 순)
+    fn list(&self) -> Result<Vec<DeadLetter>>;
+
+    /// 데드 레터 조회
+    fn get(&self, id: &Uuid) -> Result<Option<DeadLetter>>;
+
+    /// 데드 레터 제거
+    fn remove(&self, id: &Uuid) -> Result<Option<DeadLetter>>;
+}
+
+/// 재전달 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedriveReport {
+    /// 처리에 성공하여 제거된 데드 레터 수
+    pub succeeded: usize,
+    /// 다시 실패하여 남은 데드 레터 수
+    pub failed: usize,
+}
+
+/// 데드 레터 관리 API
+pub trait DeadLetterQueue: Send + Sync {
+    /// 데드 레터 목록
+    fn dead_letters(&self) -> Result<Vec<DeadLetter>>;
+
+    /// 데드 레터 조회
+    fn dead_letter(&self, id: &Uuid) -> Result<Option<DeadLetter>>;
+
+    /// 원래 구독으로 다시 전달 (성공하면 제거, 실패하면 시도 횟수와 오류를 갱신하여 보관)
+    fn redrive(&self, id: &Uuid) -> Result<()>;
+
+    /// 모든 데드 레터를 다시 전달
+    fn redrive_all(&self) -> Result<RedriveReport> {
+        let mut report = RedriveReport::default();
+        for letter in self.dead_letters()? {
+            match self.redrive(&letter.id) {
+                Ok(()) => report.succeeded += 1,
+                Err(_) => report.failed += 1,
+            }
+        }
+        Ok(report)
+    }
+
+    /// 데드 레터 폐기
+    fn discard(&self, id: &Uuid) -> Result<DeadLetter>;
+}
+
+/// 이벤트 전달 결과 지표
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeliveryMetrics {
+    /// 핸들러 실패 횟수 (재시도 포함)
+    pub failed: u64,
+    /// 예약된 재시도 횟수
+    pub retried: u64,
+    /// 재시도 끝에 처리에 성공한 이벤트 수
+    pub recovered: u64,
+    /// 데드 레터로 옮겨진 이벤트 수
+    pub dead_lettered: u64,
+    /// 재전달에 성공한 데드 레터 수
+    pub redriven: u64,
+    /// 재전달에 다시 실패한 횟수
+    pub redrive_failed: u64,
+    /// 폐기된 데드 레터 수
+    pub discarded: u64,
+}
@@ -0,0 +1,588 @@
+//! 멱등 처리와 순서 보장
+//!
+//! 브로커 재전달, 재생, 재연결 폭주로 같은 이벤트가 여러 번 전달될 수 있습니다.
+//! `IdempotentHandler`는 구독 핸들러를 감싸 중복 제거 창 안에서 이미 처리한 키의
+//! 이벤트를 건너뛰고, `SequencedHandler`는 키(예: 주문 ID)별 순번을 검사하여 순서가
+//! 어긋난 이벤트를 보류하거나 거부합니다. 둘 다 핸들러 어댑터이므로 구독할 때
+//! 선택적으로 감싸며, 버스 구현과 관계없이 동작합니다.
+//!
+//! ```ignore
+//! let handler = IdempotentHandler::in_memory(OrderFilledEventHandler::new(cache), DedupWindow::new(100_000))
+//!     .with_key(|e: &OrderFilledEvent| format!("{}:{}", e.order_id, e.total_filled));
+//! bus.subscribe(handler)?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::clock::{system_clock, SharedClock};
+use crate::error::CoreError;
+use crate::types::Result;
+use super::{Event, EventHandler};
+
+/// 중복 제거 창 (크기와 선택적 보존 기간)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DedupWindow {
+    /// 기억할 최대 키 수
+    pub max_entries: usize,
+    /// 키를 기억하는 기간 (없으면 크기로만 제한)
+    pub ttl: Option<Duration>,
+}
+
+impl DedupWindow {
+    /// 크기로 제한되는 창 생성 (0은 1로 보정)
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.max(1),
+            ttl: None,
+        }
+    }
+
+    /// 보존 기간 지정
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// 보존 기간이 지난 기준 시각
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.ttl
+            .and_then(|ttl| chrono::Duration::from_std(ttl).ok())
+            .map(|ttl| now - ttl)
+    }
+}
+
+/// 처리한 키를 기억하는 저장소
+pub trait DedupStore: Send + Sync {
+    /// 키가 창 안에 없으면 기록하고 `true`, 이미 있으면 `false` 반환
+    fn insert_if_absent(&self, key: &str, seen_at: DateTime<Utc>) -> Result<bool>;
+
+    /// 키 제거 (핸들러가 실패하여 재시도를 허용해야 할 때)
+    fn remove(&self, key: &str) -> Result<()>;
+}
+
+/// 인메모리 중복 제거 저장소
+#[derive(Debug)]
+pub struct InMemoryDedupStore {
+    window: DedupWindow,
+    state: Mutex<DedupState>,
+}
+
+#[derive(Debug, Default)]
+struct DedupState {
+    keys: HashMap<String, DateTime<Utc>>,
+    /// 기록 순서
+    order: VecDeque<(String, DateTime<Utc>)>,
+}
+
+impl InMemoryDedupStore {
+    /// 창을 지정하여 생성
+    pub fn new(window: DedupWindow) -> Self {
+        Self {
+            window,
+            state: Mutex::new(DedupState::default()),
+        }
+    }
+
+    /// 중복 제거 창
+    pub fn window(&self) -> DedupWindow {
+        self.window
+    }
+
+    /// 기억 중인 키 수
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().keys.len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 기억 중인 키와 기록 시각 (기록 순, 영속화용)
+    pub fn entries(&self) -> Vec<(String, DateTime<Utc>)> {
+        let state = self.state.lock().unwrap();
+        state
+            .order
+            .iter()
+            .filter(|(key, seen_at)| state.keys.get(key) == Some(seen_at))
+            .cloned()
+            .collect()
+    }
+
+    /// 창 밖으로 밀려난 키 정리
+    fn evict(&self, state: &mut DedupState, now: DateTime<Utc>) {
+        let cutoff = self.window.cutoff(now);
+        while let Some((key, seen_at)) = state.order.front() {
+            let expired = cutoff.is_some_and(|cutoff| *seen_at < cutoff);
+            let live = state.keys.get(key) == Some(seen_at);
+            if live && !expired && state.keys.len() <= self.window.max_entries {
+                break;
+            }
+            if live {
+                state.keys.remove(key);
+            }
+            state.order.pop_front();
+        }
+    }
+}
+
+impl DedupStore for InMemoryDedupStore {
+    fn insert_if_absent(&self, key: &str, seen_at: DateTime<Utc>) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        self.evict(&mut state, seen_at);
+
+        if state.keys.contains_key(key) {
+            return Ok(false);
+        }
+        state.keys.insert(key.to_string(), seen_at);
+        state.order.push_back((key.to_string(), seen_at));
+        self.evict(&mut state, seen_at);
+        Ok(true)
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(seen_at) = state.keys.remove(key) {
+            // 실패한 처리는 대개 방금 기록한 키이므로 뒤에서부터 찾음
+            if let Some(index) = state.order.iter().rposition(|entry| entry.0 == key && entry.1 == seen_at) {
+                state.order.remove(index);
+            }
+        }
+        Ok(())
+    }
+}
+
+type KeyFn<E> = Arc<dyn Fn(&E) -> String + Send + Sync>;
+
+/// 중복 이벤트를 건너뛰는 핸들러 어댑터
+///
+/// 처리 전에 키를 기록하고, 내부 핸들러가 실패하면 키를 지워 재시도·재전달이 다시 처리할 수 있게 합니다.
+pub struct IdempotentHandler<E: Event, H: EventHandler<E>> {
+    inner: H,
+    store: Arc<dyn DedupStore>,
+    key: KeyFn<E>,
+    clock: SharedClock,
+    skipped: AtomicU64,
+}
+
+impl<E: Event, H: EventHandler<E>> IdempotentHandler<E, H> {
+    /// `Event::id`를 키로 사용하는 어댑터 생성
+    pub fn new(inner: H, store: Arc<dyn DedupStore>) -> Self {
+        Self {
+            inner,
+            store,
+            key: Arc::new(|event: &E| event.id().to_string()),
+            clock: system_clock(),
+            skipped: AtomicU64::new(0),
+        }
+    }
+
+    /// 인메모리 저장소로 어댑터 생성
+    pub fn in_memory(inner: H, window: DedupWindow) -> Self {
+        Self::new(inner, Arc::new(InMemoryDedupStore::new(window)))
+    }
+
+    /// 사용자 정의 멱등 키 지정 (예: 주문 ID와 누적 체결 수량)
+    pub fn with_key<F>(mut self, key: F) -> Self
+    where
+        F: Fn(&E) -> String + Send + Sync + 'static,
+    {
+        self.key = Arc::new(key);
+        self
+    }
+
+    /// 창 계산에 사용할 시계 지정
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 중복으로 건너뛴 이벤트 수
+    pub fn skipped(&self) -> u64 {
+        self.skipped.load(Ordering::Relaxed)
+    }
+}
+
+impl<E: Event, H: EventHandler<E>> EventHandler<E> for IdempotentHandler<E, H> {
+    fn handle(&self, event: &E) -> Result<()> {
+        let key = (self.key)(event);
+        if !self.store.insert_if_absent(&key, self.clock.now())? {
+            self.skipped.fetch_add(1, Ordering::Relaxed);
+            tracing::debug!("중복 이벤트 건너뜀: {} ({})", event.event_type(), key);
+            return Ok(());
+        }
+
+        self.inner.handle(event).inspect_err(|_| {
+            if let Err(e) = self.store.remove(&key) {
+                tracing::error!("멱등 키 제거 실패: {} - {:?}", key, e);
+            }
+        })
+    }
+}
+
+/// 순서가 앞선 이벤트를 받았을 때의 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutOfOrderPolicy {
+    /// 빠진 순번이 도착할 때까지 보류 (키마다 최대 `max_pending`개)
+    Hold { max_pending: usize },
+    /// 오류를 반환 (버스의 재시도·데드 레터 정책에 맡김)
+    Reject,
+}
+
+type SequenceFn<E> = Arc<dyn Fn(&E) -> Option<(String, u64)> + Send + Sync>;
+type TerminalFn<E> = Arc<dyn Fn(&E) -> bool + Send + Sync>;
+
+#[derive(Debug)]
+struct SequenceState<E> {
+    next: u64,
+    held: BTreeMap<u64, E>,
+}
+
+/// 키별 순번 순서대로 처리하는 핸들러 어댑터
+///
+/// 순번 함수가 `(키, 순번)`을 돌려주는 이벤트만 검사하며, `None`이면 그대로 전달합니다.
+/// 이미 처리한 순번보다 작은 이벤트는 중복으로 보고 건너뜁니다. 순번 상태는 메모리에만
+/// 유지되며, 키별 처리 순서를 보장하기 위해 내부 핸들러는 잠금을 잡은 채 호출됩니다.
+/// 키의 상태는 종료 이벤트(예: 주문 완료·취소)를 처리하면 제거되므로, 종료 판단 함수를
+/// 지정하지 않으면 키 수만큼 계속 늘어납니다.
+pub struct SequencedHandler<E: Event + Clone, H: EventHandler<E>> {
+    inner: H,
+    sequence_of: SequenceFn<E>,
+    is_terminal: Option<TerminalFn<E>>,
+    policy: OutOfOrderPolicy,
+    initial: u64,
+    state: Mutex<HashMap<String, SequenceState<E>>>,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<E: Event + Clone, H: EventHandler<E>> SequencedHandler<E, H> {
+    /// 순번 함수와 정책으로 어댑터 생성 (키마다 첫 순번은 0)
+    pub fn new<F>(inner: H, sequence_of: F, policy: OutOfOrderPolicy) -> Self
+    where
+        F: Fn(&E) -> Option<(String, u64)> + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            sequence_of: Arc::new(sequence_of),
+            is_terminal: None,
+            policy,
+            initial: 0,
+            state: Mutex::new(HashMap::new()),
+            _event: PhantomData,
+        }
+    }
+
+    /// 키마다 처음 기대하는 순번 지정
+    pub fn with_initial_sequence(mut self, initial: u64) -> Self {
+        self.initial = initial;
+        self
+    }
+
+    /// 키의 마지막 이벤트인지 판단하는 함수 지정
+    ///
+    /// 종료 이벤트를 처리하고 보류 중인 이벤트가 없으면 키의 상태를 제거합니다. 제거한 뒤에
+    /// 늦게 도착한 중복은 처음 보는 키로 처리되므로 `IdempotentHandler`로 함께 거릅니다.
+    pub fn with_terminal<F>(mut self, is_terminal: F) -> Self
+    where
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.is_terminal = Some(Arc::new(is_terminal));
+        self
+    }
+
+    /// 순번 상태를 유지 중인 키 수
+    pub fn tracked_keys(&self) -> usize {
+        self.state.lock().unwrap().len()
+    }
+
+    /// 다음에 기대하는 순번
+    pub fn next_sequence(&self, key: &str) -> u64 {
+        self.state
+            .lock()
+            .unwrap()
+            .get(key)
+            .map_or(self.initial, |state| state.next)
+    }
+
+    /// 보류 중인 이벤트 수
+    pub fn held(&self) -> usize {
+        self.state.lock().unwrap().values().map(|state| state.held.len()).sum()
+    }
+
+    fn terminal(&self, event: &E) -> bool {
+        self.is_terminal.as_ref().is_some_and(|is_terminal| is_terminal(event))
+    }
+
+    /// 기대 순번부터 이어지는 보류 이벤트 처리 (실패하면 다시 보류), 종료 이벤트를 처리했는지 반환
+    fn drain(&self, key: &str, state: &mut SequenceState<E>) -> bool {
+        let mut terminated = false;
+        while let Some(event) = state.held.remove(&state.next) {
+            if let Err(e) = self.inner.handle(&event) {
+                tracing::error!("보류 이벤트 처리 실패: {} 순번 {} - {:?}", key, state.next, e);
+                state.held.insert(state.next, event);
+                return terminated;
+            }
+            terminated |= self.terminal(&event);
+            state.next += 1;
+        }
+        terminated
+    }
+}
+
+impl<E: Event + Clone, H: EventHandler<E>> EventHandler<E> for SequencedHandler<E, H> {
+    fn handle(&self, event: &E) -> Result<()> {
+        let Some((key, sequence)) = (self.sequence_of)(event) else {
+            return self.inner.handle(event);
+        };
+
+        let mut states = self.state.lock().unwrap();
+        let state = states.entry(key.clone()).or_insert_with(|| SequenceState {
+            next: self.initial,
+            held: BTreeMap::new(),
+        });
+
+        if sequence < state.next {
+            tracing::debug!("이미 처리한 순번 건너뜀: {} 순번 {} (기대 {})", key, sequence, state.next);
+            return Ok(());
+        }
+
+        if sequence > state.next {
+            return match self.policy {
+                OutOfOrderPolicy::Reject => Err(CoreError::Validation(format!(
+                    "순서가 맞지 않는 이벤트: {} 순번 {} (기대 {})",
+                    key, sequence, state.next
+                ))),
+                OutOfOrderPolicy::Hold { max_pending } => {
+                    if state.held.len() >= max_pending && !state.held.contains_key(&sequence) {
+                        return Err(CoreError::Validation(format!(
+                            "보류 한도 초과: {} (최대 {}개, 기대 순번 {})",
+                            key, max_pending, state.next
+                        )));
+                    }
+                    tracing::debug!("순번 {} 보류: {} (기대 {})", sequence, key, state.next);
+                    state.held.insert(sequence, event.clone());
+                    if self.drain(&key, state) && state.held.is_empty() {
+                        states.remove(&key);
+                    }
+                    Ok(())
+                }
+            };
+        }
+
+        self.inner.handle(event)?;
+        state.next += 1;
+        let terminated = self.drain(&key, state) || self.terminal(event);
+        if terminated && state.held.is_empty() {
+            tracing::debug!("종료된 키의 순번 상태 제거: {} (마지막 순번 {})", key, state.next - 1);
+            states.remove(&key);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SimulatedClock;
+    use chrono::TimeZone;
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Fill {
+        id: Uuid,
+        order: String,
+        sequence: u64,
+    }
+
+    impl Fill {
+        fn new(order: &str, sequence: u64) -> Self {
+            Self {
+                id: Uuid::new_v4(),
+                order: order.to_string(),
+                sequence,
+            }
+        }
+    }
+
+    impl Event for Fill {
+        fn event_type(&self) -> &'static str {
+            "exchange.order.filled"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<u64>>>);
+
+    impl Recorder {
+        fn seen(&self) -> Vec<u64> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    impl EventHandler<Fill> for Recorder {
+        fn handle(&self, event: &Fill) -> Result<()> {
+            self.0.lock().unwrap().push(event.sequence);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dedup_by_id_and_custom_key() {
+        let recorder = Recorder::default();
+        let handler = IdempotentHandler::in_memory(recorder.clone(), DedupWindow::new(100));
+
+        let fill = Fill::new("o1", 1);
+        handler.handle(&fill).unwrap();
+        handler.handle(&fill).unwrap();
+        assert_eq!(recorder.seen(), vec![1]);
+        assert_eq!(handler.skipped(), 1);
+
+        // 재생으로 ID가 달라져도 사용자 정의 키로 중복 판단
+        let recorder = Recorder::default();
+        let handler = IdempotentHandler::in_memory(recorder.clone(), DedupWindow::new(100))
+            .with_key(|fill: &Fill| format!("{}:{}", fill.order, fill.sequence));
+        handler.handle(&Fill::new("o1", 1)).unwrap();
+        handler.handle(&Fill::new("o1", 1)).unwrap();
+        handler.handle(&Fill::new("o1", 2)).unwrap();
+        assert_eq!(recorder.seen(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_dedup_window_bounds() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let store = InMemoryDedupStore::new(DedupWindow::new(2).with_ttl(Duration::from_secs(60)));
+
+        assert!(store.insert_if_absent("a", start).unwrap());
+        assert!(!store.insert_if_absent("a", start).unwrap());
+        assert!(store.insert_if_absent("b", start).unwrap());
+        assert!(store.insert_if_absent("c", start).unwrap());
+        // 크기 제한으로 가장 오래된 키가 밀려남
+        assert!(store.insert_if_absent("a", start).unwrap());
+        assert_eq!(store.len(), 2);
+
+        // 보존 기간이 지나면 다시 처리 가능
+        let later = start + chrono::Duration::seconds(61);
+        assert!(store.insert_if_absent("c", later).unwrap());
+        assert_eq!(store.entries(), vec![("c".to_string(), later)]);
+    }
+
+    #[test]
+    fn test_remove_drops_order_entry() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let store = InMemoryDedupStore::new(DedupWindow::new(10));
+
+        for i in 0..5 {
+            let key = format!("k{}", i);
+            assert!(store.insert_if_absent(&key, start).unwrap());
+            store.remove(&key).unwrap();
+        }
+        store.remove("missing").unwrap();
+
+        // 제거한 키의 기록 순서 항목도 남지 않음
+        assert!(store.is_empty());
+        assert!(store.state.lock().unwrap().order.is_empty());
+
+        assert!(store.insert_if_absent("k0", start).unwrap());
+        assert_eq!(store.entries(), vec![("k0".to_string(), start)]);
+    }
+
+    #[test]
+    fn test_failed_handling_releases_key() {
+        struct Failing;
+        impl EventHandler<Fill> for Failing {
+            fn handle(&self, _: &Fill) -> Result<()> {
+                Err(CoreError::Domain("주문 없음".to_string()))
+            }
+        }
+
+        let store = Arc::new(InMemoryDedupStore::new(DedupWindow::new(10)));
+        let clock = Arc::new(SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()));
+        let handler = IdempotentHandler::new(Failing, store.clone()).with_clock(clock);
+        assert!(handler.handle(&Fill::new("o1", 1)).is_err());
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_sequenced_hold_and_reject() {
+        let sequence_of = |fill: &Fill| Some((fill.order.clone(), fill.sequence));
+
+        let recorder = Recorder::default();
+        let handler = SequencedHandler::new(recorder.clone(), sequence_of, OutOfOrderPolicy::Hold { max_pending: 2 });
+        handler.handle(&Fill::new("o1", 2)).unwrap();
+        handler.handle(&Fill::new("o1", 1)).unwrap();
+        assert_eq!(recorder.seen(), Vec::<u64>::new());
+        assert_eq!(handler.held(), 2);
+        assert!(handler.handle(&Fill::new("o1", 3)).is_err());
+
+        handler.handle(&Fill::new("o1", 0)).unwrap();
+        assert_eq!(recorder.seen(), vec![0, 1, 2]);
+        assert_eq!(handler.next_sequence("o1"), 3);
+
+        // 이미 처리한 순번은 건너뜀
+        handler.handle(&Fill::new("o1", 1)).unwrap();
+        assert_eq!(recorder.seen(), vec![0, 1, 2]);
+
+        let recorder = Recorder::default();
+        let handler = SequencedHandler::new(recorder.clone(), sequence_of, OutOfOrderPolicy::Reject)
+            .with_initial_sequence(1);
+        assert!(handler.handle(&Fill::new("o2", 2)).is_err());
+        handler.handle(&Fill::new("o2", 1)).unwrap();
+        handler.handle(&Fill::new("o2", 2)).unwrap();
+        assert_eq!(recorder.seen(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_sequenced_evicts_terminated_keys() {
+        // 순번 9가 주문의 마지막 이벤트
+        let recorder = Recorder::default();
+        let handler = SequencedHandler::new(
+            recorder.clone(),
+            |fill: &Fill| Some((fill.order.clone(), fill.sequence)),
+            OutOfOrderPolicy::Hold { max_pending: 10 },
+        )
+        .with_terminal(|fill: &Fill| fill.sequence == 9);
+
+        for order in ["o1", "o2", "o3"] {
+            handler.handle(&Fill::new(order, 0)).unwrap();
+            handler.handle(&Fill::new(order, 9)).unwrap();
+        }
+        // 앞선 순번이 빠진 종료 이벤트는 보류 중이므로 제거하지 않음
+        assert_eq!(handler.tracked_keys(), 3);
+
+        for order in ["o1", "o2"] {
+            for sequence in 1..9 {
+                handler.handle(&Fill::new(order, sequence)).unwrap();
+            }
+        }
+        assert_eq!(handler.tracked_keys(), 1);
+        assert_eq!(handler.held(), 1);
+        assert_eq!(handler.next_sequence("o1"), 0);
+
+        // 순서대로 도착한 종료 이벤트도 처리 직후 제거
+        let recorder = Recorder::default();
+        let handler = SequencedHandler::new(
+            recorder.clone(),
+            |fill: &Fill| Some((fill.order.clone(), fill.sequence)),
+            OutOfOrderPolicy::Reject,
+        )
+        .with_terminal(|fill: &Fill| fill.sequence == 1);
+        handler.handle(&Fill::new("o5", 0)).unwrap();
+        handler.handle(&Fill::new("o5", 1)).unwrap();
+        assert_eq!(handler.tracked_keys(), 0);
+        assert_eq!(recorder.seen(), vec![0, 1]);
+    }
+}
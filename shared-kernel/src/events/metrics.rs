@@ -0,0 +1,766 @@
+//! 이벤트 버스 지표
+//!
+//! 버스마다 `EventBusMetrics`를 하나 두고, 발행 수는 이벤트 타입별로, 핸들러 처리 결과와
+//! 처리 시간, 큐 깊이는 구독별로 기록합니다. 관리 도구는 `snapshot`으로 현재 지표를 읽고,
+//! 모니터링은 `EventMetricsRegistry`에 등록된 모든 버스의 지표를 Prometheus 텍스트
+//! 형식으로 내보냅니다.
+//!
+//! `Event::event_type`은 인스턴스 메서드이므로 이벤트 타입 이름은 그 타입의 이벤트가
+//! 처음 발행되거나 수신될 때 알게 됩니다. 그 전까지 구독의 `event_type` 레이블은 Rust
+//! 타입 이름입니다.
+//!
+//! ```ignore
+//! let bus = AsyncInMemoryEventBus::new();
+//! let registry = EventMetricsRegistry::new();
+//! registry.register(bus.metrics().clone());
+//! // GET /metrics
+//! let body = registry.render_prometheus();
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Write};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::{DeliveryMetrics, Event, SubscriptionStats};
+
+/// 핸들러 처리 시간 히스토그램 구간 상한 (초)
+pub const LATENCY_BUCKETS: [f64; 12] = [
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 5.0,
+];
+
+/// Prometheus 지표 이름 접두사
+const METRIC_PREFIX: &str = "cryptolytica_event";
+
+/// 구독 통계에서 지표 값을 꺼내는 함수
+type StatField = fn(&SubscriptionStats) -> u64;
+
+/// 핸들러 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandlerOutcome {
+    /// 성공
+    Delivered,
+    /// 오류 반환
+    Failed,
+    /// 패닉
+    Panicked,
+}
+
+/// 재시도·데드 레터 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// 핸들러 실패 (재시도 포함)
+    Failed,
+    /// 재시도 예약
+    Retried,
+    /// 재시도 끝에 처리 성공
+    Recovered,
+    /// 데드 레터로 옮김
+    DeadLettered,
+    /// 데드 레터 재전달 성공
+    Redriven,
+    /// 데드 레터 재전달 실패
+    RedriveFailed,
+    /// 데드 레터 폐기
+    Discarded,
+}
+
+/// 재시도·데드 레터 처리 결과 카운터
+#[derive(Debug, Default)]
+struct DeliveryCounters {
+    failed: AtomicU64,
+    retried: AtomicU64,
+    recovered: AtomicU64,
+    dead_lettered: AtomicU64,
+    redriven: AtomicU64,
+    redrive_failed: AtomicU64,
+    discarded: AtomicU64,
+}
+
+impl DeliveryCounters {
+    fn counter(&self, outcome: DeliveryOutcome) -> &AtomicU64 {
+        match outcome {
+            DeliveryOutcome::Failed => &self.failed,
+            DeliveryOutcome::Retried => &self.retried,
+            DeliveryOutcome::Recovered => &self.recovered,
+            DeliveryOutcome::DeadLettered => &self.dead_lettered,
+            DeliveryOutcome::Redriven => &self.redriven,
+            DeliveryOutcome::RedriveFailed => &self.redrive_failed,
+            DeliveryOutcome::Discarded => &self.discarded,
+        }
+    }
+
+    fn snapshot(&self) -> DeliveryMetrics {
+        DeliveryMetrics {
+            failed: self.failed.load(Ordering::Relaxed),
+            retried: self.retried.load(Ordering::Relaxed),
+            recovered: self.recovered.load(Ordering::Relaxed),
+            dead_lettered: self.dead_lettered.load(Ordering::Relaxed),
+            redriven: self.redriven.load(Ordering::Relaxed),
+            redrive_failed: self.redrive_failed.load(Ordering::Relaxed),
+            discarded: self.discarded.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 처리 시간 히스토그램 (구간별 관측 수, 마지막 칸은 가장 큰 상한 초과)
+#[derive(Debug)]
+struct Histogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS.len() + 1],
+    sum_nanos: AtomicU64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            sum_nanos: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Histogram {
+    fn observe(&self, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64();
+        let index = LATENCY_BUCKETS
+            .iter()
+            .position(|le| seconds <= *le)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        self.sum_nanos
+            .fetch_add(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> LatencySnapshot {
+        let mut cumulative = 0;
+        let mut buckets = Vec::with_capacity(LATENCY_BUCKETS.len());
+        for (le, count) in LATENCY_BUCKETS.iter().zip(&self.buckets) {
+            cumulative += count.load(Ordering::Relaxed);
+            buckets.push(LatencyBucket { le: *le, count: cumulative });
+        }
+        LatencySnapshot {
+            buckets,
+            count: cumulative + self.buckets[LATENCY_BUCKETS.len()].load(Ordering::Relaxed),
+            sum_seconds: self.sum_nanos.load(Ordering::Relaxed) as f64 / 1e9,
+        }
+    }
+}
+
+/// 히스토그램 구간
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LatencyBucket {
+    /// 구간 상한 (초)
+    pub le: f64,
+    /// 상한 이하인 관측 수 (누적)
+    pub count: u64,
+}
+
+/// 핸들러 처리 시간 분포
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencySnapshot {
+    /// 구간별 누적 관측 수
+    pub buckets: Vec<LatencyBucket>,
+    /// 전체 관측 수
+    pub count: u64,
+    /// 처리 시간 합계 (초)
+    pub sum_seconds: f64,
+}
+
+impl LatencySnapshot {
+    /// 평균 처리 시간
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_secs_f64(self.sum_seconds / self.count as f64))
+    }
+
+    /// 같은 구간을 쓰는 분포 합치기
+    fn merge(&mut self, other: &LatencySnapshot) {
+        if self.buckets.is_empty() {
+            self.buckets = other.buckets.clone();
+        } else {
+            for (bucket, other) in self.buckets.iter_mut().zip(&other.buckets) {
+                bucket.count += other.count;
+            }
+        }
+        self.count += other.count;
+        self.sum_seconds += other.sum_seconds;
+    }
+}
+
+/// 구독의 이벤트 타입 레이블
+#[derive(Debug)]
+enum EventTypeLabel {
+    /// 구독한 이벤트 타입 (이름을 알기 전에는 Rust 타입 이름)
+    Typed { type_id: TypeId, type_name: &'static str },
+    /// 고정 레이블 (토픽 패턴 구독 등)
+    Fixed(String),
+}
+
+/// 구독 하나의 지표
+#[derive(Debug)]
+pub struct SubscriptionMetrics {
+    id: Uuid,
+    name: String,
+    label: EventTypeLabel,
+    delivered: AtomicU64,
+    failed: AtomicU64,
+    panicked: AtomicU64,
+    dropped: AtomicU64,
+    coalesced: AtomicU64,
+    queued: AtomicUsize,
+    latency: Histogram,
+}
+
+impl SubscriptionMetrics {
+    fn new(id: Uuid, name: String, label: EventTypeLabel) -> Self {
+        Self {
+            id,
+            name,
+            label,
+            delivered: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+            panicked: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            coalesced: AtomicU64::new(0),
+            queued: AtomicUsize::new(0),
+            latency: Histogram::default(),
+        }
+    }
+
+    /// 구독 ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 구독 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 핸들러 처리 결과와 처리 시간 기록
+    pub fn record(&self, outcome: HandlerOutcome, elapsed: Duration) {
+        let counter = match outcome {
+            HandlerOutcome::Delivered => &self.delivered,
+            HandlerOutcome::Failed => &self.failed,
+            HandlerOutcome::Panicked => &self.panicked,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        self.latency.observe(elapsed);
+    }
+
+    /// 핸들러를 실행하기 전에 실패한 전달 기록 (역직렬화 실패 등)
+    pub fn record_rejected(&self) {
+        self.failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 배압 정책으로 버려진 이벤트 기록
+    pub fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 같은 키의 최신 이벤트로 교체된 이벤트 기록
+    pub fn record_coalesced(&self) {
+        self.coalesced.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 현재 큐 깊이 기록
+    pub fn set_queued(&self, queued: usize) {
+        self.queued.store(queued, Ordering::Relaxed);
+    }
+
+    /// 처리 통계
+    pub fn stats(&self) -> SubscriptionStats {
+        SubscriptionStats {
+            queued: self.queued.load(Ordering::Relaxed),
+            delivered: self.delivered.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            panicked: self.panicked.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            coalesced: self.coalesced.load(Ordering::Relaxed),
+        }
+    }
+
+    /// 처리 시간 분포
+    pub fn latency(&self) -> LatencySnapshot {
+        self.latency.snapshot()
+    }
+}
+
+/// 이벤트 타입별 발행 카운터
+#[derive(Debug)]
+struct EventTypeCounter {
+    event_type: &'static str,
+    published: AtomicU64,
+}
+
+/// 이벤트 버스 하나의 지표
+#[derive(Debug)]
+pub struct EventBusMetrics {
+    bus: String,
+    event_types: RwLock<HashMap<TypeId, Arc<EventTypeCounter>>>,
+    subscriptions: RwLock<HashMap<Uuid, Arc<SubscriptionMetrics>>>,
+    delivery: DeliveryCounters,
+}
+
+impl EventBusMetrics {
+    /// 버스 이름(`bus` 레이블)으로 지표 생성
+    pub fn new(bus: impl Into<String>) -> Self {
+        Self {
+            bus: bus.into(),
+            event_types: RwLock::new(HashMap::new()),
+            subscriptions: RwLock::new(HashMap::new()),
+            delivery: DeliveryCounters::default(),
+        }
+    }
+
+    /// 버스 이름
+    pub fn bus(&self) -> &str {
+        &self.bus
+    }
+
+    fn counter<E: Event>(&self, event: &E) -> Arc<EventTypeCounter> {
+        let type_id = TypeId::of::<E>();
+        if let Some(counter) = self.event_types.read().unwrap_or_else(|e| e.into_inner()).get(&type_id) {
+            return counter.clone();
+        }
+        self.event_types
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(type_id)
+            .or_insert_with(|| {
+                Arc::new(EventTypeCounter {
+                    event_type: event.event_type(),
+                    published: AtomicU64::new(0),
+                })
+            })
+            .clone()
+    }
+
+    /// 이벤트 발행 기록
+    pub fn record_published<E: Event>(&self, event: &E) {
+        self.counter(event).published.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 수신한 이벤트로 이벤트 타입 이름 확인 (다른 프로세스가 발행하는 브로커 구독용)
+    pub fn observe<E: Event>(&self, event: &E) {
+        self.counter(event);
+    }
+
+    /// `E` 타입 구독 등록
+    pub fn register<E: Event>(&self, id: Uuid, name: impl Into<String>) -> Arc<SubscriptionMetrics> {
+        self.insert(SubscriptionMetrics::new(
+            id,
+            name.into(),
+            EventTypeLabel::Typed {
+                type_id: TypeId::of::<E>(),
+                type_name: std::any::type_name::<E>(),
+            },
+        ))
+    }
+
+    /// 고정된 이벤트 타입 레이블로 구독 등록 (토픽 패턴 구독 등)
+    pub fn register_labeled(
+        &self,
+        id: Uuid,
+        name: impl Into<String>,
+        event_type: impl Into<String>,
+    ) -> Arc<SubscriptionMetrics> {
+        self.insert(SubscriptionMetrics::new(id, name.into(), EventTypeLabel::Fixed(event_type.into())))
+    }
+
+    fn insert(&self, metrics: SubscriptionMetrics) -> Arc<SubscriptionMetrics> {
+        let metrics = Arc::new(metrics);
+        self.subscriptions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(metrics.id, metrics.clone());
+        metrics
+    }
+
+    /// 구독 지표 제거 (구독 취소 시)
+    pub fn unregister(&self, id: &Uuid) {
+        self.subscriptions.write().unwrap_or_else(|e| e.into_inner()).remove(id);
+    }
+
+    /// 재시도·데드 레터 처리 결과 기록
+    pub fn record_delivery(&self, outcome: DeliveryOutcome) {
+        self.delivery.counter(outcome).fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 재시도·데드 레터 처리 결과
+    pub fn delivery(&self) -> DeliveryMetrics {
+        self.delivery.snapshot()
+    }
+
+    /// 구독 지표
+    pub fn subscription(&self, id: &Uuid) -> Option<Arc<SubscriptionMetrics>> {
+        self.subscriptions.read().unwrap_or_else(|e| e.into_inner()).get(id).cloned()
+    }
+
+    /// 현재 지표
+    pub fn snapshot(&self) -> EventBusMetricsSnapshot {
+        let labels: HashMap<TypeId, Arc<EventTypeCounter>> =
+            self.event_types.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let subscriptions: Vec<Arc<SubscriptionMetrics>> = self
+            .subscriptions
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect();
+
+        let mut event_types: BTreeMap<String, EventTypeMetrics> = BTreeMap::new();
+        for counter in labels.values() {
+            event_types
+                .entry(counter.event_type.to_string())
+                .or_insert_with(|| EventTypeMetrics::new(counter.event_type))
+                .published += counter.published.load(Ordering::Relaxed);
+        }
+
+        let mut snapshots: Vec<SubscriptionMetricsSnapshot> = subscriptions
+            .iter()
+            .map(|subscription| {
+                let event_type = match &subscription.label {
+                    EventTypeLabel::Typed { type_id, type_name } => labels
+                        .get(type_id)
+                        .map_or(*type_name, |counter| counter.event_type)
+                        .to_string(),
+                    EventTypeLabel::Fixed(label) => label.clone(),
+                };
+                event_types
+                    .entry(event_type.clone())
+                    .or_insert_with(|| EventTypeMetrics::new(&event_type))
+                    .subscribers += 1;
+                SubscriptionMetricsSnapshot {
+                    id: subscription.id,
+                    name: subscription.name.clone(),
+                    event_type,
+                    stats: subscription.stats(),
+                    latency: subscription.latency(),
+                }
+            })
+            .collect();
+        snapshots.sort_by(|a, b| (&a.event_type, &a.name, a.id).cmp(&(&b.event_type, &b.name, b.id)));
+
+        EventBusMetricsSnapshot {
+            bus: self.bus.clone(),
+            event_types: event_types.into_values().collect(),
+            subscriptions: snapshots,
+            delivery: self.delivery.snapshot(),
+        }
+    }
+}
+
+/// 이벤트 타입별 지표
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventTypeMetrics {
+    /// 이벤트 타입
+    pub event_type: String,
+    /// 발행 수
+    pub published: u64,
+    /// 구독 수
+    pub subscribers: usize,
+}
+
+impl EventTypeMetrics {
+    fn new(event_type: &str) -> Self {
+        Self {
+            event_type: event_type.to_string(),
+            published: 0,
+            subscribers: 0,
+        }
+    }
+}
+
+/// 구독별 지표
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionMetricsSnapshot {
+    /// 구독 ID
+    pub id: Uuid,
+    /// 구독 이름
+    pub name: String,
+    /// 이벤트 타입 (토픽 구독은 패턴)
+    pub event_type: String,
+    /// 처리 통계
+    #[serde(flatten)]
+    pub stats: SubscriptionStats,
+    /// 핸들러 처리 시간 분포
+    pub latency: LatencySnapshot,
+}
+
+/// 이벤트 버스 지표 스냅숏
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventBusMetricsSnapshot {
+    /// 버스 이름
+    pub bus: String,
+    /// 이벤트 타입별 지표 (이름순)
+    pub event_types: Vec<EventTypeMetrics>,
+    /// 구독별 지표 (이벤트 타입, 이름순)
+    pub subscriptions: Vec<SubscriptionMetricsSnapshot>,
+    /// 재시도·데드 레터 처리 결과
+    #[serde(default)]
+    pub delivery: DeliveryMetrics,
+}
+
+impl EventBusMetricsSnapshot {
+    /// 이벤트 타입의 지표
+    pub fn event_type(&self, event_type: &str) -> Option<&EventTypeMetrics> {
+        self.event_types.iter().find(|metrics| metrics.event_type == event_type)
+    }
+
+    /// 이름으로 구독 지표 찾기
+    pub fn subscription(&self, name: &str) -> Option<&SubscriptionMetricsSnapshot> {
+        self.subscriptions.iter().find(|metrics| metrics.name == name)
+    }
+}
+
+/// 지표를 내보낼 이벤트 버스 목록
+#[derive(Debug, Default)]
+pub struct EventMetricsRegistry {
+    buses: RwLock<Vec<Arc<EventBusMetrics>>>,
+}
+
+impl EventMetricsRegistry {
+    /// 빈 목록 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 버스 지표 등록
+    pub fn register(&self, metrics: Arc<EventBusMetrics>) {
+        self.buses.write().unwrap_or_else(|e| e.into_inner()).push(metrics);
+    }
+
+    /// 등록된 모든 버스의 현재 지표
+    pub fn snapshot(&self) -> Vec<EventBusMetricsSnapshot> {
+        self.buses
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|metrics| metrics.snapshot())
+            .collect()
+    }
+
+    /// 등록된 모든 버스의 지표를 Prometheus 텍스트 형식으로 출력
+    pub fn render_prometheus(&self) -> String {
+        render_prometheus(&self.snapshot())
+    }
+}
+
+/// 지표 스냅숏을 Prometheus 텍스트 형식(0.0.4)으로 출력
+///
+/// 같은 버스에서 이벤트 타입과 이름이 같은 구독은 하나의 시계열로 합산합니다.
+pub fn render_prometheus(snapshots: &[EventBusMetricsSnapshot]) -> String {
+    let mut series: BTreeMap<(&str, &str, &str), (SubscriptionStats, LatencySnapshot)> = BTreeMap::new();
+    for snapshot in snapshots {
+        for subscription in &snapshot.subscriptions {
+            let (stats, latency) = series
+                .entry((&snapshot.bus, &subscription.event_type, &subscription.name))
+                .or_default();
+            stats.queued += subscription.stats.queued;
+            stats.delivered += subscription.stats.delivered;
+            stats.failed += subscription.stats.failed;
+            stats.panicked += subscription.stats.panicked;
+            stats.dropped += subscription.stats.dropped;
+            stats.coalesced += subscription.stats.coalesced;
+            latency.merge(&subscription.latency);
+        }
+    }
+    let event_types = || {
+        snapshots
+            .iter()
+            .flat_map(|snapshot| snapshot.event_types.iter().map(move |metrics| (snapshot.bus.as_str(), metrics)))
+    };
+
+    let mut out = String::new();
+
+    family(&mut out, "published_total", "counter", "발행된 이벤트 수");
+    for (bus, metrics) in event_types() {
+        sample(&mut out, "published_total", &[("bus", bus), ("event_type", &metrics.event_type)], metrics.published);
+    }
+
+    family(&mut out, "subscribers", "gauge", "이벤트 타입별 구독 수");
+    for (bus, metrics) in event_types() {
+        sample(&mut out, "subscribers", &[("bus", bus), ("event_type", &metrics.event_type)], metrics.subscribers);
+    }
+
+    family(&mut out, "handled_total", "counter", "핸들러 처리 결과별 이벤트 수");
+    for ((bus, event_type, name), (stats, _)) in &series {
+        for (outcome, count) in [("delivered", stats.delivered), ("failed", stats.failed), ("panicked", stats.panicked)] {
+            let labels = [("bus", *bus), ("event_type", *event_type), ("subscription", *name), ("outcome", outcome)];
+            sample(&mut out, "handled_total", &labels, count);
+        }
+    }
+
+    let per_subscription: [(&str, &str, &str, StatField); 3] = [
+        ("dropped_total", "counter", "배압 정책으로 버려진 이벤트 수", |stats| stats.dropped),
+        ("coalesced_total", "counter", "같은 키의 최신 이벤트로 교체된 이벤트 수", |stats| stats.coalesced),
+        ("queue_depth", "gauge", "구독 큐에 대기 중인 이벤트 수", |stats| stats.queued as u64),
+    ];
+    for (name, kind, help, value) in per_subscription {
+        family(&mut out, name, kind, help);
+        for ((bus, event_type, subscription), (stats, _)) in &series {
+            let labels = [("bus", *bus), ("event_type", *event_type), ("subscription", *subscription)];
+            sample(&mut out, name, &labels, value(stats));
+        }
+    }
+
+    family(&mut out, "delivery_total", "counter", "재시도·데드 레터 처리 결과별 이벤트 수");
+    for snapshot in snapshots {
+        let delivery = &snapshot.delivery;
+        let outcomes = [
+            ("failed", delivery.failed),
+            ("retried", delivery.retried),
+            ("recovered", delivery.recovered),
+            ("dead_lettered", delivery.dead_lettered),
+            ("redriven", delivery.redriven),
+            ("redrive_failed", delivery.redrive_failed),
+            ("discarded", delivery.discarded),
+        ];
+        for (outcome, count) in outcomes {
+            sample(&mut out, "delivery_total", &[("bus", &snapshot.bus), ("outcome", outcome)], count);
+        }
+    }
+
+    family(&mut out, "handler_duration_seconds", "histogram", "핸들러 처리 시간 (초)");
+    for ((bus, event_type, subscription), (_, latency)) in &series {
+        let labels = [("bus", *bus), ("event_type", *event_type), ("subscription", *subscription)];
+        for bucket in &latency.buckets {
+            let le = bucket.le.to_string();
+            let with_le = [labels[0], labels[1], labels[2], ("le", le.as_str())];
+            sample(&mut out, "handler_duration_seconds_bucket", &with_le, bucket.count);
+        }
+        let with_inf = [labels[0], labels[1], labels[2], ("le", "+Inf")];
+        sample(&mut out, "handler_duration_seconds_bucket", &with_inf, latency.count);
+        sample(&mut out, "handler_duration_seconds_sum", &labels, latency.sum_seconds);
+        sample(&mut out, "handler_duration_seconds_count", &labels, latency.count);
+    }
+
+    out
+}
+
+fn family(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {}_{} {}", METRIC_PREFIX, name, help);
+    let _ = writeln!(out, "# TYPE {}_{} {}", METRIC_PREFIX, name, kind);
+}
+
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: impl Display) {
+    let _ = write!(out, "{}_{}{{", METRIC_PREFIX, name);
+    for (index, (key, value)) in labels.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{}=\"", key);
+        for c in value.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                '\n' => out.push_str("\\n"),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+    let _ = writeln!(out, "}} {}", value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    #[derive(Debug)]
+    struct PriceUpdated(Uuid);
+
+    impl Event for PriceUpdated {
+        fn event_type(&self) -> &'static str {
+            "market.price_updated"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            DateTime::UNIX_EPOCH
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn test_snapshot_labels_subscriptions_by_event_type() {
+        let metrics = EventBusMetrics::new("in_memory");
+        let ticker = metrics.register::<PriceUpdated>(Uuid::new_v4(), "ticker");
+        metrics.register_labeled(Uuid::new_v4(), "audit", "market.#");
+
+        // 이벤트 타입 이름을 알기 전에는 Rust 타입 이름
+        let before = metrics.snapshot();
+        assert!(before.subscription("ticker").unwrap().event_type.ends_with("PriceUpdated"));
+
+        metrics.record_published(&PriceUpdated(Uuid::new_v4()));
+        metrics.record_published(&PriceUpdated(Uuid::new_v4()));
+        ticker.record(HandlerOutcome::Delivered, Duration::from_micros(300));
+        ticker.record(HandlerOutcome::Failed, Duration::from_millis(30));
+        ticker.set_queued(4);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(
+            snapshot.event_type("market.price_updated"),
+            Some(&EventTypeMetrics {
+                event_type: "market.price_updated".to_string(),
+                published: 2,
+                subscribers: 1,
+            })
+        );
+        assert_eq!(snapshot.event_type("market.#").unwrap().subscribers, 1);
+
+        let ticker = snapshot.subscription("ticker").unwrap();
+        assert_eq!(ticker.event_type, "market.price_updated");
+        assert_eq!((ticker.stats.delivered, ticker.stats.failed, ticker.stats.queued), (1, 1, 4));
+        assert_eq!(ticker.latency.count, 2);
+        assert_eq!(ticker.latency.buckets[0], LatencyBucket { le: 0.0005, count: 1 });
+        assert_eq!(ticker.latency.buckets[6], LatencyBucket { le: 0.05, count: 2 });
+    }
+
+    #[test]
+    fn test_render_prometheus() {
+        let metrics = Arc::new(EventBusMetrics::new("in_memory"));
+        let first = metrics.register::<PriceUpdated>(Uuid::new_v4(), "ticker");
+        let second = metrics.register::<PriceUpdated>(Uuid::new_v4(), "ticker");
+        metrics.register_labeled(Uuid::new_v4(), "say \"hi\"", "market.#");
+        metrics.record_published(&PriceUpdated(Uuid::new_v4()));
+        first.record(HandlerOutcome::Delivered, Duration::from_millis(2));
+        second.record(HandlerOutcome::Panicked, Duration::from_secs(10));
+        metrics.record_delivery(DeliveryOutcome::Failed);
+        metrics.record_delivery(DeliveryOutcome::Retried);
+        metrics.record_delivery(DeliveryOutcome::Failed);
+        metrics.record_delivery(DeliveryOutcome::DeadLettered);
+
+        let registry = EventMetricsRegistry::new();
+        registry.register(metrics);
+        let text = registry.render_prometheus();
+
+        assert!(text.contains("# TYPE cryptolytica_event_published_total counter\n"));
+        assert!(text.contains(
+            "cryptolytica_event_published_total{bus=\"in_memory\",event_type=\"market.price_updated\"} 1\n"
+        ));
+        assert!(text.contains(
+            "cryptolytica_event_subscribers{bus=\"in_memory\",event_type=\"market.price_updated\"} 2\n"
+        ));
+        // 이름이 같은 구독은 합산
+        let labels = "bus=\"in_memory\",event_type=\"market.price_updated\",subscription=\"ticker\"";
+        assert!(text.contains(&format!("cryptolytica_event_handled_total{{{},outcome=\"panicked\"}} 1\n", labels)));
+        assert!(text.contains(&format!("cryptolytica_event_handler_duration_seconds_bucket{{{},le=\"0.0025\"}} 1\n", labels)));
+        assert!(text.contains(&format!("cryptolytica_event_handler_duration_seconds_bucket{{{},le=\"+Inf\"}} 2\n", labels)));
+        assert!(text.contains(&format!("cryptolytica_event_handler_duration_seconds_sum{{{}}} 10.002\n", labels)));
+        assert!(text.contains("subscription=\"say \\\"hi\\\"\""));
+
+        assert!(text.contains("# TYPE cryptolytica_event_delivery_total counter\n"));
+        for (outcome, count) in [("failed", 2), ("retried", 1), ("dead_lettered", 1), ("redriven", 0), ("discarded", 0)] {
+            let line = format!("cryptolytica_event_delivery_total{{bus=\"in_memory\",outcome=\"{}\"}} {}\n", outcome, count);
+            assert!(text.contains(&line), "{}", line);
+        }
+    }
+}
@@ -3,11 +3,62 @@
 //! 이 모듈은 시스템 내 모든 모듈 간의 이벤트 기반 통신에 사용되는
 //! 공통 이벤트 타입과 관련 기능을 정의합니다.
 
+pub mod async_bus;
+pub mod causality;
+pub mod codec;
+pub mod context;
+pub mod dead_letter;
+pub mod idempotency;
+pub mod metrics;
+pub mod outbox;
+pub mod projection;
+pub mod schema;
+pub mod snapshot;
+pub mod topic;
+
+pub use async_bus::{
+    AsyncEventBus, AsyncEventHandler, BackpressurePolicy, SubscriptionOptions, SubscriptionStats,
+    SyncHandler,
+};
+pub use causality::{CausalLink, CausalNode, CausalityIndex};
+pub use codec::{
+    codec_label, split_frame, CodecKind, EnvelopeCodec, EnvelopeRef, HeaderOnly, JsonCodec, OwnedEnvelope,
+    FRAME_MAGIC, FRAME_PREFIX_LEN,
+};
+pub use context::{EventContext, EventContextGuard};
+pub use dead_letter::{
+    DeadLetter, DeadLetterQueue, DeadLetterStore, DeliveryMetrics, RedriveReport,
+};
+pub use idempotency::{
+    DedupStore, DedupWindow, IdempotentHandler, InMemoryDedupStore, OutOfOrderPolicy, SequencedHandler,
+};
+pub use metrics::{
+    render_prometheus, DeliveryOutcome, EventBusMetrics, EventBusMetricsSnapshot, EventMetricsRegistry,
+    EventTypeMetrics, HandlerOutcome, LatencyBucket, LatencySnapshot, SubscriptionMetrics, SubscriptionMetricsSnapshot,
+};
+pub use outbox::{
+    InMemoryOutboxStore, OutboxBatch, OutboxMessage, OutboxRelay, OutboxRoutes, OutboxStatus, OutboxStore, RelayReport,
+};
+pub use projection::{
+    Checkpoint, CheckpointStore, InMemoryCheckpointStore, InMemoryProjectionSource, Projection, ProjectionHandlers,
+    ProjectionRunner, ProjectionSource, ProjectionStatus, ProjectionWakeup,
+};
+pub use schema::{EventSchemaError, EventSchemaRegistry, SchemaValidatingEventBus};
+pub use snapshot::{
+    InMemorySnapshotStore, RecoveryReport, Snapshot, SnapshotPolicy, SnapshotStore, Snapshottable, Snapshotter,
+};
+pub use topic::{
+    envelope_of, envelope_with_context, header_with_context, EnvelopeHandler, EventFilter, TopicEventBus,
+    TopicPattern, TopicSubscription,
+};
+
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use std::any::TypeId;
+use crate::clock::{Clock, SystemClock};
 use crate::error::CoreError;
+use crate::id::new_id_at;
 use crate::types::Result;
 
 /// 이벤트 기본 특성
@@ -20,6 +71,29 @@ pub trait Event: Send + Sync + 'static {
     
     /// 이벤트 ID를 반환
     fn id(&self) -> &Uuid;
+    
+    /// 페이로드 스키마 버전을 반환
+    ///
+    /// 필드 구성이 바뀌면 버전을 올리고 `EventSchemaRegistry`에 이전 버전의 업캐스터를 등록합니다.
+    fn schema_version(&self) -> u32 {
+        1
+    }
+    
+    /// 순서·병합 기준이 되는 파티션 키 (예: 심볼)
+    ///
+    /// 같은 키의 이벤트는 같은 순서로 처리되어야 하며, `CoalesceByKey` 구독에서는
+    /// 대기 중인 같은 키의 이벤트가 최신 이벤트로 교체됩니다.
+    fn partition_key(&self) -> Option<String> {
+        None
+    }
+    
+    /// 이벤트가 직접 들고 있는 상관·인과 맥락
+    ///
+    /// 신호에서 나온 매매 결정처럼 원인이 값에 기록된 이벤트는, 발행하는 곳의 처리 맥락 대신
+    /// 이 맥락이 헤더에 기록됩니다.
+    fn context(&self) -> Option<EventContext> {
+        None
+    }
 }
 
 /// 이벤트 핸들러 특성
@@ -56,8 +130,21 @@ pub struct EventHeader {
     pub event_type: String,
     /// 관련 이벤트 ID (옵션)
     pub correlation_id: Option<Uuid>,
+    /// 원인 이벤트 ID (이 이벤트를 발행한 핸들러가 처리하던 이벤트)
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// 이벤트 버전
     pub version: String,
+    /// 봉투를 직렬화한 코덱과 코덱 버전 (예: `msgpack/1`, 없으면 코덱을 거치지 않은 JSON)
+    #[serde(default)]
+    pub codec: Option<String>,
+}
+
+impl EventHeader {
+    /// 헤더의 버전 문자열을 스키마 버전으로 해석 ("2", "v2", 이전 형식 "1.0" 지원)
+    pub fn schema_version(&self) -> Option<u32> {
+        schema::parse_schema_version(&self.version)
+    }
 }
 
 /// 이벤트 포장 구조체
@@ -112,19 +199,32 @@ pub fn deserialize_event<T: for<'de> Deserialize<'de>>(data: &str) -> Result<T>
     )
 }
 
-/// 새 이벤트 헤더 생성
+/// 새 이벤트 헤더 생성 (시스템 시계 기준)
 pub fn create_event_header(
     source: &str, 
     event_type: &str, 
     correlation_id: Option<Uuid>
 ) -> EventHeader {
+    create_event_header_with_clock(source, event_type, correlation_id, &SystemClock)
+}
+
+/// 주입된 시계 기준으로 새 이벤트 헤더 생성
+pub fn create_event_header_with_clock(
+    source: &str, 
+    event_type: &str, 
+    correlation_id: Option<Uuid>,
+    clock: &dyn Clock,
+) -> EventHeader {
+    let now = clock.now();
     EventHeader {
-        id: Uuid::new_v4(),
-        timestamp: Utc::now(),
+        id: new_id_at(now),
+        timestamp: now,
         source: source.to_string(),
         event_type: event_type.to_string(),
         correlation_id,
+        causation_id: None,
         version: "1.0".to_string(),
+        codec: None,
     }
 }
 
@@ -182,4 +282,16 @@ mod tests {
         assert_eq!(header.correlation_id, correlation_id);
         assert_eq!(header.version, "1.0");
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_create_event_header_with_clock() {
+        use crate::clock::SimulatedClock;
+        use chrono::TimeZone;
+        
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let clock = SimulatedClock::new(time);
+        let header = create_event_header_with_clock("backtest", "test.event", None, &clock);
+        
+        assert_eq!(header.timestamp, time);
+    }
+}
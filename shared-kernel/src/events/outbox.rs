@@ -0,0 +1,560 @@
+//! 트랜잭셔널 아웃박스
+//!
+//! 상태 변경과 그 결과로 발행할 이벤트를 같은 트랜잭션 안에서 저장소에 기록하고,
+//! 릴레이가 기록된 이벤트를 나중에 버스로 발행합니다. 저장 직후 프로세스가 죽어도
+//! 이벤트가 아웃박스에 남아 있으므로, 읽기 모델이 저장소와 어긋나지 않습니다.
+//!
+//! - 리포지토리는 상태와 함께 `OutboxBatch`의 봉투를 한 트랜잭션으로 기록합니다.
+//! - `OutboxRelay`는 대기 중인 메시지를 기록 순서대로 발행하고 발행 완료로 표시합니다.
+//! - 발행 후 표시 전에 종료되면 다시 발행하므로 전달은 최소 한 번입니다.
+//!   소비자는 이벤트 ID로 중복을 걸러야 합니다 (`IdempotentHandler`).
+//!
+//! 버스는 타입별로 발행하므로, 릴레이는 `OutboxRoutes`에 등록한 이벤트 타입만 발행할 수 있습니다.
+//! 발행할 때 헤더의 상관·인과 맥락을 복원하므로 발행된 이벤트는 기록 당시의 맥락을 유지합니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::clock::{system_clock, SharedClock};
+use crate::error::CoreError;
+use crate::types::Result;
+use super::{envelope_with_context, AsyncEventBus, Event, EventBus, EventContext, EventEnvelope};
+
+/// 상태 변경과 함께 기록할 이벤트 묶음
+#[derive(Debug, Clone, Default)]
+pub struct OutboxBatch {
+    source: String,
+    envelopes: Vec<EventEnvelope<Value>>,
+}
+
+impl OutboxBatch {
+    /// 발생 소스를 지정하여 빈 묶음 생성
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            envelopes: Vec::new(),
+        }
+    }
+
+    /// 이벤트 추가 (이벤트가 들고 있는 맥락이나 처리 중인 이벤트의 맥락을 헤더에 기록)
+    pub fn push<E: Event + Serialize>(&mut self, event: &E) -> Result<()> {
+        let context = EventContext::of(event);
+        self.envelopes.push(envelope_with_context(event, &self.source, &context)?);
+        Ok(())
+    }
+
+    /// 이벤트를 추가한 묶음 반환
+    pub fn with<E: Event + Serialize>(mut self, event: &E) -> Result<Self> {
+        self.push(event)?;
+        Ok(self)
+    }
+
+    /// 기록할 봉투 (추가한 순서)
+    pub fn envelopes(&self) -> &[EventEnvelope<Value>] {
+        &self.envelopes
+    }
+
+    /// 이벤트 수
+    pub fn len(&self) -> usize {
+        self.envelopes.len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.envelopes.is_empty()
+    }
+}
+
+/// 아웃박스 메시지 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    /// 발행 대기
+    Pending,
+    /// 발행 완료
+    Published,
+    /// 최대 시도 횟수를 넘겨 발행을 포기함 (수동 처리 대상)
+    Dead,
+}
+
+impl OutboxStatus {
+    /// 저장소에 기록하는 이름
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OutboxStatus::Pending => "pending",
+            OutboxStatus::Published => "published",
+            OutboxStatus::Dead => "dead",
+        }
+    }
+
+    /// 저장소에 기록된 이름으로 찾기
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(OutboxStatus::Pending),
+            "published" => Some(OutboxStatus::Published),
+            "dead" => Some(OutboxStatus::Dead),
+            _ => None,
+        }
+    }
+}
+
+/// 아웃박스 메시지
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxMessage {
+    /// 기록 순서 (저장소가 부여, 발행 순서)
+    pub sequence: u64,
+    /// 발행할 봉투
+    pub envelope: EventEnvelope<Value>,
+    /// 상태
+    pub status: OutboxStatus,
+    /// 기록 시각
+    pub enqueued_at: DateTime<Utc>,
+    /// 발행 시도 횟수 (실패한 시도)
+    pub attempts: u32,
+    /// 마지막 발행 오류
+    pub last_error: Option<String>,
+    /// 발행 완료 시각
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// 아웃박스 저장소
+///
+/// 구현체는 상태 변경과 같은 트랜잭션 안에서 기록하는 수단(예: 트랜잭션을 받는 `enqueue_in`)을
+/// 함께 제공해야 합니다. `enqueue`는 상태 변경 없이 이벤트만 기록할 때 사용합니다.
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+    /// 이벤트 묶음 기록 (부여된 순서 반환)
+    async fn enqueue(&self, batch: &OutboxBatch) -> Result<Vec<u64>>;
+
+    /// 발행 대기 중인 메시지 (순서대로 최대 `limit`개)
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxMessage>>;
+
+    /// 발행 완료로 표시
+    async fn mark_published(&self, sequences: &[u64], at: DateTime<Utc>) -> Result<()>;
+
+    /// 발행 실패 기록 (`dead`이면 더 이상 발행하지 않음)
+    async fn mark_failed(&self, sequence: u64, error: &str, dead: bool) -> Result<()>;
+
+    /// `before` 이전에 발행 완료된 메시지 삭제 (삭제한 수 반환)
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<usize>;
+}
+
+/// 인메모리 아웃박스 저장소 (테스트·단일 프로세스용)
+pub struct InMemoryOutboxStore {
+    messages: Mutex<(u64, BTreeMap<u64, OutboxMessage>)>,
+    clock: SharedClock,
+}
+
+impl InMemoryOutboxStore {
+    /// 새로운 인메모리 아웃박스 저장소 생성
+    pub fn new() -> Self {
+        Self::with_clock(system_clock())
+    }
+
+    /// 시계를 지정하여 생성
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            messages: Mutex::new((1, BTreeMap::new())),
+            clock,
+        }
+    }
+
+    /// 모든 메시지 (순서대로)
+    pub fn messages(&self) -> Vec<OutboxMessage> {
+        self.messages.lock().unwrap().1.values().cloned().collect()
+    }
+}
+
+impl Default for InMemoryOutboxStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OutboxStore for InMemoryOutboxStore {
+    async fn enqueue(&self, batch: &OutboxBatch) -> Result<Vec<u64>> {
+        let now = self.clock.now();
+        let mut guard = self.messages.lock().unwrap();
+        let (next, messages) = &mut *guard;
+        let mut sequences = Vec::with_capacity(batch.len());
+        for envelope in batch.envelopes() {
+            let sequence = *next;
+            *next += 1;
+            messages.insert(sequence, OutboxMessage {
+                sequence,
+                envelope: envelope.clone(),
+                status: OutboxStatus::Pending,
+                enqueued_at: now,
+                attempts: 0,
+                last_error: None,
+                published_at: None,
+            });
+            sequences.push(sequence);
+        }
+        Ok(sequences)
+    }
+
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxMessage>> {
+        Ok(self
+            .messages
+            .lock()
+            .unwrap()
+            .1
+            .values()
+            .filter(|message| message.status == OutboxStatus::Pending)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_published(&self, sequences: &[u64], at: DateTime<Utc>) -> Result<()> {
+        let mut guard = self.messages.lock().unwrap();
+        for sequence in sequences {
+            if let Some(message) = guard.1.get_mut(sequence) {
+                message.status = OutboxStatus::Published;
+                message.published_at = Some(at);
+            }
+        }
+        Ok(())
+    }
+
+    async fn mark_failed(&self, sequence: u64, error: &str, dead: bool) -> Result<()> {
+        let mut guard = self.messages.lock().unwrap();
+        let message = guard
+            .1
+            .get_mut(&sequence)
+            .ok_or_else(|| CoreError::NotFound(format!("아웃박스 메시지를 찾을 수 없음: {}", sequence)))?;
+        message.attempts += 1;
+        message.last_error = Some(error.to_string());
+        if dead {
+            message.status = OutboxStatus::Dead;
+        }
+        Ok(())
+    }
+
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<usize> {
+        let mut guard = self.messages.lock().unwrap();
+        let messages = &mut guard.1;
+        let original = messages.len();
+        messages.retain(|_, message| {
+            message.status != OutboxStatus::Published || message.published_at.is_none_or(|at| at >= before)
+        });
+        Ok(original - messages.len())
+    }
+}
+
+/// 봉투를 이벤트 타입으로 복원하여 버스에 발행하는 함수
+type Route = Arc<dyn Fn(EventEnvelope<Value>) -> BoxFuture<'static, Result<()>> + Send + Sync>;
+
+/// 이벤트 타입별 발행 경로
+#[derive(Clone, Default)]
+pub struct OutboxRoutes {
+    routes: HashMap<String, Route>,
+}
+
+impl OutboxRoutes {
+    /// 빈 경로 목록 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `event_type` 이벤트를 `E`로 복원하여 동기 버스에 발행
+    pub fn route<E, B>(mut self, event_type: &str, bus: Arc<B>) -> Self
+    where
+        E: Event + Serialize + DeserializeOwned,
+        B: EventBus + 'static,
+    {
+        let route: Route = Arc::new(move |envelope| {
+            let bus = bus.clone();
+            Box::pin(async move {
+                let event: E = decode(&envelope)?;
+                let _context = EventContext::from_header(&envelope.header).enter();
+                bus.publish(event)
+            })
+        });
+        self.routes.insert(event_type.to_string(), route);
+        self
+    }
+
+    /// `event_type` 이벤트를 `E`로 복원하여 비동기 버스에 발행
+    pub fn route_async<E, B>(mut self, event_type: &str, bus: Arc<B>) -> Self
+    where
+        E: Event + Serialize + DeserializeOwned,
+        B: AsyncEventBus + 'static,
+    {
+        let route: Route = Arc::new(move |envelope| {
+            let bus = bus.clone();
+            Box::pin(async move {
+                let event: E = decode(&envelope)?;
+                EventContext::from_header(&envelope.header).scope(bus.publish(event)).await
+            })
+        });
+        self.routes.insert(event_type.to_string(), route);
+        self
+    }
+
+    /// 발행 경로가 있는지 여부
+    pub fn handles(&self, event_type: &str) -> bool {
+        self.routes.contains_key(event_type)
+    }
+
+    /// 봉투 발행
+    pub async fn publish(&self, envelope: &EventEnvelope<Value>) -> Result<()> {
+        let route = self.routes.get(&envelope.header.event_type).ok_or_else(|| {
+            CoreError::Configuration(format!("아웃박스 발행 경로가 없는 이벤트 타입: {}", envelope.header.event_type))
+        })?;
+        route(envelope.clone()).await
+    }
+}
+
+fn decode<E: DeserializeOwned>(envelope: &EventEnvelope<Value>) -> Result<E> {
+    serde_json::from_value(envelope.payload.clone()).map_err(|e| {
+        CoreError::Data(format!("아웃박스 이벤트 역직렬화 실패 ({}): {}", envelope.header.event_type, e))
+    })
+}
+
+/// 릴레이 한 번의 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayReport {
+    /// 발행한 메시지 수
+    pub published: usize,
+    /// 발행에 실패해 다음에 다시 시도할 메시지 수
+    pub failed: usize,
+    /// 최대 시도 횟수를 넘겨 포기한 메시지 수
+    pub dead: usize,
+}
+
+/// 아웃박스 릴레이
+///
+/// 대기 중인 메시지를 기록 순서대로 발행합니다. 발행에 실패하면 뒤의 메시지가 앞지르지 않도록
+/// 그 자리에서 멈추고 다음 실행에서 다시 시도하며, 최대 시도 횟수를 넘긴 메시지는 `Dead`로
+/// 표시하고 건너뜁니다.
+pub struct OutboxRelay {
+    store: Arc<dyn OutboxStore>,
+    routes: OutboxRoutes,
+    batch_size: usize,
+    max_attempts: u32,
+    clock: SharedClock,
+}
+
+impl OutboxRelay {
+    /// 저장소와 발행 경로로 생성 (한 번에 100개, 최대 10회 시도)
+    pub fn new(store: Arc<dyn OutboxStore>, routes: OutboxRoutes) -> Self {
+        Self {
+            store,
+            routes,
+            batch_size: 100,
+            max_attempts: 10,
+            clock: system_clock(),
+        }
+    }
+
+    /// 한 번에 가져올 메시지 수 (0은 1로 보정)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 메시지별 최대 발행 시도 횟수 (0은 1로 보정)
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// 시계 지정 (발행 완료 시각)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 대기 중인 메시지를 최대 한 묶음 발행
+    pub async fn relay_once(&self) -> Result<RelayReport> {
+        let mut report = RelayReport::default();
+        let mut published = Vec::new();
+
+        for message in self.store.pending(self.batch_size).await? {
+            match self.routes.publish(&message.envelope).await {
+                Ok(()) => published.push(message.sequence),
+                Err(e) => {
+                    let dead = message.attempts + 1 >= self.max_attempts;
+                    tracing::warn!(
+                        sequence = message.sequence,
+                        attempts = message.attempts + 1,
+                        dead,
+                        "아웃박스 발행 실패: {} - {}",
+                        message.envelope.header.event_type,
+                        e
+                    );
+                    self.store.mark_failed(message.sequence, &e.to_string(), dead).await?;
+                    if dead {
+                        report.dead += 1;
+                        continue;
+                    }
+                    report.failed += 1;
+                    break;
+                }
+            }
+        }
+
+        report.published = published.len();
+        if !published.is_empty() {
+            self.store.mark_published(&published, self.clock.now()).await?;
+            tracing::debug!("아웃박스 메시지 {}개 발행", published.len());
+        }
+        Ok(report)
+    }
+
+    /// 종료 신호가 올 때까지 주기적으로 릴레이
+    ///
+    /// 한 묶음을 가득 발행했으면 기다리지 않고 이어서 발행합니다.
+    pub async fn run<F: Future<Output = ()>>(&self, interval: Duration, shutdown: F) {
+        tokio::pin!(shutdown);
+        loop {
+            let backlog = match self.relay_once().await {
+                Ok(report) => report.published + report.dead >= self.batch_size,
+                Err(e) => {
+                    tracing::error!("아웃박스 릴레이 실패: {:?}", e);
+                    false
+                }
+            };
+            if backlog {
+                continue;
+            }
+            tokio::select! {
+                _ = &mut shutdown => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+        tracing::info!("아웃박스 릴레이 종료");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EventHandler, SubscriptionHandle};
+    use std::any::TypeId;
+    use uuid::Uuid;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct OrderPlaced {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+        order: String,
+    }
+
+    impl Event for OrderPlaced {
+        fn event_type(&self) -> &'static str {
+            "test.order.placed"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    fn placed(order: &str) -> OrderPlaced {
+        OrderPlaced {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            order: order.to_string(),
+        }
+    }
+
+    /// 발행된 주문과 맥락을 기록하고, 지정한 주문은 처음 한 번 실패하는 버스
+    #[derive(Default)]
+    struct RecordingBus {
+        published: Mutex<Vec<(String, Option<EventContext>)>>,
+        fail_once: Mutex<Option<String>>,
+    }
+
+    impl EventBus for RecordingBus {
+        fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
+            let order = serde_json::to_value(&event)?["order"].as_str().unwrap_or_default().to_string();
+            let mut fail_once = self.fail_once.lock().unwrap();
+            if fail_once.as_deref() == Some(order.as_str()) {
+                *fail_once = None;
+                return Err(CoreError::Unavailable {
+                    message: "브로커 연결 끊김".to_string(),
+                    retry_after: None,
+                });
+            }
+            self.published.lock().unwrap().push((order, EventContext::current()));
+            Ok(())
+        }
+
+        fn subscribe<E: Event + for<'de> Deserialize<'de>, H: EventHandler<E>>(
+            &self,
+            _handler: H,
+        ) -> Result<SubscriptionHandle> {
+            Ok(SubscriptionHandle {
+                id: Uuid::new_v4(),
+                event_type_id: TypeId::of::<E>(),
+            })
+        }
+
+        fn unsubscribe(&self, _handle: &SubscriptionHandle) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relay_publishes_in_order_at_least_once() {
+        let store = Arc::new(InMemoryOutboxStore::new());
+        let bus = Arc::new(RecordingBus::default());
+        *bus.fail_once.lock().unwrap() = Some("b".to_string());
+
+        // 처리 중이던 이벤트의 맥락은 기록된 헤더를 거쳐 발행 시점까지 유지됨
+        let cause = EventContext::new(Uuid::new_v4(), Some(Uuid::new_v4()));
+        let batch = {
+            let _context = cause.enter();
+            OutboxBatch::new("exchange").with(&placed("a")).unwrap().with(&placed("b")).unwrap()
+        };
+        store.enqueue(&batch).await.unwrap();
+        store.enqueue(&OutboxBatch::new("exchange").with(&placed("c")).unwrap()).await.unwrap();
+
+        let routes = OutboxRoutes::new().route::<OrderPlaced, _>("test.order.placed", bus.clone());
+        let relay = OutboxRelay::new(store.clone(), routes).with_max_attempts(3);
+
+        // b에서 실패하면 c가 앞지르지 않도록 멈춤
+        let report = relay.relay_once().await.unwrap();
+        assert_eq!(report, RelayReport { published: 1, failed: 1, dead: 0 });
+        assert_eq!(store.pending(10).await.unwrap().len(), 2);
+        assert_eq!(store.pending(10).await.unwrap()[0].attempts, 1);
+
+        let report = relay.relay_once().await.unwrap();
+        assert_eq!(report.published, 2);
+        let published = bus.published.lock().unwrap().clone();
+        let orders: Vec<&str> = published.iter().map(|(order, _)| order.as_str()).collect();
+        assert_eq!(orders, ["a", "b", "c"]);
+        assert_eq!(published[0].1, Some(cause));
+        assert!(store.pending(10).await.unwrap().is_empty());
+
+        // 경로가 없는 이벤트는 최대 시도 후 포기하고 다음 메시지로 넘어감
+        let mut unknown = batch.envelopes()[0].clone();
+        unknown.header.event_type = "test.order.unknown".to_string();
+        store.enqueue(&OutboxBatch { source: "exchange".to_string(), envelopes: vec![unknown] }).await.unwrap();
+        store.enqueue(&OutboxBatch::new("exchange").with(&placed("d")).unwrap()).await.unwrap();
+        for _ in 0..2 {
+            assert_eq!(relay.relay_once().await.unwrap().failed, 1);
+        }
+        assert_eq!(relay.relay_once().await.unwrap(), RelayReport { published: 1, failed: 0, dead: 1 });
+        assert_eq!(store.messages().iter().filter(|m| m.status == OutboxStatus::Dead).count(), 1);
+
+        assert_eq!(store.purge_published(Utc::now() + chrono::Duration::seconds(1)).await.unwrap(), 4);
+        assert_eq!(store.messages().len(), 1);
+    }
+}
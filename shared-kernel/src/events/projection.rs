@@ -0,0 +1,910 @@
+//! CQRS 프로젝션
+//!
+//! 읽기 모델을 프로젝션으로 선언합니다. 프로젝션은 소비하는 이벤트 타입과 각 이벤트를 상태에
+//! 접는 방법(`ProjectionHandlers`), 처음부터 다시 만들 때의 초기화(`reset`)를 정의하고,
+//! `ProjectionRunner`가 구독, 순서, 이벤트 로그 따라잡기, 재구성, 체크포인트, 지연 보고를 맡습니다.
+//!
+//! 러너는 이벤트를 버스에서 직접 적용하지 않고 이벤트 로그(`ProjectionSource`)를 오프셋 순서대로
+//! 읽어 적용합니다. 버스 구독은 새 레코드가 생겼다는 알림으로만 쓰므로, 버스의 전달 순서나
+//! 중복과 관계없이 모든 프로젝션이 같은 순서로 이벤트를 한 번씩 적용합니다. 버스에는 이벤트
+//! 로그를 먼저 구독시키고 러너를 그다음에 구독시킵니다.
+//!
+//! 시작 위치는 다음 순서로 정합니다. 버전이 프로젝션 버전과 다르면 초기화 후 처음부터 재생합니다.
+//! - 스냅숏으로 등록한 프로젝션: 가장 최근의 유효한 스냅숏 오프셋
+//! - 상태가 프로세스 밖에 남는 프로젝션(`persists_state`): 체크포인트
+//! - 그 외: 초기화 후 로그의 처음
+//!
+//! ```ignore
+//! let runner = Arc::new(ProjectionRunner::new(Arc::new(log.clone()), Arc::new(FileCheckpointStore::open(dir)?)));
+//! runner.register_snapshotted(Arc::new(MarketDataProjection::new(cache)))?;
+//! runner.catch_up()?;
+//! bus.subscribe_topic(TopicSubscription::from_pattern(TopicPattern::all()), log.clone())?;
+//! runner.subscribe(&bus)?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock, TryLockError};
+
+use crate::clock::{system_clock, ReplayClock, SharedClock};
+use crate::error::CoreError;
+use crate::types::Result;
+use super::{
+    Event, EventEnvelope, EventHandler, EventSchemaRegistry, Snapshottable, Snapshotter, SubscriptionHandle, TopicEventBus,
+    TopicPattern, TopicSubscription,
+};
+
+/// 봉투를 프로젝션 상태에 접는 함수
+type Fold<P> = Box<dyn Fn(&P, &EventEnvelope<Value>, Option<&EventSchemaRegistry>) -> Result<()> + Send + Sync>;
+
+/// 프로젝션이 소비하는 이벤트와 접는 방법
+pub struct ProjectionHandlers<P> {
+    folds: HashMap<String, Fold<P>>,
+}
+
+impl<P: 'static> ProjectionHandlers<P> {
+    /// 빈 목록 생성
+    pub fn new() -> Self {
+        Self { folds: HashMap::new() }
+    }
+
+    /// `event_type` 이벤트를 `E`로 읽어 상태에 접는 함수 등록
+    ///
+    /// 러너에 스키마 저장소가 있으면 이전 버전 페이로드를 업캐스트한 뒤 읽습니다.
+    pub fn on<E, F>(mut self, event_type: &str, fold: F) -> Self
+    where
+        E: Event + DeserializeOwned,
+        F: Fn(&P, &E) -> Result<()> + Send + Sync + 'static,
+    {
+        let fold: Fold<P> = Box::new(move |projection, envelope, schemas| {
+            let event: E = match schemas {
+                Some(registry) => registry.decode(envelope.clone())?,
+                None => serde_json::from_value(envelope.payload.clone()).map_err(|e| {
+                    CoreError::Data(format!("프로젝션 이벤트 역직렬화 실패 ({}): {}", envelope.header.event_type, e))
+                })?,
+            };
+            fold(projection, &event)
+        });
+        self.folds.insert(event_type.to_string(), fold);
+        self
+    }
+
+    /// 이벤트 타입을 소비하는지 여부
+    pub fn handles(&self, event_type: &str) -> bool {
+        self.folds.contains_key(event_type)
+    }
+
+    /// 소비하는 이벤트 타입 (정렬됨)
+    pub fn event_types(&self) -> Vec<&str> {
+        let mut types: Vec<&str> = self.folds.keys().map(String::as_str).collect();
+        types.sort_unstable();
+        types
+    }
+
+    /// 봉투 적용 (소비하지 않는 이벤트면 거짓)
+    pub fn apply(
+        &self,
+        projection: &P,
+        envelope: &EventEnvelope<Value>,
+        schemas: Option<&EventSchemaRegistry>,
+    ) -> Result<bool> {
+        match self.folds.get(&envelope.header.event_type) {
+            Some(fold) => fold(projection, envelope, schemas).map(|_| true),
+            None => Ok(false),
+        }
+    }
+}
+
+impl<P: 'static> Default for ProjectionHandlers<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 프로젝션 (이벤트를 접어 만드는 읽기 모델)
+pub trait Projection: Send + Sync + 'static {
+    /// 러너와 체크포인트 저장소 안에서 프로젝션을 구분하는 이름 (예: `trading.market_data`)
+    fn name(&self) -> &str;
+
+    /// 접는 방식의 버전
+    ///
+    /// 접는 방법이나 상태 구조를 바꾸면 올립니다. 체크포인트의 버전이 다르면 처음부터 다시 만듭니다.
+    fn version(&self) -> u32 {
+        1
+    }
+
+    /// 소비하는 이벤트와 접는 방법 (등록할 때 한 번 호출)
+    fn handlers(&self) -> ProjectionHandlers<Self>
+    where
+        Self: Sized;
+
+    /// 처음부터 다시 만들기 위해 상태 비우기
+    fn reset(&self) -> Result<()>;
+
+    /// 상태가 프로세스 밖(데이터베이스 등)에 남는지 여부
+    ///
+    /// 참이면 재시작할 때 체크포인트부터 이어서 적용합니다. 메모리에만 있는 상태는 거짓으로 두고,
+    /// 재시작 비용을 줄이려면 `register_snapshotted`로 등록합니다.
+    fn persists_state(&self) -> bool {
+        false
+    }
+}
+
+/// 프로젝션 체크포인트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// 프로젝션 이름
+    pub projection: String,
+    /// 프로젝션 버전
+    pub version: u32,
+    /// 다음에 적용할 이벤트 로그 오프셋
+    pub offset: u64,
+    /// 저장 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 체크포인트 저장소
+pub trait CheckpointStore: Send + Sync {
+    /// 프로젝션의 체크포인트 (없으면 `None`)
+    fn load(&self, projection: &str) -> Result<Option<Checkpoint>>;
+
+    /// 체크포인트 저장 (이전 값을 교체)
+    fn save(&self, checkpoint: &Checkpoint) -> Result<()>;
+}
+
+/// 인메모리 체크포인트 저장소 (테스트·단일 프로세스용)
+#[derive(Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoints: RwLock<HashMap<String, Checkpoint>>,
+}
+
+impl InMemoryCheckpointStore {
+    /// 새로운 인메모리 체크포인트 저장소 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for InMemoryCheckpointStore {
+    fn load(&self, projection: &str) -> Result<Option<Checkpoint>> {
+        Ok(self.checkpoints.read().unwrap().get(projection).cloned())
+    }
+
+    fn save(&self, checkpoint: &Checkpoint) -> Result<()> {
+        self.checkpoints
+            .write()
+            .unwrap()
+            .insert(checkpoint.projection.clone(), checkpoint.clone());
+        Ok(())
+    }
+}
+
+/// 프로젝션이 읽는 이벤트 로그
+pub trait ProjectionSource: Send + Sync {
+    /// 남아 있는 가장 오래된 오프셋
+    fn first_offset(&self) -> u64;
+
+    /// 다음에 기록될 오프셋
+    fn next_offset(&self) -> u64;
+
+    /// `from`부터 읽기 시작한 시점의 끝까지 오프셋 순서대로 전달
+    ///
+    /// 전달한 수를 반환하며, 전달 함수가 오류를 반환하면 즉시 중단합니다.
+    fn read(&self, from: u64, apply: &mut dyn FnMut(u64, EventEnvelope<Value>) -> Result<()>) -> Result<u64>;
+}
+
+/// 인메모리 이벤트 로그 (테스트·단일 프로세스용)
+///
+/// `EnvelopeHandler`를 구현하므로 버스에 `#` 패턴으로 구독시켜 모든 이벤트를 쌓을 수 있습니다.
+#[derive(Debug, Default)]
+pub struct InMemoryProjectionSource {
+    envelopes: RwLock<Vec<EventEnvelope<Value>>>,
+}
+
+impl InMemoryProjectionSource {
+    /// 새로운 인메모리 이벤트 로그 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 봉투 추가 후 부여된 오프셋 반환
+    pub fn append(&self, envelope: EventEnvelope<Value>) -> u64 {
+        let mut envelopes = self.envelopes.write().unwrap();
+        envelopes.push(envelope);
+        envelopes.len() as u64 - 1
+    }
+}
+
+impl ProjectionSource for InMemoryProjectionSource {
+    fn first_offset(&self) -> u64 {
+        0
+    }
+
+    fn next_offset(&self) -> u64 {
+        self.envelopes.read().unwrap().len() as u64
+    }
+
+    fn read(&self, from: u64, apply: &mut dyn FnMut(u64, EventEnvelope<Value>) -> Result<()>) -> Result<u64> {
+        let pending: Vec<_> = self.envelopes.read().unwrap().iter().skip(from as usize).cloned().collect();
+        let mut count = 0;
+        for (offset, envelope) in (from..).zip(pending) {
+            apply(offset, envelope)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+impl super::EnvelopeHandler for InMemoryProjectionSource {
+    fn handle(&self, envelope: &EventEnvelope<Value>) -> Result<()> {
+        self.append(envelope.clone());
+        Ok(())
+    }
+}
+
+/// 프로젝션 상태 보고
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectionStatus {
+    /// 프로젝션 이름
+    pub name: String,
+    /// 프로젝션 버전
+    pub version: u32,
+    /// 다음에 적용할 오프셋
+    pub position: u64,
+    /// 이벤트 로그의 다음 오프셋
+    pub head: u64,
+    /// 아직 적용하지 않은 레코드 수
+    pub lag: u64,
+    /// 시작 또는 재구성 이후 적용한 이벤트 수
+    pub applied: u64,
+    /// 소비하지 않아 건너뛴 이벤트 수
+    pub skipped: u64,
+    /// 마지막으로 적용한 이벤트의 발생 시각
+    pub last_event_at: Option<DateTime<Utc>>,
+    /// 멈춘 이유 (`resume`이나 `rebuild` 전까지 적용하지 않음)
+    pub stalled: Option<String>,
+}
+
+/// 등록된 프로젝션의 진행 상태
+#[derive(Debug, Default)]
+struct SlotState {
+    started: bool,
+    position: u64,
+    saved: u64,
+    applied: u64,
+    skipped: u64,
+    last_event_at: Option<DateTime<Utc>>,
+    stalled: Option<String>,
+}
+
+type ApplyFn = Box<dyn Fn(&EventEnvelope<Value>, Option<&EventSchemaRegistry>) -> Result<bool> + Send + Sync>;
+type ResetFn = Box<dyn Fn() -> Result<()> + Send + Sync>;
+
+/// 등록된 프로젝션 (타입을 지운 형태)
+struct ProjectionSlot {
+    name: String,
+    version: u32,
+    persists_state: bool,
+    apply: ApplyFn,
+    reset: ResetFn,
+    snapshot: Option<Arc<dyn Snapshottable>>,
+    state: Mutex<SlotState>,
+}
+
+impl ProjectionSlot {
+    fn new<P: Projection>(projection: Arc<P>, snapshot: Option<Arc<dyn Snapshottable>>) -> Self {
+        let handlers = projection.handlers();
+        let applied = projection.clone();
+        let reset = projection.clone();
+        Self {
+            name: projection.name().to_string(),
+            version: projection.version(),
+            persists_state: projection.persists_state(),
+            apply: Box::new(move |envelope, schemas| handlers.apply(&applied, envelope, schemas)),
+            reset: Box::new(move || reset.reset()),
+            snapshot,
+            state: Mutex::new(SlotState::default()),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, SlotState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 레코드 하나 적용 (이미 적용했거나 멈춘 프로젝션은 건너뜀)
+    fn apply_record(&self, offset: u64, envelope: &EventEnvelope<Value>, schemas: Option<&EventSchemaRegistry>) {
+        let mut state = self.state();
+        if state.stalled.is_some() || offset != state.position {
+            return;
+        }
+        match (self.apply)(envelope, schemas) {
+            Ok(true) => {
+                state.applied += 1;
+                state.last_event_at = Some(envelope.header.timestamp);
+                state.position = offset + 1;
+            }
+            Ok(false) => {
+                state.skipped += 1;
+                state.position = offset + 1;
+            }
+            Err(e) => {
+                tracing::error!(
+                    "프로젝션 적용 실패로 멈춤: {}@{} ({}) - {:?}",
+                    self.name,
+                    offset,
+                    envelope.header.event_type,
+                    e
+                );
+                state.stalled = Some(format!("오프셋 {} ({}): {}", offset, envelope.header.event_type, e));
+            }
+        }
+    }
+}
+
+/// 프로젝션 실행기
+///
+/// 등록된 모든 프로젝션을 하나의 이벤트 로그 위에서 순서대로 진행시킵니다.
+/// 적용은 한 번에 한 스레드만 하며, 프로젝션이 적용 중에 이벤트를 발행해도 교착되지 않습니다.
+pub struct ProjectionRunner {
+    source: Arc<dyn ProjectionSource>,
+    checkpoints: Arc<dyn CheckpointStore>,
+    snapshotter: Option<Arc<Snapshotter>>,
+    schemas: Option<Arc<EventSchemaRegistry>>,
+    checkpoint_every: u64,
+    clock: SharedClock,
+    /// 재생 중인 이벤트 시각을 따라가는 시계
+    replay_clock: Option<ReplayClock>,
+    slots: RwLock<Vec<Arc<ProjectionSlot>>>,
+    apply_lock: Mutex<()>,
+    /// 적용 중에 들어온 알림 (적용 중인 스레드가 한 번 더 진행함)
+    pending: AtomicBool,
+}
+
+impl ProjectionRunner {
+    /// 이벤트 로그와 체크포인트 저장소로 생성 (체크포인트 1,000건마다)
+    pub fn new(source: Arc<dyn ProjectionSource>, checkpoints: Arc<dyn CheckpointStore>) -> Self {
+        Self {
+            source,
+            checkpoints,
+            snapshotter: None,
+            schemas: None,
+            checkpoint_every: 1_000,
+            clock: system_clock(),
+            replay_clock: None,
+            slots: RwLock::new(Vec::new()),
+            apply_lock: Mutex::new(()),
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    /// 스냅숏 관리자 지정 (`register_snapshotted`로 등록한 프로젝션의 복원과 주기적 저장)
+    pub fn with_snapshotter(mut self, snapshotter: Arc<Snapshotter>) -> Self {
+        self.snapshotter = Some(snapshotter);
+        self
+    }
+
+    /// 이전 버전 페이로드를 업캐스트할 스키마 저장소 지정
+    pub fn with_schemas(mut self, schemas: Arc<EventSchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+
+    /// 체크포인트 저장 간격 (레코드 수, 0은 1로 보정)
+    pub fn with_checkpoint_every(mut self, records: u64) -> Self {
+        self.checkpoint_every = records.max(1);
+        self
+    }
+
+    /// 시계 지정 (체크포인트 저장 시각)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 재생 시계 지정
+    ///
+    /// 레코드를 적용하기 전에 이벤트 타임스탬프를 관찰하므로, 같은 시계를 공유하는
+    /// 서비스는 재생 중인 이벤트 시각을 현재 시각으로 봅니다. 체크포인트 시각도 이 시계를 따릅니다.
+    pub fn with_replay_clock(mut self, clock: ReplayClock) -> Self {
+        self.clock = Arc::new(clock.clone());
+        self.replay_clock = Some(clock);
+        self
+    }
+
+    /// 프로젝션 등록 (다음 `catch_up`/`poll`에서 시작 위치를 정하고 따라잡음)
+    pub fn register<P: Projection>(&self, projection: Arc<P>) -> Result<()> {
+        self.insert(ProjectionSlot::new(projection, None))
+    }
+
+    /// 스냅숏으로 복원하는 프로젝션 등록
+    pub fn register_snapshotted<P: Projection + Snapshottable>(&self, projection: Arc<P>) -> Result<()> {
+        if self.snapshotter.is_none() {
+            return Err(CoreError::Configuration(format!(
+                "스냅숏 관리자 없이 스냅숏 프로젝션을 등록할 수 없음: {}",
+                projection.name()
+            )));
+        }
+        let snapshot: Arc<dyn Snapshottable> = projection.clone();
+        self.insert(ProjectionSlot::new(projection, Some(snapshot)))
+    }
+
+    fn insert(&self, slot: ProjectionSlot) -> Result<()> {
+        let mut slots = self.slots.write().unwrap();
+        if slots.iter().any(|existing| existing.name == slot.name) {
+            return Err(CoreError::Validation(format!("이미 등록된 프로젝션: {}", slot.name)));
+        }
+        tracing::debug!("프로젝션 등록됨: {} (v{})", slot.name, slot.version);
+        slots.push(Arc::new(slot));
+        Ok(())
+    }
+
+    fn slots(&self) -> Vec<Arc<ProjectionSlot>> {
+        self.slots.read().unwrap().clone()
+    }
+
+    fn slot(&self, name: &str) -> Result<Arc<ProjectionSlot>> {
+        self.slots()
+            .into_iter()
+            .find(|slot| slot.name == name)
+            .ok_or_else(|| CoreError::NotFound(format!("프로젝션을 찾을 수 없음: {}", name)))
+    }
+
+    fn lock_apply(&self) -> std::sync::MutexGuard<'_, ()> {
+        self.apply_lock.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 시작 위치를 정하고 이벤트 로그 끝까지 따라잡기 (시작할 때 호출, 읽은 레코드 수 반환)
+    pub fn catch_up(&self) -> Result<u64> {
+        let _guard = self.lock_apply();
+        let read = self.drain()?;
+        for status in self.status() {
+            tracing::info!(
+                "프로젝션 따라잡기 완료: {} (위치 {}, 지연 {})",
+                status.name,
+                status.position,
+                status.lag
+            );
+        }
+        Ok(read)
+    }
+
+    /// 새 레코드 적용 (읽은 레코드 수 반환)
+    ///
+    /// 다른 스레드가 적용 중이면 기다리지 않고 반환하며, 적용 중인 스레드가 새 레코드까지 마저 처리합니다.
+    pub fn poll(&self) -> Result<u64> {
+        let mut read = 0;
+        self.pending.store(true, Ordering::SeqCst);
+        while self.pending.load(Ordering::SeqCst) {
+            let _guard = match self.apply_lock.try_lock() {
+                Ok(guard) => guard,
+                Err(TryLockError::Poisoned(e)) => e.into_inner(),
+                Err(TryLockError::WouldBlock) => return Ok(read),
+            };
+            self.pending.store(false, Ordering::SeqCst);
+            read += self.drain()?;
+        }
+        Ok(read)
+    }
+
+    /// 프로젝션을 비우고 이벤트 로그 처음부터 다시 만들기 (적용한 이벤트 수 반환)
+    pub fn rebuild(&self, name: &str) -> Result<u64> {
+        let _guard = self.lock_apply();
+        let slot = self.slot(name)?;
+        (slot.reset)()?;
+
+        let first = self.source.first_offset();
+        if first > 0 {
+            tracing::warn!("보존 정책으로 오프셋 {} 이전 이벤트가 없어 일부만 재구성됨: {}", first, name);
+        }
+        *slot.state() = SlotState {
+            started: true,
+            position: first,
+            saved: first,
+            ..SlotState::default()
+        };
+        tracing::info!("프로젝션 재구성 시작: {} (오프셋 {}부터)", name, first);
+
+        self.drain()?;
+        self.save_checkpoint(&slot)?;
+        let applied = slot.state().applied;
+        Ok(applied)
+    }
+
+    /// 멈춘 프로젝션을 실패한 레코드부터 다시 진행
+    pub fn resume(&self, name: &str) -> Result<()> {
+        let slot = self.slot(name)?;
+        if let Some(reason) = slot.state().stalled.take() {
+            tracing::info!("프로젝션 재개: {} (멈춘 이유: {})", name, reason);
+        }
+        self.poll().map(|_| ())
+    }
+
+    /// 모든 프로젝션의 체크포인트와 스냅숏 저장 (종료할 때 호출)
+    pub fn checkpoint(&self) -> Result<()> {
+        let _guard = self.lock_apply();
+        for slot in self.slots() {
+            if !slot.state().started {
+                continue;
+            }
+            self.save_checkpoint(&slot)?;
+            if let (Some(snapshot), Some(snapshotter)) = (&slot.snapshot, &self.snapshotter) {
+                let position = slot.state().position;
+                snapshotter.snapshot(snapshot.as_ref(), position)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 프로젝션별 위치와 지연
+    pub fn status(&self) -> Vec<ProjectionStatus> {
+        let head = self.source.next_offset();
+        self.slots()
+            .iter()
+            .map(|slot| {
+                let state = slot.state();
+                ProjectionStatus {
+                    name: slot.name.clone(),
+                    version: slot.version,
+                    position: state.position,
+                    head,
+                    lag: head.saturating_sub(state.position),
+                    applied: state.applied,
+                    skipped: state.skipped,
+                    last_event_at: state.last_event_at,
+                    stalled: state.stalled.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// 프로젝션의 지연 (아직 적용하지 않은 레코드 수)
+    pub fn lag(&self, name: &str) -> Option<u64> {
+        self.status().into_iter().find(|status| status.name == name).map(|status| status.lag)
+    }
+
+    /// 버스의 모든 이벤트를 새 레코드 알림으로 구독
+    ///
+    /// 알림을 받으면 `poll`로 이벤트 로그를 따라가므로, 이벤트 로그를 먼저 구독시켜야 합니다.
+    pub fn subscribe<B: TopicEventBus>(self: &Arc<Self>, bus: &B) -> Result<SubscriptionHandle> {
+        let runner = self.clone();
+        bus.subscribe_topic(
+            TopicSubscription::from_pattern(TopicPattern::all()),
+            move |_: &EventEnvelope<Value>| runner.poll().map(|_| ()),
+        )
+    }
+
+    /// 타입별 버스 구독에 쓸 새 레코드 알림 핸들러
+    ///
+    /// `TopicEventBus`가 아닌 버스에서는 프로젝션이 소비하는 이벤트 타입마다 이 핸들러를 구독시킵니다.
+    pub fn wakeup(self: &Arc<Self>) -> ProjectionWakeup {
+        ProjectionWakeup { runner: self.clone() }
+    }
+
+    /// 스냅숏 관리자가 있는지 여부 (`register_snapshotted` 가능 여부)
+    pub fn has_snapshotter(&self) -> bool {
+        self.snapshotter.is_some()
+    }
+
+    /// 시작 위치 결정
+    fn start(&self, slot: &ProjectionSlot) -> Result<()> {
+        let restored = match (&slot.snapshot, &self.snapshotter) {
+            (Some(snapshot), Some(snapshotter)) => snapshotter.restore(snapshot.as_ref())?.0,
+            _ if slot.persists_state => match self.checkpoints.load(&slot.name)? {
+                Some(checkpoint) if checkpoint.version == slot.version => Some(checkpoint.offset),
+                Some(checkpoint) => {
+                    tracing::warn!(
+                        "프로젝션 버전이 바뀌어 처음부터 다시 만듦: {} (v{} → v{})",
+                        slot.name,
+                        checkpoint.version,
+                        slot.version
+                    );
+                    None
+                }
+                None => None,
+            },
+            _ => None,
+        };
+
+        let position = match restored {
+            Some(offset) => offset,
+            None => {
+                (slot.reset)()?;
+                self.source.first_offset()
+            }
+        };
+
+        let mut state = slot.state();
+        state.started = true;
+        state.position = position;
+        state.saved = position;
+        tracing::debug!("프로젝션 시작 위치: {}@{}", slot.name, position);
+        Ok(())
+    }
+
+    /// 등록된 프로젝션을 이벤트 로그 끝까지 진행 (적용 잠금을 잡은 상태에서 호출)
+    fn drain(&self) -> Result<u64> {
+        let slots = self.slots();
+        for slot in &slots {
+            if !slot.state().started {
+                self.start(slot)?;
+            }
+        }
+
+        // 보존 정책으로 지워진 구간에 걸린 프로젝션은 재구성 전까지 멈춤
+        let first = self.source.first_offset();
+        let mut from = None;
+        for slot in &slots {
+            let mut state = slot.state();
+            if state.stalled.is_none() && state.position < first {
+                state.stalled = Some(format!("오프셋 {}~{}의 이벤트가 보존 정책으로 삭제됨", state.position, first));
+                tracing::error!("프로젝션 멈춤: {} - {}", slot.name, state.stalled.as_deref().unwrap_or_default());
+            }
+            if state.stalled.is_none() {
+                from = Some(from.map_or(state.position, |from: u64| from.min(state.position)));
+            }
+        }
+        let Some(from) = from else {
+            return Ok(0);
+        };
+        if from >= self.source.next_offset() {
+            return Ok(0);
+        }
+
+        let schemas = self.schemas.as_deref();
+        let read = self.source.read(from, &mut |offset, envelope| {
+            if let Some(clock) = &self.replay_clock {
+                clock.observe_envelope(&envelope);
+            }
+            for slot in &slots {
+                slot.apply_record(offset, &envelope, schemas);
+            }
+            Ok(())
+        })?;
+
+        for slot in &slots {
+            let (position, saved) = {
+                let state = slot.state();
+                (state.position, state.saved)
+            };
+            if position >= saved + self.checkpoint_every {
+                self.save_checkpoint(slot)?;
+            }
+            if let (Some(snapshot), Some(snapshotter)) = (&slot.snapshot, &self.snapshotter) {
+                snapshotter.maybe_snapshot(snapshot.as_ref(), position)?;
+            }
+        }
+        Ok(read)
+    }
+
+    fn save_checkpoint(&self, slot: &ProjectionSlot) -> Result<()> {
+        let position = slot.state().position;
+        self.checkpoints.save(&Checkpoint {
+            projection: slot.name.clone(),
+            version: slot.version,
+            offset: position,
+            updated_at: self.clock.now(),
+        })?;
+        slot.state().saved = position;
+        Ok(())
+    }
+}
+
+/// 이벤트를 받으면 러너를 진행시키는 핸들러 (`ProjectionRunner::wakeup`)
+#[derive(Clone)]
+pub struct ProjectionWakeup {
+    runner: Arc<ProjectionRunner>,
+}
+
+impl<E: Event> EventHandler<E> for ProjectionWakeup {
+    fn handle(&self, _event: &E) -> Result<()> {
+        self.runner.poll().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{envelope_of, InMemorySnapshotStore, SnapshotPolicy, SnapshotStore};
+    use uuid::Uuid;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Deposited {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+        account: String,
+        amount: i64,
+    }
+
+    impl Event for Deposited {
+        fn event_type(&self) -> &'static str {
+            "test.account.deposited"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    fn deposit(source: &InMemoryProjectionSource, account: &str, amount: i64) {
+        let event = Deposited {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            account: account.to_string(),
+            amount,
+        };
+        source.append(envelope_of(&event, "test").unwrap());
+    }
+
+    /// 계좌별 잔액 (음수 입금은 실패), 상태가 밖에 남는 것으로 가정
+    #[derive(Default)]
+    struct Balances {
+        balances: Mutex<HashMap<String, i64>>,
+        durable: bool,
+    }
+
+    impl Balances {
+        fn of(&self, account: &str) -> i64 {
+            self.balances.lock().unwrap().get(account).copied().unwrap_or_default()
+        }
+    }
+
+    impl Projection for Balances {
+        fn name(&self) -> &str {
+            "test.balances"
+        }
+
+        fn handlers(&self) -> ProjectionHandlers<Self> {
+            ProjectionHandlers::new().on("test.account.deposited", |balances: &Self, event: &Deposited| {
+                if event.amount < 0 {
+                    return Err(CoreError::Validation(format!("음수 입금: {}", event.amount)));
+                }
+                *balances.balances.lock().unwrap().entry(event.account.clone()).or_default() += event.amount;
+                Ok(())
+            })
+        }
+
+        fn reset(&self) -> Result<()> {
+            self.balances.lock().unwrap().clear();
+            Ok(())
+        }
+
+        fn persists_state(&self) -> bool {
+            self.durable
+        }
+    }
+
+    impl Snapshottable for Balances {
+        fn snapshot_name(&self) -> &str {
+            "test.balances"
+        }
+
+        fn snapshot_state(&self) -> Result<Value> {
+            Ok(serde_json::to_value(&*self.balances.lock().unwrap())?)
+        }
+
+        fn restore_state(&self, state: Value) -> Result<()> {
+            *self.balances.lock().unwrap() = serde_json::from_value(state)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_catch_up_checkpoint_and_rebuild() {
+        let source = Arc::new(InMemoryProjectionSource::new());
+        let checkpoints = Arc::new(InMemoryCheckpointStore::new());
+        deposit(&source, "alice", 10);
+        deposit(&source, "bob", 5);
+        let mut other = source.envelopes.read().unwrap()[0].clone();
+        other.header.event_type = "test.account.opened".to_string();
+        source.append(other);
+
+        let runner = ProjectionRunner::new(source.clone(), checkpoints.clone()).with_checkpoint_every(2);
+        let balances = Arc::new(Balances { durable: true, ..Balances::default() });
+        runner.register(balances.clone()).unwrap();
+        assert!(runner.register(balances.clone()).is_err());
+
+        assert_eq!(runner.catch_up().unwrap(), 3);
+        assert_eq!(balances.of("alice"), 10);
+        let status = &runner.status()[0];
+        assert_eq!((status.position, status.lag, status.applied, status.skipped), (3, 0, 2, 1));
+        assert_eq!(checkpoints.load("test.balances").unwrap().unwrap().offset, 3);
+
+        // 실패한 이벤트에서 멈추고, 이후 이벤트는 지연으로 보고
+        deposit(&source, "alice", -1);
+        deposit(&source, "alice", 7);
+        runner.poll().unwrap();
+        let status = &runner.status()[0];
+        assert_eq!((status.position, status.lag), (3, 2));
+        assert!(status.stalled.as_deref().unwrap().contains("음수 입금"));
+        assert_eq!(runner.lag("test.balances"), Some(2));
+
+        // 재시작하면 체크포인트부터 이어서 적용 (상태가 밖에 남는 프로젝션)
+        let restarted = ProjectionRunner::new(source.clone(), checkpoints.clone());
+        let survived = Arc::new(Balances { durable: true, ..Balances::default() });
+        survived.balances.lock().unwrap().insert("alice".to_string(), 10);
+        restarted.register(survived.clone()).unwrap();
+        restarted.catch_up().unwrap();
+        assert_eq!(restarted.status()[0].position, 3);
+
+        // 처음부터 다시 만들면 멈춤도 풀리고 같은 이벤트에서 다시 멈춤
+        assert_eq!(runner.rebuild("test.balances").unwrap(), 2);
+        assert_eq!(balances.of("alice"), 10);
+        assert!(runner.status()[0].stalled.is_some());
+        assert!(runner.rebuild("missing").is_err());
+    }
+
+    #[test]
+    fn test_replay_clock_follows_replayed_events() {
+        use crate::clock::Clock;
+        use chrono::TimeZone;
+
+        let source = Arc::new(InMemoryProjectionSource::new());
+        let checkpoints = Arc::new(InMemoryCheckpointStore::new());
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for minute in 1..=3 {
+            let event = Deposited {
+                id: Uuid::new_v4(),
+                timestamp: start + chrono::Duration::minutes(minute),
+                account: "alice".to_string(),
+                amount: minute,
+            };
+            source.append(envelope_of(&event, "test").unwrap());
+        }
+
+        let clock = ReplayClock::new(start);
+        let runner = ProjectionRunner::new(source.clone(), checkpoints.clone())
+            .with_checkpoint_every(1)
+            .with_replay_clock(clock.clone());
+        runner.register(Arc::new(Balances { durable: true, ..Balances::default() })).unwrap();
+        runner.catch_up().unwrap();
+
+        // 재생한 마지막 이벤트 시각이 현재 시각이 되고, 체크포인트도 그 시각으로 기록
+        assert_eq!(clock.now(), start + chrono::Duration::minutes(3));
+        assert_eq!(
+            checkpoints.load("test.balances").unwrap().unwrap().updated_at,
+            start + chrono::Duration::minutes(3)
+        );
+    }
+
+    #[test]
+    fn test_snapshotted_projection_replays_only_tail() {
+        let source = Arc::new(InMemoryProjectionSource::new());
+        let snapshots = Arc::new(InMemorySnapshotStore::new());
+        let snapshotter = || Arc::new(Snapshotter::new(snapshots.clone(), SnapshotPolicy::every_events(3)));
+        for amount in 1..=4 {
+            deposit(&source, "alice", amount);
+        }
+
+        let runner = ProjectionRunner::new(source.clone(), Arc::new(InMemoryCheckpointStore::new()))
+            .with_snapshotter(snapshotter());
+        let live = Arc::new(Balances::default());
+        runner.register_snapshotted(live.clone()).unwrap();
+        runner.catch_up().unwrap();
+        assert_eq!(snapshots.offsets("test.balances").unwrap(), [4]);
+
+        deposit(&source, "alice", 5);
+        runner.poll().unwrap();
+        assert_eq!(live.of("alice"), 15);
+
+        // 재시작하면 스냅숏(오프셋 4)을 복원하고 그 뒤의 한 건만 적용
+        let restarted = ProjectionRunner::new(source.clone(), Arc::new(InMemoryCheckpointStore::new()))
+            .with_snapshotter(snapshotter());
+        let restored = Arc::new(Balances::default());
+        restarted.register_snapshotted(restored.clone()).unwrap();
+        assert_eq!(restarted.catch_up().unwrap(), 1);
+        assert_eq!(restored.of("alice"), 15);
+        assert_eq!(restarted.status()[0].applied, 1);
+
+        // 스냅숏 관리자가 없으면 등록 거부
+        let plain = ProjectionRunner::new(source, Arc::new(InMemoryCheckpointStore::new()));
+        assert!(plain.register_snapshotted(Arc::new(Balances::default())).is_err());
+    }
+}
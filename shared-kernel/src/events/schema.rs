@@ -0,0 +1,523 @@
+//! 이벤트 스키마 버전 관리
+//!
+//! 저장되거나 다른 프로세스로 전달된 이벤트 JSON은 구조체 필드가 바뀐 뒤에도
+//! 읽을 수 있어야 합니다. `EventSchemaRegistry`는 이벤트 타입별 현재 스키마 버전과
+//! 이전 버전 페이로드를 한 단계씩 올리는 업캐스터 체인을 보관하며,
+//! 디코딩 시 헤더의 버전에서 현재 버전까지 업캐스터를 차례로 적용합니다.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::error::CoreError;
+use crate::types::Result;
+use super::topic::envelope_of;
+use super::{Event, EventBus, EventEnvelope, EventHandler, SubscriptionHandle};
+
+/// 이벤트 스키마 오류
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EventSchemaError {
+    #[error("등록되지 않은 이벤트 타입: {0}")]
+    UnknownEventType(String),
+
+    #[error("지원하지 않는 스키마 버전: {event_type} v{version} (현재 v{current})")]
+    UnsupportedVersion {
+        event_type: String,
+        version: u32,
+        current: u32,
+    },
+
+    #[error("잘못된 스키마 버전 형식: '{0}'")]
+    InvalidVersion(String),
+
+    #[error("이미 다른 버전으로 등록된 이벤트 타입: {event_type} (등록 v{registered}, 요청 v{requested})")]
+    ConflictingSchema {
+        event_type: String,
+        registered: u32,
+        requested: u32,
+    },
+
+    #[error("업캐스터 버전이 범위를 벗어남: {event_type} v{from_version} (현재 v{current})")]
+    InvalidUpcaster {
+        event_type: String,
+        from_version: u32,
+        current: u32,
+    },
+
+    #[error("업캐스터 변환 실패: {event_type} v{from_version}: {message}")]
+    Upcast {
+        event_type: String,
+        from_version: u32,
+        message: String,
+    },
+
+    #[error("이벤트 타입 불일치: 기대 {expected}, 실제 {actual}")]
+    TypeMismatch { expected: String, actual: String },
+
+    #[error("이벤트 페이로드 처리 실패: {0}")]
+    Payload(String),
+}
+
+impl From<EventSchemaError> for CoreError {
+    fn from(err: EventSchemaError) -> Self {
+        match err {
+            EventSchemaError::Payload(_) | EventSchemaError::Upcast { .. } => {
+                CoreError::Data(err.to_string())
+            }
+            _ => CoreError::Validation(err.to_string()),
+        }
+    }
+}
+
+/// 페이로드를 한 버전 올리는 업캐스터
+pub type Upcaster = Arc<dyn Fn(Value) -> Result<Value> + Send + Sync>;
+
+/// 이벤트 타입별 스키마
+struct SchemaEntry {
+    /// 현재 스키마 버전
+    current: u32,
+    /// 시작 버전 -> 다음 버전으로 올리는 업캐스터
+    upcasters: BTreeMap<u32, Upcaster>,
+}
+
+/// 이벤트 타입 + 버전을 디코더에 대응시키는 스키마 저장소
+#[derive(Default)]
+pub struct EventSchemaRegistry {
+    schemas: HashMap<String, SchemaEntry>,
+}
+
+impl std::fmt::Debug for EventSchemaRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.schemas.iter().map(|(event_type, entry)| (event_type, entry.current)))
+            .finish()
+    }
+}
+
+impl EventSchemaRegistry {
+    /// 빈 저장소 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 이벤트 타입의 현재 스키마 버전 등록
+    ///
+    /// 같은 버전으로 다시 등록하는 것은 허용되지만, 다른 버전으로 등록하면 오류입니다.
+    pub fn register(&mut self, event_type: &str, current_version: u32) -> std::result::Result<(), EventSchemaError> {
+        if current_version == 0 {
+            return Err(EventSchemaError::InvalidVersion(current_version.to_string()));
+        }
+
+        match self.schemas.get(event_type) {
+            Some(entry) if entry.current != current_version => Err(EventSchemaError::ConflictingSchema {
+                event_type: event_type.to_string(),
+                registered: entry.current,
+                requested: current_version,
+            }),
+            Some(_) => Ok(()),
+            None => {
+                self.schemas.insert(
+                    event_type.to_string(),
+                    SchemaEntry {
+                        current: current_version,
+                        upcasters: BTreeMap::new(),
+                    },
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// 이벤트 값의 타입과 스키마 버전으로 등록
+    pub fn register_event<E: Event>(&mut self, event: &E) -> std::result::Result<(), EventSchemaError> {
+        self.register(event.event_type(), event.schema_version())
+    }
+
+    /// `from_version` 페이로드를 `from_version + 1`로 올리는 업캐스터 등록
+    pub fn register_upcaster<F>(
+        &mut self,
+        event_type: &str,
+        from_version: u32,
+        upcaster: F,
+    ) -> std::result::Result<(), EventSchemaError>
+    where
+        F: Fn(Value) -> Result<Value> + Send + Sync + 'static,
+    {
+        let entry = self
+            .schemas
+            .get_mut(event_type)
+            .ok_or_else(|| EventSchemaError::UnknownEventType(event_type.to_string()))?;
+
+        if from_version == 0 || from_version >= entry.current {
+            return Err(EventSchemaError::InvalidUpcaster {
+                event_type: event_type.to_string(),
+                from_version,
+                current: entry.current,
+            });
+        }
+
+        entry.upcasters.insert(from_version, Arc::new(upcaster));
+        Ok(())
+    }
+
+    /// 이벤트 타입의 현재 스키마 버전
+    pub fn current_version(&self, event_type: &str) -> Option<u32> {
+        self.schemas.get(event_type).map(|entry| entry.current)
+    }
+
+    /// 해당 버전을 현재 버전으로 디코딩할 수 있는지 여부
+    pub fn is_registered(&self, event_type: &str, version: u32) -> bool {
+        self.schemas
+            .get(event_type)
+            .is_some_and(|entry| entry.supports(version))
+    }
+
+    /// 발행 전 이벤트 스키마 검증
+    pub fn validate<E: Event>(&self, event: &E) -> std::result::Result<(), EventSchemaError> {
+        self.entry(event.event_type())?
+            .ensure_supported(event.event_type(), event.schema_version())
+    }
+
+    /// 페이로드를 현재 버전으로 업캐스트
+    pub fn upcast(
+        &self,
+        event_type: &str,
+        version: u32,
+        mut payload: Value,
+    ) -> std::result::Result<Value, EventSchemaError> {
+        let entry = self.entry(event_type)?;
+        entry.ensure_supported(event_type, version)?;
+
+        for (from_version, upcaster) in entry.upcasters.range(version..entry.current) {
+            payload = upcaster(payload).map_err(|e| EventSchemaError::Upcast {
+                event_type: event_type.to_string(),
+                from_version: *from_version,
+                message: e.to_string(),
+            })?;
+        }
+        Ok(payload)
+    }
+
+    /// 이벤트를 버전이 기록된 봉투로 인코딩 (상관 ID를 주면 현재 맥락 대신 사용)
+    pub fn encode<E: Event + Serialize>(
+        &self,
+        event: &E,
+        source: &str,
+        correlation_id: Option<Uuid>,
+    ) -> std::result::Result<EventEnvelope<Value>, EventSchemaError> {
+        self.validate(event)?;
+
+        let mut envelope = envelope_of(event, source).map_err(|e| EventSchemaError::Payload(e.to_string()))?;
+        if correlation_id.is_some() {
+            envelope.header.correlation_id = correlation_id;
+        }
+        Ok(envelope)
+    }
+
+    /// 봉투를 현재 버전의 이벤트로 디코딩
+    pub fn decode<E: Event + DeserializeOwned>(
+        &self,
+        envelope: EventEnvelope<Value>,
+    ) -> std::result::Result<E, EventSchemaError> {
+        let EventEnvelope { header, payload } = envelope;
+        let version = header
+            .schema_version()
+            .ok_or_else(|| EventSchemaError::InvalidVersion(header.version.clone()))?;
+
+        let payload = self.upcast(&header.event_type, version, payload)?;
+        let event: E = serde_json::from_value(payload).map_err(|e| EventSchemaError::Payload(e.to_string()))?;
+
+        if event.event_type() != header.event_type {
+            return Err(EventSchemaError::TypeMismatch {
+                expected: event.event_type().to_string(),
+                actual: header.event_type,
+            });
+        }
+        Ok(event)
+    }
+
+    /// JSON 문자열로 저장된 봉투를 디코딩
+    pub fn decode_json<E: Event + DeserializeOwned>(&self, data: &str) -> std::result::Result<E, EventSchemaError> {
+        let envelope: EventEnvelope<Value> =
+            serde_json::from_str(data).map_err(|e| EventSchemaError::Payload(e.to_string()))?;
+        self.decode(envelope)
+    }
+
+    fn entry(&self, event_type: &str) -> std::result::Result<&SchemaEntry, EventSchemaError> {
+        self.schemas
+            .get(event_type)
+            .ok_or_else(|| EventSchemaError::UnknownEventType(event_type.to_string()))
+    }
+}
+
+impl SchemaEntry {
+    /// 현재 버전이거나 현재 버전까지 업캐스터 체인이 이어지는 버전인지 여부
+    fn supports(&self, version: u32) -> bool {
+        version == self.current
+            || (version >= 1 && version < self.current && (version..self.current).all(|v| self.upcasters.contains_key(&v)))
+    }
+
+    fn ensure_supported(&self, event_type: &str, version: u32) -> std::result::Result<(), EventSchemaError> {
+        if self.supports(version) {
+            Ok(())
+        } else {
+            Err(EventSchemaError::UnsupportedVersion {
+                event_type: event_type.to_string(),
+                version,
+                current: self.current,
+            })
+        }
+    }
+}
+
+/// 스키마 문자열을 버전 번호로 해석 ("2", "v2", 이전 형식 "1.0")
+pub fn parse_schema_version(version: &str) -> Option<u32> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    let major = match version.split_once('.') {
+        Some((major, minor)) if minor.chars().all(|c| c == '0') => major,
+        Some(_) => return None,
+        None => version,
+    };
+    major.parse().ok().filter(|v| *v > 0)
+}
+
+/// 발행 전에 스키마 등록 여부를 검사하는 이벤트 버스 래퍼
+pub struct SchemaValidatingEventBus<B: EventBus> {
+    inner: B,
+    registry: Arc<EventSchemaRegistry>,
+}
+
+impl<B: EventBus> SchemaValidatingEventBus<B> {
+    /// 새 래퍼 생성
+    pub fn new(inner: B, registry: Arc<EventSchemaRegistry>) -> Self {
+        Self { inner, registry }
+    }
+
+    /// 스키마 저장소
+    pub fn registry(&self) -> &Arc<EventSchemaRegistry> {
+        &self.registry
+    }
+
+    /// 내부 이벤트 버스
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B: EventBus> EventBus for SchemaValidatingEventBus<B> {
+    fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
+        self.registry.validate(&event)?;
+        self.inner.publish(event)
+    }
+
+    fn subscribe<E: Event + for<'de> Deserialize<'de>, H: EventHandler<E>>(
+        &self,
+        handler: H,
+    ) -> Result<SubscriptionHandle> {
+        self.inner.subscribe(handler)
+    }
+
+    fn unsubscribe(&self, handle: &SubscriptionHandle) -> Result<()> {
+        self.inner.unsubscribe(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventHeader;
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    /// v1: `{ price: f64 }` -> v2: `{ price: String }` -> v3: `{ price, exchange }`
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct PriceEvent {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+        price: String,
+        exchange: String,
+    }
+
+    impl Event for PriceEvent {
+        fn event_type(&self) -> &'static str {
+            "test.price"
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+
+        fn schema_version(&self) -> u32 {
+            3
+        }
+    }
+
+    fn registry() -> EventSchemaRegistry {
+        let mut registry = EventSchemaRegistry::new();
+        registry.register("test.price", 3).unwrap();
+        registry
+            .register_upcaster("test.price", 1, |mut payload| {
+                let price = payload["price"]
+                    .as_f64()
+                    .ok_or_else(|| CoreError::Data("price 누락".to_string()))?;
+                payload["price"] = json!(price.to_string());
+                Ok(payload)
+            })
+            .unwrap();
+        registry
+            .register_upcaster("test.price", 2, |mut payload| {
+                payload["exchange"] = json!("unknown");
+                Ok(payload)
+            })
+            .unwrap();
+        registry
+    }
+
+    fn envelope(version: &str, payload: Value) -> EventEnvelope<Value> {
+        EventEnvelope {
+            header: EventHeader {
+                id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+                source: "test".to_string(),
+                event_type: "test.price".to_string(),
+                correlation_id: None,
+                causation_id: None,
+                version: version.to_string(),
+                codec: None,
+            },
+            payload,
+        }
+    }
+
+    #[test]
+    fn test_upcaster_chain() {
+        let registry = registry();
+        let payload = json!({
+            "id": Uuid::new_v4(),
+            "timestamp": "2024-01-01T00:00:00Z",
+            "price": 42000.5,
+        });
+
+        // 이전 형식 헤더 "1.0"도 v1로 해석
+        let event: PriceEvent = registry.decode(envelope("1.0", payload)).unwrap();
+        assert_eq!(event.price, "42000.5");
+        assert_eq!(event.exchange, "unknown");
+
+        let current = PriceEvent {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            price: "1.5".to_string(),
+            exchange: "upbit".to_string(),
+        };
+        let encoded = registry.encode(&current, "test", None).unwrap();
+        assert_eq!(encoded.header.version, "3");
+
+        let json = serde_json::to_string(&encoded).unwrap();
+        let decoded: PriceEvent = registry.decode_json(&json).unwrap();
+        assert_eq!(decoded.exchange, "upbit");
+    }
+
+    #[test]
+    fn test_unregistered_versions_fail() {
+        let mut registry = EventSchemaRegistry::new();
+        registry.register("test.price", 3).unwrap();
+        registry.register_upcaster("test.price", 2, Ok).unwrap();
+
+        // v1 -> v2 업캐스터가 없으면 v1은 지원하지 않음
+        assert!(registry.is_registered("test.price", 2));
+        assert!(!registry.is_registered("test.price", 1));
+        assert!(!registry.is_registered("test.price", 4));
+        assert_eq!(
+            registry.decode::<PriceEvent>(envelope("1", json!({}))).unwrap_err(),
+            EventSchemaError::UnsupportedVersion {
+                event_type: "test.price".to_string(),
+                version: 1,
+                current: 3,
+            }
+        );
+
+        assert!(matches!(
+            registry.register("test.price", 4),
+            Err(EventSchemaError::ConflictingSchema { .. })
+        ));
+        assert!(matches!(
+            registry.register_upcaster("test.price", 3, Ok),
+            Err(EventSchemaError::InvalidUpcaster { .. })
+        ));
+        assert!(matches!(
+            registry.decode::<PriceEvent>(envelope("1.5", json!({}))),
+            Err(EventSchemaError::InvalidVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_schema_version() {
+        assert_eq!(parse_schema_version("1.0"), Some(1));
+        assert_eq!(parse_schema_version("v2"), Some(2));
+        assert_eq!(parse_schema_version("3"), Some(3));
+        assert_eq!(parse_schema_version("0"), None);
+        assert_eq!(parse_schema_version("1.1"), None);
+        assert_eq!(parse_schema_version("latest"), None);
+    }
+
+    #[derive(Default)]
+    struct RecordingBus {
+        published: Mutex<Vec<String>>,
+    }
+
+    impl EventBus for RecordingBus {
+        fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
+            self.published.lock().unwrap().push(event.event_type().to_string());
+            Ok(())
+        }
+
+        fn subscribe<E: Event + for<'de> Deserialize<'de>, H: EventHandler<E>>(
+            &self,
+            _handler: H,
+        ) -> Result<SubscriptionHandle> {
+            Ok(SubscriptionHandle::new::<E>(Uuid::new_v4()))
+        }
+
+        fn unsubscribe(&self, _handle: &SubscriptionHandle) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_publish_requires_registered_schema() {
+        let event = PriceEvent {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            price: "1".to_string(),
+            exchange: "binance".to_string(),
+        };
+
+        let bus = SchemaValidatingEventBus::new(RecordingBus::default(), Arc::new(EventSchemaRegistry::new()));
+        assert!(matches!(bus.publish(event.clone()), Err(CoreError::Validation(_))));
+
+        let mut registry = EventSchemaRegistry::new();
+        registry.register("test.price", 2).unwrap();
+        let bus = SchemaValidatingEventBus::new(RecordingBus::default(), Arc::new(registry));
+        assert!(bus.publish(event.clone()).is_err());
+
+        let bus = SchemaValidatingEventBus::new(RecordingBus::default(), Arc::new(registry_with(&event)));
+        bus.publish(event).unwrap();
+        assert_eq!(*bus.inner().published.lock().unwrap(), vec!["test.price"]);
+    }
+
+    fn registry_with(event: &PriceEvent) -> EventSchemaRegistry {
+        let mut registry = EventSchemaRegistry::new();
+        registry.register_event(event).unwrap();
+        registry
+    }
+}
@@ -0,0 +1,470 @@
+//! 읽기 모델 스냅숏
+//!
+//! 이벤트를 처음부터 재생해 읽기 모델(캐시, 프로젝션)을 다시 만드는 대신, 주기적으로
+//! 상태와 그 상태가 반영한 이벤트 로그 오프셋을 스냅숏으로 저장해 두고, 시작할 때
+//! 가장 최근의 유효한 스냅숏을 복원한 뒤 그 오프셋 이후의 이벤트만 재생합니다.
+//!
+//! 손상되었거나 상태 형식 버전이 다른 스냅숏은 건너뛰고 더 오래된 스냅숏으로, 그마저
+//! 없으면 전체 재생으로 물러납니다. 스냅숏 오프셋은 상태를 읽기 전에 정하므로 경계의
+//! 이벤트가 다시 재생될 수 있으며, 읽기 모델은 같은 이벤트를 다시 적용해도 결과가 같아야 합니다.
+//!
+//! ```ignore
+//! let snapshotter = Snapshotter::new(Arc::new(FileSnapshotStore::open(dir)?), SnapshotPolicy::every_events(10_000));
+//! let report = snapshotter.recover(&*cache, |from| log.replay_into(ReplayFrom::Offset(from), &handler))?;
+//! // 이후 주기적으로
+//! snapshotter.maybe_snapshot(&*cache, log.next_offset())?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use crate::clock::{system_clock, SharedClock};
+use crate::error::CoreError;
+use crate::types::Result;
+
+/// 스냅숏으로 저장하고 복원할 수 있는 읽기 모델
+pub trait Snapshottable: Send + Sync {
+    /// 저장소 안에서 스냅숏을 구분하는 이름 (예: `trading.market_data_cache`)
+    fn snapshot_name(&self) -> &str;
+
+    /// 상태 형식 버전
+    ///
+    /// 상태 구조를 바꾸면 올립니다. 버전이 다른 스냅숏은 복원하지 않고 재생으로 다시 만듭니다.
+    fn snapshot_version(&self) -> u32 {
+        1
+    }
+
+    /// 현재 상태
+    fn snapshot_state(&self) -> Result<Value>;
+
+    /// 상태 복원 (기존 상태를 통째로 대체)
+    ///
+    /// 실패하면 기존 상태를 건드리지 않아야 합니다.
+    fn restore_state(&self, state: Value) -> Result<()>;
+}
+
+/// 스냅숏
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// 읽기 모델 이름
+    pub name: String,
+    /// 상태 형식 버전
+    pub version: u32,
+    /// 다음에 재생할 이벤트 로그 오프셋 (이 오프셋 이전의 이벤트가 반영됨)
+    pub offset: u64,
+    /// 생성 시각
+    pub created_at: DateTime<Utc>,
+    /// 상태
+    pub state: Value,
+}
+
+/// 스냅숏 저장소
+///
+/// 구현체는 저장한 내용의 무결성을 검사하여, 손상된 스냅숏을 `load`에서 오류로 돌려줘야 합니다.
+pub trait SnapshotStore: Send + Sync {
+    /// 스냅숏 저장 (같은 오프셋이 있으면 교체)
+    fn save(&self, snapshot: &Snapshot) -> Result<()>;
+
+    /// 읽기 모델의 스냅숏 오프셋 목록 (최신순)
+    fn offsets(&self, name: &str) -> Result<Vec<u64>>;
+
+    /// 스냅숏 읽기 (손상되었으면 오류)
+    fn load(&self, name: &str, offset: u64) -> Result<Snapshot>;
+
+    /// 최신 `keep`개만 남기고 삭제 (삭제한 수 반환)
+    fn prune(&self, name: &str, keep: usize) -> Result<usize>;
+}
+
+/// 인메모리 스냅숏 저장소 (테스트·단일 프로세스용)
+#[derive(Debug, Default)]
+pub struct InMemorySnapshotStore {
+    snapshots: RwLock<HashMap<String, BTreeMap<u64, Snapshot>>>,
+}
+
+impl InMemorySnapshotStore {
+    /// 새로운 인메모리 스냅숏 저장소 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SnapshotStore for InMemorySnapshotStore {
+    fn save(&self, snapshot: &Snapshot) -> Result<()> {
+        self.snapshots
+            .write()
+            .unwrap()
+            .entry(snapshot.name.clone())
+            .or_default()
+            .insert(snapshot.offset, snapshot.clone());
+        Ok(())
+    }
+
+    fn offsets(&self, name: &str) -> Result<Vec<u64>> {
+        Ok(self
+            .snapshots
+            .read()
+            .unwrap()
+            .get(name)
+            .map(|snapshots| snapshots.keys().rev().copied().collect())
+            .unwrap_or_default())
+    }
+
+    fn load(&self, name: &str, offset: u64) -> Result<Snapshot> {
+        self.snapshots
+            .read()
+            .unwrap()
+            .get(name)
+            .and_then(|snapshots| snapshots.get(&offset))
+            .cloned()
+            .ok_or_else(|| CoreError::NotFound(format!("스냅숏을 찾을 수 없음: {}@{}", name, offset)))
+    }
+
+    fn prune(&self, name: &str, keep: usize) -> Result<usize> {
+        let mut snapshots = self.snapshots.write().unwrap();
+        let Some(snapshots) = snapshots.get_mut(name) else {
+            return Ok(0);
+        };
+        let excess = snapshots.len().saturating_sub(keep);
+        let oldest: Vec<u64> = snapshots.keys().take(excess).copied().collect();
+        for offset in &oldest {
+            snapshots.remove(offset);
+        }
+        Ok(oldest.len())
+    }
+}
+
+/// 스냅숏 주기와 보관 개수
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotPolicy {
+    /// 마지막 스냅숏 이후 이만큼 이벤트가 쌓이면 스냅숏
+    pub every_events: Option<u64>,
+    /// 마지막 스냅숏 이후 이만큼 시간이 지나면 스냅숏 (새 이벤트가 있을 때만)
+    pub every: Option<Duration>,
+    /// 보관할 스냅숏 수 (손상 시 물러날 여유분 포함)
+    pub keep: usize,
+}
+
+impl SnapshotPolicy {
+    /// 이벤트 수 기준 정책 (보관 3개)
+    pub fn every_events(events: u64) -> Self {
+        Self {
+            every_events: Some(events.max(1)),
+            every: None,
+            keep: 3,
+        }
+    }
+
+    /// 시간 기준 정책 (보관 3개)
+    pub fn every(interval: Duration) -> Self {
+        Self {
+            every_events: None,
+            every: Some(interval),
+            keep: 3,
+        }
+    }
+
+    /// 시간 기준 추가
+    pub fn or_every(mut self, interval: Duration) -> Self {
+        self.every = Some(interval);
+        self
+    }
+
+    /// 보관 개수 지정 (0은 1로 보정)
+    pub fn with_keep(mut self, keep: usize) -> Self {
+        self.keep = keep.max(1);
+        self
+    }
+}
+
+/// 복원 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoveryReport {
+    /// 복원한 스냅숏의 오프셋 (없으면 전체 재생)
+    pub restored_offset: Option<u64>,
+    /// 건너뛴 스냅숏 수 (손상, 버전 불일치, 복원 실패)
+    pub skipped: usize,
+    /// 재생한 이벤트 수
+    pub replayed: u64,
+}
+
+/// 마지막 스냅숏 위치
+#[derive(Debug, Clone, Copy)]
+struct LastSnapshot {
+    offset: u64,
+    at: DateTime<Utc>,
+}
+
+/// 읽기 모델 스냅숏 관리자
+pub struct Snapshotter {
+    store: Arc<dyn SnapshotStore>,
+    policy: SnapshotPolicy,
+    clock: SharedClock,
+    last: Mutex<HashMap<String, LastSnapshot>>,
+}
+
+impl Snapshotter {
+    /// 저장소와 정책으로 생성
+    pub fn new(store: Arc<dyn SnapshotStore>, policy: SnapshotPolicy) -> Self {
+        Self {
+            store,
+            policy,
+            clock: system_clock(),
+            last: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 시계 지정 (시간 기준 정책과 생성 시각에 사용)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 스냅숏 정책
+    pub fn policy(&self) -> &SnapshotPolicy {
+        &self.policy
+    }
+
+    /// 가장 최근의 유효한 스냅숏 복원
+    ///
+    /// 복원한 스냅숏의 오프셋(다음에 재생할 오프셋)을 반환하며, 쓸 수 있는 스냅숏이 없으면
+    /// 상태를 건드리지 않고 `None`을 반환합니다. 건너뛴 스냅숏 수도 함께 반환합니다.
+    pub fn restore(&self, projection: &dyn Snapshottable) -> Result<(Option<u64>, usize)> {
+        let name = projection.snapshot_name();
+        let mut skipped = 0;
+
+        for offset in self.store.offsets(name)? {
+            let snapshot = match self.store.load(name, offset) {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    tracing::warn!("손상된 스냅숏 건너뜀: {}@{} - {:?}", name, offset, e);
+                    skipped += 1;
+                    continue;
+                }
+            };
+            if snapshot.version != projection.snapshot_version() {
+                tracing::warn!(
+                    "상태 형식 버전이 다른 스냅숏 건너뜀: {}@{} (v{}, 현재 v{})",
+                    name,
+                    offset,
+                    snapshot.version,
+                    projection.snapshot_version()
+                );
+                skipped += 1;
+                continue;
+            }
+
+            let created_at = snapshot.created_at;
+            if let Err(e) = projection.restore_state(snapshot.state) {
+                tracing::warn!("스냅숏 복원 실패, 이전 스냅숏으로 물러남: {}@{} - {:?}", name, offset, e);
+                skipped += 1;
+                continue;
+            }
+
+            self.last.lock().unwrap().insert(
+                name.to_string(),
+                LastSnapshot {
+                    offset,
+                    at: created_at,
+                },
+            );
+            tracing::info!("스냅숏 복원: {}@{}", name, offset);
+            return Ok((Some(offset), skipped));
+        }
+
+        Ok((None, skipped))
+    }
+
+    /// 스냅숏 복원 후 그 오프셋부터 재생
+    ///
+    /// `replay`는 시작 오프셋을 받아 이벤트를 읽기 모델에 적용하고 적용한 수를 반환합니다.
+    pub fn recover<F>(&self, projection: &dyn Snapshottable, replay: F) -> Result<RecoveryReport>
+    where
+        F: FnOnce(u64) -> Result<u64>,
+    {
+        let (restored_offset, skipped) = self.restore(projection)?;
+        let replayed = replay(restored_offset.unwrap_or(0))?;
+
+        tracing::info!(
+            "읽기 모델 복구 완료: {} (스냅숏 {:?}, 재생 {}건)",
+            projection.snapshot_name(),
+            restored_offset,
+            replayed
+        );
+        Ok(RecoveryReport {
+            restored_offset,
+            skipped,
+            replayed,
+        })
+    }
+
+    /// 스냅숏 저장 후 오래된 스냅숏 정리
+    ///
+    /// `offset`은 현재 상태에 반영된 다음 오프셋으로, 상태를 읽기 전에 구해야 합니다.
+    pub fn snapshot(&self, projection: &dyn Snapshottable, offset: u64) -> Result<Snapshot> {
+        let snapshot = Snapshot {
+            name: projection.snapshot_name().to_string(),
+            version: projection.snapshot_version(),
+            offset,
+            created_at: self.clock.now(),
+            state: projection.snapshot_state()?,
+        };
+        self.store.save(&snapshot)?;
+
+        let pruned = self.store.prune(&snapshot.name, self.policy.keep)?;
+        self.last.lock().unwrap().insert(
+            snapshot.name.clone(),
+            LastSnapshot {
+                offset,
+                at: snapshot.created_at,
+            },
+        );
+        tracing::debug!("스냅숏 저장: {}@{} (정리 {}개)", snapshot.name, offset, pruned);
+        Ok(snapshot)
+    }
+
+    /// 정책상 때가 되었으면 스냅숏 (저장했으면 참)
+    pub fn maybe_snapshot(&self, projection: &dyn Snapshottable, offset: u64) -> Result<bool> {
+        if !self.is_due(projection.snapshot_name(), offset) {
+            return Ok(false);
+        }
+        self.snapshot(projection, offset)?;
+        Ok(true)
+    }
+
+    fn is_due(&self, name: &str, offset: u64) -> bool {
+        let last = self.last.lock().unwrap().get(name).copied();
+        let Some(last) = last else {
+            return offset > 0;
+        };
+        if offset <= last.offset {
+            return false;
+        }
+
+        let by_events = self
+            .policy
+            .every_events
+            .is_some_and(|events| offset - last.offset >= events);
+        let by_time = self
+            .policy
+            .every
+            .and_then(|interval| chrono::Duration::from_std(interval).ok())
+            .is_some_and(|interval| self.clock.now() - last.at >= interval);
+        by_events || by_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SimulatedClock;
+    use chrono::TimeZone;
+
+    /// 이벤트 값을 더해 가는 읽기 모델
+    #[derive(Default)]
+    struct Counter {
+        total: Mutex<i64>,
+    }
+
+    impl Snapshottable for Counter {
+        fn snapshot_name(&self) -> &str {
+            "test.counter"
+        }
+
+        fn snapshot_state(&self) -> Result<Value> {
+            Ok(serde_json::json!({ "total": *self.total.lock().unwrap() }))
+        }
+
+        fn restore_state(&self, state: Value) -> Result<()> {
+            let total = state["total"]
+                .as_i64()
+                .ok_or_else(|| CoreError::Data("total 없음".to_string()))?;
+            *self.total.lock().unwrap() = total;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_recover_falls_back_past_bad_snapshots() {
+        let events: Vec<i64> = (1..=10).collect();
+        let replay = |counter: &Counter, from: u64| -> Result<u64> {
+            let pending = &events[from as usize..];
+            *counter.total.lock().unwrap() += pending.iter().sum::<i64>();
+            Ok(pending.len() as u64)
+        };
+
+        let clock = Arc::new(SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()));
+        let store = Arc::new(InMemorySnapshotStore::new());
+        let snapshotter = Snapshotter::new(store.clone(), SnapshotPolicy::every_events(4).with_keep(2))
+            .with_clock(clock.clone());
+
+        let live = Counter::default();
+        let mut snapshots = 0;
+        for (offset, value) in events.iter().enumerate() {
+            *live.total.lock().unwrap() += value;
+            if snapshotter.maybe_snapshot(&live, offset as u64 + 1).unwrap() {
+                snapshots += 1;
+            }
+        }
+        // 오프셋 1에서 첫 스냅숏, 이후 4건마다 (5, 9)
+        assert_eq!(snapshots, 3);
+        assert_eq!(store.offsets("test.counter").unwrap(), [9, 5]);
+
+        let restored = Counter::default();
+        let report = Snapshotter::new(store.clone(), SnapshotPolicy::every_events(4))
+            .recover(&restored, |from| replay(&restored, from))
+            .unwrap();
+        assert_eq!(report.restored_offset, Some(9));
+        assert_eq!(report.replayed, 1);
+        assert_eq!(*restored.total.lock().unwrap(), 55);
+
+        // 최신 스냅숏이 복원할 수 없는 상태면 이전 스냅숏으로 물러남
+        let mut broken = store.load("test.counter", 9).unwrap();
+        broken.state = serde_json::json!({ "unexpected": true });
+        store.save(&broken).unwrap();
+
+        let restored = Counter::default();
+        let report = Snapshotter::new(store.clone(), SnapshotPolicy::every_events(4))
+            .recover(&restored, |from| replay(&restored, from))
+            .unwrap();
+        assert_eq!(report.restored_offset, Some(5));
+        assert_eq!(report.skipped, 1);
+        assert_eq!(*restored.total.lock().unwrap(), 55);
+
+        // 버전이 다르면 전체 재생
+        let mut old = store.load("test.counter", 5).unwrap();
+        old.version = 0;
+        store.save(&old).unwrap();
+
+        let restored = Counter::default();
+        let report = Snapshotter::new(store, SnapshotPolicy::every_events(4))
+            .recover(&restored, |from| replay(&restored, from))
+            .unwrap();
+        assert_eq!(report.restored_offset, None);
+        assert_eq!(report.replayed, 10);
+        assert_eq!(*restored.total.lock().unwrap(), 55);
+    }
+
+    #[test]
+    fn test_time_based_policy() {
+        let clock = Arc::new(SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()));
+        let snapshotter = Snapshotter::new(
+            Arc::new(InMemorySnapshotStore::new()),
+            SnapshotPolicy::every(Duration::from_secs(60)),
+        )
+        .with_clock(clock.clone());
+        let counter = Counter::default();
+
+        assert!(!snapshotter.maybe_snapshot(&counter, 0).unwrap());
+        assert!(snapshotter.maybe_snapshot(&counter, 3).unwrap());
+        assert!(!snapshotter.maybe_snapshot(&counter, 100).unwrap());
+
+        clock.advance(chrono::Duration::seconds(61));
+        // 새 이벤트가 없으면 시간이 지나도 저장하지 않음
+        assert!(!snapshotter.maybe_snapshot(&counter, 3).unwrap());
+        assert!(snapshotter.maybe_snapshot(&counter, 4).unwrap());
+    }
+}
@@ -0,0 +1,347 @@
+//! 토픽 패턴 구독
+//!
+//! 이벤트 타입 문자열(`market.price.updated` 등)을 점으로 구분된 토픽으로 보고,
+//! 와일드카드 패턴과 헤더·페이로드 필터로 구독 대상을 고릅니다.
+//! 패턴 문법은 AMQP 토픽 교환기와 같습니다.
+//!
+//! - `*`: 정확히 한 구간 (`exchange.order.*` → `exchange.order.filled`)
+//! - `#`: 0개 이상의 구간 (`market.#` → 모든 market 이벤트)
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::CoreError;
+use crate::types::{Result, SymbolPair};
+use super::{Event, EventContext, EventEnvelope, EventHandler, EventHeader, SubscriptionHandle};
+
+/// 패턴 구간
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    /// `*`
+    One,
+    /// `#`
+    Any,
+}
+
+/// 토픽 와일드카드 패턴
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicPattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl TopicPattern {
+    /// 패턴 파싱
+    pub fn new(pattern: &str) -> Result<Self> {
+        pattern.parse()
+    }
+
+    /// 모든 토픽과 일치하는 패턴 (`#`)
+    pub fn all() -> Self {
+        Self {
+            pattern: "#".to_string(),
+            segments: vec![Segment::Any],
+        }
+    }
+
+    /// 토픽 일치 여부
+    pub fn matches(&self, topic: &str) -> bool {
+        let parts: Vec<&str> = topic.split('.').collect();
+        Self::match_segments(&self.segments, &parts)
+    }
+
+    fn match_segments(segments: &[Segment], parts: &[&str]) -> bool {
+        match segments.split_first() {
+            None => parts.is_empty(),
+            Some((Segment::Any, rest)) => (0..=parts.len()).any(|skip| Self::match_segments(rest, &parts[skip..])),
+            Some((segment, rest)) => match parts.split_first() {
+                Some((part, remaining)) => {
+                    let matched = match segment {
+                        Segment::Literal(literal) => literal == part,
+                        _ => true,
+                    };
+                    matched && Self::match_segments(rest, remaining)
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+impl FromStr for TopicPattern {
+    type Err = CoreError;
+
+    fn from_str(pattern: &str) -> Result<Self> {
+        let pattern = pattern.trim();
+        let segments = pattern
+            .split('.')
+            .map(|segment| match segment {
+                "*" => Ok(Segment::One),
+                "#" => Ok(Segment::Any),
+                "" => Err(CoreError::Validation(format!("빈 토픽 구간: '{}'", pattern))),
+                s if s.contains(['*', '#']) => Err(CoreError::Validation(format!(
+                    "와일드카드는 구간 전체여야 합니다: '{}'",
+                    pattern
+                ))),
+                s => Ok(Segment::Literal(s.to_string())),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            segments,
+        })
+    }
+}
+
+impl fmt::Display for TopicPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+/// 헤더·페이로드 필터
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventFilter {
+    /// 헤더의 발생 소스 일치
+    Source(String),
+    /// 페이로드의 심볼 (`symbol_pair` 또는 `symbol` 필드) 일치
+    Symbol(SymbolPair),
+    /// 페이로드의 거래소 (`exchange` 또는 `exchange_id` 필드) 일치 (대소문자 무시)
+    Exchange(String),
+    /// 페이로드의 점 경로 필드 값 일치 (예: `order.side`)
+    Field { path: String, value: Value },
+}
+
+impl EventFilter {
+    /// 심볼 필터
+    pub fn symbol(pair: SymbolPair) -> Self {
+        EventFilter::Symbol(pair)
+    }
+
+    /// 거래소 필터
+    pub fn exchange(exchange: impl Into<String>) -> Self {
+        EventFilter::Exchange(exchange.into())
+    }
+
+    /// 필드 값 필터
+    pub fn field(path: impl Into<String>, value: impl Into<Value>) -> Self {
+        EventFilter::Field {
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+
+    /// 봉투가 필터를 만족하는지 여부
+    pub fn matches(&self, envelope: &EventEnvelope<Value>) -> bool {
+        let payload = &envelope.payload;
+        match self {
+            EventFilter::Source(source) => envelope.header.source == *source,
+            EventFilter::Symbol(pair) => ["symbol_pair", "symbol"]
+                .iter()
+                .filter_map(|key| payload.get(key))
+                .any(|value| symbol_matches(value, pair)),
+            EventFilter::Exchange(exchange) => ["exchange", "exchange_id"]
+                .iter()
+                .filter_map(|key| payload.get(key).and_then(Value::as_str))
+                .any(|value| value.eq_ignore_ascii_case(exchange)),
+            EventFilter::Field { path, value } => {
+                path.split('.').try_fold(payload, |current, key| current.get(key)) == Some(value)
+            }
+        }
+    }
+}
+
+/// 직렬화된 심볼(`{"base", "quote"}` 객체 또는 `BASE/QUOTE` 문자열) 비교
+fn symbol_matches(value: &Value, pair: &SymbolPair) -> bool {
+    match value {
+        Value::String(s) => s
+            .parse::<SymbolPair>()
+            .is_ok_and(|parsed| parsed.base() == pair.base() && parsed.quote() == pair.quote()),
+        Value::Object(object) => {
+            let field = |key: &str| object.get(key).and_then(Value::as_str).map(str::to_uppercase);
+            field("base").as_deref() == Some(pair.base()) && field("quote").as_deref() == Some(pair.quote())
+        }
+        _ => false,
+    }
+}
+
+/// 토픽 패턴 + 필터 구독 조건
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicSubscription {
+    pattern: TopicPattern,
+    filters: Vec<EventFilter>,
+}
+
+impl TopicSubscription {
+    /// 패턴으로 구독 조건 생성
+    pub fn new(pattern: &str) -> Result<Self> {
+        Ok(Self::from_pattern(pattern.parse()?))
+    }
+
+    /// 파싱된 패턴으로 구독 조건 생성
+    pub fn from_pattern(pattern: TopicPattern) -> Self {
+        Self {
+            pattern,
+            filters: Vec::new(),
+        }
+    }
+
+    /// 필터 추가 (모든 필터를 만족해야 일치)
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// 토픽 패턴
+    pub fn pattern(&self) -> &TopicPattern {
+        &self.pattern
+    }
+
+    /// 필터 목록
+    pub fn filters(&self) -> &[EventFilter] {
+        &self.filters
+    }
+
+    /// 봉투가 구독 조건을 만족하는지 여부
+    pub fn matches(&self, envelope: &EventEnvelope<Value>) -> bool {
+        self.pattern.matches(&envelope.header.event_type)
+            && self.filters.iter().all(|filter| filter.matches(envelope))
+    }
+}
+
+/// 이벤트를 JSON 봉투로 변환 (헤더 ID·시각·버전은 이벤트 값을 따름)
+///
+/// 상관 ID와 원인 ID는 이벤트가 들고 있는 맥락이나 현재 처리 중인 이벤트 맥락(`EventContext::of`)에서 채웁니다.
+pub fn envelope_of<E: Event + Serialize>(event: &E, source: &str) -> Result<EventEnvelope<Value>> {
+    envelope_with_context(event, source, &EventContext::of(event))
+}
+
+/// 지정한 맥락으로 이벤트를 JSON 봉투로 변환
+pub fn envelope_with_context<E: Event + Serialize>(
+    event: &E,
+    source: &str,
+    context: &EventContext,
+) -> Result<EventEnvelope<Value>> {
+    let payload = serde_json::to_value(event)
+        .map_err(|e| CoreError::Data(format!("이벤트 직렬화 실패: {}", e)))?;
+
+    Ok(EventEnvelope {
+        header: header_with_context(event, source, context),
+        payload,
+    })
+}
+
+/// 주어진 맥락으로 이벤트 헤더 생성 (페이로드를 `Value`로 바꾸지 않고 코덱으로 직렬화할 때)
+pub fn header_with_context<E: Event>(event: &E, source: &str, context: &EventContext) -> EventHeader {
+    EventHeader {
+        id: *event.id(),
+        timestamp: event.timestamp(),
+        source: source.to_string(),
+        event_type: event.event_type().to_string(),
+        correlation_id: Some(context.correlation_id),
+        causation_id: context.causation_id,
+        version: event.schema_version().to_string(),
+        codec: None,
+    }
+}
+
+/// 구체 타입을 모르는 소비자(로거, 감사, 브리지 등)를 위한 봉투 핸들러
+pub trait EnvelopeHandler: Send + Sync + 'static {
+    /// 봉투 처리
+    fn handle(&self, envelope: &EventEnvelope<Value>) -> Result<()>;
+}
+
+impl<F> EnvelopeHandler for F
+where
+    F: Fn(&EventEnvelope<Value>) -> Result<()> + Send + Sync + 'static,
+{
+    fn handle(&self, envelope: &EventEnvelope<Value>) -> Result<()> {
+        self(envelope)
+    }
+}
+
+/// 토픽 패턴 구독을 지원하는 이벤트 버스
+pub trait TopicEventBus: Send + Sync {
+    /// 패턴과 일치하는 모든 이벤트를 JSON 봉투로 구독
+    fn subscribe_topic<H: EnvelopeHandler>(
+        &self,
+        subscription: TopicSubscription,
+        handler: H,
+    ) -> Result<SubscriptionHandle>;
+
+    /// 구독 조건과 일치하는 `E` 타입 이벤트만 구독
+    fn subscribe_matching<E, H>(
+        &self,
+        subscription: TopicSubscription,
+        handler: H,
+    ) -> Result<SubscriptionHandle>
+    where
+        E: Event + Serialize + for<'de> serde::Deserialize<'de>,
+        H: EventHandler<E>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn envelope(event_type: &str, payload: Value) -> EventEnvelope<Value> {
+        EventEnvelope {
+            header: crate::events::create_event_header("market-domain", event_type, None),
+            payload,
+        }
+    }
+
+    #[test]
+    fn test_topic_pattern() {
+        let single = TopicPattern::new("exchange.order.*").unwrap();
+        assert!(single.matches("exchange.order.filled"));
+        assert!(!single.matches("exchange.order"));
+        assert!(!single.matches("exchange.order.filled.partial"));
+
+        let market = TopicPattern::new("market.#").unwrap();
+        assert!(market.matches("market.price.updated"));
+        assert!(market.matches("market"));
+        assert!(!market.matches("exchange.order.filled"));
+
+        let middle = TopicPattern::new("#.updated").unwrap();
+        assert!(middle.matches("market.candlestick.updated"));
+        assert!(!middle.matches("exchange.order.filled"));
+
+        assert!(TopicPattern::all().matches("anything.at.all"));
+        assert!(TopicPattern::new("market..price").is_err());
+        assert!(TopicPattern::new("market.price*").is_err());
+    }
+
+    #[test]
+    fn test_filters() {
+        let price = envelope(
+            "market.price.updated",
+            json!({ "symbol_pair": { "base": "BTC", "quote": "USDT" }, "price": "42000" }),
+        );
+        let fill = envelope(
+            "exchange.order.filled",
+            json!({ "symbol_pair": "ETH/USDT", "exchange": "Binance", "order": { "side": "buy" } }),
+        );
+
+        let btc = TopicSubscription::new("#").unwrap().with_filter(EventFilter::symbol(SymbolPair::new("BTC", "USDT")));
+        assert!(btc.matches(&price));
+        assert!(!btc.matches(&fill));
+
+        let binance_fills = TopicSubscription::new("exchange.order.*")
+            .unwrap()
+            .with_filter(EventFilter::exchange("binance"))
+            .with_filter(EventFilter::symbol(SymbolPair::new("ETH", "USDT")))
+            .with_filter(EventFilter::field("order.side", "buy"));
+        assert!(binance_fills.matches(&fill));
+        assert!(!binance_fills.matches(&price));
+
+        let from_market = TopicSubscription::new("#").unwrap().with_filter(EventFilter::Source("market-domain".to_string()));
+        assert!(from_market.matches(&price));
+        assert!(!from_market.with_filter(EventFilter::field("price", "1")).matches(&price));
+    }
+}
@@ -4,9 +4,14 @@
 //! 여기에는 기본 타입, 에러 처리, 유틸리티 함수 등이 포함됩니다.
 
 pub mod error;
+pub mod clock;
+pub mod id;
+pub mod decimal;
 pub mod types;
 pub mod utils;
+pub mod domain;
 pub mod events;
+pub mod resilience;
 
 /// 라이브러리 버전 정보
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
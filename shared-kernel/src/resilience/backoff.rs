@@ -0,0 +1,129 @@
+//! 백오프 전략
+//!
+//! 재시도 간 대기 시간을 지수적으로 늘리고, 여러 클라이언트가 동시에
+//! 재시도하는 현상(thundering herd)을 막기 위해 지터를 적용합니다.
+
+use std::time::Duration;
+
+/// 지터 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// 지터 없음 (테스트 등 결정적 동작이 필요할 때)
+    None,
+    /// `[0, 지연]` 구간의 균등 난수
+    Full,
+    /// `[지연/2, 지연]` 구간의 균등 난수
+    Equal,
+}
+
+/// 지수 백오프
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialBackoff {
+    /// 첫 재시도 전 대기 시간
+    initial: Duration,
+    /// 최대 대기 시간
+    max: Duration,
+    /// 재시도마다 곱해지는 배수
+    multiplier: f64,
+    /// 지터 방식
+    jitter: Jitter,
+}
+
+impl ExponentialBackoff {
+    /// 새 백오프 생성 (배수 2, 전체 지터)
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max: max.max(initial),
+            multiplier: 2.0,
+            jitter: Jitter::Full,
+        }
+    }
+
+    /// 배수 지정 (1 미만은 1로 보정)
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = if multiplier.is_finite() { multiplier.max(1.0) } else { 1.0 };
+        self
+    }
+
+    /// 지터 방식 지정
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// 첫 대기 시간
+    pub fn initial(&self) -> Duration {
+        self.initial
+    }
+
+    /// 최대 대기 시간
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// 지터 방식
+    pub fn jitter(&self) -> Jitter {
+        self.jitter
+    }
+
+    /// 지터 적용 전 대기 시간 (`retry`는 0부터 시작하는 재시도 순번)
+    pub fn base_delay(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry).unwrap_or(i32::MAX);
+        let seconds = self.initial.as_secs_f64() * self.multiplier.powi(exponent);
+        if !seconds.is_finite() || seconds >= self.max.as_secs_f64() {
+            self.max
+        } else {
+            Duration::from_secs_f64(seconds)
+        }
+    }
+
+    /// 지터를 적용한 대기 시간
+    pub fn delay(&self, retry: u32) -> Duration {
+        let base = self.base_delay(retry);
+        match self.jitter {
+            Jitter::None => base,
+            Jitter::Full => base.mul_f64(fastrand::f64()),
+            Jitter::Equal => {
+                let half = base / 2;
+                half + half.mul_f64(fastrand::f64())
+            }
+        }
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(100), Duration::from_secs(10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_growth_is_capped() {
+        let backoff = ExponentialBackoff::new(Duration::from_millis(100), Duration::from_secs(1))
+            .with_jitter(Jitter::None);
+
+        assert_eq!(backoff.delay(0), Duration::from_millis(100));
+        assert_eq!(backoff.delay(1), Duration::from_millis(200));
+        assert_eq!(backoff.delay(3), Duration::from_millis(800));
+        assert_eq!(backoff.delay(4), Duration::from_secs(1));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_jitter_bounds() {
+        let full = ExponentialBackoff::new(Duration::from_millis(400), Duration::from_secs(10));
+        let equal = full.clone().with_jitter(Jitter::Equal);
+
+        for _ in 0..100 {
+            assert!(full.delay(0) <= Duration::from_millis(400));
+
+            let delay = equal.delay(0);
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+}
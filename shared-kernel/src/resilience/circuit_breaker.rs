@@ -0,0 +1,626 @@
+//! 회로 차단기
+//!
+//! 엔드포인트별로 연속된 일시적 오류를 세어 임계값을 넘으면 회로를 열고,
+//! 열린 동안에는 호출하지 않고 `CoreError::CircuitOpen`으로 즉시 실패합니다.
+//! 대기 시간이 지나면 반개방 상태에서 제한된 수의 탐침 호출로 복구 여부를 확인합니다.
+//! 시간은 주입된 시계 기준이므로 백테스트나 테스트에서도 결정적으로 동작합니다.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use crate::clock::SharedClock;
+use crate::error::CoreError;
+use crate::events::{Event, EventBus};
+use crate::id::new_id_at;
+use crate::types::Result;
+
+/// 회로 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// 정상 (호출 허용)
+    Closed,
+    /// 차단 (호출 거부)
+    Open,
+    /// 반개방 (탐침 호출만 허용)
+    HalfOpen,
+}
+
+/// 회로 차단기 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// 회로를 여는 연속 실패 횟수
+    pub failure_threshold: u32,
+    /// 회로가 열린 상태로 유지되는 시간
+    pub open_duration: Duration,
+    /// 반개방 상태에서 동시에 허용하는 탐침 호출 수
+    pub half_open_max_probes: u32,
+    /// 회로를 닫기 위해 필요한 탐침 성공 횟수
+    pub success_threshold: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::seconds(30),
+            half_open_max_probes: 1,
+            success_threshold: 1,
+        }
+    }
+}
+
+/// 회로 상태 전이
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitTransition {
+    /// 엔드포인트
+    pub endpoint: String,
+    /// 이전 상태
+    pub from: CircuitState,
+    /// 새 상태
+    pub to: CircuitState,
+    /// 전이 시각
+    pub at: DateTime<Utc>,
+    /// 전이 시점의 연속 실패 횟수
+    pub consecutive_failures: u32,
+    /// 회로가 열린 경우 반개방으로 전환되는 시각
+    pub open_until: Option<DateTime<Utc>>,
+}
+
+/// 회로 상태 전이 리스너
+pub trait CircuitBreakerListener: Send + Sync {
+    /// 상태 전이 통지 (차단기 잠금 밖에서 호출됨)
+    fn on_transition(&self, transition: &CircuitTransition);
+}
+
+/// 회로 열림 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerOpened {
+    /// 이벤트 ID
+    pub id: Uuid,
+    /// 엔드포인트
+    pub endpoint: String,
+    /// 연속 실패 횟수
+    pub consecutive_failures: u32,
+    /// 회로가 열린 시각
+    pub opened_at: DateTime<Utc>,
+    /// 반개방으로 전환되는 시각
+    pub open_until: DateTime<Utc>,
+}
+
+impl Event for CircuitBreakerOpened {
+    fn event_type(&self) -> &'static str {
+        "resilience.circuit_breaker.opened"
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.opened_at
+    }
+
+    fn id(&self) -> &Uuid {
+        &self.id
+    }
+}
+
+/// 회로가 열릴 때 `CircuitBreakerOpened` 이벤트를 발행하는 리스너
+pub struct EventBusCircuitListener<B: EventBus> {
+    bus: Arc<B>,
+}
+
+impl<B: EventBus> EventBusCircuitListener<B> {
+    /// 새 리스너 생성
+    pub fn new(bus: Arc<B>) -> Self {
+        Self { bus }
+    }
+}
+
+impl<B: EventBus> CircuitBreakerListener for EventBusCircuitListener<B> {
+    fn on_transition(&self, transition: &CircuitTransition) {
+        let (CircuitState::Open, Some(open_until)) = (transition.to, transition.open_until) else {
+            return;
+        };
+
+        let event = CircuitBreakerOpened {
+            id: new_id_at(transition.at),
+            endpoint: transition.endpoint.clone(),
+            consecutive_failures: transition.consecutive_failures,
+            opened_at: transition.at,
+            open_until,
+        };
+        if let Err(e) = self.bus.publish(event) {
+            tracing::error!("회로 열림 이벤트 발행 실패 ({}): {}", transition.endpoint, e);
+        }
+    }
+}
+
+/// 내부 상태
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: DateTime<Utc>, failures: u32 },
+    HalfOpen { in_flight: u32, successes: u32 },
+}
+
+impl State {
+    fn kind(&self) -> CircuitState {
+        match self {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+}
+
+/// 엔드포인트 단위 회로 차단기
+pub struct CircuitBreaker {
+    endpoint: String,
+    config: CircuitBreakerConfig,
+    clock: SharedClock,
+    state: Mutex<State>,
+    listeners: Vec<Arc<dyn CircuitBreakerListener>>,
+}
+
+impl std::fmt::Debug for CircuitBreaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreaker")
+            .field("endpoint", &self.endpoint)
+            .field("config", &self.config)
+            .field("state", &self.state())
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
+
+impl CircuitBreaker {
+    /// 새 회로 차단기 생성
+    pub fn new(endpoint: impl Into<String>, config: CircuitBreakerConfig, clock: SharedClock) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            config,
+            clock,
+            state: Mutex::new(State::Closed { failures: 0 }),
+            listeners: Vec::new(),
+        }
+    }
+
+    /// 상태 전이 리스너 추가
+    pub fn with_listener(mut self, listener: Arc<dyn CircuitBreakerListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// 엔드포인트
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// 설정
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// 현재 상태
+    pub fn state(&self) -> CircuitState {
+        self.lock().kind()
+    }
+
+    /// 회로 차단기를 통해 작업 실행
+    ///
+    /// 일시적 오류만 실패로 집계합니다. 영구 오류는 엔드포인트가 응답했다는 뜻이므로
+    /// 성공으로 간주합니다.
+    pub async fn call<T, F, Fut>(&self, operation: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut permit = self.acquire()?;
+        let result = operation().await;
+        permit.completed = true;
+
+        match &result {
+            Err(error) if error.is_retryable() => self.on_failure(permit.probe),
+            _ => self.on_success(permit.probe),
+        }
+        result
+    }
+
+    /// 호출 허가 획득
+    fn acquire(&self) -> Result<Permit<'_>> {
+        let now = self.clock.now();
+        let mut transition = None;
+
+        let probe = {
+            let mut state = self.lock();
+            if let State::Open { until, failures } = *state {
+                if now < until {
+                    return Err(CoreError::CircuitOpen {
+                        endpoint: self.endpoint.clone(),
+                        retry_after: (until - now).to_std().ok(),
+                    });
+                }
+                *state = State::HalfOpen { in_flight: 0, successes: 0 };
+                transition = Some(self.transition(CircuitState::Open, CircuitState::HalfOpen, now, failures, None));
+            }
+
+            match &mut *state {
+                State::HalfOpen { in_flight, .. } => {
+                    if *in_flight >= self.config.half_open_max_probes.max(1) {
+                        drop(state);
+                        self.notify(transition);
+                        return Err(CoreError::CircuitOpen {
+                            endpoint: self.endpoint.clone(),
+                            retry_after: None,
+                        });
+                    }
+                    *in_flight += 1;
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        self.notify(transition);
+        Ok(Permit { breaker: self, probe, completed: false })
+    }
+
+    fn on_success(&self, probe: bool) {
+        let now = self.clock.now();
+        let mut transition = None;
+        {
+            let mut state = self.lock();
+            match &mut *state {
+                State::Closed { failures } => *failures = 0,
+                State::HalfOpen { in_flight, successes } if probe => {
+                    *in_flight = in_flight.saturating_sub(1);
+                    *successes += 1;
+                    if *successes >= self.config.success_threshold.max(1) {
+                        *state = State::Closed { failures: 0 };
+                        transition = Some(self.transition(CircuitState::HalfOpen, CircuitState::Closed, now, 0, None));
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.notify(transition);
+    }
+
+    fn on_failure(&self, probe: bool) {
+        let now = self.clock.now();
+        let until = now + self.config.open_duration;
+        let mut transition = None;
+        {
+            let mut state = self.lock();
+            match *state {
+                State::Closed { failures } => {
+                    let failures = failures + 1;
+                    if failures >= self.config.failure_threshold.max(1) {
+                        *state = State::Open { until, failures };
+                        transition = Some(self.transition(CircuitState::Closed, CircuitState::Open, now, failures, Some(until)));
+                    } else {
+                        *state = State::Closed { failures };
+                    }
+                }
+                // 탐침 실패 시 즉시 다시 열림
+                State::HalfOpen { .. } if probe => {
+                    *state = State::Open { until, failures: 1 };
+                    transition = Some(self.transition(CircuitState::HalfOpen, CircuitState::Open, now, 1, Some(until)));
+                }
+                _ => {}
+            }
+        }
+        self.notify(transition);
+    }
+
+    /// 결과 없이 끝난 탐침 호출의 슬롯 반환
+    fn release_probe(&self) {
+        if let State::HalfOpen { in_flight, .. } = &mut *self.lock() {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+    }
+
+    fn transition(
+        &self,
+        from: CircuitState,
+        to: CircuitState,
+        at: DateTime<Utc>,
+        consecutive_failures: u32,
+        open_until: Option<DateTime<Utc>>,
+    ) -> CircuitTransition {
+        CircuitTransition {
+            endpoint: self.endpoint.clone(),
+            from,
+            to,
+            at,
+            consecutive_failures,
+            open_until,
+        }
+    }
+
+    fn notify(&self, transition: Option<CircuitTransition>) {
+        let Some(transition) = transition else {
+            return;
+        };
+
+        if transition.to == CircuitState::Open {
+            tracing::warn!(
+                endpoint = %transition.endpoint,
+                failures = transition.consecutive_failures,
+                "회로 차단기 열림"
+            );
+        } else {
+            tracing::info!(endpoint = %transition.endpoint, "회로 차단기 상태 전이: {:?} -> {:?}", transition.from, transition.to);
+        }
+
+        for listener in &self.listeners {
+            listener.on_transition(&transition);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 호출 허가 (호출이 취소되어도 탐침 슬롯이 반환되도록 보장)
+struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    completed: bool,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.completed {
+            self.breaker.release_probe();
+        }
+    }
+}
+
+/// 엔드포인트별 회로 차단기 저장소
+///
+/// 같은 엔드포인트에 대해서는 항상 같은 차단기를 반환하므로,
+/// 커넥터 인스턴스 간에도 실패 집계가 공유됩니다.
+pub struct CircuitBreakerRegistry {
+    config: CircuitBreakerConfig,
+    clock: SharedClock,
+    listeners: Vec<Arc<dyn CircuitBreakerListener>>,
+    breakers: Mutex<HashMap<String, Arc<CircuitBreaker>>>,
+}
+
+impl CircuitBreakerRegistry {
+    /// 새 저장소 생성
+    pub fn new(config: CircuitBreakerConfig, clock: SharedClock) -> Self {
+        Self {
+            config,
+            clock,
+            listeners: Vec::new(),
+            breakers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 이후 생성되는 모든 차단기에 리스너 추가
+    pub fn with_listener(mut self, listener: Arc<dyn CircuitBreakerListener>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    /// 엔드포인트의 차단기 (없으면 생성)
+    pub fn breaker(&self, endpoint: &str) -> Arc<CircuitBreaker> {
+        let mut breakers = self.breakers.lock().unwrap_or_else(|e| e.into_inner());
+        breakers
+            .entry(endpoint.to_string())
+            .or_insert_with(|| {
+                let breaker = self.listeners.iter().fold(
+                    CircuitBreaker::new(endpoint, self.config.clone(), self.clock.clone()),
+                    |breaker, listener| breaker.with_listener(listener.clone()),
+                );
+                Arc::new(breaker)
+            })
+            .clone()
+    }
+
+    /// 엔드포인트별 현재 상태
+    pub fn states(&self) -> HashMap<String, CircuitState> {
+        self.breakers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(endpoint, breaker)| (endpoint.clone(), breaker.state()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::SimulatedClock;
+    use crate::events::{EventHandler, SubscriptionHandle};
+    use crate::resilience::{ExponentialBackoff, Jitter, RetryPolicy};
+    use chrono::TimeZone;
+    use serde::Deserialize;
+
+    fn clock() -> SimulatedClock {
+        SimulatedClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+    }
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::seconds(10),
+            half_open_max_probes: 1,
+            success_threshold: 1,
+        }
+    }
+
+    async fn fail(breaker: &CircuitBreaker) -> Result<()> {
+        breaker
+            .call(|| async { Err(CoreError::Timeout("응답 없음".to_string())) })
+            .await
+    }
+
+    async fn succeed(breaker: &CircuitBreaker) -> Result<()> {
+        breaker.call(|| async { Ok(()) }).await
+    }
+
+    #[derive(Default)]
+    struct RecordingListener {
+        transitions: Mutex<Vec<CircuitTransition>>,
+    }
+
+    impl CircuitBreakerListener for RecordingListener {
+        fn on_transition(&self, transition: &CircuitTransition) {
+            self.transitions.lock().unwrap().push(transition.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_consecutive_failures() {
+        let clock = clock();
+        let breaker = CircuitBreaker::new("binance:/api/v3/order", config(), Arc::new(clock.clone()));
+
+        // 영구 오류는 실패로 집계하지 않음
+        let _ = breaker
+            .call(|| async { Err::<(), _>(CoreError::Validation("수량 오류".to_string())) })
+            .await;
+        fail(&breaker).await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        fail(&breaker).await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // 열린 동안에는 작업을 호출하지 않음
+        let called = std::sync::atomic::AtomicBool::new(false);
+        let result = breaker
+            .call(|| async {
+                called.store(true, std::sync::atomic::Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+        assert!(!called.load(std::sync::atomic::Ordering::SeqCst));
+        match result {
+            Err(CoreError::CircuitOpen { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(std::time::Duration::from_secs(10)));
+            }
+            other => panic!("예상치 못한 결과: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe() {
+        let clock = clock();
+        let listener = Arc::new(RecordingListener::default());
+        let breaker = CircuitBreaker::new("upbit:/v1/orders", config(), Arc::new(clock.clone()))
+            .with_listener(listener.clone());
+
+        fail(&breaker).await.unwrap_err();
+        fail(&breaker).await.unwrap_err();
+
+        // 탐침 실패 시 다시 열림
+        clock.advance(Duration::seconds(10));
+        fail(&breaker).await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(succeed(&breaker).await, Err(CoreError::CircuitOpen { .. })));
+
+        // 탐침 성공 시 닫힘
+        clock.advance(Duration::seconds(10));
+        succeed(&breaker).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        let states: Vec<_> = listener
+            .transitions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|t| (t.from, t.to))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (CircuitState::Closed, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Closed),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_half_open_limits_probes() {
+        let clock = clock();
+        let breaker = CircuitBreaker::new("bithumb:/info", config(), Arc::new(clock.clone()));
+        fail(&breaker).await.unwrap_err();
+        fail(&breaker).await.unwrap_err();
+        clock.advance(Duration::seconds(10));
+
+        let (probe_started, release_probe) = (tokio::sync::Notify::new(), tokio::sync::Notify::new());
+        let probe = breaker.call(|| async {
+            probe_started.notify_one();
+            release_probe.notified().await;
+            Ok(())
+        });
+        let second = async {
+            probe_started.notified().await;
+            let result = succeed(&breaker).await;
+            release_probe.notify_one();
+            result
+        };
+
+        let (probe, second) = tokio::join!(probe, second);
+        probe.unwrap();
+        assert!(matches!(second, Err(CoreError::CircuitOpen { retry_after: None, .. })));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[derive(Default)]
+    struct CapturingBus {
+        published: Mutex<Vec<String>>,
+    }
+
+    impl EventBus for CapturingBus {
+        fn publish<E: Event + Serialize>(&self, event: E) -> Result<()> {
+            self.published.lock().unwrap().push(event.event_type().to_string());
+            Ok(())
+        }
+
+        fn subscribe<E: Event + for<'de> Deserialize<'de>, H: EventHandler<E>>(
+            &self,
+            _handler: H,
+        ) -> Result<SubscriptionHandle> {
+            Ok(SubscriptionHandle::new::<E>(Uuid::new_v4()))
+        }
+
+        fn unsubscribe(&self, _handle: &SubscriptionHandle) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_registry_with_retry_policy() {
+        let clock = clock();
+        let bus = Arc::new(CapturingBus::default());
+        let registry = CircuitBreakerRegistry::new(config(), Arc::new(clock.clone()))
+            .with_listener(Arc::new(EventBusCircuitListener::new(bus.clone())));
+        let retry = RetryPolicy::new(5).with_backoff(
+            ExponentialBackoff::new(std::time::Duration::from_millis(10), std::time::Duration::from_secs(1))
+                .with_jitter(Jitter::None),
+        );
+
+        let breaker = registry.breaker("binance:/api/v3/ticker");
+        assert!(Arc::ptr_eq(&breaker, &registry.breaker("binance:/api/v3/ticker")));
+
+        // 재시도 중 회로가 열리면 CircuitOpen도 일시적 오류로 재시도되다가 소진됨
+        let result: Result<()> = retry
+            .run(|| breaker.call(|| async { Err(CoreError::Timeout("응답 없음".to_string())) }))
+            .await;
+        assert!(matches!(result, Err(CoreError::CircuitOpen { .. })));
+
+        assert_eq!(registry.states()["binance:/api/v3/ticker"], CircuitState::Open);
+        assert_eq!(registry.breaker("upbit:/v1/ticker").state(), CircuitState::Closed);
+        assert_eq!(*bus.published.lock().unwrap(), vec!["resilience.circuit_breaker.opened"]);
+    }
+}
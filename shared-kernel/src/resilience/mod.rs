@@ -0,0 +1,21 @@
+//! 복원력(Resilience) 모듈
+//!
+//! 거래소 커넥터와 이벤트 핸들러가 외부 호출을 감쌀 수 있는 비동기 정책을 제공합니다.
+//! `CoreError::is_retryable`로 일시적 오류와 영구 오류를 구분하여,
+//! 지터가 적용된 지수 백오프 재시도와 엔드포인트별 회로 차단기를 조합해 사용합니다.
+//!
+//! ```ignore
+//! let breaker = breakers.breaker("binance:/api/v3/order");
+//! let order = retry.run(|| breaker.call(|| client.place_order(&request))).await?;
+//! ```
+
+pub mod backoff;
+pub mod retry;
+pub mod circuit_breaker;
+
+pub use backoff::{ExponentialBackoff, Jitter};
+pub use retry::RetryPolicy;
+pub use circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerListener, CircuitBreakerOpened,
+    CircuitBreakerRegistry, CircuitState, CircuitTransition, EventBusCircuitListener,
+};
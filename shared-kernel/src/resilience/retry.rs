@@ -0,0 +1,240 @@
+//! 재시도 정책
+//!
+//! 일시적 오류(`CoreError::is_retryable`)만 재시도하며, 최대 시도 횟수와
+//! 전체 기한을 넘기지 않습니다. 서버가 `Retry-After`를 알려준 경우
+//! 백오프 지연보다 짧게 기다리지 않습니다.
+
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::error::CoreError;
+use crate::types::Result;
+use super::backoff::ExponentialBackoff;
+
+/// 비동기 재시도 정책
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// 최대 시도 횟수 (첫 시도 포함)
+    max_attempts: u32,
+    /// 재시도 간 백오프
+    backoff: ExponentialBackoff,
+    /// 첫 시도부터의 전체 기한
+    deadline: Option<Duration>,
+}
+
+impl RetryPolicy {
+    /// 최대 시도 횟수로 정책 생성 (0은 1로 보정)
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: ExponentialBackoff::default(),
+            deadline: None,
+        }
+    }
+
+    /// 재시도하지 않는 정책
+    pub fn no_retry() -> Self {
+        Self::new(1)
+    }
+
+    /// 백오프 지정
+    pub fn with_backoff(mut self, backoff: ExponentialBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// 전체 기한 지정
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// 최대 시도 횟수
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// 백오프
+    pub fn backoff(&self) -> &ExponentialBackoff {
+        &self.backoff
+    }
+
+    /// 전체 기한
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// 작업을 정책에 따라 실행
+    ///
+    /// 영구 오류는 즉시, 시도 횟수나 기한을 소진하면 마지막 오류를 반환합니다.
+    /// 기한이 지나도록 끝나지 않은 시도는 `CoreError::Timeout`으로 중단됩니다.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let started = Instant::now();
+        let mut attempt = 1;
+
+        loop {
+            let result = match self.remaining(started) {
+                Some(remaining) => match tokio::time::timeout(remaining, operation()).await {
+                    Ok(result) => result,
+                    Err(_) => return Err(self.deadline_exceeded(attempt)),
+                },
+                None => operation().await,
+            };
+
+            let error = match result {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            if !error.is_retryable() || attempt >= self.max_attempts {
+                return Err(error);
+            }
+
+            let delay = self.delay_for(attempt - 1, &error);
+            if let Some(remaining) = self.remaining(started) {
+                if delay >= remaining {
+                    tracing::debug!(attempt, ?delay, "재시도 기한 내에 재시도할 수 없음: {}", error);
+                    return Err(error);
+                }
+            }
+
+            tracing::warn!(attempt, max_attempts = self.max_attempts, ?delay, "일시적 오류로 재시도: {}", error);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// 재시도 전 대기 시간 (백오프와 서버 힌트 중 큰 값)
+    fn delay_for(&self, retry: u32, error: &CoreError) -> Duration {
+        let delay = self.backoff.delay(retry);
+        match error.retry_after() {
+            Some(hint) => delay.max(hint),
+            None => delay,
+        }
+    }
+
+    /// 기한까지 남은 시간
+    fn remaining(&self, started: Instant) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_sub(started.elapsed()))
+    }
+
+    fn deadline_exceeded(&self, attempt: u32) -> CoreError {
+        CoreError::Timeout(format!(
+            "재시도 기한({:?})을 {}번째 시도 중 초과했습니다",
+            self.deadline.unwrap_or_default(),
+            attempt
+        ))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resilience::Jitter;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts).with_backoff(
+            ExponentialBackoff::new(Duration::from_millis(100), Duration::from_secs(1))
+                .with_jitter(Jitter::None),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_transient_errors() {
+        let attempts = AtomicU32::new(0);
+        let started = Instant::now();
+
+        let result = policy(5)
+            .run(|| async {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(CoreError::Timeout("느린 응답".to_string()))
+                } else {
+                    Ok(42)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        // 100ms + 200ms 백오프
+        assert_eq!(started.elapsed(), Duration::from_millis(300));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_permanent_error_is_not_retried() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<()> = policy(5)
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(CoreError::Authentication("잘못된 API 키".to_string()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Authentication(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_max_attempts_and_retry_after() {
+        let attempts = AtomicU32::new(0);
+        let started = Instant::now();
+
+        let result: Result<()> = policy(3)
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(CoreError::RateLimited {
+                    message: "HTTP 429".to_string(),
+                    retry_after: Some(Duration::from_secs(2)),
+                })
+            })
+            .await;
+
+        assert!(matches!(result, Err(CoreError::RateLimited { .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        // 백오프보다 긴 서버 힌트를 따름
+        assert_eq!(started.elapsed(), Duration::from_secs(4));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_deadline() {
+        let attempts = AtomicU32::new(0);
+
+        // 기한 안에 다음 재시도를 할 수 없으면 마지막 오류 반환
+        let result: Result<()> = policy(10)
+            .with_deadline(Duration::from_millis(250))
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(CoreError::Timeout("느린 응답".to_string()))
+            })
+            .await;
+        assert!(matches!(result, Err(CoreError::Timeout(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // 진행 중인 시도도 기한에서 중단
+        let result: Result<()> = policy(10)
+            .with_deadline(Duration::from_secs(1))
+            .run(|| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+        match result {
+            Err(CoreError::Timeout(msg)) => assert!(msg.contains("기한")),
+            other => panic!("예상치 못한 결과: {:?}", other),
+        }
+    }
+}
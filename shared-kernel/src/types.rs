@@ -4,97 +4,19 @@
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use uuid::Uuid;
 
-/// 암호화폐 거래 쌍(Symbol Pair)
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct SymbolPair {
-    /// 기본 자산 (예: 'BTC', 'ETH')
-    pub base: String,
-    /// 견적 자산 (예: 'USDT', 'USD')
-    pub quote: String,
-}
-
-impl SymbolPair {
-    /// 새로운 거래 쌍 생성
-    pub fn new(base: impl Into<String>, quote: impl Into<String>) -> Self {
-        Self {
-            base: base.into(),
-            quote: quote.into(),
-        }
-    }
+pub use crate::decimal::{Decimal, DecimalExt, RoundingMode};
 
-    /// 표준 형식으로 반환 (예: "BTC/USDT")
-    pub fn to_string(&self) -> String {
-        format!("{}/{}", self.base, self.quote)
-    }
-}
+/// 암호화폐 거래 쌍(Symbol Pair)
+pub use crate::domain::value_object::symbol::SymbolPair;
 
-impl fmt::Display for SymbolPair {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}/{}", self.base, self.quote)
-    }
-}
+/// 거래 상품 (현물/무기한/만기 선물/옵션 계약 조건)
+pub use crate::domain::value_object::instrument::{
+    ContractMargin, ExerciseStyle, Instrument, InstrumentKind, OptionType,
+};
 
-/// 타임프레임 정의 (차트 기간)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Timeframe {
-    #[serde(rename = "1m")]
-    Minute1,
-    #[serde(rename = "5m")]
-    Minute5,
-    #[serde(rename = "15m")]
-    Minute15,
-    #[serde(rename = "30m")]
-    Minute30,
-    #[serde(rename = "1h")]
-    Hour1,
-    #[serde(rename = "4h")]
-    Hour4,
-    #[serde(rename = "12h")]
-    Hour12,
-    #[serde(rename = "1d")]
-    Day1,
-    #[serde(rename = "1w")]
-    Week1,
-    #[serde(rename = "1M")]
-    Month1,
-}
-
-impl fmt::Display for Timeframe {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            Timeframe::Minute1 => "1m",
-            Timeframe::Minute5 => "5m",
-            Timeframe::Minute15 => "15m",
-            Timeframe::Minute30 => "30m",
-            Timeframe::Hour1 => "1h",
-            Timeframe::Hour4 => "4h",
-            Timeframe::Hour12 => "12h",
-            Timeframe::Day1 => "1d",
-            Timeframe::Week1 => "1w",
-            Timeframe::Month1 => "1M",
-        };
-        write!(f, "{}", s)
-    }
-}
-
-impl Timeframe {
-    /// 타임프레임을 분 단위로 변환
-    pub fn to_minutes(&self) -> u32 {
-        match self {
-            Timeframe::Minute1 => 1,
-            Timeframe::Minute5 => 5,
-            Timeframe::Minute15 => 15,
-            Timeframe::Minute30 => 30,
-            Timeframe::Hour1 => 60,
-            Timeframe::Hour4 => 240,
-            Timeframe::Hour12 => 720,
-            Timeframe::Day1 => 1440,
-            Timeframe::Week1 => 10080,
-            Timeframe::Month1 => 43200, // 30일 기준
-        }
-    }
-}
+pub use crate::domain::value_object::timeframe::{Timeframe, TimeframeUnit};
 
 /// 거래소 ID 타입
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -157,6 +79,118 @@ impl fmt::Display for OrderSide {
     }
 }
 
+/// 주문 ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OrderId(pub Uuid);
+
+impl OrderId {
+    /// 새로운 주문 ID 생성
+    pub fn new() -> Self {
+        Self(crate::id::new_id())
+    }
+
+    /// 내부 UUID 조회
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for OrderId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for OrderId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl fmt::Display for OrderId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 주문 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderType {
+    #[serde(rename = "market")]
+    Market,
+    #[serde(rename = "limit")]
+    Limit,
+    #[serde(rename = "stop_loss")]
+    StopLoss,
+    #[serde(rename = "stop_limit")]
+    StopLimit,
+    #[serde(rename = "take_profit")]
+    TakeProfit,
+    #[serde(rename = "take_profit_limit")]
+    TakeProfitLimit,
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            OrderType::Market => "market",
+            OrderType::Limit => "limit",
+            OrderType::StopLoss => "stop_loss",
+            OrderType::StopLimit => "stop_limit",
+            OrderType::TakeProfit => "take_profit",
+            OrderType::TakeProfitLimit => "take_profit_limit",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 주문 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderStatus {
+    /// 내부적으로 생성되었으나 거래소에 제출되지 않음
+    #[serde(rename = "created")]
+    Created,
+    /// 거래소에 접수됨
+    #[serde(rename = "new")]
+    New,
+    #[serde(rename = "partially_filled")]
+    PartiallyFilled,
+    #[serde(rename = "filled")]
+    Filled,
+    #[serde(rename = "canceled")]
+    Canceled,
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[serde(rename = "expired")]
+    Expired,
+}
+
+impl OrderStatus {
+    /// 더 이상 상태가 변하지 않는 최종 상태인지 확인
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Filled | OrderStatus::Canceled | OrderStatus::Rejected | OrderStatus::Expired
+        )
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            OrderStatus::Created => "created",
+            OrderStatus::New => "new",
+            OrderStatus::PartiallyFilled => "partially_filled",
+            OrderStatus::Filled => "filled",
+            OrderStatus::Canceled => "canceled",
+            OrderStatus::Rejected => "rejected",
+            OrderStatus::Expired => "expired",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// 결과 타입 단축형
 pub type Result<T> = std::result::Result<T, crate::error::CoreError>;
 
@@ -167,15 +201,34 @@ mod tests {
     #[test]
     fn test_symbol_pair() {
         let pair = SymbolPair::new("BTC", "USDT");
-        assert_eq!(pair.base, "BTC");
-        assert_eq!(pair.quote, "USDT");
+        assert_eq!(pair.base(), "BTC");
+        assert_eq!(pair.quote(), "USDT");
         assert_eq!(pair.to_string(), "BTC/USDT");
     }
 
+    #[test]
+    fn test_order_id_serialization() {
+        let order_id = OrderId::new();
+        let json = serde_json::to_string(&order_id).unwrap();
+        assert_eq!(json, format!("\"{}\"", order_id));
+
+        let parsed: OrderId = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, order_id);
+    }
+
+    #[test]
+    fn test_order_status_terminal() {
+        assert!(OrderStatus::Filled.is_terminal());
+        assert!(OrderStatus::Canceled.is_terminal());
+        assert!(!OrderStatus::New.is_terminal());
+        assert!(!OrderStatus::PartiallyFilled.is_terminal());
+    }
+
     #[test]
     fn test_timeframe_to_minutes() {
-        assert_eq!(Timeframe::Minute1.to_minutes(), 1);
-        assert_eq!(Timeframe::Hour1.to_minutes(), 60);
-        assert_eq!(Timeframe::Day1.to_minutes(), 1440);
+        assert_eq!(Timeframe::Minute1.to_minutes(), Some(1));
+        assert_eq!(Timeframe::Hour1.to_minutes(), Some(60));
+        assert_eq!(Timeframe::Day1.to_minutes(), Some(1440));
+        assert_eq!(Timeframe::Month1.to_minutes(), None);
     }
 } 
\ No newline at end of file
@@ -12,12 +12,10 @@ pub fn ms_timestamp_to_datetime(ts: i64) -> DateTime<Utc> {
     let seconds = ts / 1000;
     let nanoseconds = (ts % 1000) * 1_000_000;
     
-    let dt = match DateTime::from_timestamp(seconds, nanoseconds as u32) {
+    match DateTime::from_timestamp(seconds, nanoseconds as u32) {
         Some(dt) => dt,
         None => DateTime::from_timestamp(0, 0).unwrap()
-    };
-    
-    dt
+    }
 }
 
 /// DateTime<Utc>를 타임스탬프(밀리초)로 변환
@@ -7,9 +7,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use cryptolytica_shared_kernel::events::{Event, EventHandler};
-use cryptolytica_shared_kernel::error::CoreError;
 use cryptolytica_shared_kernel::types::{Decimal, OrderId, OrderSide, OrderStatus, OrderType, Result, SymbolPair};
-use crate::model::exchange_view::order::{OrderCache, OrderView};
+use crate::events::projections::OrderProjection;
+use crate::model::exchange_view::order::OrderCache;
 
 /// 주문 생성 이벤트
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -130,6 +130,16 @@ impl Event for OrderFilledEvent {
     }
 }
 
+impl OrderFilledEvent {
+    /// 멱등 키 (주문 ID + 누적 체결 수량)
+    ///
+    /// 재생이나 재전달로 이벤트 ID가 달라져도 같은 체결 상태는 같은 키가 되므로,
+    /// `IdempotentHandler::with_key`에 사용하여 중복 적용을 막습니다.
+    pub fn idempotency_key(&self) -> String {
+        format!("{}:{}:{}", self.exchange, self.order_id, self.total_filled.normalize())
+    }
+}
+
 /// 주문 생성 이벤트 핸들러
 pub struct OrderCreatedEventHandler {
     order_cache: Arc<OrderCache>,
@@ -145,31 +155,7 @@ impl OrderCreatedEventHandler {
 impl EventHandler<OrderCreatedEvent> for OrderCreatedEventHandler {
     /// 주문 생성 이벤트 처리
     fn handle(&self, event: &OrderCreatedEvent) -> Result<()> {
-        // 이벤트로부터 OrderView 생성
-        let order_view = OrderView::new(
-            event.order_id,
-            event.exchange.clone(),
-            event.symbol_pair.clone(),
-            event.order_type,
-            event.side,
-            event.quantity,
-            event.price,
-            event.client_order_id.clone(),
-        );
-        
-        // 주문 캐시에 추가
-        self.order_cache.upsert_order(order_view);
-        
-        tracing::debug!(
-            "주문 생성됨: {} - {} {} {} @ {:?}",
-            event.order_id,
-            event.exchange,
-            event.symbol_pair,
-            event.side,
-            event.price
-        );
-        
-        Ok(())
+        OrderProjection::fold_created(&self.order_cache, event)
     }
 }
 
@@ -188,31 +174,7 @@ impl OrderStatusUpdatedEventHandler {
 impl EventHandler<OrderStatusUpdatedEvent> for OrderStatusUpdatedEventHandler {
     /// 주문 상태 업데이트 이벤트 처리
     fn handle(&self, event: &OrderStatusUpdatedEvent) -> Result<()> {
-        // 주문 상태 업데이트
-        self.order_cache.update_order_status(
-            &event.order_id,
-            event.status,
-            event.timestamp,
-        );
-        
-        // 체결 정보가 있으면 체결 정보도 업데이트
-        if let Some(filled_quantity) = event.filled_quantity {
-            self.order_cache.update_order_fill(
-                &event.order_id,
-                filled_quantity,
-                event.average_fill_price,
-                event.timestamp,
-            );
-        }
-        
-        tracing::debug!(
-            "주문 상태 업데이트됨: {} - {} - {:?}",
-            event.order_id,
-            event.exchange,
-            event.status
-        );
-        
-        Ok(())
+        OrderProjection::fold_status(&self.order_cache, event)
     }
 }
 
@@ -231,38 +193,6 @@ impl OrderFilledEventHandler {
 impl EventHandler<OrderFilledEvent> for OrderFilledEventHandler {
     /// 주문 체결 이벤트 처리
     fn handle(&self, event: &OrderFilledEvent) -> Result<()> {
-        // 주문 체결 정보 업데이트
-        self.order_cache.update_order_fill(
-            &event.order_id,
-            event.total_filled,
-            Some(event.average_fill_price),
-            event.timestamp,
-        );
-        
-        // 완전 체결되었으면 상태를 완료로 변경
-        if event.is_complete {
-            self.order_cache.update_order_status(
-                &event.order_id,
-                OrderStatus::Filled,
-                event.timestamp,
-            );
-        } else {
-            self.order_cache.update_order_status(
-                &event.order_id,
-                OrderStatus::PartiallyFilled,
-                event.timestamp,
-            );
-        }
-        
-        tracing::debug!(
-            "주문 체결됨: {} - {} - 수량: {}, 가격: {}, 완료: {}",
-            event.order_id,
-            event.exchange,
-            event.fill_quantity,
-            event.fill_price,
-            event.is_complete
-        );
-        
-        Ok(())
+        OrderProjection::fold_filled(&self.order_cache, event)
     }
 } 
\ No newline at end of file
@@ -7,9 +7,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use cryptolytica_shared_kernel::events::{Event, EventHandler};
-use cryptolytica_shared_kernel::error::CoreError;
 use cryptolytica_shared_kernel::types::{Decimal, Result, SymbolPair, Timeframe};
-use crate::model::market_view::{CandlestickView, MarketDataCache};
+use crate::model::market_view::MarketDataCache;
+use crate::events::projections::MarketDataProjection;
 
 /// 가격 업데이트 이벤트
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +42,10 @@ impl Event for PriceUpdatedEvent {
     fn id(&self) -> &uuid::Uuid {
         &self.id
     }
+    
+    fn partition_key(&self) -> Option<String> {
+        Some(self.symbol_pair.to_string())
+    }
 }
 
 /// 캔들스틱 업데이트 이벤트
@@ -83,6 +87,10 @@ impl Event for CandlestickUpdatedEvent {
     fn id(&self) -> &uuid::Uuid {
         &self.id
     }
+    
+    fn partition_key(&self) -> Option<String> {
+        Some(format!("{}:{}", self.symbol_pair, self.timeframe))
+    }
 }
 
 /// 가격 업데이트 이벤트 핸들러
@@ -100,37 +108,7 @@ impl PriceUpdatedEventHandler {
 impl EventHandler<PriceUpdatedEvent> for PriceUpdatedEventHandler {
     /// 가격 업데이트 이벤트 처리
     fn handle(&self, event: &PriceUpdatedEvent) -> Result<()> {
-        // 시장 데이터 캐시에 가격 업데이트
-        self.market_data_cache.update_price(
-            event.symbol_pair.clone(),
-            event.price,
-            event.timestamp,
-        );
-        
-        // 24시간 데이터도 있으면 업데이트
-        if let (Some(high), Some(low), Some(volume)) = (event.high_24h, event.low_24h, event.volume_24h) {
-            if let Some(price_view) = self.market_data_cache.get_price(&event.symbol_pair) {
-                // 기존 뷰를 가져와서 메모리에서 업데이트
-                let mut updated_view = price_view;
-                updated_view.update_24h_data(high, low, volume);
-                
-                // 시장 데이터 캐시 업데이트
-                self.market_data_cache.update_price(
-                    event.symbol_pair.clone(),
-                    event.price,
-                    event.timestamp,
-                );
-            }
-        }
-        
-        tracing::debug!(
-            "가격 업데이트됨: {} - {} @ {}",
-            event.symbol_pair,
-            event.price,
-            event.timestamp
-        );
-        
-        Ok(())
+        MarketDataProjection::fold_price(&self.market_data_cache, event)
     }
 }
 
@@ -149,44 +127,6 @@ impl CandlestickUpdatedEventHandler {
 impl EventHandler<CandlestickUpdatedEvent> for CandlestickUpdatedEventHandler {
     /// 캔들스틱 업데이트 이벤트 처리
     fn handle(&self, event: &CandlestickUpdatedEvent) -> Result<()> {
-        // 캔들스틱 뷰 생성
-        let candle = CandlestickView::new(
-            event.symbol_pair.clone(),
-            event.timeframe,
-            event.candle_timestamp,
-            event.open,
-            event.high,
-            event.low,
-            event.close,
-            event.volume,
-        );
-        
-        // 시장 데이터 캐시에 캔들스틱 업데이트
-        self.market_data_cache.update_candle(candle);
-        
-        // 캔들이 완성되었고, 종가가 현재 가격을 반영하는 경우 가격도 업데이트
-        if event.is_complete {
-            self.market_data_cache.update_price(
-                event.symbol_pair.clone(),
-                event.close,
-                event.timestamp,
-            );
-            
-            tracing::debug!(
-                "캔들스틱 완성됨: {} - {} @ {}",
-                event.symbol_pair,
-                event.timeframe,
-                event.candle_timestamp
-            );
-        } else {
-            tracing::debug!(
-                "캔들스틱 업데이트됨: {} - {} @ {}",
-                event.symbol_pair,
-                event.timeframe,
-                event.candle_timestamp
-            );
-        }
-        
-        Ok(())
+        MarketDataProjection::fold_candle(&self.market_data_cache, event)
     }
 } 
\ No newline at end of file
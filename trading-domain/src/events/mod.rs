@@ -3,13 +3,14 @@
 // 도메인 간 이벤트 처리를 위한 모듈 정의
 
 pub mod market_events;
-pub mod exchange_events;
+pub mod projections;
 
 // 내부 모듈 가져오기
 pub use market_events::{
     PriceUpdatedEvent, CandlestickUpdatedEvent,
     PriceUpdatedEventHandler, CandlestickUpdatedEventHandler,
 };
+pub use projections::{MarketDataProjection, OrderProjection};
 
 // 거래소 이벤트 모듈
 pub mod exchange_events {
@@ -21,85 +22,244 @@ pub mod exchange_events {
     };
 }
 
-/// 이벤트 서비스 - 이벤트 구독 및 핸들러 관리
+/// 트레이딩 도메인이 사용하는 이벤트 타입과 현재 스키마 버전
+pub const EVENT_SCHEMAS: [(&str, u32); 5] = [
+    ("market.price.updated", 1),
+    ("market.candlestick.updated", 1),
+    ("exchange.order.created", 1),
+    ("exchange.order.status_updated", 1),
+    ("exchange.order.filled", 1),
+];
+
+/// 트레이딩 도메인 이벤트 스키마를 저장소에 등록
+///
+/// 이벤트 필드를 바꿀 때는 위 목록의 버전과 이벤트의 `schema_version()`을 함께 올리고,
+/// 이전 버전 페이로드를 변환하는 업캐스터를 여기에서 등록합니다.
+pub fn register_event_schemas(
+    registry: &mut cryptolytica_shared_kernel::events::EventSchemaRegistry,
+) -> std::result::Result<(), cryptolytica_shared_kernel::events::EventSchemaError> {
+    for (event_type, version) in EVENT_SCHEMAS {
+        registry.register(event_type, version)?;
+    }
+    Ok(())
+}
+
+/// 이벤트 서비스 - 프로젝션 등록 및 이벤트 구독 관리
 pub mod service {
     use std::sync::Arc;
-    use cryptolytica_shared_kernel::events::{EventBus, EventHandler, SubscriptionHandle};
+    use cryptolytica_shared_kernel::error::CoreError;
+    use cryptolytica_shared_kernel::events::{
+        EventBus, EventSchemaRegistry, Projection, ProjectionRunner, ProjectionStatus, SubscriptionHandle,
+    };
     use cryptolytica_shared_kernel::types::Result;
     use crate::model::market_view::MarketDataCache;
     use crate::model::exchange_view::order::OrderCache;
     use super::*;
     
     /// 트레이딩 도메인의 이벤트 서비스
-    pub struct TradingEventService {
-        event_bus: Arc<dyn EventBus>,
-        market_data_cache: Arc<MarketDataCache>,
-        order_cache: Arc<OrderCache>,
+    ///
+    /// 캐시 갱신은 프로젝션이 이벤트 로그를 따라가며 수행하고, 버스 구독은 러너를 깨우는 데만 씁니다.
+    /// 이벤트 로그는 인프라 계층에서 러너보다 먼저 버스에 구독시켜야 합니다.
+    /// `EventBus`는 제네릭 메서드를 가지므로 트레이트 객체 대신 버스 타입으로 매개변수화합니다.
+    pub struct TradingEventService<B: EventBus + 'static> {
+        event_bus: Arc<B>,
+        runner: Arc<ProjectionRunner>,
+        market_data: Arc<MarketDataProjection>,
+        orders: Arc<OrderProjection>,
+        schemas: Option<Arc<EventSchemaRegistry>>,
         subscriptions: Vec<SubscriptionHandle>,
     }
     
-    impl TradingEventService {
+    impl<B: EventBus + 'static> TradingEventService<B> {
         /// 새로운 이벤트 서비스 생성
         pub fn new(
-            event_bus: Arc<dyn EventBus>,
+            event_bus: Arc<B>,
+            runner: Arc<ProjectionRunner>,
             market_data_cache: Arc<MarketDataCache>,
             order_cache: Arc<OrderCache>,
         ) -> Self {
             Self {
                 event_bus,
-                market_data_cache,
-                order_cache,
+                runner,
+                market_data: Arc::new(MarketDataProjection::new(market_data_cache)),
+                orders: Arc::new(OrderProjection::new(order_cache)),
+                schemas: None,
                 subscriptions: Vec::new(),
             }
         }
         
-        /// 외부 도메인 이벤트 구독 설정
+        /// 구독 전에 검증할 스키마 저장소 지정
+        ///
+        /// 버스와 러너에 넘긴 저장소와 같은 것을 지정하면, 이 도메인이 소비하는 이벤트 버전을
+        /// 디코딩할 수 없는 구성에서는 구독을 시작하지 않습니다.
+        pub fn with_schemas(mut self, schemas: Arc<EventSchemaRegistry>) -> Self {
+            self.schemas = Some(schemas);
+            self
+        }
+        
+        /// 소비하는 이벤트 스키마가 모두 등록되어 있는지 확인
+        fn validate_schemas(&self) -> Result<()> {
+            let Some(schemas) = &self.schemas else {
+                return Ok(());
+            };
+            for (event_type, version) in EVENT_SCHEMAS {
+                if !schemas.is_registered(event_type, version) {
+                    return Err(CoreError::Configuration(format!(
+                        "트레이딩 도메인이 소비하는 이벤트 스키마가 등록되지 않음: {} v{}",
+                        event_type, version
+                    )));
+                }
+            }
+            Ok(())
+        }
+        
+        /// 프로젝션을 등록해 이벤트 로그를 따라잡은 뒤 외부 도메인 이벤트 구독
+        ///
+        /// 러너에 스냅숏 관리자가 있으면 캐시를 스냅숏으로 복원하고 이후 이벤트만 재생합니다.
         pub fn subscribe_to_events(&mut self) -> Result<()> {
-            // 시장 가격 업데이트 이벤트 구독
-            let price_handler = Arc::new(PriceUpdatedEventHandler::new(self.market_data_cache.clone()));
-            let handle = self.event_bus.subscribe(price_handler)?;
-            self.subscriptions.push(handle);
-            
-            // 캔들스틱 업데이트 이벤트 구독
-            let candle_handler = Arc::new(CandlestickUpdatedEventHandler::new(self.market_data_cache.clone()));
-            let handle = self.event_bus.subscribe(candle_handler)?;
-            self.subscriptions.push(handle);
-            
-            // 주문 생성 이벤트 구독
-            let order_created_handler = Arc::new(exchange_events::OrderCreatedEventHandler::new(self.order_cache.clone()));
-            let handle = self.event_bus.subscribe(order_created_handler)?;
-            self.subscriptions.push(handle);
-            
-            // 주문 상태 업데이트 이벤트 구독
-            let order_status_handler = Arc::new(exchange_events::OrderStatusUpdatedEventHandler::new(self.order_cache.clone()));
-            let handle = self.event_bus.subscribe(order_status_handler)?;
-            self.subscriptions.push(handle);
+            self.validate_schemas()?;
+            if self.runner.has_snapshotter() {
+                self.runner.register_snapshotted(self.market_data.clone())?;
+                self.runner.register_snapshotted(self.orders.clone())?;
+            } else {
+                self.runner.register(self.market_data.clone())?;
+                self.runner.register(self.orders.clone())?;
+            }
+            self.runner.catch_up()?;
             
-            // 주문 체결 이벤트 구독
-            let order_filled_handler = Arc::new(exchange_events::OrderFilledEventHandler::new(self.order_cache.clone()));
-            let handle = self.event_bus.subscribe(order_filled_handler)?;
-            self.subscriptions.push(handle);
+            // 프로젝션이 소비하는 이벤트가 들어오면 러너를 깨움
+            let wakeup = self.runner.wakeup();
+            self.subscriptions.push(self.event_bus.subscribe::<PriceUpdatedEvent, _>(wakeup.clone())?);
+            self.subscriptions.push(self.event_bus.subscribe::<CandlestickUpdatedEvent, _>(wakeup.clone())?);
+            self.subscriptions.push(self.event_bus.subscribe::<exchange_events::OrderCreatedEvent, _>(wakeup.clone())?);
+            self.subscriptions.push(self.event_bus.subscribe::<exchange_events::OrderStatusUpdatedEvent, _>(wakeup.clone())?);
+            self.subscriptions.push(self.event_bus.subscribe::<exchange_events::OrderFilledEvent, _>(wakeup)?);
             
             tracing::info!("트레이딩 도메인 이벤트 구독 설정 완료");
             Ok(())
         }
         
-        /// 모든 구독 취소
+        /// 프로젝션을 비우고 이벤트 로그 처음부터 다시 만들기
+        pub fn rebuild_projections(&self) -> Result<()> {
+            self.runner.rebuild(self.market_data.name())?;
+            self.runner.rebuild(self.orders.name())?;
+            Ok(())
+        }
+        
+        /// 프로젝션별 위치와 지연
+        pub fn projection_status(&self) -> Vec<ProjectionStatus> {
+            self.runner.status()
+        }
+        
+        /// 모든 구독 취소 후 체크포인트와 스냅숏 저장
         pub fn unsubscribe_all(&mut self) -> Result<()> {
             for handle in self.subscriptions.drain(..) {
                 self.event_bus.unsubscribe(&handle)?;
             }
+            self.runner.checkpoint()?;
             
             tracing::info!("트레이딩 도메인 이벤트 구독 취소 완료");
             Ok(())
         }
     }
     
-    impl Drop for TradingEventService {
+    impl<B: EventBus + 'static> Drop for TradingEventService<B> {
         fn drop(&mut self) {
             if let Err(e) = self.unsubscribe_all() {
                 tracing::error!("이벤트 구독 취소 중 오류 발생: {:?}", e);
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    mod market_events_test;
+    mod order_events_test;
+    
+    use std::sync::Arc;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+    use cryptolytica_shared_kernel::events::{
+        Event, EventBus, EventHandler, EventSchemaRegistry, InMemoryCheckpointStore, InMemoryProjectionSource,
+        ProjectionRunner, SubscriptionHandle,
+    };
+    use cryptolytica_shared_kernel::types::{Result, SymbolPair};
+    use crate::model::exchange_view::order::OrderCache;
+    use crate::model::market_view::MarketDataCache;
+    use super::*;
+    
+    /// 구독만 받아 두는 버스
+    #[derive(Default)]
+    struct NullBus;
+    
+    impl EventBus for NullBus {
+        fn publish<E: Event + serde::Serialize>(&self, _event: E) -> Result<()> {
+            Ok(())
+        }
+        
+        fn subscribe<E: Event + for<'de> serde::Deserialize<'de>, H: EventHandler<E>>(
+            &self,
+            _handler: H,
+        ) -> Result<SubscriptionHandle> {
+            Ok(SubscriptionHandle::new::<E>(Uuid::new_v4()))
+        }
+        
+        fn unsubscribe(&self, _handle: &SubscriptionHandle) -> Result<()> {
+            Ok(())
+        }
+    }
+    
+    fn event_service(schemas: EventSchemaRegistry) -> service::TradingEventService<NullBus> {
+        let runner = ProjectionRunner::new(
+            Arc::new(InMemoryProjectionSource::new()),
+            Arc::new(InMemoryCheckpointStore::new()),
+        );
+        service::TradingEventService::new(
+            Arc::new(NullBus),
+            Arc::new(runner),
+            Arc::new(MarketDataCache::new()),
+            Arc::new(OrderCache::new()),
+        )
+        .with_schemas(Arc::new(schemas))
+    }
+    
+    #[test]
+    fn test_register_event_schemas_round_trip() {
+        let mut registry = EventSchemaRegistry::new();
+        register_event_schemas(&mut registry).unwrap();
+        for (event_type, version) in EVENT_SCHEMAS {
+            assert_eq!(registry.current_version(event_type), Some(version));
+        }
+        
+        let event = PriceUpdatedEvent {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            symbol_pair: SymbolPair::new("BTC", "KRW"),
+            price: dec!(95000000),
+            high_24h: None,
+            low_24h: None,
+            volume_24h: None,
+        };
+        
+        let envelope = registry.encode(&event, "market-domain", None).unwrap();
+        assert_eq!(envelope.header.version, "1");
+        
+        let decoded: PriceUpdatedEvent = registry.decode(envelope).unwrap();
+        assert_eq!(decoded.id, event.id);
+        assert_eq!(decoded.price, event.price);
+    }
+    
+    #[test]
+    fn test_subscribe_requires_registered_schemas() {
+        // 소비하는 스키마가 빠져 있으면 구독을 시작하지 않음
+        let mut service = event_service(EventSchemaRegistry::new());
+        assert!(service.subscribe_to_events().is_err());
+        
+        let mut registry = EventSchemaRegistry::new();
+        register_event_schemas(&mut registry).unwrap();
+        let mut service = event_service(registry);
+        service.subscribe_to_events().unwrap();
+    }
+}
@@ -0,0 +1,434 @@
+// projections.rs
+//
+// 이 파일은 외부 도메인 이벤트를 트레이딩 도메인의 캐시에 접는 프로젝션을 정의합니다.
+// 이벤트마다 캐시를 어떻게 갱신하는지는 여기의 fold 함수가 유일한 정의이며,
+// 이벤트 핸들러도 같은 함수를 호출합니다. 구독, 순서, 재구성은 `ProjectionRunner`가 맡습니다.
+
+use serde_json::Value;
+use std::sync::Arc;
+use cryptolytica_shared_kernel::events::{Projection, ProjectionHandlers, Snapshottable};
+use cryptolytica_shared_kernel::types::{OrderStatus, Result};
+use crate::model::exchange_view::order::{OrderCache, OrderView};
+use crate::model::market_view::{CandlestickView, MarketDataCache};
+use super::exchange_events::order_events::{OrderCreatedEvent, OrderFilledEvent, OrderStatusUpdatedEvent};
+use super::market_events::{CandlestickUpdatedEvent, PriceUpdatedEvent};
+
+/// 마켓 데이터 프로젝션 (시세·캔들스틱 → `MarketDataCache`)
+pub struct MarketDataProjection {
+    cache: Arc<MarketDataCache>,
+}
+
+impl MarketDataProjection {
+    /// 캐시를 채우는 프로젝션 생성
+    pub fn new(cache: Arc<MarketDataCache>) -> Self {
+        Self { cache }
+    }
+
+    /// 대상 캐시
+    pub fn cache(&self) -> Arc<MarketDataCache> {
+        self.cache.clone()
+    }
+
+    /// 가격 업데이트 반영
+    pub fn fold_price(cache: &MarketDataCache, event: &PriceUpdatedEvent) -> Result<()> {
+        // 시장 데이터 캐시에 가격 업데이트
+        cache.update_price(event.symbol_pair.clone(), event.price, event.timestamp);
+
+        // 24시간 데이터도 있으면 업데이트
+        if let (Some(high), Some(low), Some(volume)) = (event.high_24h, event.low_24h, event.volume_24h) {
+            cache.update_24h_data(&event.symbol_pair, high, low, volume, event.timestamp);
+        }
+
+        tracing::debug!("가격 업데이트됨: {} - {} @ {}", event.symbol_pair, event.price, event.timestamp);
+        Ok(())
+    }
+
+    /// 캔들스틱 업데이트 반영 (완성된 캔들의 종가는 현재 가격으로도 반영)
+    pub fn fold_candle(cache: &MarketDataCache, event: &CandlestickUpdatedEvent) -> Result<()> {
+        cache.update_candle(CandlestickView::new(
+            event.symbol_pair.clone(),
+            event.timeframe,
+            event.candle_timestamp,
+            event.open,
+            event.high,
+            event.low,
+            event.close,
+            event.volume,
+        ));
+
+        if event.is_complete {
+            cache.update_price(event.symbol_pair.clone(), event.close, event.timestamp);
+            tracing::debug!(
+                "캔들스틱 완성됨: {} - {} @ {}",
+                event.symbol_pair,
+                event.timeframe,
+                event.candle_timestamp
+            );
+        } else {
+            tracing::debug!(
+                "캔들스틱 업데이트됨: {} - {} @ {}",
+                event.symbol_pair,
+                event.timeframe,
+                event.candle_timestamp
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Projection for MarketDataProjection {
+    fn name(&self) -> &str {
+        "trading.market_data"
+    }
+
+    fn handlers(&self) -> ProjectionHandlers<Self> {
+        ProjectionHandlers::new()
+            .on("market.price.updated", |projection: &Self, event: &PriceUpdatedEvent| {
+                Self::fold_price(&projection.cache, event)
+            })
+            .on("market.candlestick.updated", |projection: &Self, event: &CandlestickUpdatedEvent| {
+                Self::fold_candle(&projection.cache, event)
+            })
+    }
+
+    fn reset(&self) -> Result<()> {
+        self.cache.clear();
+        Ok(())
+    }
+}
+
+// 기존 캐시 스냅숏과 호환되도록 캐시에 그대로 위임
+impl Snapshottable for MarketDataProjection {
+    fn snapshot_name(&self) -> &str {
+        self.cache.snapshot_name()
+    }
+
+    fn snapshot_version(&self) -> u32 {
+        self.cache.snapshot_version()
+    }
+
+    fn snapshot_state(&self) -> Result<Value> {
+        self.cache.snapshot_state()
+    }
+
+    fn restore_state(&self, state: Value) -> Result<()> {
+        self.cache.restore_state(state)
+    }
+}
+
+/// 주문 프로젝션 (주문 생성·상태·체결 → `OrderCache`)
+pub struct OrderProjection {
+    cache: Arc<OrderCache>,
+}
+
+impl OrderProjection {
+    /// 캐시를 채우는 프로젝션 생성
+    pub fn new(cache: Arc<OrderCache>) -> Self {
+        Self { cache }
+    }
+
+    /// 대상 캐시
+    pub fn cache(&self) -> Arc<OrderCache> {
+        self.cache.clone()
+    }
+
+    /// 주문 생성 반영
+    pub fn fold_created(cache: &OrderCache, event: &OrderCreatedEvent) -> Result<()> {
+        cache.upsert_order(OrderView::new(
+            event.order_id,
+            event.exchange.clone(),
+            event.symbol_pair.clone(),
+            event.order_type,
+            event.side,
+            event.quantity,
+            event.price,
+            event.client_order_id.clone(),
+            event.timestamp,
+        ));
+
+        tracing::debug!(
+            "주문 생성됨: {} - {} {} {} @ {:?}",
+            event.order_id,
+            event.exchange,
+            event.symbol_pair,
+            event.side,
+            event.price
+        );
+        Ok(())
+    }
+
+    /// 주문 상태 업데이트 반영 (체결 정보가 있으면 함께 반영)
+    pub fn fold_status(cache: &OrderCache, event: &OrderStatusUpdatedEvent) -> Result<()> {
+        cache.update_order_status(&event.order_id, event.status, event.timestamp);
+        if let Some(filled_quantity) = event.filled_quantity {
+            cache.update_order_fill(&event.order_id, filled_quantity, event.average_fill_price, event.timestamp);
+        }
+
+        tracing::debug!("주문 상태 업데이트됨: {} - {} - {:?}", event.order_id, event.exchange, event.status);
+        Ok(())
+    }
+
+    /// 주문 체결 반영 (완전 체결이면 완료, 아니면 부분 체결 상태)
+    pub fn fold_filled(cache: &OrderCache, event: &OrderFilledEvent) -> Result<()> {
+        cache.update_order_fill(&event.order_id, event.total_filled, Some(event.average_fill_price), event.timestamp);
+        let status = if event.is_complete {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        cache.update_order_status(&event.order_id, status, event.timestamp);
+
+        tracing::debug!(
+            "주문 체결됨: {} - {} - 수량: {}, 가격: {}, 완료: {}",
+            event.order_id,
+            event.exchange,
+            event.fill_quantity,
+            event.fill_price,
+            event.is_complete
+        );
+        Ok(())
+    }
+}
+
+impl Projection for OrderProjection {
+    fn name(&self) -> &str {
+        "trading.orders"
+    }
+
+    fn handlers(&self) -> ProjectionHandlers<Self> {
+        ProjectionHandlers::new()
+            .on("exchange.order.created", |projection: &Self, event: &OrderCreatedEvent| {
+                Self::fold_created(&projection.cache, event)
+            })
+            .on("exchange.order.status_updated", |projection: &Self, event: &OrderStatusUpdatedEvent| {
+                Self::fold_status(&projection.cache, event)
+            })
+            .on("exchange.order.filled", |projection: &Self, event: &OrderFilledEvent| {
+                Self::fold_filled(&projection.cache, event)
+            })
+    }
+
+    fn reset(&self) -> Result<()> {
+        self.cache.clear();
+        Ok(())
+    }
+}
+
+impl Snapshottable for OrderProjection {
+    fn snapshot_name(&self) -> &str {
+        self.cache.snapshot_name()
+    }
+
+    fn snapshot_version(&self) -> u32 {
+        self.cache.snapshot_version()
+    }
+
+    fn snapshot_state(&self) -> Result<Value> {
+        self.cache.snapshot_state()
+    }
+
+    fn restore_state(&self, state: Value) -> Result<()> {
+        self.cache.restore_state(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+    use cryptolytica_shared_kernel::events::{
+        envelope_of, CheckpointStore, InMemoryCheckpointStore, InMemoryProjectionSource, ProjectionRunner,
+    };
+    use cryptolytica_shared_kernel::types::{OrderId, OrderSide, OrderType, SymbolPair};
+
+    fn price_event(symbol_pair: &SymbolPair, price: rust_decimal::Decimal) -> PriceUpdatedEvent {
+        PriceUpdatedEvent {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            symbol_pair: symbol_pair.clone(),
+            price,
+            high_24h: None,
+            low_24h: None,
+            volume_24h: None,
+        }
+    }
+
+    fn created_event(order_id: &OrderId, symbol_pair: &SymbolPair) -> OrderCreatedEvent {
+        OrderCreatedEvent {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            order_id: *order_id,
+            exchange: "Binance".to_string(),
+            symbol_pair: symbol_pair.clone(),
+            order_type: OrderType::Limit,
+            side: OrderSide::Buy,
+            quantity: dec!(2),
+            price: Some(dec!(50000)),
+            client_order_id: None,
+        }
+    }
+
+    fn filled_event(order_id: &OrderId, symbol_pair: &SymbolPair, total_filled: rust_decimal::Decimal) -> OrderFilledEvent {
+        OrderFilledEvent {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            order_id: *order_id,
+            exchange: "Binance".to_string(),
+            symbol_pair: symbol_pair.clone(),
+            fill_quantity: total_filled,
+            fill_price: dec!(50000),
+            total_filled,
+            average_fill_price: dec!(50000),
+            fee: None,
+            fee_asset: None,
+            is_complete: total_filled == dec!(2),
+        }
+    }
+
+    #[test]
+    fn test_handlers_apply_own_events_only() {
+        let symbol_pair = SymbolPair::new("BTC", "USDT");
+        let order_id = OrderId::new();
+        let projection = MarketDataProjection::new(Arc::new(MarketDataCache::new()));
+        let handlers = projection.handlers();
+
+        let price = envelope_of(&price_event(&symbol_pair, dec!(50000)), "market-domain").unwrap();
+        let order = envelope_of(&created_event(&order_id, &symbol_pair), "exchange-domain").unwrap();
+
+        assert!(handlers.apply(&projection, &price, None).unwrap());
+        assert!(!handlers.apply(&projection, &order, None).unwrap());
+        assert_eq!(projection.cache().get_price(&symbol_pair).unwrap().price, dec!(50000));
+        assert_eq!(handlers.event_types(), vec!["market.candlestick.updated", "market.price.updated"]);
+    }
+
+    #[test]
+    fn test_order_projection_folds_partial_then_complete_fill() {
+        let symbol_pair = SymbolPair::new("ETH", "USDT");
+        let order_id = OrderId::new();
+        let projection = OrderProjection::new(Arc::new(OrderCache::new()));
+        let handlers = projection.handlers();
+
+        for envelope in [
+            envelope_of(&created_event(&order_id, &symbol_pair), "exchange-domain").unwrap(),
+            envelope_of(&filled_event(&order_id, &symbol_pair, dec!(1)), "exchange-domain").unwrap(),
+        ] {
+            assert!(handlers.apply(&projection, &envelope, None).unwrap());
+        }
+        let order = projection.cache().get_order(&order_id).unwrap();
+        assert_eq!((order.status, order.filled_quantity), (OrderStatus::PartiallyFilled, dec!(1)));
+
+        let complete = envelope_of(&filled_event(&order_id, &symbol_pair, dec!(2)), "exchange-domain").unwrap();
+        handlers.apply(&projection, &complete, None).unwrap();
+        let order = projection.cache().get_order(&order_id).unwrap();
+        assert_eq!((order.status, order.filled_quantity), (OrderStatus::Filled, dec!(2)));
+    }
+
+    #[test]
+    fn test_projections_catch_up_and_rebuild_from_log() {
+        // 설정: 이벤트 로그에 시세와 주문 이벤트를 섞어 기록
+        let log = Arc::new(InMemoryProjectionSource::new());
+        let symbol_pair = SymbolPair::new("BTC", "USDT");
+        let order_id = OrderId::new();
+        log.append(envelope_of(&price_event(&symbol_pair, dec!(50000)), "market-domain").unwrap());
+        log.append(envelope_of(&created_event(&order_id, &symbol_pair), "exchange-domain").unwrap());
+        log.append(envelope_of(&filled_event(&order_id, &symbol_pair, dec!(2)), "exchange-domain").unwrap());
+
+        let market_data_cache = Arc::new(MarketDataCache::new());
+        let order_cache = Arc::new(OrderCache::new());
+        let runner = ProjectionRunner::new(log.clone(), Arc::new(InMemoryCheckpointStore::new()));
+        runner.register(Arc::new(MarketDataProjection::new(market_data_cache.clone()))).unwrap();
+        runner.register(Arc::new(OrderProjection::new(order_cache.clone()))).unwrap();
+
+        // 실행
+        runner.catch_up().unwrap();
+
+        // 검증: 각 프로젝션은 자기 이벤트만 적용하고 나머지는 건너뜀
+        assert_eq!(market_data_cache.get_price(&symbol_pair).unwrap().price, dec!(50000));
+        assert_eq!(order_cache.get_order(&order_id).unwrap().status, OrderStatus::Filled);
+        for status in runner.status() {
+            assert_eq!(status.lag, 0);
+            assert_eq!(status.applied + status.skipped, 3);
+        }
+
+        // 캐시가 오염되어도 재구성하면 로그 기준 상태로 돌아옴
+        order_cache.update_order_status(&order_id, OrderStatus::Canceled, Utc::now());
+        assert_eq!(runner.rebuild("trading.orders").unwrap(), 2);
+        assert_eq!(order_cache.get_order(&order_id).unwrap().status, OrderStatus::Filled);
+    }
+
+    #[test]
+    fn test_repeated_catch_up_applies_each_record_once() {
+        let log = Arc::new(InMemoryProjectionSource::new());
+        let checkpoints = Arc::new(InMemoryCheckpointStore::new());
+        let symbol_pair = SymbolPair::new("BTC", "USDT");
+        log.append(envelope_of(&price_event(&symbol_pair, dec!(50000)), "market-domain").unwrap());
+
+        let cache = Arc::new(MarketDataCache::new());
+        let runner = ProjectionRunner::new(log.clone(), checkpoints.clone());
+        runner.register(Arc::new(MarketDataProjection::new(cache.clone()))).unwrap();
+        runner.catch_up().unwrap();
+
+        // 이미 적용한 레코드는 다시 따라잡아도 적용하지 않음
+        assert_eq!(runner.catch_up().unwrap(), 0);
+        assert_eq!(runner.status()[0].applied, 1);
+
+        // 새 레코드만 적용하고 체크포인트는 로그 끝을 가리킴
+        log.append(envelope_of(&price_event(&symbol_pair, dec!(51000)), "market-domain").unwrap());
+        runner.poll().unwrap();
+        runner.checkpoint().unwrap();
+        assert_eq!(runner.status()[0].applied, 2);
+        assert_eq!(cache.get_price(&symbol_pair).unwrap().price, dec!(51000));
+        assert_eq!(checkpoints.load("trading.market_data").unwrap().unwrap().offset, 2);
+    }
+
+    #[test]
+    fn test_replay_clock_follows_projected_events() {
+        use chrono::{Duration, TimeZone};
+        use cryptolytica_shared_kernel::clock::ReplayClock;
+        use crate::model::service::ModelService;
+
+        let log = Arc::new(InMemoryProjectionSource::new());
+        let symbol_pair = SymbolPair::new("BTC", "USDT");
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for (minute, price) in [(1, dec!(50000)), (2, dec!(50100))] {
+            let mut event = price_event(&symbol_pair, price);
+            event.timestamp = start + Duration::minutes(minute);
+            log.append(envelope_of(&event, "market-domain").unwrap());
+        }
+
+        // 모델 서비스와 러너가 같은 재생 시계를 공유
+        let clock = ReplayClock::new(start);
+        let model_service = ModelService::with_clock(Arc::new(clock.clone()));
+        let runner = ProjectionRunner::new(log.clone(), Arc::new(InMemoryCheckpointStore::new()))
+            .with_replay_clock(clock);
+        runner.register(Arc::new(MarketDataProjection::new(model_service.market_data_cache()))).unwrap();
+        runner.catch_up().unwrap();
+
+        let now = model_service.clock().now();
+        assert_eq!(now, start + Duration::minutes(2));
+        assert_eq!(model_service.market_data_cache().get_price(&symbol_pair).unwrap().last_updated, now);
+    }
+
+    #[test]
+    fn test_replaying_same_event_is_idempotent() {
+        let symbol_pair = SymbolPair::new("BTC", "USDT");
+        let order_id = OrderId::new();
+        let projection = OrderProjection::new(Arc::new(OrderCache::new()));
+        let handlers = projection.handlers();
+        let created = envelope_of(&created_event(&order_id, &symbol_pair), "exchange-domain").unwrap();
+        let filled = envelope_of(&filled_event(&order_id, &symbol_pair, dec!(2)), "exchange-domain").unwrap();
+
+        handlers.apply(&projection, &created, None).unwrap();
+        handlers.apply(&projection, &filled, None).unwrap();
+        let once = projection.cache().get_order(&order_id).unwrap();
+
+        // 체결 이벤트는 누적 체결량을 담으므로 같은 이벤트를 다시 접어도 상태가 같음
+        handlers.apply(&projection, &filled, None).unwrap();
+        let twice = projection.cache().get_order(&order_id).unwrap();
+        assert_eq!(twice.filled_quantity, once.filled_quantity);
+        assert_eq!(twice.average_fill_price, once.average_fill_price);
+        assert_eq!(twice.status, once.status);
+    }
+}
@@ -1,7 +1,6 @@
 use std::sync::Arc;
 use chrono::Utc;
 use uuid::Uuid;
-use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
 use cryptolytica_shared_kernel::types::{SymbolPair, Timeframe};
@@ -44,9 +43,9 @@ fn test_price_updated_event_handler() {
     
     let price_view = price_view.unwrap();
     assert_eq!(price_view.price, price);
-    assert_eq!(price_view.high_24h.unwrap(), high_24h);
-    assert_eq!(price_view.low_24h.unwrap(), low_24h);
-    assert_eq!(price_view.volume_24h.unwrap(), volume_24h);
+    assert_eq!(price_view.high_24h, high_24h);
+    assert_eq!(price_view.low_24h, low_24h);
+    assert_eq!(price_view.volume_24h, volume_24h);
 }
 
 #[test]
@@ -80,7 +79,7 @@ fn test_candlestick_updated_event_handler() {
     assert!(result.is_ok());
     
     // 캔들스틱 캐시 검증
-    let candles = market_data_cache.get_candles(&symbol_pair, timeframe);
+    let candles = market_data_cache.get_candles(&symbol_pair, timeframe, 10);
     assert!(!candles.is_empty());
     assert_eq!(candles[0].open, dec!(2000));
     assert_eq!(candles[0].high, dec!(2100));
@@ -133,4 +132,4 @@ fn test_incomplete_candlestick_event() {
     let price_view = market_data_cache.get_price(&symbol_pair);
     assert!(price_view.is_some());
     assert_eq!(price_view.unwrap().price, init_price); // 초기 가격 유지
-} 
\ No newline at end of file
+}
@@ -30,7 +30,7 @@ fn test_order_created_event_handler() {
     let event = OrderCreatedEvent {
         id: Uuid::new_v4(),
         timestamp: Utc::now(),
-        order_id: order_id.clone(),
+        order_id,
         exchange: exchange.clone(),
         symbol_pair: symbol_pair.clone(),
         order_type,
@@ -57,7 +57,7 @@ fn test_order_created_event_handler() {
     assert_eq!(order.quantity, quantity);
     assert_eq!(order.price, price);
     assert_eq!(order.client_order_id, client_order_id);
-    assert_eq!(order.status, OrderStatus::New); // 기본 상태
+    assert_eq!(order.status, OrderStatus::Created); // 기본 상태
 }
 
 #[test]
@@ -75,7 +75,7 @@ fn test_order_status_updated_event_handler() {
     let create_event = OrderCreatedEvent {
         id: Uuid::new_v4(),
         timestamp: Utc::now(),
-        order_id: order_id.clone(),
+        order_id,
         exchange: exchange.clone(),
         symbol_pair: symbol_pair.clone(),
         order_type: OrderType::Limit,
@@ -95,7 +95,7 @@ fn test_order_status_updated_event_handler() {
     let status_event = OrderStatusUpdatedEvent {
         id: Uuid::new_v4(),
         timestamp: Utc::now(),
-        order_id: order_id.clone(),
+        order_id,
         exchange: exchange.clone(),
         symbol_pair: symbol_pair.clone(),
         status: new_status,
@@ -111,7 +111,7 @@ fn test_order_status_updated_event_handler() {
     assert!(result.is_ok());
     let order = order_cache.get_order(&order_id).unwrap();
     assert_eq!(order.status, new_status);
-    assert_eq!(order.filled_quantity, Some(filled_quantity));
+    assert_eq!(order.filled_quantity, filled_quantity);
     assert_eq!(order.average_fill_price, Some(avg_price));
 }
 
@@ -131,7 +131,7 @@ fn test_order_filled_event_handler() {
     let create_event = OrderCreatedEvent {
         id: Uuid::new_v4(),
         timestamp: Utc::now(),
-        order_id: order_id.clone(),
+        order_id,
         exchange: exchange.clone(),
         symbol_pair: symbol_pair.clone(),
         order_type: OrderType::Market,
@@ -153,7 +153,7 @@ fn test_order_filled_event_handler() {
     let fill_event = OrderFilledEvent {
         id: Uuid::new_v4(),
         timestamp: Utc::now(),
-        order_id: order_id.clone(),
+        order_id,
         exchange: exchange.clone(),
         symbol_pair: symbol_pair.clone(),
         fill_quantity,
@@ -171,7 +171,7 @@ fn test_order_filled_event_handler() {
     // 검증
     assert!(result.is_ok());
     let order = order_cache.get_order(&order_id).unwrap();
-    assert_eq!(order.filled_quantity, Some(total_filled));
+    assert_eq!(order.filled_quantity, total_filled);
     assert_eq!(order.average_fill_price, Some(avg_fill_price));
     assert_eq!(order.status, OrderStatus::PartiallyFilled); // 부분 체결로 상태 변경됨
     
@@ -185,7 +185,7 @@ fn test_order_filled_event_handler() {
     let fill_event2 = OrderFilledEvent {
         id: Uuid::new_v4(),
         timestamp: Utc::now(),
-        order_id: order_id.clone(),
+        order_id,
         exchange: exchange.clone(),
         symbol_pair: symbol_pair.clone(),
         fill_quantity: fill_quantity2,
@@ -203,7 +203,7 @@ fn test_order_filled_event_handler() {
     // 검증
     assert!(result2.is_ok());
     let order2 = order_cache.get_order(&order_id).unwrap();
-    assert_eq!(order2.filled_quantity, Some(total_filled2));
+    assert_eq!(order2.filled_quantity, total_filled2);
     assert_eq!(order2.average_fill_price, Some(avg_fill_price2));
     assert_eq!(order2.status, OrderStatus::Filled); // 완전 체결로 상태 변경됨
 } 
\ No newline at end of file
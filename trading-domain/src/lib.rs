@@ -10,44 +10,73 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 // 모듈 정의
 pub mod model;
 pub mod events;
-pub mod strategy;
-pub mod error;
 
 // 공개 타입
 pub use events::service::TradingEventService;
 pub use model::service::ModelService;
 
+use std::sync::Arc;
+use cryptolytica_shared_kernel::events::{EventBus, EventSchemaRegistry};
+
 /// 트레이딩 도메인 서비스
-/// 트레이딩 도메인의 주요 기능을 제공하는 진입점
-pub struct TradingDomainService {
+/// 트레이딩 도메인의 주요 기능을 제공하는 진입점 (`B`는 구독에 사용할 이벤트 버스 타입)
+pub struct TradingDomainService<B: EventBus + 'static> {
     model_service: model::service::ModelService,
-    event_service: Option<events::service::TradingEventService>,
+    event_service: Option<events::service::TradingEventService<B>>,
+    schemas: Option<Arc<EventSchemaRegistry>>,
 }
 
-impl TradingDomainService {
+impl<B: EventBus + 'static> TradingDomainService<B> {
     /// 새로운 트레이딩 도메인 서비스 생성
     pub fn new() -> Self {
         Self {
             model_service: model::service::ModelService::new(),
             event_service: None,
+            schemas: None,
+        }
+    }
+    
+    /// 주입된 시계로 트레이딩 도메인 서비스 생성
+    ///
+    /// 캐시의 시각은 이벤트 타임스탬프를 따르며, 시계는 `ModelService::clock`으로 도메인 코드에 제공됩니다.
+    /// 이벤트 재생 시에는 같은 `ReplayClock`을 `ProjectionRunner::with_replay_clock`에도 넘겨야
+    /// 재생 중인 이벤트 시각으로 시계가 진행합니다.
+    pub fn with_clock(clock: cryptolytica_shared_kernel::clock::SharedClock) -> Self {
+        Self {
+            model_service: model::service::ModelService::with_clock(clock),
+            event_service: None,
+            schemas: None,
         }
     }
     
+    /// 구독 전에 검증할 이벤트 스키마 저장소 지정 (`with_event_bus`보다 먼저 호출)
+    pub fn with_schemas(mut self, schemas: Arc<EventSchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+    
     /// 이벤트 버스 설정 및 구독 초기화
+    ///
+    /// 캐시는 `runner`가 읽는 이벤트 로그로부터 프로젝션으로 채워집니다.
     pub fn with_event_bus(
         mut self,
-        event_bus: std::sync::Arc<dyn cryptolytica_shared_kernel::events::EventBus>,
+        event_bus: Arc<B>,
+        runner: Arc<cryptolytica_shared_kernel::events::ProjectionRunner>,
     ) -> cryptolytica_shared_kernel::types::Result<Self> {
         let market_data_cache = self.model_service.market_data_cache();
         let order_cache = self.model_service.order_cache();
         
         let mut event_service = events::service::TradingEventService::new(
             event_bus,
+            runner,
             market_data_cache,
             order_cache,
         );
+        if let Some(schemas) = &self.schemas {
+            event_service = event_service.with_schemas(schemas.clone());
+        }
         
-        // 이벤트 구독 설정
+        // 프로젝션 등록 및 이벤트 구독 설정
         event_service.subscribe_to_events()?;
         
         self.event_service = Some(event_service);
@@ -65,7 +94,7 @@ impl TradingDomainService {
     }
 }
 
-impl Default for TradingDomainService {
+impl<B: EventBus + 'static> Default for TradingDomainService<B> {
     fn default() -> Self {
         Self::new()
     }
@@ -86,14 +115,6 @@ mod tests {
     }
 }
 
-// 이벤트 핸들러 테스트 모듈
-#[cfg(test)]
-pub mod tests {
-    // 이벤트 핸들러 테스트
-    pub mod market_events_test;
-    pub mod order_events_test;
-}
-
 // 아직 구현되지 않은 모듈 스텁
 pub mod strategy {
     //! 트레이딩 전략 관련 기능
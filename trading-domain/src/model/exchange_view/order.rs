@@ -7,7 +7,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use cryptolytica_shared_kernel::types::{Decimal, OrderId, OrderSide, OrderStatus, OrderType, SymbolPair};
+use serde_json::Value;
+use cryptolytica_shared_kernel::events::Snapshottable;
+use cryptolytica_shared_kernel::types::{Decimal, OrderId, OrderSide, OrderStatus, OrderType, Result, SymbolPair};
 
 /// 거래소 주문 뷰 - 주문 정보를 나타내는 간소화된 뷰 모델
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +46,9 @@ pub struct OrderView {
 
 impl OrderView {
     /// 새로운 주문 뷰 생성
+    ///
+    /// `created_at`은 주문 생성 시각(이벤트 타임스탬프)입니다.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         order_id: OrderId,
         exchange: String,
@@ -53,8 +58,8 @@ impl OrderView {
         quantity: Decimal,
         price: Option<Decimal>,
         client_order_id: Option<String>,
+        created_at: DateTime<Utc>,
     ) -> Self {
-        let now = Utc::now();
         Self {
             order_id,
             exchange,
@@ -66,8 +71,8 @@ impl OrderView {
             filled_quantity: Decimal::ZERO,
             average_fill_price: None,
             status: OrderStatus::Created,
-            created_at: now,
-            updated_at: now,
+            created_at,
+            updated_at: created_at,
             expires_at: None,
             client_order_id,
         }
@@ -225,10 +230,53 @@ impl OrderCache {
             .cloned()
             .collect()
     }
+    
+    /// 모든 주문 삭제 (프로젝션 재구성용)
+    pub fn clear(&self) {
+        self.orders.write().unwrap().clear();
+        self.client_id_map.write().unwrap().clear();
+    }
 }
 
 impl Default for OrderCache {
     fn default() -> Self {
         Self::new()
     }
-} 
\ No newline at end of file
+}
+
+/// 주문 캐시 스냅숏 상태
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrderCacheSnapshot {
+    /// 주문 정보 (클라이언트 주문 ID 매핑은 복원 시 다시 만듦)
+    pub orders: Vec<OrderView>,
+}
+
+impl Snapshottable for OrderCache {
+    fn snapshot_name(&self) -> &str {
+        "trading.order_cache"
+    }
+
+    fn snapshot_state(&self) -> Result<Value> {
+        let orders = self.orders.read().unwrap().values().cloned().collect();
+        Ok(serde_json::to_value(OrderCacheSnapshot { orders })?)
+    }
+
+    fn restore_state(&self, state: Value) -> Result<()> {
+        let snapshot: OrderCacheSnapshot = serde_json::from_value(state)?;
+
+        let client_id_map = snapshot
+            .orders
+            .iter()
+            .filter_map(|order| Some((order.client_order_id.clone()?, order.order_id)))
+            .collect();
+        let orders = snapshot
+            .orders
+            .into_iter()
+            .map(|order| (order.order_id, order))
+            .collect();
+
+        *self.orders.write().unwrap() = orders;
+        *self.client_id_map.write().unwrap() = client_id_map;
+        Ok(())
+    }
+}
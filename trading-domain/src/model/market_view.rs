@@ -7,7 +7,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use cryptolytica_shared_kernel::types::{Decimal, SymbolPair, Timeframe};
+use serde_json::Value;
+use cryptolytica_shared_kernel::events::Snapshottable;
+use cryptolytica_shared_kernel::types::{Decimal, Result, SymbolPair, Timeframe};
+
+/// (심볼페어, 타임프레임)마다 보관하는 최대 캔들 수
+pub const MAX_CANDLES: usize = 1000;
 
 /// 마켓 시세 뷰 - 시장 가격 정보를 나타내는 간소화된 뷰 모델
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,12 +33,15 @@ pub struct MarketPriceView {
 
 impl MarketPriceView {
     /// 새로운 마켓 시세 뷰 생성
+    ///
+    /// `timestamp`는 시세를 관측한 시각(이벤트 타임스탬프)입니다.
     pub fn new(
         symbol_pair: SymbolPair,
         price: Decimal,
         high_24h: Decimal,
         low_24h: Decimal,
         volume_24h: Decimal,
+        timestamp: DateTime<Utc>,
     ) -> Self {
         Self {
             symbol_pair,
@@ -41,7 +49,7 @@ impl MarketPriceView {
             high_24h,
             low_24h,
             volume_24h,
-            last_updated: Utc::now(),
+            last_updated: timestamp,
         }
     }
 
@@ -60,11 +68,11 @@ impl MarketPriceView {
     }
     
     /// 24시간 정보 업데이트
-    pub fn update_24h_data(&mut self, high: Decimal, low: Decimal, volume: Decimal) {
+    pub fn update_24h_data(&mut self, high: Decimal, low: Decimal, volume: Decimal, timestamp: DateTime<Utc>) {
         self.high_24h = high;
         self.low_24h = low;
         self.volume_24h = volume;
-        self.last_updated = Utc::now();
+        self.last_updated = timestamp;
     }
 }
 
@@ -151,11 +159,26 @@ impl MarketDataCache {
                 price,  // 초기 최고가는 현재가로 설정
                 price,  // 초기 최저가는 현재가로 설정
                 Decimal::ZERO,
+                timestamp,
             );
             prices.insert(symbol_pair, price_view);
         }
     }
     
+    /// 24시간 정보 업데이트 (시세가 없는 심볼은 무시)
+    pub fn update_24h_data(
+        &self,
+        symbol_pair: &SymbolPair,
+        high: Decimal,
+        low: Decimal,
+        volume: Decimal,
+        timestamp: DateTime<Utc>,
+    ) {
+        if let Some(price_view) = self.prices.write().unwrap().get_mut(symbol_pair) {
+            price_view.update_24h_data(high, low, volume, timestamp);
+        }
+    }
+    
     /// 캔들스틱 업데이트
     pub fn update_candle(&self, candle: CandlestickView) {
         let mut candles = self.candles.write().unwrap();
@@ -178,7 +201,6 @@ impl MarketDataCache {
         candle_vec.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
         
         // 캔들 개수 제한 (메모리 관리)
-        const MAX_CANDLES: usize = 1000;
         if candle_vec.len() > MAX_CANDLES {
             *candle_vec = candle_vec.drain(candle_vec.len() - MAX_CANDLES..).collect();
         }
@@ -211,10 +233,107 @@ impl MarketDataCache {
             Vec::new()
         }
     }
+    
+    /// 모든 시세와 캔들스틱 삭제 (프로젝션 재구성용)
+    pub fn clear(&self) {
+        self.prices.write().unwrap().clear();
+        self.candles.write().unwrap().clear();
+    }
 }
 
 impl Default for MarketDataCache {
     fn default() -> Self {
         Self::new()
     }
-} 
\ No newline at end of file
+}
+
+/// 마켓 데이터 캐시 스냅숏 상태
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarketDataSnapshot {
+    /// 시세 정보
+    pub prices: Vec<MarketPriceView>,
+    /// 캔들스틱 정보 (모든 심볼·타임프레임)
+    pub candles: Vec<CandlestickView>,
+}
+
+impl Snapshottable for MarketDataCache {
+    fn snapshot_name(&self) -> &str {
+        "trading.market_data_cache"
+    }
+
+    fn snapshot_state(&self) -> Result<Value> {
+        let prices = self.prices.read().unwrap().values().cloned().collect();
+        let candles = self.candles.read().unwrap().values().flatten().cloned().collect();
+        Ok(serde_json::to_value(MarketDataSnapshot { prices, candles })?)
+    }
+
+    fn restore_state(&self, state: Value) -> Result<()> {
+        let snapshot: MarketDataSnapshot = serde_json::from_value(state)?;
+
+        let prices = snapshot
+            .prices
+            .into_iter()
+            .map(|price| (price.symbol_pair.clone(), price))
+            .collect();
+        let mut candles: HashMap<(SymbolPair, Timeframe), Vec<CandlestickView>> = HashMap::new();
+        for candle in snapshot.candles {
+            candles
+                .entry((candle.symbol_pair.clone(), candle.timeframe))
+                .or_default()
+                .push(candle);
+        }
+        for candle_vec in candles.values_mut() {
+            candle_vec.sort_by_key(|c| c.timestamp);
+            candle_vec.dedup_by(|a, b| a.timestamp == b.timestamp);
+            if candle_vec.len() > MAX_CANDLES {
+                candle_vec.drain(..candle_vec.len() - MAX_CANDLES);
+            }
+        }
+
+        *self.prices.write().unwrap() = prices;
+        *self.candles.write().unwrap() = candles;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let btc = SymbolPair::new("BTC", "USDT");
+        let cache = MarketDataCache::new();
+        cache.update_price(btc.clone(), Decimal::new(50_000, 0), start);
+        cache.update_price(btc.clone(), Decimal::new(49_000, 0), start + Duration::seconds(1));
+        for minute in 0..3 {
+            let price = Decimal::new(50_000 + minute, 0);
+            cache.update_candle(CandlestickView::new(
+                btc.clone(),
+                Timeframe::Minute1,
+                start + Duration::minutes(minute),
+                price,
+                price,
+                price,
+                price,
+                Decimal::ONE,
+            ));
+        }
+
+        let restored = MarketDataCache::new();
+        restored.restore_state(cache.snapshot_state().unwrap()).unwrap();
+
+        let price = restored.get_price(&btc).unwrap();
+        assert_eq!(price.price, Decimal::new(49_000, 0));
+        assert_eq!(price.high_24h, Decimal::new(50_000, 0));
+        let candles = restored.get_candles(&btc, Timeframe::Minute1, 10);
+        assert_eq!(candles.len(), 3);
+        assert!(candles.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp));
+
+        // 형식이 맞지 않는 상태는 거부하고 기존 상태를 유지
+        assert!(restored.restore_state(serde_json::json!({ "prices": 1 })).is_err());
+        assert!(restored.get_price(&btc).is_some());
+    }
+}
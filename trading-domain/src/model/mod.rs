@@ -5,9 +5,6 @@
 // 마켓 데이터 뷰 모델
 pub mod market_view;
 
-// 거래소 뷰 모델
-pub mod exchange_view;
-
 // 거래소 관련 뷰
 pub mod exchange_view {
     pub mod order;
@@ -18,7 +15,8 @@ pub mod exchange_view {
 // 모델 서비스 - 캐시된 뷰 모델 관리
 pub mod service {
     use std::sync::Arc;
-    use cryptolytica_shared_kernel::types::Result;
+    use cryptolytica_shared_kernel::clock::{system_clock, SharedClock};
+    use cryptolytica_shared_kernel::events::Snapshottable;
     use super::market_view::MarketDataCache;
     use super::exchange_view::order::OrderCache;
     
@@ -27,17 +25,29 @@ pub mod service {
     pub struct ModelService {
         market_data_cache: Arc<MarketDataCache>,
         order_cache: Arc<OrderCache>,
+        clock: SharedClock,
     }
     
     impl ModelService {
-        /// 새로운 모델 서비스 생성
+        /// 새로운 모델 서비스 생성 (시스템 시계 사용)
         pub fn new() -> Self {
+            Self::with_clock(system_clock())
+        }
+        
+        /// 주입된 시계로 모델 서비스 생성 (백테스트/재생용)
+        pub fn with_clock(clock: SharedClock) -> Self {
             Self {
                 market_data_cache: Arc::new(MarketDataCache::new()),
                 order_cache: Arc::new(OrderCache::new()),
+                clock,
             }
         }
         
+        /// 도메인 시계 접근
+        pub fn clock(&self) -> SharedClock {
+            self.clock.clone()
+        }
+        
         /// 마켓 데이터 캐시 접근
         pub fn market_data_cache(&self) -> Arc<MarketDataCache> {
             self.market_data_cache.clone()
@@ -47,6 +57,13 @@ pub mod service {
         pub fn order_cache(&self) -> Arc<OrderCache> {
             self.order_cache.clone()
         }
+        
+        /// 스냅숏으로 저장·복원할 읽기 모델 목록
+        ///
+        /// 시작할 때 각 모델을 `Snapshotter::recover`로 복원하고, 이후 `maybe_snapshot`을 주기적으로 호출합니다.
+        pub fn snapshot_targets(&self) -> Vec<Arc<dyn Snapshottable>> {
+            vec![self.market_data_cache.clone(), self.order_cache.clone()]
+        }
     }
     
     impl Default for ModelService {
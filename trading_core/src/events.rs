@@ -0,0 +1,217 @@
+//! 트레이딩 이벤트 정의
+//!
+//! 신호와 매매 결정을 이벤트 버스로 발행하기 위한 이벤트입니다. 이벤트 ID는 신호·결정 ID와
+//! 같고, 값에 기록된 상관·인과 맥락이 헤더에 그대로 기록되므로 결정을 어디서 발행하든
+//! 인과 사슬(신호 → 매매 결정 → 주문 → 체결)이 이어집니다.
+
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use cryptolytica_shared_kernel::events::{Event, EventContext};
+use crate::models::{Signal, TradeDecision};
+
+/// 신호 생성 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignalGeneratedEvent {
+    /// 생성된 신호
+    pub signal: Signal,
+}
+
+impl SignalGeneratedEvent {
+    /// 이벤트 생성
+    pub fn new(signal: Signal) -> Self {
+        Self { signal }
+    }
+}
+
+impl Event for SignalGeneratedEvent {
+    fn event_type(&self) -> &'static str {
+        "trading.signal.generated"
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.signal.timestamp
+    }
+
+    fn id(&self) -> &Uuid {
+        &self.signal.id
+    }
+
+    fn partition_key(&self) -> Option<String> {
+        Some(self.signal.symbol.to_string())
+    }
+
+    fn context(&self) -> Option<EventContext> {
+        self.signal.context
+    }
+}
+
+/// 매매 결정 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TradeDecisionMadeEvent {
+    /// 매매 결정
+    pub decision: TradeDecision,
+}
+
+impl TradeDecisionMadeEvent {
+    /// 이벤트 생성
+    pub fn new(decision: TradeDecision) -> Self {
+        Self { decision }
+    }
+}
+
+impl Event for TradeDecisionMadeEvent {
+    fn event_type(&self) -> &'static str {
+        "trading.decision.made"
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.decision.timestamp
+    }
+
+    fn id(&self) -> &Uuid {
+        &self.decision.id
+    }
+
+    fn partition_key(&self) -> Option<String> {
+        Some(self.decision.symbol.to_string())
+    }
+
+    fn context(&self) -> Option<EventContext> {
+        self.decision.context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use cryptolytica_shared_kernel::events::{envelope_of, CausalityIndex, EventEnvelope};
+    use cryptolytica_shared_kernel::types::SymbolPair;
+    use serde_json::Value;
+    use crate::strategy::tests::create_test_candles;
+    use crate::strategy::{MovingAverageCrossoverStrategy, Strategy};
+
+    /// 버스 밖 단계(캔들 마감, 주문, 체결)를 흉내 내는 이벤트
+    #[derive(Debug, Clone, Serialize)]
+    struct Step {
+        id: Uuid,
+        timestamp: DateTime<Utc>,
+        event_type: &'static str,
+    }
+
+    impl Step {
+        fn new(event_type: &'static str) -> Self {
+            Self {
+                id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+                event_type,
+            }
+        }
+    }
+
+    impl Event for Step {
+        fn event_type(&self) -> &'static str {
+            self.event_type
+        }
+
+        fn timestamp(&self) -> DateTime<Utc> {
+            self.timestamp
+        }
+
+        fn id(&self) -> &Uuid {
+            &self.id
+        }
+    }
+
+    /// 봉투를 처리하는 핸들러 안에서 다음 단계 이벤트를 발행
+    fn handled(cause: &EventEnvelope<Value>, next: &'static str) -> EventEnvelope<Value> {
+        let _context = EventContext::from_header(&cause.header).handling(cause.header.id).enter();
+        envelope_of(&Step::new(next), "trading-core").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_decision_event_continues_signal_chain() {
+        let mut strategy = MovingAverageCrossoverStrategy::new(
+            "Test MA Crossover",
+            SymbolPair::new("BTC", "USDT"),
+            5,
+            10,
+            dec!(0.1),
+            Some(dec!(0.05)),
+            Some(dec!(0.15)),
+        );
+        strategy.initialize().await.unwrap();
+
+        // 캔들 마감 이벤트를 처리하는 동안 신호 생성
+        let candle = envelope_of(&Step::new("market.candle.closed"), "market-data").unwrap();
+        let signal = EventContext::from_header(&candle.header)
+            .handling(candle.header.id)
+            .scope(strategy.generate_signal(&create_test_candles()))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(signal.context, Some(EventContext::new(candle.header.id, Some(candle.header.id))));
+        let signal = envelope_of(&SignalGeneratedEvent::new(signal), "trading-core").unwrap();
+
+        // 결정은 맥락 밖에서 내리고, 무관한 이벤트를 처리하는 중에 발행해도 신호 흐름을 이음
+        let signal_value: Signal = serde_json::from_value(signal.payload.clone()).unwrap();
+        let decision = strategy.decide_entry(&signal_value, None).await.unwrap().unwrap();
+        let decision = {
+            let _unrelated = EventContext::new(Uuid::new_v4(), Some(Uuid::new_v4())).enter();
+            envelope_of(&TradeDecisionMadeEvent::new(decision), "trading-core").unwrap()
+        };
+        assert_eq!(decision.header.correlation_id, Some(candle.header.id));
+        assert_eq!(decision.header.causation_id, Some(signal.header.id));
+
+        // 결정 페이로드의 맥락은 직렬화를 거쳐도 유지
+        let decoded: TradeDecision = serde_json::from_value(decision.payload.clone()).unwrap();
+        assert_eq!(decoded.context, Some(signal_value.decision_context()));
+
+        let order = handled(&decision, "exchange.order.created");
+        let fill = handled(&order, "exchange.order.filled");
+
+        let index = CausalityIndex::new();
+        for envelope in [&fill, &decision, &candle, &order, &signal] {
+            index.record(&envelope.header);
+        }
+        let path: Vec<_> = index.ancestors(&fill.header.id).into_iter().map(|link| link.event_type).collect();
+        assert_eq!(
+            path,
+            [
+                "market.candle.closed",
+                "trading.signal.generated",
+                "trading.decision.made",
+                "exchange.order.created",
+                "exchange.order.filled"
+            ]
+        );
+        let chain = index.chain(&decision.header.id);
+        assert_eq!(chain.len(), 5);
+        assert!(chain.iter().all(|node| node.link.correlation_id == candle.header.id));
+    }
+
+    #[test]
+    fn test_signal_without_context_starts_flow() {
+        let signal = Signal {
+            id: Uuid::new_v4(),
+            strategy_id: Uuid::new_v4(),
+            symbol: SymbolPair::new("ETH", "USDT"),
+            timestamp: Utc::now(),
+            side: cryptolytica_exchange_core::models::OrderSide::Sell,
+            strength: crate::models::SignalStrength::Medium,
+            price: None,
+            expiration: None,
+            metadata: serde_json::json!({}),
+            context: None,
+        };
+
+        let header = envelope_of(&SignalGeneratedEvent::new(signal.clone()), "trading-core").unwrap().header;
+        assert_eq!(header.correlation_id, Some(signal.id));
+        assert_eq!(header.causation_id, None);
+        assert_eq!(signal.decision_context(), EventContext::new(signal.id, Some(signal.id)));
+    }
+}
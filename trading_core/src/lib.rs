@@ -4,6 +4,7 @@
 //! 위한 핵심 기능들을 제공합니다.
 
 pub mod strategy;
+pub mod events;
 pub mod backtest;
 pub mod execution;
 pub mod risk;
@@ -7,8 +7,10 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use std::fmt;
 
-use cryptolytica_common_core::types::SymbolPair;
+use cryptolytica_common_core::types::Decimal;
 use cryptolytica_exchange_core::models::OrderSide;
+use cryptolytica_shared_kernel::events::EventContext;
+use cryptolytica_shared_kernel::types::{ContractMargin, Instrument, InstrumentKind, SymbolPair};
 
 /// 전략 타입
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -113,11 +115,23 @@ pub struct Signal {
     /// 신호 강도
     pub strength: SignalStrength,
     /// 추천 가격 (옵션)
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     /// 신호 만료 시간 (옵션)
     pub expiration: Option<DateTime<Utc>>,
     /// 추가 메타데이터
     pub metadata: serde_json::Value,
+    /// 신호를 만든 이벤트 흐름의 상관·인과 맥락 (없으면 신호가 흐름의 시작)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<EventContext>,
+}
+
+impl Signal {
+    /// 이 신호에서 나온 매매 결정의 맥락 (신호 흐름을 잇고 원인은 신호)
+    pub fn decision_context(&self) -> EventContext {
+        self.context
+            .unwrap_or_else(|| EventContext::new(self.id, None))
+            .handling(self.id)
+    }
 }
 
 /// 트레이딩 액션
@@ -168,17 +182,20 @@ pub struct TradeDecision {
     /// 액션 유형
     pub action: TradeAction,
     /// 예상 가격
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     /// 수량
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     /// 결정 이유
     pub reason: String,
     /// 손절 수준 (비율)
-    pub stop_loss: Option<f64>,
+    pub stop_loss: Option<Decimal>,
     /// 이익실현 수준 (비율)
-    pub take_profit: Option<f64>,
+    pub take_profit: Option<Decimal>,
     /// 추가 메타데이터
     pub metadata: serde_json::Value,
+    /// 결정의 상관·인과 맥락 (진입 결정은 신호를 원인으로 가짐)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<EventContext>,
 }
 
 /// 포지션 상태
@@ -213,63 +230,92 @@ pub struct Position {
     pub signal_id: Uuid,
     /// 거래 쌍
     pub symbol: SymbolPair,
+    /// 거래 상품 (파생상품 포지션의 계약 정보, 없으면 현물로 취급)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<Instrument>,
     /// 매수/매도 방향
     pub side: OrderSide,
     /// 포지션 상태
     pub status: PositionStatus,
     /// 진입 가격
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     /// 포지션 수량
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// 현재 시장 가격
-    pub current_price: Option<f64>,
+    pub current_price: Option<Decimal>,
     /// 손절가
-    pub stop_loss: Option<f64>,
+    pub stop_loss: Option<Decimal>,
     /// 이익실현가
-    pub take_profit: Option<f64>,
+    pub take_profit: Option<Decimal>,
     /// 진입 시간
     pub entry_time: Option<DateTime<Utc>>,
     /// 종료 시간
     pub exit_time: Option<DateTime<Utc>>,
     /// 종료 가격
-    pub exit_price: Option<f64>,
-    /// 미실현 손익 (달러)
-    pub unrealized_pnl: Option<f64>,
-    /// 실현 손익 (달러)
-    pub realized_pnl: Option<f64>,
+    pub exit_price: Option<Decimal>,
+    /// 미실현 손익 (정산 통화 기준)
+    pub unrealized_pnl: Option<Decimal>,
+    /// 실현 손익 (정산 통화 기준)
+    pub realized_pnl: Option<Decimal>,
     /// 포지션 비용 (수수료 등)
-    pub costs: f64,
+    pub costs: Decimal,
     /// 포지션 수익률 (%)
-    pub profit_percentage: Option<f64>,
+    pub profit_percentage: Option<Decimal>,
     /// 추가 메타데이터
     pub metadata: serde_json::Value,
 }
 
 impl Position {
     /// 포지션의 현재 손익 계산
-    pub fn calculate_pnl(&self) -> Option<f64> {
+    ///
+    /// 거래 상품이 지정되어 있으면 계약 크기를 반영하고, 역방향 선물은
+    /// 명목 가치 차이(기본 자산 기준)로 계산합니다. 옵션은 증거금 방식과 무관하게
+    /// `(현재 프리미엄 - 진입 프리미엄) × 수량 × 계약 크기`를 정산 통화로 계산합니다.
+    /// 오버플로가 발생하면 `None`을 반환합니다.
+    pub fn calculate_pnl(&self) -> Option<Decimal> {
         let current_price = self.current_price?;
         
-        match self.side {
-            OrderSide::Buy => Some((current_price - self.entry_price) * self.quantity - self.costs),
-            OrderSide::Sell => Some((self.entry_price - current_price) * self.quantity - self.costs),
-        }
+        let gross = match self.instrument.as_ref().map(|i| (i, i.kind())) {
+            Some((instrument, InstrumentKind::Perpetual { margin: ContractMargin::Inverse }))
+            | Some((instrument, InstrumentKind::Future { margin: ContractMargin::Inverse, .. })) => {
+                let entry_value = instrument.notional_value(self.entry_price, self.quantity).ok()?;
+                let current_value = instrument.notional_value(current_price, self.quantity).ok()?;
+                match self.side {
+                    OrderSide::Buy => entry_value.checked_sub(current_value)?,
+                    OrderSide::Sell => current_value.checked_sub(entry_value)?,
+                }
+            }
+            _ => {
+                // 현물, 선형 선물, 옵션(가격 = 프리미엄)
+                let price_diff = match self.side {
+                    OrderSide::Buy => current_price.checked_sub(self.entry_price)?,
+                    OrderSide::Sell => self.entry_price.checked_sub(current_price)?,
+                };
+                let contract_size = self.instrument.as_ref().map_or(Decimal::ONE, |i| i.contract_size());
+                price_diff.checked_mul(self.quantity)?.checked_mul(contract_size)?
+            }
+        };
+        
+        gross.checked_sub(self.costs)
     }
     
     /// 포지션 수익률 계산
-    pub fn calculate_profit_percentage(&self) -> Option<f64> {
+    pub fn calculate_profit_percentage(&self) -> Option<Decimal> {
         let pnl = self.calculate_pnl()?;
-        let investment = self.entry_price * self.quantity;
+        let investment = match &self.instrument {
+            Some(instrument) => instrument.notional_value(self.entry_price, self.quantity).ok()?,
+            None => self.entry_price.checked_mul(self.quantity)?,
+        };
         
-        if investment == 0.0 {
+        if investment.is_zero() {
             return None;
         }
         
-        Some((pnl / investment) * 100.0)
+        pnl.checked_div(investment)?.checked_mul(Decimal::ONE_HUNDRED)
     }
     
     /// 포지션 업데이트
-    pub fn update_with_price(&mut self, current_price: f64) -> Option<f64> {
+    pub fn update_with_price(&mut self, current_price: Decimal) -> Option<Decimal> {
         self.current_price = Some(current_price);
         let pnl = self.calculate_pnl()?;
         self.unrealized_pnl = Some(pnl);
@@ -300,11 +346,11 @@ impl Position {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BacktestMetrics {
     /// 시작 자본
-    pub initial_capital: f64,
+    pub initial_capital: Decimal,
     /// 최종 자본
-    pub final_capital: f64,
+    pub final_capital: Decimal,
     /// 순 수익
-    pub net_profit: f64,
+    pub net_profit: Decimal,
     /// 수익률 (%)
     pub profit_percentage: f64,
     /// 연간 수익률 (%)
@@ -338,6 +384,9 @@ pub struct BacktestMetrics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+    use cryptolytica_shared_kernel::types::{ExerciseStyle, OptionType};
+    use rust_decimal_macros::dec;
     
     #[test]
     fn test_signal_strength_ordering() {
@@ -354,26 +403,27 @@ mod tests {
             strategy_id: Uuid::new_v4(),
             signal_id: Uuid::new_v4(),
             symbol: SymbolPair::new("BTC", "USDT"),
+            instrument: None,
             side: OrderSide::Buy,
             status: PositionStatus::Active,
-            entry_price: 40000.0,
-            quantity: 0.1,
-            current_price: Some(42000.0),
-            stop_loss: Some(39000.0),
-            take_profit: Some(45000.0),
+            entry_price: dec!(40000),
+            quantity: dec!(0.1),
+            current_price: Some(dec!(42000)),
+            stop_loss: Some(dec!(39000)),
+            take_profit: Some(dec!(45000)),
             entry_time: Some(Utc::now()),
             exit_time: None,
             exit_price: None,
             unrealized_pnl: None,
             realized_pnl: None,
-            costs: 10.0,
+            costs: dec!(10),
             profit_percentage: None,
             metadata: serde_json::json!({}),
         };
         
         // 계산
         let pnl = long_position.calculate_pnl().unwrap();
-        assert_eq!(pnl, (42000.0 - 40000.0) * 0.1 - 10.0);
+        assert_eq!(pnl, dec!(190));
         
         // 반대 방향 테스트
         let mut short_position = Position {
@@ -381,31 +431,135 @@ mod tests {
             strategy_id: Uuid::new_v4(),
             signal_id: Uuid::new_v4(),
             symbol: SymbolPair::new("BTC", "USDT"),
+            instrument: None,
             side: OrderSide::Sell,
             status: PositionStatus::Active,
-            entry_price: 40000.0,
-            quantity: 0.1,
-            current_price: Some(38000.0),
-            stop_loss: Some(41000.0),
-            take_profit: Some(35000.0),
+            entry_price: dec!(40000),
+            quantity: dec!(0.1),
+            current_price: Some(dec!(38000)),
+            stop_loss: Some(dec!(41000)),
+            take_profit: Some(dec!(35000)),
             entry_time: Some(Utc::now()),
             exit_time: None,
             exit_price: None,
             unrealized_pnl: None,
             realized_pnl: None,
-            costs: 10.0,
+            costs: dec!(10),
             profit_percentage: None,
             metadata: serde_json::json!({}),
         };
         
         // 계산
         let pnl = short_position.calculate_pnl().unwrap();
-        assert_eq!(pnl, (40000.0 - 38000.0) * 0.1 - 10.0);
+        assert_eq!(pnl, dec!(190));
         
         // 업데이트 테스트
-        let trigger_result = long_position.update_with_price(45000.0);
+        let trigger_result = long_position.update_with_price(dec!(45000));
         assert!(trigger_result.is_some()); // 이익실현 발동
-        assert_eq!(long_position.profit_percentage.unwrap(), 
-            ((45000.0 - 40000.0) * 0.1 - 10.0) / (40000.0 * 0.1) * 100.0);
+        // (5000 * 0.1 - 10) / (40000 * 0.1) * 100 = 12.25%
+        assert_eq!(long_position.profit_percentage.unwrap(), dec!(12.25));
+    }
+    
+    #[test]
+    fn test_inverse_perpetual_pnl() {
+        // 계약 1개 = 100 USD, BTC 정산
+        let instrument = Instrument::perpetual(
+            SymbolPair::new("BTC", "USD"),
+            ContractMargin::Inverse,
+        )
+        .with_contract_size(dec!(100))
+        .unwrap();
+        
+        let position = Position {
+            id: Uuid::new_v4(),
+            strategy_id: Uuid::new_v4(),
+            signal_id: Uuid::new_v4(),
+            symbol: SymbolPair::new("BTC", "USD"),
+            instrument: Some(instrument),
+            side: OrderSide::Buy,
+            status: PositionStatus::Active,
+            entry_price: dec!(40000),
+            quantity: dec!(100),
+            current_price: Some(dec!(50000)),
+            stop_loss: None,
+            take_profit: None,
+            entry_time: Some(Utc::now()),
+            exit_time: None,
+            exit_price: None,
+            unrealized_pnl: None,
+            realized_pnl: None,
+            costs: Decimal::ZERO,
+            profit_percentage: None,
+            metadata: serde_json::json!({}),
+        };
+        
+        // 10000 USD / 40000 - 10000 USD / 50000 = 0.25 - 0.2 = 0.05 BTC
+        assert_eq!(position.calculate_pnl().unwrap(), dec!(0.05));
+        assert_eq!(position.calculate_profit_percentage().unwrap(), dec!(20));
     }
-} 
\ No newline at end of file
+    
+    fn option_position(instrument: Instrument, side: OrderSide, entry: Decimal, current: Decimal) -> Position {
+        Position {
+            id: Uuid::new_v4(),
+            strategy_id: Uuid::new_v4(),
+            signal_id: Uuid::new_v4(),
+            symbol: instrument.pair().clone(),
+            instrument: Some(instrument),
+            side,
+            status: PositionStatus::Active,
+            entry_price: entry,
+            quantity: dec!(10),
+            current_price: Some(current),
+            stop_loss: None,
+            take_profit: None,
+            entry_time: Some(Utc::now()),
+            exit_time: None,
+            exit_price: None,
+            unrealized_pnl: None,
+            realized_pnl: None,
+            costs: Decimal::ZERO,
+            profit_percentage: None,
+            metadata: serde_json::json!({}),
+        }
+    }
+    
+    fn btc_call(margin: ContractMargin) -> Instrument {
+        Instrument::option(
+            SymbolPair::new("BTC", "USD"),
+            margin,
+            Utc.with_ymd_and_hms(2024, 12, 27, 8, 0, 0).unwrap(),
+            dec!(60000),
+            OptionType::Call,
+            ExerciseStyle::European,
+        )
+        .with_contract_size(dec!(0.1))
+        .unwrap()
+    }
+    
+    #[test]
+    fn test_inverse_option_pnl_uses_premium_difference() {
+        // BTC 정산 옵션: 프리미엄도 BTC로 표시
+        let instrument = btc_call(ContractMargin::Inverse);
+        assert_eq!(instrument.settlement(), "BTC");
+        
+        // (0.08 - 0.05) × 10 × 0.1 = 0.003 BTC
+        let long = option_position(instrument.clone(), OrderSide::Buy, dec!(0.05), dec!(0.08));
+        assert_eq!(long.calculate_pnl().unwrap(), dec!(0.003));
+        // 진입 프리미엄 0.05 × 10 × 0.1 = 0.05 BTC 대비 6%
+        assert_eq!(long.calculate_profit_percentage().unwrap(), dec!(6));
+        
+        let short = option_position(instrument, OrderSide::Sell, dec!(0.05), dec!(0.08));
+        assert_eq!(short.calculate_pnl().unwrap(), dec!(-0.003));
+    }
+    
+    #[test]
+    fn test_linear_option_pnl_uses_premium_difference() {
+        let instrument = btc_call(ContractMargin::Linear);
+        assert_eq!(instrument.settlement(), "USD");
+        
+        // (1500 - 2000) × 10 × 0.1 = -500 USD
+        let mut long = option_position(instrument, OrderSide::Buy, dec!(2000), dec!(1500));
+        long.costs = dec!(5);
+        assert_eq!(long.calculate_pnl().unwrap(), dec!(-505));
+    }
+}
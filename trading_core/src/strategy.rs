@@ -7,8 +7,12 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use cryptolytica_common_core::types::{SymbolPair, Timeframe, Candle};
+use cryptolytica_common_core::types::{self as common, Decimal, Timeframe, Candle};
 use cryptolytica_exchange_core::models::OrderSide;
+use cryptolytica_shared_kernel::clock::{system_clock, SharedClock};
+use cryptolytica_shared_kernel::events::EventContext;
+use cryptolytica_shared_kernel::id::IdGenerator;
+use cryptolytica_shared_kernel::types::SymbolPair;
 use crate::error::Result;
 use crate::models::{Signal, Position, TradeDecision, SignalStrength, StrategyType, StrategyState};
 
@@ -66,11 +70,11 @@ pub struct StrategyParameters {
     /// 지표 설정
     pub indicators: serde_json::Value,
     /// 포지션 사이즈 (0.0 ~ 1.0 사이의 비율)
-    pub position_size: f64,
+    pub position_size: Decimal,
     /// 스톱로스 비율 (0.0 ~ 1.0 사이의 비율)
-    pub stop_loss: Option<f64>,
+    pub stop_loss: Option<Decimal>,
     /// 테이크프로핏 비율 (0.0 ~ 1.0 사이의 비율)
-    pub take_profit: Option<f64>,
+    pub take_profit: Option<Decimal>,
     /// 최대 동시 포지션 수
     pub max_positions: u32,
     /// 기타 설정
@@ -85,9 +89,13 @@ pub struct MovingAverageCrossoverStrategy {
     state: StrategyState,
     fast_period: u32,
     slow_period: u32,
-    fast_ma: Vec<f64>,
-    slow_ma: Vec<f64>,
+    fast_ma: Vec<Decimal>,
+    slow_ma: Vec<Decimal>,
     last_updated: Option<DateTime<Utc>>,
+    /// 신호/결정 시각에 사용하는 시계 (백테스트 시 시뮬레이션 시계 주입)
+    clock: SharedClock,
+    /// 신호/결정 ID 생성기 (시계 기준 시간순)
+    ids: IdGenerator,
 }
 
 impl MovingAverageCrossoverStrategy {
@@ -96,9 +104,9 @@ impl MovingAverageCrossoverStrategy {
         symbol: SymbolPair,
         fast_period: u32,
         slow_period: u32,
-        position_size: f64,
-        stop_loss: Option<f64>,
-        take_profit: Option<f64>,
+        position_size: Decimal,
+        stop_loss: Option<Decimal>,
+        take_profit: Option<Decimal>,
     ) -> Self {
         let indicators = serde_json::json!({
             "fast_period": fast_period,
@@ -125,20 +133,29 @@ impl MovingAverageCrossoverStrategy {
             fast_ma: Vec::new(),
             slow_ma: Vec::new(),
             last_updated: None,
+            clock: system_clock(),
+            ids: IdGenerator::system(),
         }
     }
     
+    /// 시계 주입
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.ids = IdGenerator::new(clock.clone());
+        self.clock = clock;
+        self
+    }
+    
     /// 이동평균 계산
-    fn calculate_ma(&self, prices: &[f64], period: usize) -> Vec<f64> {
-        if prices.len() < period {
+    fn calculate_ma(&self, prices: &[Decimal], period: usize) -> Vec<Decimal> {
+        if period == 0 || prices.len() < period {
             return Vec::new();
         }
         
         let mut result = Vec::with_capacity(prices.len() - period + 1);
         
         for i in 0..=prices.len() - period {
-            let sum: f64 = prices[i..i+period].iter().sum();
-            result.push(sum / period as f64);
+            let sum: Decimal = prices[i..i+period].iter().sum();
+            result.push(sum / Decimal::from(period));
         }
         
         result
@@ -158,10 +175,10 @@ impl MovingAverageCrossoverStrategy {
         // 골든 크로스 (빠른 이평선이 느린 이평선을 상향 돌파)
         if fast_prev <= slow_prev && fast_curr > slow_curr {
             return Some(Signal {
-                id: Uuid::new_v4(),
+                id: self.ids.next_id(),
                 strategy_id: self.id,
                 symbol: self.params.symbol.clone(),
-                timestamp: Utc::now(),
+                timestamp: self.clock.now(),
                 side: OrderSide::Buy,
                 strength: SignalStrength::Strong,
                 price: None,
@@ -171,16 +188,17 @@ impl MovingAverageCrossoverStrategy {
                     "fast_ma": fast_curr,
                     "slow_ma": slow_curr,
                 }),
+                context: EventContext::current(),
             });
         }
         
         // 데드 크로스 (빠른 이평선이 느린 이평선을 하향 돌파)
         if fast_prev >= slow_prev && fast_curr < slow_curr {
             return Some(Signal {
-                id: Uuid::new_v4(),
+                id: self.ids.next_id(),
                 strategy_id: self.id,
                 symbol: self.params.symbol.clone(),
-                timestamp: Utc::now(),
+                timestamp: self.clock.now(),
                 side: OrderSide::Sell,
                 strength: SignalStrength::Strong,
                 price: None,
@@ -190,6 +208,7 @@ impl MovingAverageCrossoverStrategy {
                     "fast_ma": fast_curr,
                     "slow_ma": slow_curr,
                 }),
+                context: EventContext::current(),
             });
         }
         
@@ -229,7 +248,7 @@ impl Strategy for MovingAverageCrossoverStrategy {
     }
     
     async fn update(&mut self, candle: &Candle) -> Result<Option<Signal>> {
-        if candle.symbol != self.params.symbol {
+        if to_kernel_pair(&candle.symbol) != self.params.symbol {
             return Err(crate::error::TradingError::StrategyError(
                 format!("심볼 불일치: 예상 {}, 받음 {}", 
                     self.params.symbol.to_string(), 
@@ -261,7 +280,7 @@ impl Strategy for MovingAverageCrossoverStrategy {
             ));
         }
         
-        if candles[0].symbol != self.params.symbol {
+        if to_kernel_pair(&candles[0].symbol) != self.params.symbol {
             return Err(crate::error::TradingError::StrategyError(
                 format!("심볼 불일치: 예상 {}, 받음 {}", 
                     self.params.symbol.to_string(), 
@@ -271,7 +290,7 @@ impl Strategy for MovingAverageCrossoverStrategy {
         }
         
         // 이동평균 계산을 위해 종가만 추출
-        let prices: Vec<f64> = candles.iter().map(|c| c.close).collect();
+        let prices: Vec<Decimal> = candles.iter().map(|c| c.close).collect();
         
         if prices.len() < self.slow_period as usize {
             return Err(crate::error::TradingError::InsufficientDataError(
@@ -299,7 +318,7 @@ impl Strategy for MovingAverageCrossoverStrategy {
         // 골든 크로스 (빠른 이평선이 느린 이평선을 상향 돌파)
         if fast_prev <= slow_prev && fast_curr > slow_curr {
             return Ok(Some(Signal {
-                id: Uuid::new_v4(),
+                id: self.ids.next_id(),
                 strategy_id: self.id,
                 symbol: self.params.symbol.clone(),
                 timestamp: candles.last().unwrap().timestamp,
@@ -312,13 +331,14 @@ impl Strategy for MovingAverageCrossoverStrategy {
                     "fast_ma": fast_curr,
                     "slow_ma": slow_curr,
                 }),
+                context: EventContext::current(),
             }));
         }
         
         // 데드 크로스 (빠른 이평선이 느린 이평선을 하향 돌파)
         if fast_prev >= slow_prev && fast_curr < slow_curr {
             return Ok(Some(Signal {
-                id: Uuid::new_v4(),
+                id: self.ids.next_id(),
                 strategy_id: self.id,
                 symbol: self.params.symbol.clone(),
                 timestamp: candles.last().unwrap().timestamp,
@@ -331,6 +351,7 @@ impl Strategy for MovingAverageCrossoverStrategy {
                     "fast_ma": fast_curr,
                     "slow_ma": slow_curr,
                 }),
+                context: EventContext::current(),
             }));
         }
         
@@ -346,10 +367,10 @@ impl Strategy for MovingAverageCrossoverStrategy {
         // 신호가 충분히 강하면 진입
         if signal.strength >= SignalStrength::Medium {
             let decision = TradeDecision {
-                id: Uuid::new_v4(),
+                id: self.ids.next_id(),
                 strategy_id: self.id,
                 signal_id: signal.id,
-                timestamp: Utc::now(),
+                timestamp: self.clock.now(),
                 symbol: signal.symbol.clone(),
                 side: signal.side,
                 action: crate::models::TradeAction::Enter,
@@ -361,6 +382,7 @@ impl Strategy for MovingAverageCrossoverStrategy {
                 stop_loss: self.params.stop_loss,
                 take_profit: self.params.take_profit,
                 metadata: signal.metadata.clone(),
+                context: Some(signal.decision_context()),
             };
             
             return Ok(Some(decision));
@@ -392,11 +414,12 @@ impl Strategy for MovingAverageCrossoverStrategy {
         };
         
         if exit_signal {
+            let now = self.clock.now();
             let decision = TradeDecision {
-                id: Uuid::new_v4(),
+                id: self.ids.next_id(),
                 strategy_id: self.id,
                 signal_id: position.signal_id,
-                timestamp: Utc::now(),
+                timestamp: now,
                 symbol: position.symbol.clone(),
                 side: position.side.clone(),
                 action: crate::models::TradeAction::Exit,
@@ -408,9 +431,10 @@ impl Strategy for MovingAverageCrossoverStrategy {
                 metadata: serde_json::json!({
                     "exit_type": "signal_reversal",
                     "position_held_time": position.entry_time
-                        .map(|t| (Utc::now() - t).num_seconds())
+                        .map(|t| (now - t).num_seconds())
                         .unwrap_or(0),
                 }),
+                context: EventContext::current(),
             };
             
             return Ok(Some(decision));
@@ -457,37 +481,43 @@ impl Strategy for MovingAverageCrossoverStrategy {
     }
 }
 
+fn to_kernel_pair(symbol: &common::SymbolPair) -> SymbolPair {
+    SymbolPair::new(symbol.base.as_str(), symbol.quote.as_str())
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use chrono::TimeZone;
+    use rust_decimal_macros::dec;
     
-    fn create_test_candles() -> Vec<Candle> {
-        let symbol = SymbolPair::new("BTC", "USDT");
+    pub(crate) fn create_test_candles() -> Vec<Candle> {
+        let symbol = common::SymbolPair::new("BTC", "USDT");
         let base_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
         
         // 시간별 종가 데이터 (빠른 MA가 느린 MA를 상향 돌파하는 패턴)
         let closes = vec![
-            100.0, 101.0, 102.0, 103.0, 104.0, // 시작 데이터
-            103.0, 102.0, 101.0, 100.0, 99.0,  // 하락 트렌드
-            98.0, 97.0, 96.0, 95.0, 94.0,
-            93.0, 92.0, 93.0, 94.0, 95.0,      // 반등 시작
-            97.0, 99.0, 101.0, 103.0, 105.0,   // 상승 트렌드 (골든 크로스 예상)
-            106.0, 107.0, 108.0, 109.0, 110.0,
+            100, 101, 102, 103, 104, // 시작 데이터
+            103, 102, 101, 100, 99,  // 하락 트렌드
+            98, 97, 96, 95, 94,
+            93, 92, 93, 94, 95,      // 반등 시작
+            94, 93, 92, 91, 90,      // 재하락
+            89, 90, 91, 92, 110,     // 급등 (마지막 캔들에서 골든 크로스)
         ];
         
         let mut candles = Vec::with_capacity(closes.len());
         
-        for (i, close) in closes.iter().enumerate() {
+        for (i, close) in closes.into_iter().enumerate() {
             let time = base_time + chrono::Duration::hours(i as i64);
+            let close = Decimal::from(close);
             candles.push(Candle {
                 symbol: symbol.clone(),
                 timestamp: time,
-                open: *close - 1.0,
-                high: *close + 1.0,
-                low: *close - 2.0,
-                close: *close,
-                volume: 100.0 + (i as f64),
+                open: close - Decimal::ONE,
+                high: close + Decimal::ONE,
+                low: close - Decimal::TWO,
+                close,
+                volume: Decimal::from(100 + i),
             });
         }
         
@@ -502,9 +532,9 @@ mod tests {
             symbol.clone(),
             5,  // 빠른 이동평균 기간
             10, // 느린 이동평균 기간
-            0.1, // 포지션 사이즈
-            Some(0.05), // 5% 스톱로스
-            Some(0.15), // 15% 테이크프로핏
+            dec!(0.1), // 포지션 사이즈
+            Some(dec!(0.05)), // 5% 스톱로스
+            Some(dec!(0.15)), // 15% 테이크프로핏
         );
         
         strategy.initialize().await.unwrap();
@@ -524,5 +554,8 @@ mod tests {
         let decision = decision.unwrap();
         assert_eq!(decision.action, crate::models::TradeAction::Enter);
         assert_eq!(decision.side, OrderSide::Buy);
+        assert_eq!(decision.price, Some(dec!(110)));
+        assert_eq!(decision.stop_loss, Some(dec!(0.05)));
+        assert_eq!(decision.take_profit, Some(dec!(0.15)));
     }
 } 
\ No newline at end of file